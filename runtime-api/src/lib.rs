@@ -0,0 +1,51 @@
+//! Runtime API definition for the game registry pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_gameregistry::{
+	EngineDetails, GameEngine, GameEntry, GameRule, GameRuleType, GameState, RunningGame,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Api to query the game registry, without knowing its storage layout.
+	pub trait GameRegistryApi<AccountId, Hash, BlockNumber, Location> where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+		Location: Codec,
+	{
+		/// All registered game engines, with their rules, queue depth and TEE coverage.
+		fn engines() -> Vec<EngineDetails<GameRule<GameRuleType>>>;
+
+		/// All games a player is part of.
+		fn games_of(account: AccountId) -> Vec<Hash>;
+
+		/// Games an AjunaTEE acknowledged or is running.
+		fn tee_assignments(tee: AccountId) -> Vec<Hash>;
+
+		/// All games in a state, `GameState::Finished` matches finished games of any outcome.
+		fn games_by_state(game_state: GameState<AccountId>) -> Vec<Hash>;
+
+		/// The game entry of a game.
+		fn game(
+			game_hash: Hash,
+		) -> Option<GameEntry<Hash, AccountId, GameEngine, GameState<AccountId>, BlockNumber, Location>>;
+
+		/// Amount of waiting games in the game queue of each game engine.
+		fn queue_lengths() -> Vec<(GameEngine, u32)>;
+
+		/// Estimated queue-to-match time in blocks of each game engine and bracket.
+		fn estimated_waits() -> Vec<(GameEngine, u8, BlockNumber)>;
+
+		/// A page of the running games, `limit` is capped at 100 games.
+		fn running_games(offset: u32, limit: u32) -> Vec<RunningGame<Hash, AccountId, BlockNumber>>;
+
+		/// The game of a short match code like `AJN-4F7K`.
+		fn resolve_match_code(code: Vec<u8>) -> Option<Hash>;
+
+		/// The states a game moved into with the blocks it moved in, oldest first.
+		fn game_timeline(game_hash: Hash) -> Vec<(GameState<AccountId>, BlockNumber)>;
+	}
+}
@@ -4,16 +4,1462 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as GameRegistry;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{EnsureOrigin, Get, Hooks};
 use frame_system::RawOrigin;
+use sp_runtime::{traits::Bounded, KeyTypeId};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+/// Key type of the enclave signing keys generated in the keystore.
+const ENCLAVE_KEY: KeyTypeId = KeyTypeId(*b"encl");
+
+/// Account holding enough funds for all bonds and deposits.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+/// Game engine version registered in its own bracket, hosted by enough AjunaTEEs.
+fn registered_engine<T: Config>() -> GameEngine {
+	let game_engine = GameEngine::new(1, 1);
+	if !<SupportedEngines<T>>::contains_key(&game_engine) {
+		GameRegistry::<T>::do_register_game_engine(game_engine.clone(), 1)
+			.expect("engine is not registered yet; qed");
+		<EngineTeeCount<T>>::insert(&game_engine, T::MinTees::get());
+	}
+	game_engine
+}
+
+/// AjunaTEE registered for a game engine version, with a fresh attestation.
+fn registered_tee<T: Config>(game_engine: &GameEngine, index: u32) -> T::AccountId {
+	let tee = funded_account::<T>("tee", index);
+	<RegisteredTees<T>>::insert(
+		&tee,
+		TeeInfo {
+			supported_engines: BoundedVec::try_from(vec![game_engine.clone()])
+				.expect("a single game engine fits; qed"),
+			..Default::default()
+		},
+	);
+	<AttestedAt<T>>::insert(&tee, <frame_system::Pallet<T>>::block_number());
+	tee
+}
+
+/// Cluster of registered AjunaTEEs serving a game engine version, returns its operator.
+fn clustered_tees<T: Config>(game_engine: &GameEngine, tees: &[T::AccountId]) -> T::AccountId {
+	let operator: T::AccountId = account("operator", 0, SEED);
+	GameRegistry::<T>::register_cluster(
+		T::GovernanceOrigin::successful_origin(),
+		vec![game_engine.clone()],
+		operator.clone(),
+	)
+	.expect("governance registers clusters; qed");
+	for tee in tees {
+		GameRegistry::<T>::add_cluster_tee(
+			RawOrigin::Signed(operator.clone()).into(),
+			0,
+			tee.clone(),
+		)
+		.expect("tee is registered; qed");
+	}
+	operator
+}
+
+/// AjunaTEE with an enclave signing key in the keystore, with the function signing payloads
+/// with it.
+fn signing_tee<T: Config>(
+	game_engine: &GameEngine,
+) -> (T::AccountId, impl Fn(&[u8]) -> MultiSignature) {
+	let tee = registered_tee::<T>(game_engine, 0);
+	let public = sp_io::crypto::sr25519_generate(ENCLAVE_KEY, None);
+	<RegisteredTees<T>>::mutate(&tee, |tee_info| {
+		if let Some(tee_info) = tee_info {
+			tee_info.signing_key = Some(AccountId32::new(public.0));
+		}
+	});
+	let sign = move |payload: &[u8]| {
+		sp_io::crypto::sr25519_sign(ENCLAVE_KEY, &public, payload)
+			.expect("key was generated in the keystore; qed")
+			.into()
+	};
+	(tee, sign)
+}
+
+/// Game waiting for an AjunaTEE, between two funded players.
+fn waiting_game<T: Config>(game_engine: &GameEngine) -> (T::Hash, Vec<T::AccountId>) {
+	let index = <NextGameIndex<T>>::get() as u32 * 2;
+	let players: Vec<T::AccountId> =
+		(index..index + 2).map(|index| funded_account::<T>("player", index)).collect();
+	let game_hash =
+		GameRegistry::<T>::queue_game(game_engine.clone(), players.clone(), None, false, None)
+			.expect("game engine is registered; qed");
+	(game_hash, players)
+}
+
+/// Game running on an AjunaTEE.
+fn running_game<T: Config>(
+	game_engine: &GameEngine,
+	tee: &T::AccountId,
+) -> (T::Hash, Vec<T::AccountId>) {
+	let (game_hash, players) = waiting_game::<T>(game_engine);
+	GameRegistry::<T>::ack_game(
+		RawOrigin::Signed(tee.clone()).into(),
+		game_engine.clone(),
+		vec![game_hash],
+	)
+	.expect("game is next in the queue; qed");
+	GameRegistry::<T>::ready_game(RawOrigin::Signed(tee.clone()).into(), game_hash)
+		.expect("game is accepted; qed");
+	(game_hash, players)
+}
+
+/// Game finished by an AjunaTEE, won by its first player.
+fn finished_game<T: Config>(
+	game_engine: &GameEngine,
+	tee: &T::AccountId,
+) -> (T::Hash, Vec<T::AccountId>) {
+	let (game_hash, players) = running_game::<T>(game_engine, tee);
+	GameRegistry::<T>::finish_game(
+		RawOrigin::Signed(tee.clone()).into(),
+		game_hash,
+		GameOutcome::Winner(players[0].clone()),
+		T::Hash::default(),
+		None,
+		None,
+		None,
+	)
+	.expect("game is running; qed");
+	(game_hash, players)
+}
+
+/// Fill the game queue of a game engine version up to its maximum size.
+fn fill_game_queue<T: Config>(game_engine: &GameEngine) -> Vec<T::Hash> {
+	(0..T::MaxQueueSize::get()).map(|_| waiting_game::<T>(game_engine).0).collect()
+}
 
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
-		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+	set_settings {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let settings = PalletSettings { auto_matchmaking: false, ..Default::default() };
+	}: _<T::Origin>(origin, settings.clone())
+	verify {
+		assert_eq!(Settings::<T>::get(), settings);
+	}
+
+	pause {
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(Paused::<T>::get());
+	}
+
+	unpause {
+		let origin = T::GovernanceOrigin::successful_origin();
+		<Paused<T>>::put(true);
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(!Paused::<T>::get());
+	}
+
+	set_call_disabled {
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin, RegistryCall::Queue, true)
+	verify {
+		assert!(DisabledCalls::<T>::get(RegistryCall::Queue));
+	}
+
+	set_parameter {
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin, RegistryParameter::GamesPerBlock(1))
+	verify {
+		assert_eq!(GameRegistry::<T>::parameters().games_per_block, 1);
+	}
+
+	register_game_engine {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = GameEngine::new(1, 1);
+	}: _<T::Origin>(origin, game_engine.clone(), 1)
+	verify {
+		assert!(SupportedEngines::<T>::contains_key(&game_engine));
+	}
+
+	deprecate_game_engine {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+	}: _<T::Origin>(origin, game_engine.clone())
+	verify {
+		assert!(DeprecatedEngines::<T>::contains_key(&game_engine));
+	}
+
+	upgrade_game_engine {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let from = registered_engine::<T>();
+		let to = GameEngine::new(1, 2);
+		GameRegistry::<T>::do_register_game_engine(to.clone(), 2)?;
+		fill_game_queue::<T>(&from);
+	}: _<T::Origin>(origin, from.clone(), to.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::game_queues(&to).length(), T::MaxQueueSize::get());
+	}
+
+	set_game_rules {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let game_rules = vec![GameRule::new(GameRuleType::MinFinishedGames(1), [0; 16])];
+	}: _<T::Origin>(origin, game_engine.clone(), game_rules.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::game_requirements(&game_engine).to_vec(), game_rules);
+	}
+
+	set_game_rule {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let game_rule = GameRule::new(GameRuleType::MinRating(1_600), [0; 16]);
+	}: _<T::Origin>(origin, game_engine.clone(), game_rule.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::game_requirements(&game_engine).to_vec(), vec![game_rule]);
+	}
+
+	clear_game_rules {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		GameRegistry::<T>::set_game_rules(
+			T::GovernanceOrigin::successful_origin(),
+			game_engine.clone(),
+			vec![GameRule::new(GameRuleType::MinFinishedGames(1), [0; 16])],
+		)?;
+	}: _<T::Origin>(origin, game_engine.clone())
+	verify {
+		assert!(GameRegistry::<T>::game_requirements(&game_engine).is_empty());
+	}
+
+	set_engine_spectator_settings {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let settings = SpectatorSettings {
+			policy: SpectatorPolicy::Invited,
+			max_spectators: T::MaxSpectators::get(),
+		};
+	}: _<T::Origin>(origin, game_engine.clone(), settings)
+	verify {
+		assert!(EngineSpectatorSettings::<T>::contains_key(&game_engine));
+	}
+
+	set_max_games_per_player {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+	}: _<T::Origin>(origin, game_engine.clone(), Some(T::MaxGamesPerPlayer::get() + 1))
+	verify {
+		assert_eq!(
+			Pallet::<T>::max_games_per_player(&game_engine),
+			T::MaxGamesPerPlayer::get() + 1
+		);
+	}
+
+	set_engine_client_versions {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let client_versions = ClientVersions { min: 1, max: u16::MAX };
+	}: _<T::Origin>(origin, game_engine.clone(), Some(client_versions))
+	verify {
+		assert_eq!(Pallet::<T>::engine_client_versions(&game_engine), Some(client_versions));
+	}
+
+	set_engine_stake {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+	}: _<T::Origin>(origin, game_engine.clone(), Some(T::QueueBond::get()))
+	verify {
+		assert!(EngineStakes::<T>::contains_key(&game_engine));
+	}
+
+	add_time_control {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let time_control = TimeControl::new(*b"blitz   ", 180, 2, 2);
+	}: _<T::Origin>(origin, game_engine.clone(), time_control)
+	verify {
+		assert_eq!(GameRegistry::<T>::time_controls(&game_engine).len(), 1);
+	}
+
+	register_tee {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let tee: T::AccountId = account("tee", 0, SEED);
+		// without an attestation module in the runtime, dev mode attests the AjunaTEE
+		<DevMode<T>>::put(true);
+	}: _<T::Origin>(origin, tee.clone(), vec![game_engine], None)
+	verify {
+		assert!(RegisteredTees::<T>::contains_key(&tee));
+	}
+
+	update_tee_engines {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let upgraded = GameEngine::new(1, 2);
+	}: _(RawOrigin::Signed(tee.clone()), vec![game_engine, upgraded.clone()])
+	verify {
+		assert_eq!(GameRegistry::<T>::engine_tee_count(&upgraded), 1);
+	}
+
+	set_tee_endpoint {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let endpoint = vec![b'a'; MAX_ENDPOINT_LENGTH as usize];
+	}: _(RawOrigin::Signed(tee.clone()), endpoint.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::registered_tees(&tee).unwrap().endpoint, endpoint);
+	}
+
+	reattest_tee {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		<DevMode<T>>::put(true);
+	}: _(RawOrigin::Signed(tee.clone()), None)
+	verify {
+		assert!(AttestedAt::<T>::contains_key(&tee));
+	}
+
+	bond_tee {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let amount = T::HighValueTeeBond::get();
+	}: _(RawOrigin::Signed(tee.clone()), amount)
+	verify {
+		assert_eq!(GameRegistry::<T>::tee_bonds(&tee), amount);
+	}
+
+	unbond_tee {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let amount = T::HighValueTeeBond::get();
+		GameRegistry::<T>::bond_tee(RawOrigin::Signed(tee.clone()).into(), amount)?;
+	}: _(RawOrigin::Signed(tee.clone()), amount)
+	verify {
+		assert!(!TeeBonds::<T>::contains_key(&tee));
+	}
+
+	deregister_tee {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		GameRegistry::<T>::bond_tee(
+			RawOrigin::Signed(tee.clone()).into(),
+			T::HighValueTeeBond::get(),
+		)?;
+		clustered_tees::<T>(&game_engine, &[tee.clone()]);
+	}: _<T::Origin>(origin, tee.clone())
+	verify {
+		assert!(!RegisteredTees::<T>::contains_key(&tee));
+		assert!(GameRegistry::<T>::clusters(0).unwrap().tees.is_empty());
+	}
+
+	register_cluster {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let operator: T::AccountId = account("operator", 0, SEED);
+	}: _<T::Origin>(origin, vec![game_engine], operator.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::clusters(0).unwrap().operator, operator);
+	}
+
+	add_cluster_tee {
+		let game_engine = registered_engine::<T>();
+		let operator = clustered_tees::<T>(&game_engine, &[]);
+		let tee = registered_tee::<T>(&GameEngine::new(1, 2), 0);
+	}: _(RawOrigin::Signed(operator), 0, tee.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::tee_cluster(&tee), Some(0));
+	}
+
+	remove_cluster_tee {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let operator = clustered_tees::<T>(&game_engine, &[tee.clone()]);
+	}: _(RawOrigin::Signed(operator), 0, tee.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::tee_cluster(&tee), None);
+	}
+
+	set_cluster_engines {
+		let t in 0 .. MAX_CLUSTER_TEES;
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let tees: Vec<_> = (0..t).map(|index| registered_tee::<T>(&game_engine, index)).collect();
+		clustered_tees::<T>(&game_engine, &tees);
+		let upgraded = GameEngine::new(1, 2);
+	}: _<T::Origin>(origin, 0, vec![upgraded.clone()])
+	verify {
+		assert_eq!(GameRegistry::<T>::engine_tee_count(&upgraded), t);
+	}
+
+	drain_game_queue {
+		let origin = T::OperatorOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		fill_game_queue::<T>(&game_engine);
+	}: _<T::Origin>(origin, game_engine.clone())
+	verify {
+		assert!(!GameQueues::<T>::contains_key(&game_engine));
+		assert!(!PriorityGameQueues::<T>::contains_key(&game_engine));
+	}
+
+	schedule_maintenance {
+		let origin = T::OperatorOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let start = <frame_system::Pallet<T>>::block_number() + 1u32.into();
+	}: _<T::Origin>(origin, game_engine.clone(), start, 10u32.into())
+	verify {
+		assert!(!GameRegistry::<T>::in_maintenance(&game_engine));
+	}
+
+	set_engine_maintenance {
+		let game_engine = registered_engine::<T>();
+	}: _(RawOrigin::Root, game_engine.clone(), true)
+	verify {
+		assert!(GameRegistry::<T>::in_maintenance(&game_engine));
+	}
+
+	set_engine_paused {
+		let origin = T::OperatorOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+	}: _<T::Origin>(origin, game_engine.clone(), true)
+	verify {
+		assert!(GameRegistry::<T>::paused_engines(&game_engine));
+	}
+
+	set_engine_init_data {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let init_data = vec![0; T::MaxInitData::get() as usize];
+	}: _<T::Origin>(origin, game_engine.clone(), Some(init_data))
+	verify {
+		assert!(EngineInitData::<T>::contains_key(&game_engine));
+	}
+
+	ban_account {
+		// running games the account forfeits
+		let g in 0 .. T::MaxGamesPerPlayer::get();
+		let origin = T::ModerationOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let player = funded_account::<T>("banned", 0);
+		let mut games = Vec::new();
+		for index in 0..g {
+			let players = vec![player.clone(), funded_account::<T>("opponent", index)];
+			let game_hash =
+				GameRegistry::<T>::queue_game(game_engine.clone(), players, None, false, None)
+					.expect("game engine is registered; qed");
+			GameRegistry::<T>::ack_game(
+				RawOrigin::Signed(tee.clone()).into(),
+				game_engine.clone(),
+				vec![game_hash],
+			)
+			.expect("game is next in the queue; qed");
+			GameRegistry::<T>::ready_game(RawOrigin::Signed(tee.clone()).into(), game_hash)
+				.expect("game is accepted; qed");
+			games.push(game_hash);
+		}
+	}: _<T::Origin>(origin, player.clone())
+	verify {
+		assert!(GameRegistry::<T>::is_banned(&player));
+		for game_hash in games {
+			assert!(matches!(
+				GameRegistry::<T>::game_entry(&game_hash).map(|game_entry| game_entry.game_state),
+				Some(GameState::Finished(_))
+			));
+		}
+	}
+
+	unban_account {
+		let origin = T::ModerationOrigin::successful_origin();
+		let player: T::AccountId = account("player", 0, SEED);
+		<BannedAccounts<T>>::insert(&player, true);
+	}: _<T::Origin>(origin, player.clone())
+	verify {
+		assert!(!GameRegistry::<T>::is_banned(&player));
+	}
+
+	ban_player {
+		let origin = T::ModerationOrigin::successful_origin();
+		let player: T::AccountId = account("player", 0, SEED);
+		let until = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+	}: _<T::Origin>(origin, player.clone(), until, ReportCategory::Cheating)
+	verify {
+		assert!(GameRegistry::<T>::is_sanctioned(&player, SanctionKind::QueueBan));
+	}
+
+	unban_player {
+		let origin = T::ModerationOrigin::successful_origin();
+		let player: T::AccountId = account("player", 0, SEED);
+		let until = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+		<Sanctions<T>>::insert(&player, SanctionKind::QueueBan, until);
+	}: _<T::Origin>(origin, player.clone())
+	verify {
+		assert!(!GameRegistry::<T>::is_sanctioned(&player, SanctionKind::QueueBan));
+	}
+
+	add_to_allowlist {
+		let a in 1 .. MAX_ALLOWLIST_BATCH as u32;
+		let origin = T::ModerationOrigin::successful_origin();
+		let accounts: Vec<T::AccountId> = (0..a).map(|index| account("player", index, SEED)).collect();
+	}: _<T::Origin>(origin, accounts.clone())
+	verify {
+		assert!(accounts.iter().all(|who| GameRegistry::<T>::is_allowlisted(who)));
+	}
+
+	remove_from_allowlist {
+		let a in 1 .. MAX_ALLOWLIST_BATCH as u32;
+		let origin = T::ModerationOrigin::successful_origin();
+		let accounts: Vec<T::AccountId> = (0..a).map(|index| account("player", index, SEED)).collect();
+		for who in accounts.iter() {
+			<Allowlist<T>>::insert(who, true);
+		}
+	}: _<T::Origin>(origin, accounts.clone())
+	verify {
+		assert!(accounts.iter().all(|who| !GameRegistry::<T>::is_allowlisted(who)));
+	}
+
+	resolve_report {
+		let origin = T::ModerationOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let (game_hash, players) = waiting_game::<T>(&game_engine);
+		GameRegistry::<T>::report_player(
+			RawOrigin::Signed(players[0].clone()).into(),
+			game_hash,
+			players[1].clone(),
+			ReportCategory::Cheating,
+		)?;
+	}: _<T::Origin>(origin, 0, false)
+	verify {
+		assert!(!Reports::<T>::contains_key(0));
+	}
+
+	sanction_player {
+		let origin = T::ModerationOrigin::successful_origin();
+		let player: T::AccountId = account("player", 0, SEED);
+	}: _<T::Origin>(origin, player.clone(), SanctionKind::QueueBan, 10u32.into())
+	verify {
+		assert!(GameRegistry::<T>::is_sanctioned(&player, SanctionKind::QueueBan));
+	}
+
+	appeal_sanction {
+		let player: T::AccountId = account("player", 0, SEED);
+		let until = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+		<Sanctions<T>>::insert(&player, SanctionKind::QueueBan, until);
+	}: _(RawOrigin::Signed(player.clone()), SanctionKind::QueueBan)
+	verify {
+		assert!(Appeals::<T>::contains_key(&player, SanctionKind::QueueBan));
+	}
+
+	resolve_appeal {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let player: T::AccountId = account("player", 0, SEED);
+		let until = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+		<Sanctions<T>>::insert(&player, SanctionKind::QueueBan, until);
+		<Appeals<T>>::insert(&player, SanctionKind::QueueBan, ());
+	}: _<T::Origin>(origin, player.clone(), SanctionKind::QueueBan, true)
+	verify {
+		assert!(!GameRegistry::<T>::is_sanctioned(&player, SanctionKind::QueueBan));
+	}
+
+	rule_on_fraud_proof {
+		let origin = T::ArbitrationOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+		GameRegistry::<T>::submit_fraud_proof(
+			RawOrigin::Signed(players[1].clone()).into(),
+			game_hash,
+			vec![0; MAX_FRAUD_PROOF_LENGTH as usize],
+		)?;
+	}: _<T::Origin>(origin, game_hash, true)
+	verify {
+		assert!(!ArchivedGames::<T>::contains_key(&game_hash));
+	}
+
+	overturn_result {
+		let origin = T::ArbitrationOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+		GameRegistry::<T>::submit_fraud_proof(
+			RawOrigin::Signed(players[1].clone()).into(),
+			game_hash,
+			vec![0; MAX_FRAUD_PROOF_LENGTH as usize],
+		)?;
+	}: _<T::Origin>(origin, game_hash, GameOutcome::Winner(players[1].clone()))
+	verify {
+		assert_eq!(
+			GameRegistry::<T>::game_entry(&game_hash).map(|game_entry| game_entry.game_state),
+			Some(GameState::Finished(GameOutcome::Winner(players[1].clone())))
+		);
+	}
+
+	queue {
+		let game_engine = registered_engine::<T>();
+		let player = funded_account::<T>("player", 0);
+		let client_versions = ClientVersions { min: 1, max: u16::MAX };
+		EngineClientVersions::<T>::insert(&game_engine, client_versions);
+		PlayerClients::<T>::insert(&player, client_versions);
+	}: _(RawOrigin::Signed(player.clone()), game_engine.id, None, None)
+	verify {
+		assert!(QueuedPlayers::<T>::contains_key(&player));
+	}
+
+	leave_queue {
+		let game_engine = registered_engine::<T>();
+		let player = funded_account::<T>("player", 0);
+		GameRegistry::<T>::queue(
+			RawOrigin::Signed(player.clone()).into(),
+			game_engine.id,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(player.clone()))
+	verify {
+		assert!(!QueuedPlayers::<T>::contains_key(&player));
+	}
+
+	set_preferences {
+		let player = funded_account::<T>("player", 0);
+		let preferences = PlayerPreferences {
+			auto_requeue: true,
+			engine_id: Some(0),
+			time_control: Some(0),
+			region: Some(0),
+		};
+	}: _(RawOrigin::Signed(player.clone()), Some(preferences))
+	verify {
+		assert!(Preferences::<T>::contains_key(&player));
+	}
+
+	set_client_versions {
+		let player = funded_account::<T>("player", 0);
+		let client_versions = ClientVersions { min: 1, max: u16::MAX };
+	}: _(RawOrigin::Signed(player.clone()), Some(client_versions))
+	verify {
+		assert_eq!(PlayerClients::<T>::get(&player), Some(client_versions));
+	}
+
+	queue_committed {
+		let game_engine = registered_engine::<T>();
+		let payer = funded_account::<T>("payer", 0);
+		let player: T::AccountId = account("player", 0, SEED);
+		let commitment = T::Hashing::hash_of(&(&player, [0u8; 32]));
+	}: _(RawOrigin::Signed(payer), game_engine.id, None, None, commitment)
+	verify {
+		assert!(QueuedPlayers::<T>::contains_key(GameRegistry::<T>::committed_player(&commitment)));
+	}
+
+	create_match {
+		let o in 1 .. T::MaxPlayersPerGame::get() - 1;
+		let game_engine = registered_engine::<T>();
+		let creator = funded_account::<T>("player", 0);
+		let opponents: Vec<T::AccountId> =
+			(1..=o).map(|index| funded_account::<T>("player", index)).collect();
+		let init_data = vec![0; T::MaxInitData::get() as usize];
+		let start_at = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+	}: _(RawOrigin::Signed(creator), opponents, game_engine, Some(init_data), Some(start_at))
+	verify {
+		assert_eq!(MatchInvites::<T>::iter_keys().count(), 1);
+	}
+
+	accept_invite {
+		let game_engine = registered_engine::<T>();
+		let creator = funded_account::<T>("player", 0);
+		let opponent = funded_account::<T>("player", 1);
+		GameRegistry::<T>::create_match(
+			RawOrigin::Signed(creator).into(),
+			vec![opponent.clone()],
+			game_engine.clone(),
+			Some(vec![0; T::MaxInitData::get() as usize]),
+			None,
+		)?;
+		let match_id = MatchInvites::<T>::iter_keys().next().expect("match is created; qed");
+	}: _(RawOrigin::Signed(opponent), match_id)
+	verify {
+		assert!(!MatchInvites::<T>::contains_key(&match_id));
+		assert_eq!(GameRegistry::<T>::game_queues(&game_engine).length(), 1);
+	}
+
+	start_scheduled_game {
+		let game_engine = registered_engine::<T>();
+		registered_tee::<T>(&game_engine, 0);
+		let players = vec![funded_account::<T>("player", 0), funded_account::<T>("player", 1)];
+		let start_at = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+		let game_hash = GameRegistry::<T>::new_game(
+			game_engine.clone(),
+			players,
+			None,
+			false,
+			InitDataOf::<T>::try_from(vec![0; T::MaxInitData::get() as usize]).ok(),
+			Some(start_at),
+		)?;
+	}: _(RawOrigin::Root, game_hash)
+	verify {
+		assert_eq!(GameRegistry::<T>::game_registry(&game_hash).game_state, GameState::Waiting);
+		assert_eq!(GameRegistry::<T>::game_queues(&game_engine).length(), 1);
+	}
+
+	queue_party {
+		let m in 0 .. T::MaxPartySize::get().min(T::MaxPlayersPerGame::get() / 2) - 1;
+		let game_engine = registered_engine::<T>();
+		let leader = funded_account::<T>("player", 0);
+		let members: Vec<T::AccountId> =
+			(1..=m).map(|index| funded_account::<T>("player", index)).collect();
+		let members = BoundedVec::try_from(members).expect("members fit the party; qed");
+	}: _(RawOrigin::Signed(leader.clone()), game_engine, members)
+	verify {
+		assert!(Parties::<T>::contains_key(&leader));
+	}
+
+	accept_party_invite {
+		let game_engine = registered_engine::<T>();
+		let leader = funded_account::<T>("player", 0);
+		let member = funded_account::<T>("player", 1);
+		let members = BoundedVec::try_from(vec![member.clone()]).expect("member fits the party; qed");
+		GameRegistry::<T>::queue_party(
+			RawOrigin::Signed(leader.clone()).into(),
+			game_engine.clone(),
+			members,
+		)?;
+	}: _(RawOrigin::Signed(member), leader.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::party_queue(&game_engine), vec![leader]);
+	}
+
+	disband_party {
+		let m in 1 .. T::MaxPartySize::get().min(T::MaxPlayersPerGame::get() / 2);
+		let game_engine = registered_engine::<T>();
+		let leader = funded_account::<T>("player", 0);
+		let members: Vec<T::AccountId> =
+			(1..m).map(|index| funded_account::<T>("player", index)).collect();
+		GameRegistry::<T>::queue_party(
+			RawOrigin::Signed(leader.clone()).into(),
+			game_engine.clone(),
+			BoundedVec::try_from(members.clone()).expect("members fit the party; qed"),
+		)?;
+		for member in members {
+			GameRegistry::<T>::accept_party_invite(
+				RawOrigin::Signed(member).into(),
+				leader.clone(),
+			)?;
+		}
+	}: _(RawOrigin::Signed(leader.clone()))
+	verify {
+		assert!(!Parties::<T>::contains_key(&leader));
+		assert!(GameRegistry::<T>::party_queue(&game_engine).is_empty());
+	}
+
+	remove_expired_invite {
+		let game_engine = registered_engine::<T>();
+		let creator = funded_account::<T>("player", 0);
+		let opponent = funded_account::<T>("player", 1);
+		GameRegistry::<T>::create_match(
+			RawOrigin::Signed(creator).into(),
+			vec![opponent],
+			game_engine,
+			Some(vec![0; T::MaxInitData::get() as usize]),
+			None,
+		)?;
+		let n = <frame_system::Pallet<T>>::block_number() + T::InviteTimeout::get();
+	}: {
+		GameRegistry::<T>::remove_expired_invites(n, Weight::max_value());
+	}
+	verify {
+		assert_eq!(MatchInvites::<T>::iter_keys().count(), 0);
+	}
+
+	reveal_identity {
+		let game_engine = registered_engine::<T>();
+		let payer = funded_account::<T>("payer", 0);
+		let player: T::AccountId = account("player", 0, SEED);
+		let salt = [0u8; 32];
+		let commitment = T::Hashing::hash_of(&(&player, salt));
+		GameRegistry::<T>::queue_committed(
+			RawOrigin::Signed(payer).into(),
+			game_engine.id,
+			None,
+			None,
+			commitment,
+		)?;
+		let opponent = funded_account::<T>("player", 1);
+		let players = vec![GameRegistry::<T>::committed_player(&commitment), opponent];
+		let game_hash = GameRegistry::<T>::queue_game(game_engine, players, None, false, None)?;
+	}: _(RawOrigin::Signed(player.clone()), game_hash, salt)
+	verify {
+		assert!(GameRegistry::<T>::game_registry(&game_hash).players.contains(&player));
+	}
+
+	queue_foreign {
+		let origin = T::ForeignOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let location = T::ForeignOrigin::ensure_origin(origin.clone())
+			.expect("origin is successful; qed");
+		let player = T::LocationToAccountId::convert(location);
+		T::Currency::make_free_balance_be(&player, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _<T::Origin>(origin, game_engine.id, None, None)
+	verify {
+		assert!(ForeignPlayers::<T>::contains_key(&player));
+	}
+
+	accept_match {
+		let game_engine = registered_engine::<T>();
+		let player = funded_account::<T>("player", 0);
+		GameRegistry::<T>::queue(
+			RawOrigin::Signed(player.clone()).into(),
+			game_engine.id,
+			None,
+			None,
+		)?;
+		let opponent = funded_account::<T>("player", 1);
+		let players = vec![player.clone(), opponent];
+		let game_hash = GameRegistry::<T>::queue_game(game_engine, players, None, false, None)?;
+	}: _(RawOrigin::Signed(player.clone()), game_hash)
+	verify {
+		assert!(!QueueBonds::<T>::contains_key(&player));
+	}
+
+	concede {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = running_game::<T>(&game_engine, &tee);
+	}: _(RawOrigin::Signed(players[0].clone()), game_hash)
+
+	report_player {
+		let game_engine = registered_engine::<T>();
+		let (game_hash, players) = waiting_game::<T>(&game_engine);
+	}: _(
+		RawOrigin::Signed(players[0].clone()),
+		game_hash,
+		players[1].clone(),
+		ReportCategory::Cheating
+	)
+	verify {
+		assert!(Reports::<T>::contains_key(0));
+	}
+
+	flag_result {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+	}: _(RawOrigin::Signed(players[1].clone()), game_hash)
+	verify {
+		assert!(ResultFlags::<T>::contains_key(&game_hash));
+	}
+
+	reconfirm_result {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+		GameRegistry::<T>::flag_result(RawOrigin::Signed(players[1].clone()).into(), game_hash)?;
+		let second_tee = registered_tee::<T>(&game_engine, 1);
+	}: _(RawOrigin::Signed(second_tee), game_hash, GameOutcome::Winner(players[0].clone()))
+	verify {
+		assert!(!ResultFlags::<T>::contains_key(&game_hash));
+	}
+
+	submit_fraud_proof {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+		let proof = vec![0; MAX_FRAUD_PROOF_LENGTH as usize];
+	}: _(RawOrigin::Signed(players[1].clone()), game_hash, proof)
+	verify {
+		assert!(FraudProofs::<T>::contains_key(&game_hash));
+	}
+
+	register_spectator {
+		let game_engine = registered_engine::<T>();
+		let (game_hash, _) = waiting_game::<T>(&game_engine);
+		let spectator: T::AccountId = account("spectator", 0, SEED);
+	}: _(RawOrigin::Signed(spectator.clone()), game_hash)
+	verify {
+		assert!(GameRegistry::<T>::spectators(&game_hash).contains(&spectator));
+	}
+
+	set_spectator_settings {
+		let game_engine = registered_engine::<T>();
+		let (game_hash, players) = waiting_game::<T>(&game_engine);
+		let settings = SpectatorSettings {
+			policy: SpectatorPolicy::Invited,
+			max_spectators: T::MaxSpectators::get(),
+		};
+	}: _(RawOrigin::Signed(players[0].clone()), game_hash, settings)
+	verify {
+		assert!(GameSpectatorSettings::<T>::contains_key(&game_hash));
+	}
+
+	invite_spectator {
+		let game_engine = registered_engine::<T>();
+		let (game_hash, players) = waiting_game::<T>(&game_engine);
+		let spectator: T::AccountId = account("spectator", 0, SEED);
+	}: _(RawOrigin::Signed(players[0].clone()), game_hash, spectator.clone())
+	verify {
+		assert!(SpectatorInvites::<T>::contains_key(&game_hash, &spectator));
+	}
+
+	set_session_key {
+		let player: T::AccountId = account("player", 0, SEED);
+		let session: T::AccountId = account("session", 0, SEED);
+	}: _(RawOrigin::Signed(player.clone()), session.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::session_key_proposal(&session), Some(player));
+	}
+
+	accept_session_key {
+		let player: T::AccountId = account("player", 0, SEED);
+		let session: T::AccountId = account("session", 0, SEED);
+		<SessionKeyProposals<T>>::insert(&session, &player);
+	}: _(RawOrigin::Signed(session.clone()), player.clone())
+	verify {
+		assert_eq!(GameRegistry::<T>::session_keys(&session), Some(player));
+	}
+
+	remove_session_key {
+		let player: T::AccountId = account("player", 0, SEED);
+		let session: T::AccountId = account("session", 0, SEED);
+		<SessionKeys<T>>::insert(&session, &player);
+		<SessionKeyCount<T>>::insert(&player, 1);
+	}: _(RawOrigin::Signed(player), session.clone())
+	verify {
+		assert!(!SessionKeys::<T>::contains_key(&session));
+	}
+
+	drop_game {
+		let q in 1 .. T::MaxQueueSize::get();
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		// the dropped game is the last one of the queue
+		for _ in 1..q {
+			waiting_game::<T>(&game_engine);
+		}
+		let (game_hash, _) = waiting_game::<T>(&game_engine);
+	}: _(RawOrigin::Signed(tee), game_hash, game_engine.clone(), DropReason::NoShow)
+	verify {
+		assert!(!crate::GameRegistry::<T>::contains_key(&game_hash));
+		assert_eq!(GameRegistry::<T>::game_queues(&game_engine).length(), q - 1);
+	}
+
+	note_tee_liveness {
+		let t in 1 .. 100;
+		let game_engine = registered_engine::<T>();
+		let tees: Vec<T::AccountId> =
+			(0..t).map(|index| registered_tee::<T>(&game_engine, index)).collect();
+	}: _(RawOrigin::None, tees.clone())
+	verify {
+		assert!(tees.iter().all(|tee| TeeLastSeen::<T>::contains_key(tee)));
+	}
+
+	tee_heartbeat {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+	}: _(RawOrigin::Signed(tee.clone()))
+	verify {
+		assert!(TeeLastSeen::<T>::contains_key(&tee));
+	}
+
+	reassign_game {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, _) = running_game::<T>(&game_engine, &tee);
+	}: {
+		GameRegistry::<T>::reassign_game(game_hash, &tee);
+	}
+	verify {
+		assert_eq!(GameRegistry::<T>::game_registry(&game_hash).game_state, GameState::Waiting);
+	}
+
+	expire_game {
+		let game_engine = registered_engine::<T>();
+		let (game_hash, _) = waiting_game::<T>(&game_engine);
+		let timeout = GameRegistry::<T>::parameters().waiting_timeout;
+		<frame_system::Pallet<T>>::set_block_number(
+			<frame_system::Pallet<T>>::block_number() + timeout + 1u32.into(),
+		);
+	}: _(RawOrigin::None, game_hash)
+	verify {
+		assert!(!crate::GameRegistry::<T>::contains_key(&game_hash));
+	}
+
+	ack_game {
+		// a batch is taken from a single queue, so it's bound by the queue size as well
+		let g in 1 .. T::MaxAckBatch::get().min(T::MaxQueueSize::get());
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let games = fill_game_queue::<T>(&game_engine);
+		let batch: Vec<T::Hash> = games.into_iter().take(g as usize).collect();
+	}: _(RawOrigin::Signed(tee), game_engine.clone(), batch.clone())
+	verify {
+		assert!(batch.iter().all(|game_hash| {
+			GameRegistry::<T>::game_registry(game_hash).game_state == GameState::Accepted
+		}));
+		assert_eq!(GameRegistry::<T>::game_queues(&game_engine).length(), T::MaxQueueSize::get() - g);
+	}
+
+	ack_game_unsigned {
+		let g in 1 .. T::MaxAckBatch::get().min(T::MaxQueueSize::get());
+		let game_engine = registered_engine::<T>();
+		let (tee, sign) = signing_tee::<T>(&game_engine);
+		let games = fill_game_queue::<T>(&game_engine);
+		let batch: Vec<T::Hash> = games.into_iter().take(g as usize).collect();
+		let signature = sign(&(&game_engine, &batch, 0u64).encode());
+	}: _(RawOrigin::None, tee, game_engine.clone(), batch.clone(), 0, signature)
+	verify {
+		assert!(batch.iter().all(|game_hash| {
+			GameRegistry::<T>::game_registry(game_hash).game_state == GameState::Accepted
+		}));
+		assert_eq!(GameRegistry::<T>::game_queues(&game_engine).length(), T::MaxQueueSize::get() - g);
+	}
+
+	ready_game {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, _) = waiting_game::<T>(&game_engine);
+		GameRegistry::<T>::ack_game(
+			RawOrigin::Signed(tee.clone()).into(),
+			game_engine,
+			vec![game_hash],
+		)?;
+	}: _(RawOrigin::Signed(tee), game_hash)
+	verify {
+		assert_eq!(GameRegistry::<T>::game_registry(&game_hash).game_state, GameState::Running);
+	}
+
+	checkpoint_game {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, _) = running_game::<T>(&game_engine, &tee);
+	}: _(RawOrigin::Signed(tee), game_hash, T::Hash::default(), 1)
+	verify {
+		assert!(GameCheckpoints::<T>::contains_key(&game_hash));
+	}
+
+	queue_game {
+		let q in 0 .. T::MaxQueueSize::get() - 1;
+		let game_engine = registered_engine::<T>();
+		for _ in 0..q {
+			waiting_game::<T>(&game_engine);
+		}
+		let players: Vec<T::AccountId> =
+			(0..2).map(|index| funded_account::<T>("matched", index)).collect();
+	}: {
+		GameRegistry::<T>::queue_game(game_engine.clone(), players, None, false, None)?;
+	}
+	verify {
+		assert_eq!(GameRegistry::<T>::game_queues(&game_engine).length(), q + 1);
+	}
+
+	on_initialize_idle {
+		let n = <frame_system::Pallet<T>>::block_number() + 1u32.into();
+	}: {
+		GameRegistry::<T>::on_initialize(n);
+		GameRegistry::<T>::on_finalize(n);
+	}
+
+	on_initialize_migration {
+		let n = <frame_system::Pallet<T>>::block_number() + 1u32.into();
+		let cursor = MatchHistory::<T>::final_prefix().to_vec();
+		MigrationCursor::<T>::put(GameRegistry::<T>::storage_cursor(cursor));
+	}: {
+		GameRegistry::<T>::on_initialize(n);
+	}
+	verify {
+		assert_eq!(GameRegistry::<T>::migration_cursor(), None);
+		assert_eq!(GameRegistry::<T>::deadline_cursor(), Some(n));
+	}
+
+	escalate_result {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+		GameRegistry::<T>::flag_result(RawOrigin::Signed(players[1].clone()).into(), game_hash)?;
+		let game_entry = GameRegistry::<T>::game_entry(&game_hash).expect("game is flagged; qed");
+		let outcome = GameOutcome::Winner(players[0].clone());
+	}: {
+		GameRegistry::<T>::escalate_result(game_hash, game_entry, outcome, Vec::new());
+	}
+	verify {
+		assert!(FraudProofs::<T>::contains_key(&game_hash));
+	}
+
+	settle_result {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+		let game_entry = GameRegistry::<T>::game_entry(&game_hash).expect("game is finished; qed");
+		let outcome = GameOutcome::Winner(players[0].clone());
+	}: {
+		GameRegistry::<T>::settle_result(&game_hash, &game_entry, &outcome);
+	}
+	verify {
+		assert!(!GameStakes::<T>::contains_key(&game_hash));
+	}
+
+	set_result_confirmation {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+	}: _<T::Origin>(origin, game_engine.clone(), true)
+	verify {
+		assert!(GameRegistry::<T>::confirms_results(&game_engine));
+	}
+
+	confirm_result {
+		let game_engine = registered_engine::<T>();
+		<ResultConfirmationEngines<T>>::insert(&game_engine, true);
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+		GameRegistry::<T>::confirm_result(RawOrigin::Signed(players[0].clone()).into(), game_hash)?;
+	}: _(RawOrigin::Signed(players[1].clone()), game_hash)
+	verify {
+		assert_eq!(GameRegistry::<T>::result_confirmations(&game_hash).len(), 2);
+	}
+
+	resume_games {
+		let g in 1 .. T::MaxAckBatch::get();
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let mut games = Vec::new();
+		for _ in 0..g {
+			let (game_hash, _) = running_game::<T>(&game_engine, &tee);
+			// setting up the games isn't limited by the lifecycle calls of a block
+			TeeCallCount::<T>::remove_all(None);
+			// games past their resumes are dropped, the heavier case
+			GameResumes::<T>::insert(&game_hash, T::MaxGameResumes::get());
+			games.push(game_hash);
+		}
+		let games = BoundedVec::try_from(games).expect("games are within `MaxAckBatch`; qed");
+	}: _(RawOrigin::Signed(tee), games.clone())
+	verify {
+		assert!(games.iter().all(|game_hash| !crate::GameRegistry::<T>::contains_key(game_hash)));
+	}
+
+	unsuspend_tee {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		SuspendedTees::<T>::insert(&tee, T::BlockNumber::from(1u32));
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin, tee.clone())
+	verify {
+		assert!(GameRegistry::<T>::suspended_tees(&tee).is_none());
+	}
+
+	match_game {
+		let game_engine = registered_engine::<T>();
+		// the new game goes into an almost full game queue
+		for _ in 1..T::MaxQueueSize::get() {
+			waiting_game::<T>(&game_engine);
+		}
+		let client_versions = ClientVersions { min: 1, max: u16::MAX };
+		EngineClientVersions::<T>::insert(&game_engine, client_versions);
+		for index in 0..2 {
+			let player = funded_account::<T>("matched", index);
+			PlayerClients::<T>::insert(&player, client_versions);
+			GameRegistry::<T>::queue(RawOrigin::Signed(player).into(), game_engine.id, None, None)?;
+		}
+		let n = <frame_system::Pallet<T>>::block_number() + 1u32.into();
+	}: {
+		GameRegistry::<T>::on_idle(n, Weight::max_value());
+	}
+	verify {
+		assert_eq!(GameRegistry::<T>::game_queues(&game_engine).length(), T::MaxQueueSize::get());
+		assert_eq!(GameClientVersions::<T>::iter().count(), 1);
+	}
+
+	no_match {
+		let n = <frame_system::Pallet<T>>::block_number() + 1u32.into();
+	}: {
+		GameRegistry::<T>::on_idle(n, Weight::max_value());
+	}
+
+	match_parties {
+		// every two parties of one make a game, all of them fit the game queue
+		let p in 2 .. 2 * T::MaxQueueSize::get().min(50);
+		let game_engine = registered_engine::<T>();
+		for index in 0..p {
+			GameRegistry::<T>::queue_party(
+				RawOrigin::Signed(funded_account::<T>("player", index)).into(),
+				game_engine.clone(),
+				BoundedVec::default(),
+			)?;
+		}
+	}: {
+		GameRegistry::<T>::match_parties(&game_engine);
+	}
+	verify {
+		assert_eq!(GameTeams::<T>::iter().count() as u32, p / 2);
+	}
+
+	heartbeat {
+		let e in 1 .. 100;
+		for id in 0..e {
+			GameRegistry::<T>::do_register_game_engine(GameEngine::new(id as u8, 1), id as u8)?;
+		}
+		let n = T::HeartbeatInterval::get();
+	}: {
+		GameRegistry::<T>::on_initialize(n);
+	}
+
+	end_season {
+		let champions: Vec<(T::AccountId, u32)> = (0..T::LeaderboardSize::get())
+			.map(|index| (account("player", index, SEED), index))
+			.collect();
+		<SeasonLeaderboard<T>>::put(
+			LeaderboardOf::<T>::try_from(champions.clone()).expect("leaderboard is full; qed"),
+		);
+	}: {
+		GameRegistry::<T>::end_season();
+	}
+	verify {
+		assert_eq!(GameRegistry::<T>::season_champions(0), champions);
+	}
+
+	finish_game {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = running_game::<T>(&game_engine, &tee);
+		let replay = vec![0; MAX_REPLAY_LENGTH as usize];
+		let result_proof = T::Hashing::hash(&[]);
+	}: _(
+		RawOrigin::Signed(tee),
+		game_hash,
+		GameOutcome::Winner(players[0].clone()),
+		T::Hash::default(),
+		None,
+		Some(replay),
+		Some(result_proof)
+	)
+	verify {
+		assert!(GameReplays::<T>::contains_key(&game_hash));
+		assert!(ResultProofs::<T>::contains_key(&game_hash));
+	}
+
+	finish_game_unsigned {
+		let game_engine = registered_engine::<T>();
+		let (tee, sign) = signing_tee::<T>(&game_engine);
+		let (game_hash, players) = running_game::<T>(&game_engine, &tee);
+		let outcome = GameOutcome::Winner(players[0].clone());
+		let final_state = T::Hash::default();
+		let replay = Some(vec![0; MAX_REPLAY_LENGTH as usize]);
+		let result_proof = Some(T::Hashing::hash(&[]));
+		let payload = (game_hash, &outcome, final_state, 0u64, &replay, result_proof).encode();
+		let result = GameSubmission {
+			game_hash,
+			outcome,
+			final_state,
+			signature: Some(sign(&payload)),
+			replay,
+			result_proof,
+		};
+	}: _(RawOrigin::None, tee.clone(), result, 0)
+	verify {
+		assert!(GameReplays::<T>::contains_key(&game_hash));
+		assert_eq!(GameRegistry::<T>::tee_result_nonce(&tee), 1);
+	}
+
+	verify_result {
+		let l in 0 .. MAX_TRANSCRIPT_LENGTH;
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = running_game::<T>(&game_engine, &tee);
+		let transcript = vec![0; l as usize];
+		GameRegistry::<T>::finish_game(
+			RawOrigin::Signed(tee).into(),
+			game_hash,
+			GameOutcome::Winner(players[0].clone()),
+			T::Hash::default(),
+			None,
+			None,
+			Some(T::Hashing::hash(&transcript)),
+		)?;
+		let player = players[1].clone();
+	}: _(RawOrigin::Signed(player), game_hash, transcript)
+	verify {
+		assert!(ResultProofs::<T>::contains_key(&game_hash));
+	}
+
+	request_rematch {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = finished_game::<T>(&game_engine, &tee);
+		GameRegistry::<T>::request_rematch(RawOrigin::Signed(players[0].clone()).into(), game_hash)?;
+		// the last request queues the rematch
+		let player = players[1].clone();
+	}: _(RawOrigin::Signed(player), game_hash)
+	verify {
+		assert!(!RematchRequests::<T>::contains_key(&game_hash));
+	}
+
+	create_tournament {
+		let game_engine = registered_engine::<T>();
+		let creator = funded_account::<T>("creator", 0);
+		let start_block = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+	}: _(RawOrigin::Signed(creator), game_engine, T::MaxTournamentPlayers::get(), 100u32.into(), start_block)
+	verify {
+		assert!(Tournaments::<T>::contains_key(0));
+	}
+
+	join_tournament {
+		let game_engine = registered_engine::<T>();
+		let creator = funded_account::<T>("creator", 0);
+		let start_block = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+		GameRegistry::<T>::create_tournament(
+			RawOrigin::Signed(creator).into(),
+			game_engine,
+			T::MaxTournamentPlayers::get(),
+			100u32.into(),
+			start_block,
+		)?;
+		let player = funded_account::<T>("player", 0);
+	}: _(RawOrigin::Signed(player.clone()), 0)
+	verify {
+		assert_eq!(GameRegistry::<T>::tournaments(0).unwrap().players, vec![player]);
+	}
+
+	start_tournament_round {
+		let p in 2 .. T::MaxTournamentPlayers::get();
+		let game_engine = registered_engine::<T>();
+		let creator = funded_account::<T>("creator", 0);
+		let start_block = <frame_system::Pallet<T>>::block_number() + 1u32.into();
+		GameRegistry::<T>::create_tournament(
+			RawOrigin::Signed(creator).into(),
+			game_engine,
+			T::MaxTournamentPlayers::get(),
+			100u32.into(),
+			start_block,
+		)?;
+		for index in 0..p {
+			let player = funded_account::<T>("player", index);
+			GameRegistry::<T>::join_tournament(RawOrigin::Signed(player).into(), 0)?;
+		}
+	}: {
+		GameRegistry::<T>::start_tournament_round(0);
+	}
+	verify {
+		assert_eq!(GameRegistry::<T>::tournaments(0).unwrap().round, 1);
+	}
+
+	propose_abort {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = running_game::<T>(&game_engine, &tee);
+		GameRegistry::<T>::propose_abort(RawOrigin::Signed(players[0].clone()).into(), game_hash)?;
+		// the last player agreeing aborts the game
+		let player = players[1].clone();
+	}: _(RawOrigin::Signed(player), game_hash)
+	verify {
+		assert_eq!(
+			GameRegistry::<T>::game_entry(&game_hash).unwrap().game_state,
+			GameState::Finished(GameOutcome::Aborted)
+		);
+	}
+
+	handover_games {
+		let g in 1 .. T::MaxGamesPerTee::get();
+		let origin = T::OperatorOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let to_tee = registered_tee::<T>(&game_engine, 1);
+		let game_hashes: Vec<_> = (0..g).map(|_| waiting_game::<T>(&game_engine).0).collect();
+		GameRegistry::<T>::ack_game(
+			RawOrigin::Signed(tee).into(),
+			game_engine,
+			game_hashes.clone(),
+		)?;
+	}: _<T::Origin>(origin, to_tee.clone(), game_hashes)
+	verify {
+		assert_eq!(GameRegistry::<T>::tee_loads(&to_tee).accepted, g);
+	}
+
+	prune_game {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, _) = finished_game::<T>(&game_engine, &tee);
+		let n = <frame_system::Pallet<T>>::block_number() + T::GameRetention::get();
+	}: {
+		GameRegistry::<T>::prune_game(&game_hash, n);
+	}
+	verify {
+		assert!(!ArchivedGames::<T>::contains_key(&game_hash));
+	}
+
+	clean_game_queue {
+		let q in 1 .. T::MaxQueueSize::get();
+		let game_engine = registered_engine::<T>();
+		// games removed outside of the queue
+		let game_hashes: Vec<_> =
+			(0..q).map(|_| waiting_game::<T>(&game_engine).0).collect();
+		for game_hash in game_hashes.iter() {
+			crate::GameRegistry::<T>::remove(game_hash);
+		}
+	}: {
+		GameRegistry::<T>::clean_game_queue(&game_engine);
+	}
+	verify {
+		assert!(GameRegistry::<T>::game_queues(&game_engine).is_empty());
+	}
+
+	remove_lapsed_sanction {
+		let player: T::AccountId = account("player", 0, SEED);
+		let until: T::BlockNumber = 1u32.into();
+		Sanctions::<T>::insert(&player, SanctionKind::QueueBan, until);
+		let n = <frame_system::Pallet<T>>::block_number() + 1u32.into();
+	}: {
+		GameRegistry::<T>::remove_lapsed_sanction(&player, SanctionKind::QueueBan, n);
+	}
+	verify {
+		assert!(!Sanctions::<T>::contains_key(&player, SanctionKind::QueueBan));
+	}
+
+	batch_drop_games {
+		let g in 1 .. T::MaxQueueSize::get();
+		let origin = T::OperatorOrigin::successful_origin();
+		let game_engine = registered_engine::<T>();
+		let game_hashes: Vec<_> =
+			(0..g).map(|_| waiting_game::<T>(&game_engine).0).collect();
+	}: _<T::Origin>(origin, game_hashes.clone(), DropReason::NoShow)
+	verify {
+		assert!(game_hashes.iter().all(|game_hash| !crate::GameRegistry::<T>::contains_key(game_hash)));
+	}
+
+	batch_finish_games {
+		let g in 1 .. T::MaxAckBatch::get();
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let mut results = Vec::new();
+		for _ in 0..g {
+			let (game_hash, players) = running_game::<T>(&game_engine, &tee);
+			// setting up the games isn't limited by the lifecycle calls of a block
+			TeeCallCount::<T>::remove_all(None);
+			results.push(GameSubmission {
+				game_hash,
+				outcome: GameOutcome::Winner(players[0].clone()),
+				final_state: T::Hash::default(),
+				signature: None,
+				replay: Some(vec![0; MAX_REPLAY_LENGTH as usize]),
+				result_proof: Some(T::Hash::default()),
+			});
+		}
+		let game_hashes: Vec<_> = results.iter().map(|result| result.game_hash).collect();
+	}: _(RawOrigin::Signed(tee), results)
+	verify {
+		assert!(game_hashes.iter().all(|game_hash| GameReplays::<T>::contains_key(game_hash)));
+	}
+
+	report_no_show {
+		let game_engine = registered_engine::<T>();
+		let tee = registered_tee::<T>(&game_engine, 0);
+		let (game_hash, players) = running_game::<T>(&game_engine, &tee);
+		<frame_system::Pallet<T>>::set_block_number(
+			<frame_system::Pallet<T>>::block_number() + T::NoShowGracePeriod::get(),
+		);
+		// the no-show bans the player from queueing
+		<NoShowStrikes<T>>::insert(&players[1], T::MaxNoShows::get().saturating_sub(1));
+	}: _(RawOrigin::Signed(tee), game_hash, players[1].clone())
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert!(GameRegistry::<T>::is_sanctioned(&players[1], SanctionKind::QueueBan));
 	}
 }
 
@@ -0,0 +1,31 @@
+#[cfg(feature = "std")]
+use codec::Encode;
+use sp_inherents::InherentIdentifier;
+
+/// Identifier of the inherent data with the AjunaTEEs the block author observed alive.
+pub const INHERENT_IDENTIFIER: InherentIdentifier = *b"ajunatee";
+
+/// Provides the AjunaTEEs the block author observed alive, e.g. through their heartbeats.
+#[cfg(feature = "std")]
+pub struct InherentDataProvider<AccountId>(pub Vec<AccountId>);
+
+#[cfg(feature = "std")]
+#[async_trait::async_trait]
+impl<AccountId: Encode + Send + Sync> sp_inherents::InherentDataProvider
+	for InherentDataProvider<AccountId>
+{
+	fn provide_inherent_data(
+		&self,
+		inherent_data: &mut sp_inherents::InherentData,
+	) -> Result<(), sp_inherents::Error> {
+		inherent_data.put_data(INHERENT_IDENTIFIER, &self.0)
+	}
+
+	async fn try_handle_error(
+		&self,
+		_identifier: &InherentIdentifier,
+		_error: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		None
+	}
+}
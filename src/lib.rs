@@ -3,33 +3,42 @@
 /// Edit this file to define custom logic or remove it if it is not needed.
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://substrate.dev/docs/en/knowledgebase/runtime/frame>
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
-	dispatch::DispatchResult,
-	log,
+	dispatch::{DispatchError, DispatchResult},
+	ensure, log,
+	storage::{with_transaction, StoragePrefixedMap},
 	traits::{
-		schedule::{DispatchTime, Named},
-		LockIdentifier, Randomness,
+		schedule::{DispatchTime, Named, HARD_DEADLINE},
+		BalanceStatus, Currency, ExistenceRequirement, Get, LockIdentifier, OnUnbalanced,
+		Randomness, ReservableCurrency, WithdrawReasons,
 	},
+	transactional,
+	weights::{Weight, WithPostDispatchInfo},
+	BoundedVec,
 };
 
-//use frame_system::WeightInfo;
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{
-	traits::{Dispatchable, Hash, TrailingZeroInput},
-	RuntimeDebug,
+	offchain::storage::StorageValueRef,
+	traits::{
+		Convert, Dispatchable, Hash, One, Saturating, TrailingZeroInput, UniqueSaturatedInto,
+		Verify, Zero,
+	},
+	AccountId32, MultiSignature, Perbill, Percent, RuntimeDebug, TransactionOutcome,
 };
-use sp_std::vec::Vec;
+use sp_std::{convert::TryFrom, fmt::Debug, vec::Vec};
 
-use pallet_matchmaker::MatchFunc;
-
-use log::info;
+use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
+use sp_inherents::MakeFatalError;
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
-#[cfg(test)]
-mod mock;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
 
 #[cfg(test)]
 mod tests;
@@ -38,51 +47,994 @@ mod tests;
 mod benchmarking;
 
 // importing the `weights.rs` here
-//pub mod weights;
+pub mod weights;
+pub use weights::WeightInfo;
 
 // importing queues, for game management
-mod queues;
+pub mod queues;
+
+use queues::{LanedQueue, QueueBounds};
+
+// traits for other pallets to interact with the game registry
+pub mod traits;
+
+// inherent with the AjunaTEEs observed alive by the block author
+pub mod inherent;
+
+// storage migrations, stepped over multiple blocks
+pub mod migrations;
+
+// single-elimination tournaments, played as games of the registry
+mod tournaments;
+
+// premade parties, matched against teams of the same size
+mod parties;
+
+// reward points and win streaks of players, earned with settled games
+mod rewards;
+
+pub use parties::Party;
+pub use rewards::RewardPoints;
+pub use tournaments::{Tournament, TournamentState};
+
+use traits::{
+	AssetOwnership, ForeignResultNotifier, GameIdGenerator, GameObserver, GameStartCall,
+	OnGameResult, RewardHandler, TeeAttestation,
+};
+
+pub use traits::Matchmaker;
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+/// Game queue of a game engine version, with a priority lane drained before its normal lane.
+/// Each lane is stored as a ring buffer.
+pub type GameQueueOf<T> = LanedQueue<
+	GameEngine,
+	<T as frame_system::Config>::Hash,
+	GameQueues<T>,
+	GameQueueItems<T>,
+	PriorityGameQueues<T>,
+	PriorityGameQueueItems<T>,
+>;
+
+pub type MatchResultOf<T> = MatchResult<
+	<T as frame_system::Config>::Hash,
+	AccountIdOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::MaxPlayersPerGame,
+>;
+
+pub type MatchInviteOf<T> = MatchInvite<
+	AccountIdOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::MaxPlayersPerGame,
+>;
+
+pub type PartyOf<T> = Party<AccountIdOf<T>, <T as Config>::MaxPartySize>;
+
+pub type TeeClusterOf<T> = TeeCluster<AccountIdOf<T>>;
+
+pub type TournamentOf<T> = Tournament<
+	AccountIdOf<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::MaxTournamentPlayers,
+>;
+
+pub type InitDataOf<T> = BoundedVec<u8, <T as Config>::MaxInitData>;
+
+pub type TimelineOf<T> =
+	BoundedVec<(GameStateOf<T>, <T as frame_system::Config>::BlockNumber), MaxTransitions>;
+
+pub type TeamsOf<T> = BoundedVec<
+	BoundedVec<AccountIdOf<T>, <T as Config>::MaxPlayersPerGame>,
+	<T as Config>::MaxPlayersPerGame,
+>;
+
+pub type LeaderboardOf<T> = BoundedVec<(AccountIdOf<T>, u32), <T as Config>::LeaderboardSize>;
+
+pub type BlockGamesOf<T> =
+	BoundedVec<<T as frame_system::Config>::Hash, <T as Config>::MaxFinishedPerBlock>;
+
+pub type ReplayOf = BoundedVec<u8, MaxReplayLength>;
+
+pub type MatchCode = BoundedVec<u8, MaxMatchCodeLength>;
+
+pub type RawStorageKey = BoundedVec<u8, MaxStorageKeyLength>;
 
-use queues::Queue;
+pub type GameStateOf<T> = GameState<AccountIdOf<T>, <T as Config>::MaxPlayersPerGame>;
+
+pub type GameOutcomeOf<T> = GameOutcome<AccountIdOf<T>, <T as Config>::MaxPlayersPerGame>;
+
+pub type GameResultOf<T> = GameResult<AccountIdOf<T>, <T as Config>::MaxPlayersPerGame>;
+
+pub type GameEntryOf<T> = GameEntry<
+	<T as frame_system::Config>::Hash,
+	AccountIdOf<T>,
+	GameEngine,
+	GameStateOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::ForeignLocation,
+	<T as Config>::MaxPlayersPerGame,
+>;
 
 /// GameState structure, allowing Client & TEE to determine actions.
-#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
-pub enum GameState<AccountId> {
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum GameState<AccountId, MaxPlayers> {
 	None,
 	Waiting,
 	Accepted,
 	Running,
-	Finished(AccountId),
+	Finished(GameOutcome<AccountId, MaxPlayers>),
+	/// Result suspended by a fraud proof, till the arbitration rules on it.
+	Suspended(GameOutcome<AccountId, MaxPlayers>),
+	/// Result flagged by a player, till an AjunaTEE re-confirms it.
+	Reconfirming(GameOutcome<AccountId, MaxPlayers>),
+	/// Game created for a later block, queued by the scheduler once it starts.
+	Scheduled,
 }
-impl<AccountId> Default for GameState<AccountId> {
+impl<AccountId, MaxPlayers> Default for GameState<AccountId, MaxPlayers> {
 	fn default() -> Self {
 		Self::None
 	}
 }
+impl<AccountId, MaxPlayers> GameState<AccountId, MaxPlayers> {
+	/// Whether the lifecycle of a game allows to move from this state to another one.
+	///
+	/// Games are queued, acknowledged by an AjunaTEE, run and finished. Scheduled games are queued
+	/// at their start block. Accepted games are queued again when they expire, running games when
+	/// their AjunaTEE fails after a checkpoint, or accepted again when their AjunaTEE restarted.
+	/// Results can be flagged, suspended and ruled on, ongoing games dropped and finished or
+	/// voided games removed from the registry.
+	pub fn can_transition_to(&self, to: &Self) -> bool {
+		use GameState::*;
+		matches!(
+			(self, to),
+			(None, Waiting) |
+				(None, Scheduled) |
+				(Scheduled, Waiting) |
+				(Waiting, Accepted) |
+				(Accepted, Running) |
+				(Accepted, Waiting) |
+				(Running, Waiting) |
+				(Running, Accepted) |
+				(Running, Finished(_)) |
+				(Finished(_), Reconfirming(_)) |
+				(Finished(_), Suspended(_)) |
+				(Reconfirming(_), Finished(_)) |
+				(Reconfirming(_), Suspended(_)) |
+				(Suspended(_), Finished(_)) |
+				(Scheduled, None) |
+				(Waiting, None) |
+				(Accepted, None) |
+				(Running, None) |
+				(Finished(_), None) |
+				(Suspended(_), None)
+		)
+	}
+}
+
+/// Outcome of a finished game, as reported by its AjunaTEE.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum GameOutcome<AccountId, MaxPlayers> {
+	/// A single player won the game.
+	Winner(AccountId),
+	/// The game ended in a draw between all its players.
+	Draw,
+	/// Every player ranked, from the first to the last place.
+	Ranked(BoundedVec<AccountId, MaxPlayers>),
+	/// The game ended without a result, its stakes are refunded.
+	Aborted,
+}
+impl<AccountId: Ord, MaxPlayers> GameOutcome<AccountId, MaxPlayers> {
+	/// The player in the first place, none for draws and aborted games.
+	pub fn winner(&self) -> Option<&AccountId> {
+		match self {
+			Self::Winner(winner) => Some(winner),
+			Self::Ranked(ranking) => ranking.first(),
+			Self::Draw | Self::Aborted => None,
+		}
+	}
+
+	/// Whether the outcome only names players of the game, rankings every one of them once.
+	pub fn fits(&self, players: &[AccountId]) -> bool {
+		match self {
+			Self::Winner(winner) => players.contains(winner),
+			Self::Ranked(ranking) => {
+				let mut ranked: Vec<_> = ranking.iter().collect();
+				let mut players: Vec<_> = players.iter().collect();
+				ranked.sort();
+				players.sort();
+				ranked == players
+			},
+			Self::Draw | Self::Aborted => true,
+		}
+	}
+}
+
+/// Final result of a game, reported to `OnGameResult` handlers.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum GameResult<AccountId, MaxPlayers> {
+	/// The game was finished with a winner.
+	Won(AccountId),
+	/// The game was dropped without a result.
+	Dropped,
+	/// The game ended in a draw.
+	Draw,
+	/// The game was finished with the players ranked from the first to the last place.
+	Ranked(BoundedVec<AccountId, MaxPlayers>),
+}
+impl<AccountId, MaxPlayers> From<GameOutcome<AccountId, MaxPlayers>>
+	for GameResult<AccountId, MaxPlayers>
+{
+	fn from(outcome: GameOutcome<AccountId, MaxPlayers>) -> Self {
+		match outcome {
+			GameOutcome::Winner(winner) => Self::Won(winner),
+			GameOutcome::Draw => Self::Draw,
+			GameOutcome::Ranked(ranking) => Self::Ranked(ranking),
+			GameOutcome::Aborted => Self::Dropped,
+		}
+	}
+}
 
 /// Connect four board structure containing two players and the board
-#[derive(Encode, Decode, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct GameEngine {
 	id: u8,
 	version: u8,
 }
+impl GameEngine {
+	pub fn new(id: u8, version: u8) -> Self {
+		Self { id, version }
+	}
+}
+
+/// Registration of a game engine version, every version is matched in its own bracket.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct EngineInfo {
+	bracket: u8,
+	/// Version new games are redirected to, once this version has been drained.
+	successor: Option<GameEngine>,
+	/// No new games are queued or matched while in maintenance.
+	maintenance: bool,
+}
+
+/// Compact record of a finalized game, committed to the MMR through the block results.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct GameResultLeaf<Hash, AccountId, BlockNumber, MaxPlayers> {
+	pub game_hash: Hash,
+	pub game_engine: GameEngine,
+	pub outcome: GameOutcome<AccountId, MaxPlayers>,
+	pub block: BlockNumber,
+}
+
+/// Prefix of the offchain index keys of finalized game records.
+pub const OFFCHAIN_GAME_PREFIX: &[u8] = b"gameregistry::game::";
+
+/// Prefix of the offchain index keys of the games finalized in a block.
+pub const OFFCHAIN_BLOCK_PREFIX: &[u8] = b"gameregistry::block::";
+
+/// Record of a finalized game exported to the offchain index, for indexer nodes.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct GameRecord<Hash, AccountId, BlockNumber, MaxPlayers> {
+	pub game_hash: Hash,
+	pub game_engine: GameEngine,
+	pub players: Vec<AccountId>,
+	pub outcome: GameOutcome<AccountId, MaxPlayers>,
+	pub block: BlockNumber,
+}
+
+/// Result of a finished game in the match history of a player.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MatchResult<Hash, AccountId, BlockNumber, MaxPlayers> {
+	pub game_hash: Hash,
+	pub game_engine: GameEngine,
+	pub opponents: BoundedVec<AccountId, MaxPlayers>,
+	pub outcome: GameOutcome<AccountId, MaxPlayers>,
+	pub block: BlockNumber,
+}
+
+/// Wins and losses of a player in a season.
+#[derive(
+	Encode, Decode, MaxEncodedLen, Default, Clone, Copy, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct SeasonTally {
+	pub wins: u32,
+	pub losses: u32,
+}
+
+/// Offchain storage key of the full leaderboard built by the offchain worker.
+pub const OFFCHAIN_LEADERBOARD_KEY: &[u8] = b"gameregistry::leaderboard";
+
+/// Prefix of the offchain storage keys of the blocks the offchain worker last submitted the
+/// expiry of a game in.
+pub const OFFCHAIN_EXPIRY_PREFIX: &[u8] = b"gameregistry::expiry::";
+
+/// Offchain storage key of the game registry key the offchain worker continues its scan for
+/// expired games after.
+pub const OFFCHAIN_EXPIRY_CURSOR_KEY: &[u8] = b"gameregistry::expiry_cursor";
+
+/// Game entries the offchain worker looks at for expired games in a block.
+const EXPIRY_SCAN_KEYS: u32 = 64;
+
+/// Entry of the full leaderboard built by the offchain worker.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct LeaderboardEntry<AccountId> {
+	pub player: AccountId,
+	pub wins: u32,
+	pub games: u32,
+	/// Share of players with less wins, in percent.
+	pub percentile: u8,
+}
+
+/// Prefix of the match codes players share their games with.
+pub const MATCH_CODE_PREFIX: &[u8] = b"AJN-";
+
+/// Characters of match codes, Crockford's base32 leaves out characters easily mistaken.
+pub const MATCH_CODE_ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Least amount of characters of a match code, after the prefix.
+const MATCH_CODE_MIN_LENGTH: usize = 4;
+
+/// Version of the canonical result encoding, bumped on any layout change.
+pub const CANONICAL_RESULT_VERSION: u8 = 2;
+
+/// Canonical encoding of a finalized result, all fields fixed size so the encoding has a fixed
+/// width of 72 bytes that can be decoded outside of substrate, e.g. on Ethereum.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CanonicalResult {
+	pub version: u8,
+	/// Game hash, zero padded to 32 bytes.
+	pub game_hash: [u8; 32],
+	pub engine_id: u8,
+	pub engine_version: u8,
+	/// Kind of the outcome, 0 for a winner, 1 for a draw, 2 for a ranking and 3 for an aborted
+	/// game.
+	pub outcome: u8,
+	/// Account in the first place, zero padded to 32 bytes, all zero without one.
+	pub winner: [u8; 32],
+	/// Block the game was finished, little endian.
+	pub finished_at: u32,
+}
+
+/// Time control preset of a game engine, enforced by the AjunaTEE.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TimeControl {
+	name: [u8; 8],
+	/// Initial time of each player in seconds.
+	initial_time: u32,
+	/// Time added after each move in seconds.
+	increment: u32,
+	/// Matchmaking bracket of players that selected this preset.
+	bracket: u8,
+}
+impl TimeControl {
+	pub fn new(name: [u8; 8], initial_time: u32, increment: u32, bracket: u8) -> Self {
+		Self { name, initial_time, increment, bracket }
+	}
+}
+
+/// Operational toggles of the registry, open to everyone by default.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PalletSettings {
+	/// Players in matchmaking are matched into games.
+	pub auto_matchmaking: bool,
+	/// Players can queue for games.
+	pub registrations_open: bool,
+	/// Spectators can register for games.
+	pub spectators_enabled: bool,
+	/// Only allowlisted accounts can queue, e.g. for a closed beta.
+	pub allowlist_only: bool,
+}
+impl Default for PalletSettings {
+	fn default() -> Self {
+		Self {
+			auto_matchmaking: true,
+			registrations_open: true,
+			spectators_enabled: true,
+			allowlist_only: false,
+		}
+	}
+}
+
+/// Entry point of the registry, that can be disabled on its own.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RegistryCall {
+	/// Queueing for games, local and foreign.
+	Queue,
+	/// Accepting matches.
+	AcceptMatch,
+	/// Conceding running games and aborting them by consent.
+	Concede,
+	/// Registering as spectator.
+	RegisterSpectator,
+	/// Acknowledging games by AjunaTEEs.
+	AckGame,
+	/// Starting and resuming games by AjunaTEEs.
+	ReadyGame,
+	/// Finishing games by AjunaTEEs, paying out results.
+	FinishGame,
+	/// Dropping games by AjunaTEEs.
+	DropGame,
+	/// Posting state checkpoints of running games by AjunaTEEs.
+	CheckpointGame,
+	/// Challenging friends to matches and accepting their invites.
+	CreateMatch,
+}
+
+/// Numeric parameter of the registry, settable without a runtime upgrade.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum RegistryParameter<BlockNumber> {
+	/// Blocks a waiting game can wait for an AjunaTEE, before it expires.
+	WaitingTimeout(BlockNumber),
+	/// Blocks an accepted game can wait to get ready, before it's queued again.
+	AcceptedTimeout(BlockNumber),
+	/// Blocks a game can run, before it expires without a result.
+	MaxGameDuration(BlockNumber),
+	/// Games an AjunaTEE can acknowledge in one batch.
+	AckBatchLimit(u32),
+	/// Games matched per block.
+	GamesPerBlock(u8),
+	/// Lifecycle calls an AjunaTEE can land per block.
+	TeeCallsPerBlock(u32),
+	/// Part of the queue bond slashed, when a player abandons a match.
+	AbandonmentSlash(Perbill),
+	/// Part of the report deposit slashed, when a report is dismissed.
+	FrivolousReportSlash(Perbill),
+	/// Weight matchmaking may use of the weight left over in a block.
+	MatchmakingWeight(Weight),
+	/// Weight the maintenance tasks may use of the weight left over by matchmaking.
+	MaintenanceWeight(Weight),
+}
+
+/// Numeric parameters of the registry, defaults come from the runtime configuration.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RegistryParameters<BlockNumber> {
+	pub waiting_timeout: BlockNumber,
+	pub accepted_timeout: BlockNumber,
+	pub max_game_duration: BlockNumber,
+	pub ack_batch_limit: u32,
+	pub games_per_block: u8,
+	pub tee_calls_per_block: u32,
+	pub abandonment_slash: Perbill,
+	pub frivolous_report_slash: Perbill,
+	pub matchmaking_weight: Weight,
+	pub maintenance_weight: Weight,
+}
+
+/// Who is allowed to spectate a game.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum SpectatorPolicy {
+	/// Anyone can spectate.
+	Open,
+	/// Only accounts invited by the players can spectate.
+	Invited,
+	/// Nobody can spectate.
+	Disabled,
+}
+
+/// Spectator policy and cap of a game, or the default of a game engine.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SpectatorSettings {
+	pub policy: SpectatorPolicy,
+	/// Maximum amount of spectators, at most `MaxSpectators`.
+	pub max_spectators: u32,
+}
+
+/// Time-boxed sanction applied to a player by moderation.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum SanctionKind {
+	/// The player can't queue for games.
+	QueueBan,
+	/// Wins of the player don't count towards the leaderboard.
+	RankedBan,
+}
+
+/// Reason a game expired, telling slow AjunaTEEs apart in monitoring.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ExpiryReason {
+	/// No AjunaTEE acknowledged the waiting game in time.
+	NotAcknowledged,
+	/// The AjunaTEE acknowledged the game, but didn't get it ready in time.
+	NotReady,
+	/// The game was running longer than the maximum game duration.
+	Overtime,
+}
+
+/// Task of the maintenance pipeline in `on_idle`, each task resumes where it ran out of weight.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum MaintenanceTask {
+	/// Expire the games past their timeouts.
+	Expiry,
+	/// Prune the finished games past their retention.
+	Pruning,
+	/// Remove games that aren't waiting anymore from the game queues.
+	StaleQueues,
+	/// Remove the lapsed sanctions of players.
+	Compaction,
+	/// Remove the match invites past their expiry.
+	Invites,
+}
+impl Default for MaintenanceTask {
+	fn default() -> Self {
+		Self::Expiry
+	}
+}
+impl MaintenanceTask {
+	/// Amount of maintenance tasks.
+	pub const COUNT: u32 = 5;
+
+	/// The task run after this one.
+	pub fn next(self) -> Self {
+		match self {
+			Self::Expiry => Self::Pruning,
+			Self::Pruning => Self::StaleQueues,
+			Self::StaleQueues => Self::Compaction,
+			Self::Compaction => Self::Invites,
+			Self::Invites => Self::Expiry,
+		}
+	}
+}
+
+/// Reason a player was taken out of matchmaking.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DequeueReason {
+	/// The player was matched for a game.
+	Matched,
+	/// The player left the queue.
+	Left,
+	/// The player was banned while queued.
+	Banned,
+	/// The game engine version was deprecated while the player was queued.
+	EngineDeprecated,
+	/// The player stopped meeting the game rules while queued.
+	RequirementNotMet,
+}
+
+/// Reason an AjunaTEE dropped a game before it was finished.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DropReason {
+	/// Players didn't show up for the game, bonds not released yet are slashed.
+	NoShow,
+	/// The AjunaTEE failed to run the game, the players are not to blame.
+	TeeFailure,
+	/// The game engine failed to run the game, it isn't resumed by another AjunaTEE.
+	EngineError,
+	/// The operator dropped the game, e.g. after an incident.
+	AdminAction,
+	/// The game was resumed more often than `MaxGameResumes` after restarts of its AjunaTEE.
+	ResumeLimit,
+}
+
+/// Record of a dropped game, kept for clients looking the game up till its retention is over.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct DroppedGame<AccountId, BlockNumber> {
+	pub reason: DropReason,
+	/// The AjunaTEE or the operator that dropped the game.
+	pub by: Option<AccountId>,
+	pub dropped: BlockNumber,
+}
+
+/// Fraud proof submitted against the result of a finished game.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct FraudProof<AccountId, Balance, BlockNumber> {
+	pub submitter: AccountId,
+	pub proof: BoundedVec<u8, MaxFraudProofLength>,
+	pub submitted: BlockNumber,
+	/// Reserved from the submitter, returned if the proof is upheld and slashed otherwise.
+	pub deposit: Balance,
+}
+
+/// Administrative action of the registry, kept in the audit log.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum AdminAction {
+	SetSettings,
+	SetParameter,
+	Pause,
+	Unpause,
+	SetCallDisabled,
+	DeregisterTee,
+	DrainGameQueue,
+	BanAccount,
+	UnbanAccount,
+	SanctionPlayer,
+	ResolveAppeal,
+	RuleOnFraudProof,
+	DropGames,
+	OverturnResult,
+	PauseEngine,
+	HandoverGames,
+	UnsuspendTee,
+	BanPlayer,
+	UnbanPlayer,
+	RegisterGameEngine,
+	DeprecateGameEngine,
+	UpgradeGameEngine,
+	SetGameRules,
+	SetGameRule,
+	ClearGameRules,
+	SetEngineStake,
+	RegisterTee,
+	ScheduleMaintenance,
+	AddToAllowlist,
+	RemoveFromAllowlist,
+	ResolveReport,
+	RegisterCluster,
+	SetClusterEngines,
+	SetResultConfirmation,
+}
+
+/// Entry of the audit log, the payload is kept as hash of the call arguments.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AuditEntry<AccountId, BlockNumber, Hash> {
+	pub action: AdminAction,
+	/// Signer of the action, none for origins without an account, e.g. root or a council.
+	pub actor: Option<AccountId>,
+	pub block: BlockNumber,
+	pub payload_hash: Hash,
+}
+
+/// Category of a report against a player.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ReportCategory {
+	Cheating,
+	Abuse,
+	Griefing,
+}
+
+/// Report filed by a player against an opponent, with the deposit backing it.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PlayerReport<AccountId, Hash, Balance> {
+	pub reporter: AccountId,
+	pub offender: AccountId,
+	pub game_hash: Hash,
+	pub category: ReportCategory,
+	pub deposit: Balance,
+}
+
+/// Activity of a player kept alongside its rating for a game engine id.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RatingActivity<BlockNumber> {
+	/// Rated games the player finished.
+	pub games_played: u32,
+	/// Block the player last finished a rated game in, or the rating last decayed up to.
+	pub last_played: BlockNumber,
+}
+
+/// Lifetime counters of the games of a game engine, or of all of the registry.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct GameCounters {
+	pub created: u64,
+	pub acknowledged: u64,
+	pub finished: u64,
+	pub dropped: u64,
+	pub expired: u64,
+}
+
+/// Aggregate metrics of the games of a game engine, updated as its games change their state.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct EngineMetrics {
+	/// Games an AjunaTEE ran and finished.
+	pub games_played: u64,
+	/// Games dropped without a result after an AjunaTEE acknowledged them.
+	pub games_abandoned: u64,
+	/// Blocks the acknowledged games waited in the queue, from queued till accepted.
+	pub queue_wait_blocks: u64,
+	/// Games acknowledged, the queue waits are counted for.
+	pub queue_waits: u64,
+	/// Players in games acknowledged or run by AjunaTEEs.
+	pub active_players: u32,
+}
+impl EngineMetrics {
+	/// Average blocks an acknowledged game waited in the queue.
+	pub fn average_queue_wait(&self) -> u64 {
+		self.queue_wait_blocks.checked_div(self.queue_waits).unwrap_or_default()
+	}
+
+	/// Share of the acknowledged games that were abandoned instead of played to the end.
+	pub fn abandonment_rate(&self) -> Perbill {
+		match self.games_played.saturating_add(self.games_abandoned) {
+			0 => Perbill::zero(),
+			total => Perbill::from_rational(self.games_abandoned, total),
+		}
+	}
+}
+
+/// Matchmaking preferences a player keeps across games.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PlayerPreferences {
+	/// Queue again once an AjunaTEE finished a game of the player.
+	pub auto_requeue: bool,
+	/// Game engine to queue again for, the game engine of the finished game if `None`.
+	pub engine_id: Option<u8>,
+	/// Time control preset to queue again with.
+	pub time_control: Option<u8>,
+	/// Region code the player prefers to be matched in.
+	pub region: Option<u8>,
+}
+
+/// Range of game client versions, both ends included.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ClientVersions {
+	pub min: u16,
+	pub max: u16,
+}
+
+impl ClientVersions {
+	/// The versions in both ranges, if they overlap.
+	pub fn overlap(&self, other: &Self) -> Option<Self> {
+		let versions = Self { min: self.min.max(other.min), max: self.max.min(other.max) };
+		(versions.min <= versions.max).then(|| versions)
+	}
+}
+
+/// Match a player challenged friends to, queued for an AjunaTEE once all of them accepted.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MatchInvite<AccountId, BlockNumber, MaxPlayers> {
+	pub game_engine: GameEngine,
+	/// The player that created the match.
+	pub creator: AccountId,
+	/// The creator and the players that accepted the invite.
+	pub accepted: BoundedVec<AccountId, MaxPlayers>,
+	/// Invited players that didn't accept yet.
+	pub pending: BoundedVec<AccountId, MaxPlayers>,
+	/// Block the invite expires in.
+	pub expires: BlockNumber,
+}
+
+/// Matchmaking details of a queued player.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct QueueInfo {
+	game_engine: GameEngine,
+	time_control: Option<u8>,
+	bracket: u8,
+}
+
+/// Game engine details, used by game launchers to discover available games.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct EngineDetails<GameRule> {
+	pub game_engine: GameEngine,
+	pub engine_info: EngineInfo,
+	pub game_rules: Vec<GameRule>,
+	/// Players can queue for the game engine.
+	pub enabled: bool,
+	pub queue_depth: u32,
+	pub tee_coverage: u32,
+}
+
+/// Running game listed for dashboards and spectator lobbies, players of private games are left
+/// out.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RunningGame<Hash, AccountId, BlockNumber> {
+	pub game_hash: Hash,
+	pub game_engine: GameEngine,
+	pub players: Vec<AccountId>,
+	pub tee: Option<AccountId>,
+	pub started_at: BlockNumber,
+}
+
+/// Last state commitment an AjunaTEE posted for a running game.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct GameCheckpoint<Hash, BlockNumber> {
+	pub state_root: Hash,
+	pub turn: u32,
+	pub block: BlockNumber,
+}
+
+/// Result of a game an AjunaTEE submits in a batch, see `finish_game` for the fields.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct GameSubmission<AccountId, Hash, MaxPlayers> {
+	pub game_hash: Hash,
+	pub outcome: GameOutcome<AccountId, MaxPlayers>,
+	pub final_state: Hash,
+	pub signature: Option<MultiSignature>,
+	pub replay: Option<Vec<u8>>,
+	pub result_proof: Option<Hash>,
+}
+
+/// Games an AjunaTEE acknowledged and is running.
+#[derive(
+	Encode, Decode, MaxEncodedLen, Default, Clone, Copy, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct TeeLoad {
+	pub accepted: u32,
+	pub running: u32,
+}
+
+impl TeeLoad {
+	pub fn total(&self) -> u32 {
+		self.accepted.saturating_add(self.running)
+	}
+}
+
+/// How the games of a game engine version are assigned to its AjunaTEEs.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum TeeAssignment {
+	/// Any AjunaTEE acknowledges the head of the game queue.
+	FirstCome,
+	/// Games are assigned to the AjunaTEEs in turn when they're queued.
+	RoundRobin,
+	/// Games are assigned to a random AjunaTEE when they're queued.
+	Random,
+}
+
+/// Registered AjunaTEE, with the game engine versions it is able to run.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TeeInfo {
+	supported_engines: EnginesOf,
+	/// Measurement of the attested enclave.
+	enclave: [u8; 32],
+	/// Endpoint clients connect to for their games.
+	endpoint: BoundedVec<u8, MaxEndpointLength>,
+	/// Key the enclave signs its results with.
+	signing_key: Option<AccountId32>,
+}
+
+/// Cluster of AjunaTEEs run by an operator, all running the game engine versions of the cluster.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TeeCluster<AccountId> {
+	/// Account adding and removing the AjunaTEEs of the cluster.
+	pub operator: AccountId,
+	/// Game engine versions served by the cluster.
+	pub engines: EnginesOf,
+	/// AjunaTEEs of the cluster, in the order they were added.
+	pub tees: BoundedVec<AccountId, MaxClusterTees>,
+}
+
+/// Game engine versions an AjunaTEE or a cluster of AjunaTEEs runs.
+pub type EnginesOf = BoundedVec<GameEngine, MaxTeeEngines>;
+
+/// Blocks a game went through the stages of its lifecycle, zero for stages not reached yet.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct GameTimestamps<BlockNumber> {
+	pub queued: BlockNumber,
+	pub accepted: BlockNumber,
+	pub started: BlockNumber,
+	pub finished: BlockNumber,
+}
+
+/// Maximum amount of state transitions kept in the timeline of a game.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxTransitions;
+impl Get<u32> for MaxTransitions {
+	fn get() -> u32 {
+		MAX_GAME_TRANSITIONS
+	}
+}
+
+/// Maximum length of the replay reference of a finished game.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxReplayLength;
+impl Get<u32> for MaxReplayLength {
+	fn get() -> u32 {
+		MAX_REPLAY_LENGTH
+	}
+}
+
+/// Maximum length of a match code, with its prefix.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxMatchCodeLength;
+impl Get<u32> for MaxMatchCodeLength {
+	fn get() -> u32 {
+		MAX_MATCH_CODE_LENGTH
+	}
+}
+
+/// Maximum length of the proof of a fraud proof.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxFraudProofLength;
+impl Get<u32> for MaxFraudProofLength {
+	fn get() -> u32 {
+		MAX_FRAUD_PROOF_LENGTH
+	}
+}
+
+/// Maximum length of the endpoint of an AjunaTEE.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxEndpointLength;
+impl Get<u32> for MaxEndpointLength {
+	fn get() -> u32 {
+		MAX_ENDPOINT_LENGTH
+	}
+}
+
+/// Maximum amount of game engine versions an AjunaTEE or a cluster runs.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxTeeEngines;
+impl Get<u32> for MaxTeeEngines {
+	fn get() -> u32 {
+		MAX_TEE_ENGINES
+	}
+}
+
+/// Maximum amount of AjunaTEEs of a cluster.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxClusterTees;
+impl Get<u32> for MaxClusterTees {
+	fn get() -> u32 {
+		MAX_CLUSTER_TEES
+	}
+}
+
+/// Maximum amount of time control presets of a game engine.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxTimeControls;
+impl Get<u32> for MaxTimeControls {
+	fn get() -> u32 {
+		MAX_TIME_CONTROLS
+	}
+}
+
+/// Maximum length of the raw storage keys the scans of the registry continue after.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxStorageKeyLength;
+impl Get<u32> for MaxStorageKeyLength {
+	fn get() -> u32 {
+		MAX_STORAGE_KEY_LENGTH
+	}
+}
+
+/// Maximum amount of tournaments starting their next round in a block.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxRoundsPerBlock;
+impl Get<u32> for MaxRoundsPerBlock {
+	fn get() -> u32 {
+		MAX_ROUNDS_PER_BLOCK
+	}
+}
+
+/// Maximum amount of finished games waiting in the settlement queue.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MaxSettlementQueue;
+impl Get<u32> for MaxSettlementQueue {
+	fn get() -> u32 {
+		MAX_SETTLEMENT_QUEUE
+	}
+}
 
 /// Connect four board structure containing two players and the board
-#[derive(Encode, Decode, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
-pub struct GameEntry<Hash, AccountId, GameEngine, GameState, BlockNumber> {
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct GameEntry<Hash, AccountId, GameEngine, GameState, BlockNumber, Location, MaxPlayers> {
 	id: Hash,
 	tee_id: Option<AccountId>,
 	game_engine: GameEngine,
-	players: Vec<AccountId>,
+	players: BoundedVec<AccountId, MaxPlayers>,
 	game_state: GameState,
-	state_change: [BlockNumber; 4],
+	timestamps: GameTimestamps<BlockNumber>,
+	/// Result of the game, once it's finished.
+	result: Option<GameResult<AccountId, MaxPlayers>>,
+	time_control: Option<TimeControl>,
+	/// Players that queued from another chain, with the location they queued from.
+	foreign_players: BoundedVec<(AccountId, Location), MaxPlayers>,
 }
 
 /// GameState structure, allowing Client & TEE to determine actions.
-#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum GameRuleType {
 	None,
+	/// Minimum and maximum amount of players of a game, matchmaking forms games of the maximum.
 	PlayersPerGame([u8; 2]),
+	/// Player must have played a first game at least this amount of blocks ago.
+	MinAccountAge(u32),
+	/// Player must have finished at least this amount of games.
+	MinFinishedGames(u32),
+	/// Player must hold at least this free balance.
+	MinBalance(u128),
+	/// Player must have at least this rating for the game engine.
+	MinRating(u32),
+	/// Player must own the asset with this id, e.g. an NFT unlocking the game.
+	RequiredAssetOwnership(u32),
 }
 impl Default for GameRuleType {
 	fn default() -> Self {
@@ -91,15 +1043,54 @@ impl Default for GameRuleType {
 }
 
 /// Connect four board structure containing two players and the board
-#[derive(Encode, Decode, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct GameRule<GameRuleType> {
 	game_rule_type: GameRuleType,
 	game_rule_info: [u8; 16],
 }
+impl<GameRuleType> GameRule<GameRuleType> {
+	pub fn new(game_rule_type: GameRuleType, game_rule_info: [u8; 16]) -> Self {
+		Self { game_rule_type, game_rule_info }
+	}
+}
 
 const GAMEREGISTRY_ID: LockIdentifier = *b"gameregi";
-const MAX_GAMES_PER_BLOCK: u8 = 10;
-const MAX_QUEUE_SIZE: u8 = 64;
+const EXPIRY_RESUBMIT_BLOCKS: u32 = 3;
+/// Blocks unsigned submissions of AjunaTEEs stay valid in the transaction pool.
+const TEE_SUBMISSION_LONGEVITY: u32 = 5;
+const MAX_ENDPOINT_LENGTH: u32 = 128;
+const MAX_ALLOWLIST_BATCH: usize = 100;
+const MAX_FRAUD_PROOF_LENGTH: u32 = 4096;
+const WAIT_SMOOTHING: u32 = 8;
+const MAX_RUNNING_GAMES_PAGE: u32 = 100;
+const MAX_REPLAY_LENGTH: u32 = 128;
+const MAX_TRANSCRIPT_LENGTH: u32 = 65_536;
+/// AjunaTEEs and games the sweep for offline AjunaTEEs looks at in a block.
+const MAX_TEE_TIMEOUT_SWEEP: u32 = 32;
+
+/// AjunaTEEs a cluster can have at most.
+pub const MAX_CLUSTER_TEES: u32 = 64;
+/// Game engine versions an AjunaTEE or a cluster can run at most.
+pub const MAX_TEE_ENGINES: u32 = 32;
+/// Time control presets of a game engine, indexed by a `u8`.
+const MAX_TIME_CONTROLS: u32 = u8::MAX as u32 + 1;
+/// Match codes of a `u64` index, the prefix and 13 base32 digits.
+const MAX_MATCH_CODE_LENGTH: u32 = 17;
+/// Longest raw key of the storage maps the registry scans, a prefix and two blake2 128 concat
+/// hashed accounts.
+const MAX_STORAGE_KEY_LENGTH: u32 = 128;
+/// Tournaments starting a round in a block, later ones start in the blocks after.
+const MAX_ROUNDS_PER_BLOCK: u32 = 16;
+/// Finished games waiting for `on_idle`, games finished beyond are settled right away.
+const MAX_SETTLEMENT_QUEUE: u32 = 1024;
+/// Blocks passed during a stepped migration whose deadlines are caught up on in a block.
+const MAX_MISSED_BLOCKS: u32 = 8;
+const MAX_GAME_TRANSITIONS: u32 = 16;
+const INITIAL_RATING: u32 = 1500;
+const RATING_K_FACTOR: u32 = 32;
+/// K-factor of the rated games of players still in their placement games.
+const PLACEMENT_K_FACTOR: u32 = 64;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -111,7 +1102,7 @@ pub mod pallet {
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
 		type Proposal: Parameter + Dispatchable<Origin = Self::Origin> + From<Call<Self>>;
 
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
@@ -120,445 +1111,8462 @@ pub mod pallet {
 		/// The generator used to supply randomness to contracts through `seal_random`.
 		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
 
+		/// Strategy generating the ids of new games, e.g. `traits::RandomGameIds` or
+		/// `traits::DeterministicGameIds` for chains without a good randomness source.
+		type GameIdGenerator: GameIdGenerator<Self::AccountId, Self::Hash>;
+
 		type Scheduler: Named<Self::BlockNumber, Self::Proposal, Self::PalletsOrigin>;
 
 		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
 
-		type MatchMaker: MatchFunc<Self::AccountId>;
+		/// Matchmaker of the queued players, e.g. `pallet_matchmaker` or a custom one.
+		type MatchMaker: Matchmaker<Self::AccountId>;
 
-		// /// Weight information for extrinsics in this pallet.
-		//type WeightInfo: WeightInfo;
-	}
+		/// Origin administrating the registry, e.g. a council or a multisig.
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
 
-	#[pallet::pallet]
-	#[pallet::generate_store(pub(super) trait Store)]
-	pub struct Pallet<T>(_);
+		/// Origin containing incidents, e.g. draining game queues and scheduling maintenance.
+		type OperatorOrigin: EnsureOrigin<Self::Origin>;
 
-	// The pallet's runtime storage items.
-	// https://substrate.dev/docs/en/knowledgebase/runtime/storage
-	#[pallet::storage]
-	#[pallet::getter(fn something)]
-	// Learn more about declaring storage items:
-	// https://substrate.dev/docs/en/knowledgebase/runtime/storage#declaring-storage-items
-	pub type Something<T> = StorageValue<_, u32>;
+		/// Origin moderating players, e.g. banning cheaters identified by AjunaTEE telemetry.
+		type ModerationOrigin: EnsureOrigin<Self::Origin>;
 
-	#[pallet::storage]
-	#[pallet::getter(fn founder_key)]
-	/// Founder key set in genesis, and maintained only for administration purpose.
-	pub type FounderKey<T: Config> = StorageValue<_, T::AccountId>;
+		/// Origin ruling on fraud proofs against game results.
+		type ArbitrationOrigin: EnsureOrigin<Self::Origin>;
 
-	#[pallet::storage]
-	#[pallet::getter(fn game_queues)]
-	/// Store all queues for the games.
-	pub type GameQueues<T: Config> =
-		StorageMap<_, Identity, GameEngine, Queue<T::Hash>, ValueQuery>;
+		/// The currency used for queue bonds.
+		type Currency: ReservableCurrency<Self::AccountId>;
 
-	#[pallet::storage]
-	#[pallet::getter(fn game_registry)]
-	/// Store all queues for the games.
-	pub type GameRegistry<T: Config> = StorageMap<
-		_,
-		Identity,
-		T::Hash,
-		GameEntry<T::Hash, T::AccountId, GameEngine, GameState<T::AccountId>, T::BlockNumber>,
-		ValueQuery,
-	>;
+		/// Bond reserved when queueing, released once the player accepts the match.
+		#[pallet::constant]
+		type QueueBond: Get<BalanceOf<Self>>;
+
+		/// Handler of the slashed queue bonds of players that didn't show up, e.g. the treasury.
+		type BondSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Handler notified about the lifecycle of games.
+		type GameObserver: GameObserver<Self::AccountId, Self::Hash, Self::MaxPlayersPerGame>;
+
+		/// Handlers of final game results, e.g. a betting pallet settling its markets.
+		type OnGameResult: OnGameResult<Self::AccountId, Self::Hash, Self::MaxPlayersPerGame>;
+
+		/// Call dispatched as root when a game starts running, e.g. to set up its board.
+		type GameStartCall: GameStartCall<Self::AccountId, Self::Hash, Self::Proposal>;
+
+		/// Ownership of the assets game rules can require, e.g. an NFT pallet.
+		type AssetOwnership: AssetOwnership<Self::AccountId>;
+
+		/// Handler of the reward points players earn, e.g. a rewards or assets pallet minting
+		/// them.
+		type RewardHandler: RewardHandler<Self::AccountId>;
+
+		/// Reward points the players of a settled game earn.
+		#[pallet::constant]
+		type RewardPoints: Get<RewardPoints>;
+
+		/// Location of players on other chains, e.g. a `MultiLocation`.
+		type ForeignLocation: Parameter + Default + MaxEncodedLen;
+
+		/// Origin of players queueing from other chains, e.g. `pallet_xcm::EnsureXcm`.
+		type ForeignOrigin: EnsureOrigin<Self::Origin, Success = Self::ForeignLocation>;
+
+		/// Converts the location of a foreign player into the account used in the registry.
+		type LocationToAccountId: Convert<Self::ForeignLocation, Self::AccountId>;
+
+		/// Reports results to foreign players, e.g. by sending an XCM message to their chain.
+		type ResultNotifier: ForeignResultNotifier<
+			Self::ForeignLocation,
+			Self::Hash,
+			Self::AccountId,
+			Self::MaxPlayersPerGame,
+		>;
+
+		/// Minimum of registered AjunaTEEs supporting a game engine, before players can queue.
+		#[pallet::constant]
+		type MinTees: Get<u32>;
+
+		/// Attestation of AjunaTEEs, unattested AjunaTEEs can't be registered.
+		type TeeAttestation: TeeAttestation<Self::AccountId>;
+
+		/// Blocks a waiting game can wait for an AjunaTEE, before it expires.
+		#[pallet::constant]
+		type WaitingTimeout: Get<Self::BlockNumber>;
+
+		/// Blocks an accepted game can wait to get ready, before it's queued again.
+		#[pallet::constant]
+		type AcceptedTimeout: Get<Self::BlockNumber>;
+
+		/// Blocks invited players have to accept a match, before the invite expires.
+		#[pallet::constant]
+		type InviteTimeout: Get<Self::BlockNumber>;
+
+		/// Blocks a game can run, before it expires without a result.
+		#[pallet::constant]
+		type MaxGameDuration: Get<Self::BlockNumber>;
+
+		/// Game entries migrated per block by a stepped storage migration.
+		#[pallet::constant]
+		type MigrationBatchSize: Get<u32>;
+
+		/// Maximum amount of spectators of a game.
+		#[pallet::constant]
+		type MaxSpectators: Get<u32>;
+
+		/// Ascending ratings separating the skill tiers of matchmaking. Game engine versions and
+		/// time controls own a bracket per tier, starting at their own bracket.
+		#[pallet::constant]
+		type RatingBoundaries: Get<Vec<u32>>;
+
+		/// Blocks without a finished game after which the rating of a player drifts towards the
+		/// initial rating, zero switches the decay off.
+		#[pallet::constant]
+		type DecayPeriod: Get<Self::BlockNumber>;
+
+		/// Part of the distance to the initial rating a rating drifts for every decay period.
+		#[pallet::constant]
+		type RatingDecay: Get<Perbill>;
+
+		/// First rated games of a player per game engine id, played with a higher K-factor to
+		/// place the player quickly.
+		#[pallet::constant]
+		type PlacementGames: Get<u32>;
+
+		/// Default of the ongoing games a player can be in per game engine, game engines with
+		/// asynchronous games can allow more.
+		#[pallet::constant]
+		type MaxGamesPerPlayer: Get<u32>;
+
+		/// Ongoing games a player can be in over all game engines.
+		#[pallet::constant]
+		type MaxCurrentGames: Get<u32>;
+
+		/// Amount of recent results kept in the match history of a player, older ones are pruned.
+		#[pallet::constant]
+		type MaxHistory: Get<u32>;
+
+		/// Maximum amount of game rules of a game engine version.
+		#[pallet::constant]
+		type MaxRulesPerEngine: Get<u32>;
+
+		/// Maximum amount of players of a game.
+		#[pallet::constant]
+		type MaxPlayersPerGame: Get<u32> + Clone + Default + PartialEq + Debug + TypeInfo;
+
+		/// Maximum length of the initialization data of a game, e.g. its board size or variant.
+		#[pallet::constant]
+		type MaxInitData: Get<u32>;
+
+		/// Deposit reserved when reporting a player, refunded if the report is upheld.
+		#[pallet::constant]
+		type ReportDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum amount of reports waiting for moderation.
+		#[pallet::constant]
+		type MaxReports: Get<u32>;
+
+		/// Blocks after a game finished, fraud proofs can be submitted against its result.
+		#[pallet::constant]
+		type FraudProofWindow: Get<Self::BlockNumber>;
+
+		/// Deposit reserved when submitting a fraud proof, returned if the proof is upheld.
+		#[pallet::constant]
+		type FraudProofDeposit: Get<BalanceOf<Self>>;
+
+		/// Blocks a finished game is kept in the registry before it's pruned to its compact
+		/// result, longer than the fraud proof window.
+		#[pallet::constant]
+		type GameRetention: Get<Self::BlockNumber>;
+
+		/// Blocks an AjunaTEE has to re-confirm a flagged result, before it goes to arbitration.
+		#[pallet::constant]
+		type ReconfirmationPeriod: Get<Self::BlockNumber>;
+
+		/// Amount of administrative actions kept in the audit log, older ones are overwritten.
+		#[pallet::constant]
+		type MaxAuditEntries: Get<u32>;
+
+		/// Blocks between matching and finishing a game, for it to count as quick pairing.
+		#[pallet::constant]
+		type CollusionWindow: Get<Self::BlockNumber>;
+
+		/// Quick pairings of the same players, past which collusion is suspected.
+		#[pallet::constant]
+		type CollusionThreshold: Get<u32>;
+
+		/// Blocks after a game started, before the AjunaTEE can report a player not showing up.
+		#[pallet::constant]
+		type NoShowGracePeriod: Get<Self::BlockNumber>;
+
+		/// No-shows of a player, after which it's banned from queueing.
+		#[pallet::constant]
+		type MaxNoShows: Get<u32>;
+
+		/// Blocks a player is banned from queueing for, after `MaxNoShows` no-shows.
+		#[pallet::constant]
+		type NoShowBanDuration: Get<Self::BlockNumber>;
+
+		/// Blocks the players of a finished game have to request a rematch.
+		#[pallet::constant]
+		type RematchWindow: Get<Self::BlockNumber>;
+
+		/// Players a tournament can have at most.
+		#[pallet::constant]
+		type MaxTournamentPlayers: Get<u32> + Clone + Default + PartialEq + Debug + TypeInfo;
+
+		/// Players a premade party can have at most, its leader included.
+		#[pallet::constant]
+		type MaxPartySize: Get<u32> + Clone + Default + PartialEq + Debug + TypeInfo;
+
+		/// Resumes of a running game after restarts of its AjunaTEE, past which the game is
+		/// dropped.
+		#[pallet::constant]
+		type MaxGameResumes: Get<u32>;
+
+		/// Combined wager above which a game is only run by AjunaTEEs of the high-value tier.
+		#[pallet::constant]
+		type HighValueWager: Get<BalanceOf<Self>>;
+
+		/// Bond an AjunaTEE has to hold to run games of the high-value tier.
+		#[pallet::constant]
+		type HighValueTeeBond: Get<BalanceOf<Self>>;
+
+		/// Part of the pot of a won game rewarded to the AjunaTEE running it, for its uptime.
+		#[pallet::constant]
+		type TeeRewardPercent: Get<Percent>;
+
+		/// Part of the pot of a won game taken as protocol fee.
+		#[pallet::constant]
+		type ProtocolFeePercent: Get<Percent>;
+
+		/// Handler of the protocol fees, e.g. the treasury.
+		type ProtocolFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Blocks an attestation stays valid, AjunaTEEs have to re-attest to get new games after.
+		#[pallet::constant]
+		type AttestationValidity: Get<Self::BlockNumber>;
+
+		/// Maximum amount of games an AjunaTEE has acknowledged or is running at a time.
+		#[pallet::constant]
+		type MaxGamesPerTee: Get<u32>;
+
+		/// Blocks an AjunaTEE can go unseen before its games are reassigned, zero switches the
+		/// reassignment off.
+		#[pallet::constant]
+		type TeeTimeout: Get<Self::BlockNumber>;
+
+		/// Amount of players kept in the on-chain leaderboard.
+		#[pallet::constant]
+		type LeaderboardSize: Get<u32>;
+
+		/// Blocks of a season, zero switches seasons off.
+		#[pallet::constant]
+		type SeasonLength: Get<Self::BlockNumber>;
+
+		/// Blocks players should wait for a match at most, longer waits degrade the bracket.
+		#[pallet::constant]
+		type MatchWaitSla: Get<Self::BlockNumber>;
+
+		/// Blocks between two registry heartbeats, zero switches them off.
+		#[pallet::constant]
+		type HeartbeatInterval: Get<Self::BlockNumber>;
+
+		/// Default weight matchmaking may use of the weight left over in a block, on top of the
+		/// limit of games per block.
+		#[pallet::constant]
+		type MatchmakingWeightLimit: Get<Weight>;
+
+		/// Default limit of games matched per block, within the matchmaking weight.
+		#[pallet::constant]
+		type MaxGamesPerBlock: Get<u8>;
+
+		/// Games the game queue of a game engine version holds at most, parties its party queue.
+		#[pallet::constant]
+		type MaxQueueSize: Get<u32>;
+
+		/// Default limit of games acknowledged, dropped or finished by an AjunaTEE in one batch.
+		#[pallet::constant]
+		type MaxAckBatch: Get<u32>;
+
+		/// Default limit of lifecycle calls an AjunaTEE lands in one block.
+		#[pallet::constant]
+		type MaxTeeCallsPerBlock: Get<u32>;
+
+		/// Blocks in a row an AjunaTEE can use up its lifecycle calls in, before it's suspended.
+		#[pallet::constant]
+		type MaxTeeThrottles: Get<u32>;
+
+		/// Default weight the maintenance tasks may use of the weight left over in a block, zero
+		/// switches them off.
+		#[pallet::constant]
+		type MaintenanceWeightLimit: Get<Weight>;
+
+		/// Whether the expiry of every ongoing game is scheduled at its deadline, besides the
+		/// maintenance and the offchain worker picking up expired games.
+		#[pallet::constant]
+		type ScheduledExpiry: Get<bool>;
+
+		/// Whether the stakes and ratings of a finished game are only settled once its fraud proof
+		/// window closed, instead of right when the AjunaTEE finishes it.
+		#[pallet::constant]
+		type DeferredSettlement: Get<bool>;
+
+		/// Finished games settled per block with the settlement queue in `on_idle`, instead of
+		/// right when the AjunaTEE finishes them. Zero settles them in the finishing call.
+		#[pallet::constant]
+		type SettlementsPerBlock: Get<u32>;
+
+		/// Games finishing in a block at most. Settlements, deadlines and the pruning of
+		/// finished games scheduled for a full block move on to the next block with room.
+		#[pallet::constant]
+		type MaxFinishedPerBlock: Get<u32>;
+
+		/// How games are assigned to AjunaTEEs. Assigned games are only acknowledged by their
+		/// AjunaTEE, in any order.
+		#[pallet::constant]
+		type TeeAssignment: Get<TeeAssignment>;
+
+		/// Blocks between two full leaderboards of the offchain worker, zero switches them off.
+		#[pallet::constant]
+		type LeaderboardInterval: Get<Self::BlockNumber>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	// The pallet's runtime storage items.
+	// https://substrate.dev/docs/en/knowledgebase/runtime/storage
+	#[pallet::storage]
+	/// Store the numeric parameters set by governance, the runtime defaults apply till then.
+	pub type Parameters<T: Config> = StorageValue<_, RegistryParameters<T::BlockNumber>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	/// Store whether the registry is paused, to contain incidents.
+	pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_banned)]
+	/// Store the accounts banned from playing.
+	pub type BannedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_allowlisted)]
+	/// Store the accounts allowed to queue, while the allowlist mode is on.
+	pub type Allowlist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn call_disabled)]
+	/// Store the entry points of the registry disabled on their own.
+	pub type DisabledCalls<T: Config> =
+		StorageMap<_, Blake2_128Concat, RegistryCall, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn settings)]
+	/// Store the operational toggles of the registry.
+	pub type Settings<T: Config> = StorageValue<_, PalletSettings, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dev_mode)]
+	/// Dev mode set in genesis, ordinary accounts stand in for AjunaTEEs on dev networks.
+	pub type DevMode<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	/// Store the head and tail of the game queue of each game engine version.
+	pub type GameQueues<T: Config> = StorageMap<_, Identity, GameEngine, QueueBounds, ValueQuery>;
+
+	#[pallet::storage]
+	/// Store the games waiting in the game queues by their position.
+	pub type GameQueueItems<T: Config> =
+		StorageDoubleMap<_, Identity, GameEngine, Twox64Concat, u32, T::Hash>;
+
+	#[pallet::storage]
+	/// Store the head and tail of the priority lane of the game queue of each game engine version.
+	pub type PriorityGameQueues<T: Config> =
+		StorageMap<_, Identity, GameEngine, QueueBounds, ValueQuery>;
+
+	#[pallet::storage]
+	/// Store the games waiting in the priority lanes of the game queues by their position.
+	pub type PriorityGameQueueItems<T: Config> =
+		StorageDoubleMap<_, Identity, GameEngine, Twox64Concat, u32, T::Hash>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_registry)]
+	/// Store all queues for the games.
+	pub type GameRegistry<T: Config> = StorageMap<_, Identity, T::Hash, GameEntryOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_requirements)]
+	/// Store all requirements for a sepecific game engine and it's version.
+	pub type GameRequirments<T: Config> = StorageMap<
+		_,
+		Identity,
+		GameEngine,
+		BoundedVec<GameRule<GameRuleType>, T::MaxRulesPerEngine>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn supported_engines)]
+	/// Store all registered game engine versions, that players can queue for.
+	pub type SupportedEngines<T: Config> = StorageMap<_, Identity, GameEngine, EngineInfo>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn deprecated_engines)]
+	/// Store the block game engine versions were deprecated at, they take no new games.
+	pub type DeprecatedEngines<T: Config> = StorageMap<_, Identity, GameEngine, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn paused_engines)]
+	/// Store the game engine versions stopped for an incident, they take no new games till
+	/// they're unpaused.
+	pub type PausedEngines<T: Config> = StorageMap<_, Identity, GameEngine, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn latest_engine_version)]
+	/// Store the latest registered version of each game engine id.
+	pub type LatestEngineVersion<T: Config> = StorageMap<_, Identity, u8, u8>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_brackets)]
+	/// Store which game engine version owns a matchmaking bracket.
+	pub type EngineBrackets<T: Config> = StorageMap<_, Identity, u8, GameEngine>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn registered_tees)]
+	/// Store all registered AjunaTEEs and the game engine versions they support.
+	pub type RegisteredTees<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, TeeInfo>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_tee_count)]
+	/// Store the amount of registered AjunaTEEs supporting a game engine version.
+	pub type EngineTeeCount<T: Config> = StorageMap<_, Identity, GameEngine, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tee_loads)]
+	/// Store the games an AjunaTEE acknowledged and is running.
+	pub type TeeLoads<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, TeeLoad, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn assignment_turn)]
+	/// Store the turn of the round-robin assignment of the AjunaTEEs of a game engine version.
+	pub type AssignmentTurns<T: Config> = StorageMap<_, Identity, GameEngine, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// Store the games an AjunaTEE acknowledged or is running, to reassign them if it goes
+	/// offline.
+	pub type TeeGames<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Identity, T::Hash, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn player_first_seen)]
+	/// Store the block a player was first matched into a game.
+	pub type PlayerFirstSeen<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn finished_games)]
+	/// Store the amount of finished games of a player.
+	pub type FinishedGames<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn match_history)]
+	/// Store the results of the latest finished games of a player, oldest first.
+	pub type MatchHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<MatchResultOf<T>, T::MaxHistory>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn queue_bonds)]
+	/// Store the queue bond held for a player, until the player accepted the match.
+	pub type QueueBonds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_stake)]
+	/// Store the stake players put into the pot of each game of a game engine version.
+	pub type EngineStakes<T: Config> = StorageMap<_, Identity, GameEngine, BalanceOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn queue_stakes)]
+	/// Store the stake reserved for a queued player, until the player is matched.
+	pub type QueueStakes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_stakes)]
+	/// Store the stakes of the players of a game, paid to the winner as pot.
+	pub type GameStakes<T: Config> = StorageMap<
+		_,
+		Identity,
+		T::Hash,
+		BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxPlayersPerGame>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bond_payers)]
+	/// Store the account paying the queue bond of a committed player.
+	pub type BondPayers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_private_game)]
+	/// Store the games keeping their players out of public events till they are finished.
+	pub type PrivateGames<T: Config> = StorageMap<_, Identity, T::Hash, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_tournament_game)]
+	/// Store the games created for tournaments, queued in the priority lane of their game queue.
+	pub type TournamentGames<T: Config> = StorageMap<_, Identity, T::Hash, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_replay)]
+	/// Store the content hash or CID of the replay of a finished game, produced by its AjunaTEE.
+	pub type GameReplays<T: Config> = StorageMap<_, Identity, T::Hash, ReplayOf>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_game_index)]
+	/// Store the sequential index of the next game, its match code is derived from it.
+	pub type NextGameIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn match_code)]
+	/// Store the short match code of a game.
+	pub type MatchCodes<T: Config> = StorageMap<_, Identity, T::Hash, MatchCode>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn resolve_match_code)]
+	/// Store the game of a match code, to resolve shared codes back to the game hash.
+	pub type MatchCodeGames<T: Config> = StorageMap<_, Blake2_128Concat, MatchCode, T::Hash>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_seed)]
+	/// Store the random seed of a game, the AjunaTEE derives the randomness of the game from it.
+	pub type GameSeeds<T: Config> = StorageMap<_, Identity, T::Hash, T::Hash>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_init_data)]
+	/// Store the initialization data of a game, the AjunaTEE sets up the game session from it.
+	pub type GameInitData<T: Config> = StorageMap<_, Identity, T::Hash, InitDataOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_client_versions)]
+	/// Store the game client versions a game engine version supports, if not every client.
+	pub type EngineClientVersions<T: Config> = StorageMap<_, Identity, GameEngine, ClientVersions>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn player_client)]
+	/// Store the versions the game client of a player supports, if the player set them.
+	pub type PlayerClients<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ClientVersions>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_client_version)]
+	/// Store the highest client version the players of a matched game all support.
+	pub type GameClientVersions<T: Config> = StorageMap<_, Identity, T::Hash, u16>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_teams)]
+	/// Store the teams of games matched out of premade parties, the players of each team.
+	pub type GameTeams<T: Config> = StorageMap<_, Identity, T::Hash, TeamsOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_max_games_per_player)]
+	/// Store the ongoing games a player can be in for a game engine, if it's not the default.
+	pub type EngineMaxGamesPerPlayer<T: Config> = StorageMap<_, Identity, GameEngine, u32>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn player_games)]
+	/// Store the amount of scheduled, waiting, accepted and running games of a player, per game
+	/// engine id.
+	pub type PlayerGames<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u8, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn player_rating)]
+	/// Store the Elo rating of players per game engine id, players start at `INITIAL_RATING`.
+	pub type PlayerRating<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u8, u32>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rating_activity)]
+	/// Store the rated games of players per game engine id and the block they last played one
+	/// in, for placement and rating decay.
+	pub type RatingActivities<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u8,
+		RatingActivity<T::BlockNumber>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn win_streak)]
+	/// Store the games players won in a row per game engine id.
+	pub type WinStreaks<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u8, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn lifetime_points)]
+	/// Store the reward points players earned with all their settled games.
+	pub type LifetimePoints<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn current_games)]
+	/// Store the scheduled, waiting, accepted and running games of a player, oldest first.
+	pub type CurrentGames<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::Hash, T::MaxCurrentGames>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn archived_game)]
+	/// Store the finished games moved out of the registry, till they're pruned after
+	/// `GameRetention` blocks.
+	pub type ArchivedGames<T: Config> = StorageMap<_, Identity, T::Hash, GameEntryOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_transitions)]
+	/// Store the states the games in the registry or the archive moved into, with the blocks
+	/// they moved in, oldest first.
+	pub type GameTimelines<T: Config> = StorageMap<_, Identity, T::Hash, TimelineOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn final_state)]
+	/// Store the hash of the terminal state of a finished game, alongside its result.
+	pub type FinalStates<T: Config> = StorageMap<_, Identity, T::Hash, T::Hash>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn result_proof)]
+	/// Store the hash of the transcript of a finished game its AjunaTEE reported the result with,
+	/// kept after the game is pruned.
+	pub type ResultProofs<T: Config> = StorageMap<_, Identity, T::Hash, T::Hash>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_checkpoint)]
+	/// Store the last checkpoint of a game, another AjunaTEE resumes the game from it.
+	pub type GameCheckpoints<T: Config> =
+		StorageMap<_, Identity, T::Hash, GameCheckpoint<T::Hash, T::BlockNumber>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn resume_count)]
+	/// Store how often a running game was resumed after restarts of its AjunaTEE.
+	pub type GameResumes<T: Config> = StorageMap<_, Identity, T::Hash, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_wagers)]
+	/// Store the combined wager of the players of a game, if it's played for stakes.
+	pub type GameWagers<T: Config> = StorageMap<_, Identity, T::Hash, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tee_bonds)]
+	/// Store the bond an AjunaTEE reserved, backing the games it runs.
+	pub type TeeBonds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_cluster_id)]
+	/// Store the id of the next cluster of AjunaTEEs.
+	pub type NextClusterId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn clusters)]
+	/// Store the clusters of AjunaTEEs by their id.
+	pub type Clusters<T: Config> = StorageMap<_, Twox64Concat, u32, TeeClusterOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tee_cluster)]
+	/// Store the cluster an AjunaTEE belongs to.
+	pub type TeeClusterIds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn active_games)]
+	/// Store the amount of games of a game engine acknowledged or run by AjunaTEEs.
+	pub type ActiveGames<T: Config> = StorageMap<_, Blake2_128Concat, GameEngine, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_counters)]
+	/// Store the lifetime counters of the games of each game engine.
+	pub type EngineCounters<T: Config> =
+		StorageMap<_, Blake2_128Concat, GameEngine, GameCounters, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn total_counters)]
+	/// Store the lifetime counters of all games of the registry.
+	pub type TotalCounters<T: Config> = StorageValue<_, GameCounters, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_stats)]
+	/// Store the aggregate metrics of the games of each game engine, for operators and
+	/// dashboards.
+	pub type EngineStats<T: Config> =
+		StorageMap<_, Blake2_128Concat, GameEngine, EngineMetrics, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn queued_players)]
+	/// Store the game engine version and time control each player in matchmaking queued for.
+	pub type QueuedPlayers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, QueueInfo>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn player_preferences)]
+	/// Store the matchmaking preferences of players.
+	pub type Preferences<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, PlayerPreferences>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn match_invites)]
+	/// Store the matches players challenged friends to, till all invited players accepted.
+	pub type MatchInvites<T: Config> = StorageMap<_, Identity, T::Hash, MatchInviteOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn invite_init_data)]
+	/// Store the initialization data the creator of a match set, till its game is queued.
+	pub type InviteInitData<T: Config> = StorageMap<_, Identity, T::Hash, InitDataOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn invite_start)]
+	/// Store the block the creator of a match set its game to start at, till its game is queued.
+	pub type InviteStarts<T: Config> = StorageMap<_, Identity, T::Hash, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn queued_since)]
+	/// Store the block each player in matchmaking queued at.
+	pub type QueuedSince<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn estimated_wait)]
+	/// Store the exponentially weighted average of the queue-to-match time of a bracket.
+	pub type EstimatedWaits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		GameEngine,
+		Twox64Concat,
+		u8,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_degraded)]
+	/// Store the brackets waiting longer than `MatchWaitSla` for their matches.
+	pub type DegradedBrackets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, GameEngine, Twox64Concat, u8, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn left_players)]
+	/// Store the bracket of players that left the queue, skipped once the matchmaker matches them.
+	pub type LeftPlayers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u8>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn forming_parties)]
+	/// Store the matched players of a bracket waiting for more players, for game engines with
+	/// more players per game than the matchmaker matches.
+	pub type FormingParties<T: Config> =
+		StorageMap<_, Twox64Concat, u8, BoundedVec<T::AccountId, T::MaxPlayersPerGame>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn parties)]
+	/// Store the premade parties by their leader, till they are matched or disbanded.
+	pub type Parties<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, PartyOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn party_queue)]
+	/// Store the leaders of the parties queued for a game engine version, in the order they
+	/// queued.
+	pub type PartyQueues<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		GameEngine,
+		BoundedVec<T::AccountId, T::MaxQueueSize>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn audit_log_count)]
+	/// Store the amount of administrative actions ever logged.
+	pub type AuditLogCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn audit_log)]
+	/// Store the audit log of administrative actions, as ring buffer of `MaxAuditEntries` slots.
+	pub type AuditLog<T: Config> =
+		StorageMap<_, Twox64Concat, u32, AuditEntry<T::AccountId, T::BlockNumber, T::Hash>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sanctions)]
+	/// Store the block until which a sanction of a player lasts.
+	pub type Sanctions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		SanctionKind,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn no_show_strikes)]
+	/// Store the no-shows of a player since its last ban from queueing.
+	pub type NoShowStrikes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn appeals)]
+	/// Store the sanctions appealed by players, waiting for governance.
+	pub type Appeals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		SanctionKind,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pairing_count)]
+	/// Store how often two players got matched and finished within the `CollusionWindow`.
+	pub type PairingCounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn fraud_proofs)]
+	/// Store the fraud proofs against suspended game results.
+	pub type FraudProofs<T: Config> =
+		StorageMap<_, Identity, T::Hash, FraudProof<T::AccountId, BalanceOf<T>, T::BlockNumber>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn result_flags)]
+	/// Store the player that flagged a result, waiting for re-confirmation.
+	pub type ResultFlags<T: Config> = StorageMap<_, Identity, T::Hash, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn confirms_results)]
+	/// Store the game engine versions whose players confirm the results of their games, the
+	/// results are settled once all players confirmed or the fraud proof window closed.
+	pub type ResultConfirmationEngines<T: Config> =
+		StorageMap<_, Identity, GameEngine, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn result_confirmations)]
+	/// Store the players that confirmed the result of a finished game.
+	pub type ResultConfirmations<T: Config> = StorageMap<
+		_,
+		Identity,
+		T::Hash,
+		BoundedVec<T::AccountId, T::MaxPlayersPerGame>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reconfirmation_deadlines)]
+	/// Store the flagged results going to arbitration in a block, if not re-confirmed till then.
+	pub type ReconfirmationDeadlines<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, BlockGamesOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_settlements)]
+	/// Store the finished games settled in a block, once their fraud proof window closed.
+	pub type PendingSettlements<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, BlockGamesOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn settlement_queue)]
+	/// Store the finished games waiting to be settled, in the order they finished.
+	pub type SettlementQueue<T: Config> =
+		StorageValue<_, BoundedVec<T::Hash, MaxSettlementQueue>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rematch_requests)]
+	/// Store the players of a finished game that requested a rematch.
+	pub type RematchRequests<T: Config> = StorageMap<
+		_,
+		Identity,
+		T::Hash,
+		BoundedVec<T::AccountId, T::MaxPlayersPerGame>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rematch_deadlines)]
+	/// Store the rematch requests expiring in a block, if not requested by all players till then.
+	pub type RematchDeadlines<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, BlockGamesOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn abort_proposals)]
+	/// Store the players of a running game that agreed to abort it.
+	pub type AbortProposals<T: Config> = StorageMap<
+		_,
+		Identity,
+		T::Hash,
+		BoundedVec<T::AccountId, T::MaxPlayersPerGame>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_tournament_id)]
+	/// Store the id of the next tournament.
+	pub type NextTournamentId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tournaments)]
+	/// Store the tournaments by their id.
+	pub type Tournaments<T: Config> = StorageMap<_, Twox64Concat, u32, TournamentOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tournament_matches)]
+	/// Store the tournament and the match in its current round of each tournament game.
+	pub type TournamentMatches<T: Config> = StorageMap<_, Identity, T::Hash, (u32, u32)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tournament_rounds)]
+	/// Store the tournaments starting their next round in a block.
+	pub type TournamentRounds<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<u32, MaxRoundsPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_report_id)]
+	/// Store the id of the next report.
+	pub type NextReportId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reports)]
+	/// Store the reports waiting for moderation.
+	pub type Reports<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, PlayerReport<T::AccountId, T::Hash, BalanceOf<T>>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn report_queue)]
+	/// Store the moderation queue, ids of the reports in filing order.
+	pub type ReportQueue<T: Config> = StorageValue<_, BoundedVec<u32, T::MaxReports>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn spectators)]
+	/// Store the spectators of a game, the AjunaTEE allows them to stream the game.
+	pub type Spectators<T: Config> =
+		StorageMap<_, Identity, T::Hash, BoundedVec<T::AccountId, T::MaxSpectators>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_spectator_settings)]
+	/// Store the default spectator settings of the games of a game engine.
+	pub type EngineSpectatorSettings<T: Config> =
+		StorageMap<_, Identity, GameEngine, SpectatorSettings>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn engine_init_data)]
+	/// Store the default initialization data of the games of a game engine version.
+	pub type EngineInitData<T: Config> = StorageMap<_, Identity, GameEngine, InitDataOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn game_spectator_settings)]
+	/// Store the spectator settings the players have set for their game.
+	pub type GameSpectatorSettings<T: Config> = StorageMap<_, Identity, T::Hash, SpectatorSettings>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn spectator_invites)]
+	/// Store the accounts the players have invited to spectate their game.
+	pub type SpectatorInvites<T: Config> =
+		StorageDoubleMap<_, Identity, T::Hash, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn session_keys)]
+	/// Store the main account of a session key, which plays on its behalf.
+	pub type SessionKeys<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn session_key_proposal)]
+	/// Store the main account proposing a session key, till the session key accepts it.
+	pub type SessionKeyProposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn session_key_count)]
+	/// Store the amount of session keys playing on behalf of a main account.
+	pub type SessionKeyCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_players)]
+	/// Store the location of players in matchmaking, that queued from another chain.
+	pub type ForeignPlayers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::ForeignLocation>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn migration_cursor)]
+	/// Store the raw key of the last game entry migrated, while a stepped migration is ongoing.
+	pub type MigrationCursor<T: Config> = StorageValue<_, RawStorageKey>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn deadline_cursor)]
+	/// Store the first block whose deadlines, settlements and tournament rounds are still to be
+	/// processed, for the blocks passed during a stepped migration.
+	pub type DeadlineCursor<T: Config> = StorageValue<_, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn attested_at)]
+	/// Store the block the attestation of an AjunaTEE was verified at.
+	pub type AttestedAt<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tee_last_seen)]
+	/// Store the last block an AjunaTEE was observed alive, by a block author or its own
+	/// heartbeat.
+	pub type TeeLastSeen<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::storage]
+	/// Store whether the liveness of the AjunaTEEs was noted in the current block.
+	pub type TeeLivenessNoted<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tee_result_nonce)]
+	/// Store the nonce of the next result signed by an AjunaTEE enclave, against replays.
+	pub type TeeResultNonce<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// Store the lifecycle calls landed by each AjunaTEE in the current block.
+	pub type TeeCallCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tee_throttles)]
+	/// Store the blocks in a row each AjunaTEE used up its lifecycle calls in.
+	pub type TeeThrottles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn suspended_tees)]
+	/// Store the AjunaTEEs suspended for flooding the registry, with the block they were
+	/// suspended in, till governance reviewed them.
+	pub type SuspendedTees<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn games_won)]
+	/// Store the amount of games won by a player.
+	pub type GamesWon<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn leaderboard)]
+	/// Store the top players by games won, the full leaderboard is built offchain.
+	pub type Leaderboard<T: Config> = StorageValue<_, LeaderboardOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn current_season)]
+	/// Store the index of the ongoing season.
+	pub type CurrentSeason<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn season_tallies)]
+	/// Store the wins and losses of the players per season, past seasons are kept.
+	pub type SeasonTallies<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		SeasonTally,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn season_leaderboard)]
+	/// Store the top players by games won in the ongoing season.
+	pub type SeasonLeaderboard<T: Config> = StorageValue<_, LeaderboardOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn season_champions)]
+	/// Store the top players of the past seasons.
+	pub type SeasonChampions<T: Config> =
+		StorageMap<_, Twox64Concat, u32, LeaderboardOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn block_result_leaves)]
+	/// Store the leaf hashes of the games finalized in the current block.
+	pub type BlockResultLeaves<T: Config> = StorageValue<_, BlockGamesOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn block_finished_games)]
+	/// Store the games finalized in the current block, exported to the offchain index.
+	pub type BlockFinishedGames<T: Config> = StorageValue<_, BlockGamesOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn results_commitment)]
+	/// Store the commitment to the results of the last block, provided as MMR leaf data.
+	pub type ResultsCommitment<T: Config> = StorageValue<_, T::Hash, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn canonical_result_hashes)]
+	/// Store the keccak hash of the canonical result encoding of finished games.
+	pub type CanonicalResultHashes<T: Config> = StorageMap<_, Identity, T::Hash, [u8; 32]>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dropped_game)]
+	/// Store why a game was dropped, removed with the games pruned in the block it was dropped.
+	pub type DroppedGames<T: Config> =
+		StorageMap<_, Identity, T::Hash, DroppedGame<T::AccountId, T::BlockNumber>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn prune_schedule)]
+	/// Store the games finished in a block, pruned once their retention is over.
+	pub type PruneSchedule<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, BlockGamesOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn prune_cursor)]
+	/// Store the next block to prune the finished games of.
+	pub type PruneCursor<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn maintenance_step)]
+	/// Store the maintenance task to start with in the next block.
+	pub type MaintenanceStep<T: Config> = StorageValue<_, MaintenanceTask, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn maintenance_cursor)]
+	/// Store the raw storage key a maintenance task continues its scan after.
+	pub type MaintenanceCursors<T: Config> =
+		StorageMap<_, Twox64Concat, MaintenanceTask, RawStorageKey>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tee_timeout_cursor)]
+	/// Store the raw storage key the sweep for offline AjunaTEEs continues after.
+	pub type TeeTimeoutCursor<T: Config> = StorageValue<_, RawStorageKey>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn time_controls)]
+	/// Store the time control presets of a game engine.
+	pub type TimeControls<T: Config> =
+		StorageMap<_, Identity, GameEngine, BoundedVec<TimeControl, MaxTimeControls>, ValueQuery>;
+
+	// Default value for Nonce
+	#[pallet::type_value]
+	pub fn NonceDefault<T: Config>() -> u64 {
+		0
+	}
+	// Nonce used for generating a different seed each time.
+	#[pallet::storage]
+	pub type Nonce<T: Config> = StorageValue<_, u64, ValueQuery, NonceDefault<T>>;
+
+	// The genesis config type.
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Relax the AjunaTEE requirements, never enable it on a public network.
+		pub dev_mode: bool,
+		/// Game engine versions registered at genesis, with their bracket.
+		pub game_engines: Vec<(GameEngine, u8)>,
+		/// Game rules of game engines registered at genesis.
+		pub game_rules: Vec<(GameEngine, Vec<GameRule<GameRuleType>>)>,
+		/// AjunaTEEs registered at genesis, with the game engines they run.
+		pub tees: Vec<(T::AccountId, Vec<GameEngine>)>,
+		/// Numeric parameters, the runtime defaults are used without.
+		pub parameters: Option<RegistryParameters<T::BlockNumber>>,
+		/// Start the registry paused.
+		pub paused: bool,
+		/// Game engines of the genesis paused from the start.
+		pub paused_engines: Vec<GameEngine>,
+		/// Entry points of the registry disabled from the start.
+		pub disabled_calls: Vec<RegistryCall>,
+	}
+
+	// The default value for the genesis config type.
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				dev_mode: false,
+				game_engines: Vec::new(),
+				game_rules: Vec::new(),
+				tees: Vec::new(),
+				parameters: None,
+				paused: false,
+				paused_engines: Vec::new(),
+				disabled_calls: Vec::new(),
+			}
+		}
+	}
+
+	// The build of genesis for the pallet.
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			<DevMode<T>>::put(self.dev_mode);
+
+			for (game_engine, bracket) in self.game_engines.iter() {
+				Pallet::<T>::do_register_game_engine(game_engine.clone(), *bracket)
+					.expect("game engines and brackets of the genesis are unique");
+			}
+
+			for (game_engine, game_rules) in self.game_rules.iter() {
+				assert!(
+					SupportedEngines::<T>::contains_key(game_engine),
+					"game rules of the genesis are for registered game engines"
+				);
+				let game_rules =
+					BoundedVec::<_, T::MaxRulesPerEngine>::try_from(game_rules.clone())
+						.expect("game rules of the genesis are within `MaxRulesPerEngine`");
+				<GameRequirments<T>>::insert(game_engine, game_rules);
+			}
+
+			// AjunaTEEs of the genesis are trusted, attested or not
+			for (tee, supported_engines) in self.tees.iter() {
+				let supported_engines = Pallet::<T>::dedup_engines(supported_engines.clone())
+					.expect("AjunaTEEs of the genesis run at most `MAX_TEE_ENGINES` game engines");
+				Pallet::<T>::update_engine_tee_count(&[], &supported_engines);
+				let enclave = T::TeeAttestation::enclave_of(tee).unwrap_or([0; 32]);
+				let signing_key = T::TeeAttestation::signing_key_of(tee);
+				<RegisteredTees<T>>::insert(
+					tee,
+					TeeInfo {
+						supported_engines,
+						enclave,
+						endpoint: Default::default(),
+						signing_key,
+					},
+				);
+				<AttestedAt<T>>::insert(tee, T::BlockNumber::zero());
+			}
+
+			if let Some(parameters) = &self.parameters {
+				<Parameters<T>>::put(parameters);
+			}
+
+			if self.paused {
+				<Paused<T>>::put(true);
+			}
+			for game_engine in self.paused_engines.iter() {
+				assert!(
+					SupportedEngines::<T>::contains_key(game_engine),
+					"paused game engines of the genesis are registered"
+				);
+				<PausedEngines<T>>::insert(game_engine, true);
+			}
+			for call in self.disabled_calls.iter() {
+				<DisabledCalls<T>>::insert(call, true);
+			}
+		}
+	}
+
+	// Pallets use events to inform users when important changes are made.
+	// https://substrate.dev/docs/en/knowledgebase/runtime/events
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Operational toggles of the registry set. [settings]
+		SettingsSet(PalletSettings),
+
+		/// Numeric parameter of the registry set. [parameter]
+		ParameterSet(RegistryParameter<T::BlockNumber>),
+
+		/// Registry paused, no queueing, matchmaking or AjunaTEE calls.
+		RegistryPaused,
+
+		/// Registry unpaused.
+		RegistryUnpaused,
+
+		/// Entry point of the registry disabled or enabled. [call, disabled]
+		CallDisabledSet(RegistryCall, bool),
+
+		// Player has queued to play.
+		PlayerQueued(T::AccountId),
+
+		/// Player taken out of matchmaking. [player, reason]
+		PlayerDequeued(T::AccountId, DequeueReason),
+
+		/// Matchmaking preferences of a player set or cleared. [player]
+		PreferencesSet(T::AccountId),
+
+		/// Player with auto-requeue couldn't be queued again after a game. [player, error]
+		AutoRequeueFailed(T::AccountId, DispatchError),
+
+		/// Player from another chain has queued to play. [player, location]
+		ForeignPlayerQueued(T::AccountId, T::ForeignLocation),
+
+		/// Result leaf of a finalized game, committed with the block results. [game_hash, leaf_hash]
+		ResultLeafAppended(T::Hash, T::Hash),
+
+		/// Canonical encoding of a finalized result. [game_hash, result]
+		GameResultEncoded(T::Hash, CanonicalResult),
+
+		/// Finished game pruned from the registry after its retention, only the hash of its
+		/// canonical result is kept. [game_hash]
+		GamePruned(T::Hash),
+
+		/// Games that aren't waiting anymore removed from a game queue. [game_engine, removed]
+		StaleGamesRemoved(GameEngine, u32),
+
+		/// Lapsed sanction of a player removed. [player, sanction]
+		SanctionLapsed(T::AccountId, SanctionKind),
+
+		/// Game result reported to the chain of a foreign player. [game_hash, location]
+		ForeignResultSent(T::Hash, T::ForeignLocation),
+
+		/// Game result couldn't be reported to the chain of a foreign player. [game_hash, location]
+		ForeignResultFailed(T::Hash, T::ForeignLocation),
+
+		/// Queue bond reserved for a player. [player, amount]
+		QueueBondReserved(T::AccountId, BalanceOf<T>),
+
+		/// Queue bond released back to a player. [player, amount]
+		QueueBondReleased(T::AccountId, BalanceOf<T>),
+
+		/// Queue bond slashed, player failed the ready-check. [player, amount]
+		QueueBondSlashed(T::AccountId, BalanceOf<T>),
+
+		/// Stake of a game engine set, `None` for games without stakes. [game_engine, stake]
+		EngineStakeSet(GameEngine, Option<BalanceOf<T>>),
+
+		/// Stake reserved for the pot of the next game of a player. [player, amount]
+		StakeReserved(T::AccountId, BalanceOf<T>),
+
+		/// Stake released back to a player that left the queue. [player, amount]
+		StakeReleased(T::AccountId, BalanceOf<T>),
+
+		/// Pot of a game paid to its winner, less the reward and the fee. [game_hash, winner, pot]
+		PotPaid(T::Hash, T::AccountId, BalanceOf<T>),
+
+		/// AjunaTEE rewarded from the pot of a game it ran. [game_hash, tee, reward]
+		TeeRewarded(T::Hash, T::AccountId, BalanceOf<T>),
+
+		/// Protocol fee taken from the pot of a game. [game_hash, fee]
+		ProtocolFeePaid(T::Hash, BalanceOf<T>),
+
+		/// Stakes of a game without a result refunded to its players. [game_hash]
+		StakesRefunded(T::Hash),
+
+		/// Player accepted the match of a game. [game_hash, player]
+		MatchAccepted(T::Hash, T::AccountId),
+
+		/// Player challenged friends to a match. [match_id, creator, opponents]
+		MatchCreated(T::Hash, T::AccountId, Vec<T::AccountId>),
+
+		/// Invited player accepted a match. [match_id, player]
+		InviteAccepted(T::Hash, T::AccountId),
+
+		/// All invited players accepted, the game of the match is queued. [match_id, game_hash]
+		MatchStarted(T::Hash, T::Hash),
+
+		/// Game created for a later block, it's queued once it starts.
+		/// [game_hash, game_engine, start_at]
+		GameScheduled(T::Hash, GameEngine, T::BlockNumber),
+
+		/// Game couldn't be created, nothing of it is kept. [game_engine, players, error]
+		GameCreationFailed(GameEngine, Vec<T::AccountId>, DispatchError),
+
+		/// Match invite expired before all invited players accepted. [match_id]
+		InviteExpired(T::Hash),
+
+		/// Committed player revealed its identity for a game. [game_hash, player]
+		IdentityRevealed(T::Hash, T::AccountId),
+
+		/// Players of a private game made public, once it's finished. [game_hash, players]
+		PrivatePlayersRevealed(T::Hash, Vec<T::AccountId>),
+
+		/// Players quickly paired past the collusion threshold, for moderation. [player, player, count]
+		SuspectedCollusion(T::AccountId, T::AccountId, u32),
+
+		/// Game engine version registered for matchmaking. [game_engine, bracket]
+		GameEngineRegistered(GameEngine, u8),
+
+		/// Game engine version drained into a newer version. [from, to, games_moved]
+		GameEngineUpgraded(GameEngine, GameEngine, u32),
+
+		/// Game engine version deprecated, its games still finish. [game_engine]
+		GameEngineDeprecated(GameEngine),
+
+		/// Maintenance window scheduled for a game engine. [game_engine, start, end]
+		MaintenanceScheduled(GameEngine, T::BlockNumber, T::BlockNumber),
+
+		/// Game engine maintenance started or ended. [game_engine, in_maintenance]
+		EngineMaintenance(GameEngine, bool),
+
+		/// Game engine paused or unpaused. [game_engine, paused]
+		EnginePaused(GameEngine, bool),
+
+		/// Game rules of a game engine replaced. [game_engine]
+		GameRulesSet(GameEngine),
+
+		/// Game rules of a game engine cleared. [game_engine]
+		GameRulesCleared(GameEngine),
+
+		/// Time control preset added to a game engine. [game_engine, index]
+		TimeControlAdded(GameEngine, u8),
+
+		/// AjunaTEE registered or updated with its supported game engines. [tee]
+		TeeRegistered(T::AccountId),
+
+		/// AjunaTEE published the endpoint clients connect to. [tee, endpoint]
+		TeeEndpointSet(T::AccountId, Vec<u8>),
+
+		/// AjunaTEE removed from the registry. [tee]
+		TeeDeregistered(T::AccountId),
+
+		/// Cluster of AjunaTEEs registered. [cluster_id, operator]
+		ClusterRegistered(u32, T::AccountId),
+
+		/// AjunaTEE added to a cluster, running its game engine versions. [cluster_id, tee]
+		ClusterTeeAdded(u32, T::AccountId),
+
+		/// AjunaTEE removed from a cluster, running no game engine versions. [cluster_id, tee]
+		ClusterTeeRemoved(u32, T::AccountId),
+
+		/// Game engine versions served by a cluster changed. [cluster_id, engines]
+		ClusterEnginesSet(u32, Vec<GameEngine>),
+
+		/// AjunaTEE attested its enclave again. [tee, enclave]
+		TeeReattested(T::AccountId, [u8; 32]),
+
+		/// AjunaTEE changed the bond backing its games. [tee, bond]
+		TeeBondChanged(T::AccountId, BalanceOf<T>),
+
+		/// Waiting game dropped by an emergency queue drain. [game_engine, game_hash]
+		GameDrained(GameEngine, T::Hash),
+
+		/// Game queue of a game engine drained. [game_engine, games_dropped]
+		GameQueueDrained(GameEngine, u32),
+
+		/// Main account proposed a session key, waiting for the session key to accept.
+		/// [main, session]
+		SessionKeyProposed(T::AccountId, T::AccountId),
+
+		/// Session key set to play on behalf of a main account. [main, session]
+		SessionKeySet(T::AccountId, T::AccountId),
+
+		/// Session key removed from a main account. [main, session]
+		SessionKeyRemoved(T::AccountId, T::AccountId),
+
+		/// Spectator registered to stream a game from the AjunaTEE. [game_hash, spectator]
+		SpectatorRegistered(T::Hash, T::AccountId),
+
+		/// Default spectator settings of a game engine set. [game_engine]
+		EngineSpectatorSettingsSet(GameEngine),
+
+		/// Default initialization data of a game engine set or cleared. [game_engine]
+		EngineInitDataSet(GameEngine),
+
+		/// Ongoing games a player can be in set for a game engine, `None` for the default.
+		/// [game_engine, max_games]
+		MaxGamesPerPlayerSet(GameEngine, Option<u32>),
+
+		/// Spectator settings of a game set by a player. [game_hash, player]
+		GameSpectatorSettingsSet(T::Hash, T::AccountId),
+
+		/// Spectator invited to a game by a player. [game_hash, spectator]
+		SpectatorInvited(T::Hash, T::AccountId),
+
+		/// Player conceded a running game, the AjunaTEE finishes it. [game_hash, player]
+		GameConceded(T::Hash, T::AccountId),
+
+		/// Player agreed to abort a running game. [game_hash, player]
+		AbortProposed(T::Hash, T::AccountId),
+
+		/// Running game aborted by consent of all of its players, the AjunaTEE running it tears
+		/// down its session. [game_hash, tee]
+		GameAborted(T::Hash, Option<T::AccountId>),
+
+		/// Game handed over to another AjunaTEE, which resumes it after getting it ready.
+		/// [game_hash, from, to]
+		GameHandedOver(T::Hash, Option<T::AccountId>, T::AccountId),
+
+		/// Game client versions a game engine version supports set, `None` for every client.
+		/// [game_engine, client_versions]
+		EngineClientVersionsSet(GameEngine, Option<ClientVersions>),
+
+		/// Versions the game client of a player supports set. [player, client_versions]
+		ClientVersionsSet(T::AccountId, Option<ClientVersions>),
+
+		/// Player reported for not showing up to a running game. [game_hash, player, strikes]
+		NoShowReported(T::Hash, T::AccountId, u32),
+
+		/// Player requested a rematch of a finished game. [game_hash, player]
+		RematchRequested(T::Hash, T::AccountId),
+
+		/// Rematch of a finished game queued, once all of its players requested it.
+		/// [game_hash, rematch]
+		RematchCreated(T::Hash, T::Hash),
+
+		/// Rematch of a finished game not requested by all of its players in time. [game_hash]
+		RematchExpired(T::Hash),
+
+		/// Tournament created. [tournament_id, game_engine, creator]
+		TournamentCreated(u32, GameEngine, T::AccountId),
+
+		/// Player joined a tournament, reserving its entry fee. [tournament_id, player]
+		TournamentJoined(u32, T::AccountId),
+
+		/// Round of a tournament started, its games are queued. [tournament_id, round]
+		TournamentRoundStarted(u32, u32),
+
+		/// Tournament won, the entry fees are paid to the winner. [tournament_id, winner, prize]
+		TournamentWon(u32, T::AccountId, BalanceOf<T>),
+
+		/// Tournament cancelled without enough players, the entry fees are refunded.
+		/// [tournament_id]
+		TournamentCancelled(u32),
+
+		/// Party formed, the invited members have to accept. [leader, game_engine, members]
+		PartyCreated(T::AccountId, GameEngine, Vec<T::AccountId>),
+
+		/// Player accepted the invite to a party. [leader, player]
+		PartyInviteAccepted(T::AccountId, T::AccountId),
+
+		/// Party queued with all its members, waiting for opponents. [leader, size]
+		PartyQueued(T::AccountId, u32),
+
+		/// Party disbanded, the bonds of its members are released. [leader]
+		PartyDisbanded(T::AccountId),
+
+		/// Parties matched against each other, the first leader's party meets the others.
+		/// [game_hash, leaders]
+		PartiesMatched(T::Hash, Vec<T::AccountId>),
+
+		/// Account banned from playing. [account]
+		AccountBanned(T::AccountId),
+
+		/// Account unbanned. [account]
+		AccountUnbanned(T::AccountId),
+
+		/// Player banned from queueing till the block, for the reason. [player, until, reason]
+		PlayerBanned(T::AccountId, T::BlockNumber, ReportCategory),
+
+		/// Temporary ban of a player lifted before it ended. [player]
+		PlayerUnbanned(T::AccountId),
+
+		/// Accounts added to the allowlist. [accounts]
+		AddedToAllowlist(Vec<T::AccountId>),
+
+		/// Accounts removed from the allowlist. [accounts]
+		RemovedFromAllowlist(Vec<T::AccountId>),
+
+		/// Player reported by an opponent. [report_id, reporter, offender, game_hash]
+		PlayerReported(u32, T::AccountId, T::AccountId, T::Hash),
+
+		/// Report resolved by moderation. [report_id, upheld]
+		ReportResolved(u32, bool),
+
+		/// Sanction applied to a player. [player, sanction, until]
+		PlayerSanctioned(T::AccountId, SanctionKind, T::BlockNumber),
+
+		/// Sanction appealed by a player, escalated to governance. [player, sanction]
+		SanctionAppealed(T::AccountId, SanctionKind),
+
+		/// Appeal of a sanction resolved by governance. [player, sanction, lifted]
+		AppealResolved(T::AccountId, SanctionKind, bool),
+
+		/// Fraud proof submitted, the game result is suspended. [game_hash, submitter]
+		FraudProofSubmitted(T::Hash, T::AccountId),
+
+		/// Arbitration ruled on a fraud proof, fraudulent results are voided. [game_hash, fraudulent]
+		FraudProofRuled(T::Hash, bool),
+
+		/// Arbitration overturned a suspended result with another outcome. [game_hash, outcome]
+		ResultOverturned(T::Hash, GameOutcomeOf<T>),
+
+		/// Rating of an inactive player drifted towards the initial rating.
+		/// [player, engine_id, from, to]
+		RatingDecayed(T::AccountId, u8, u32, u32),
+
+		/// Stakes and ratings of a finished game settled. [game_hash, outcome]
+		ResultSettled(T::Hash, GameOutcomeOf<T>),
+
+		/// Result confirmation of a game engine version switched on or off.
+		/// [game_engine, enabled]
+		ResultConfirmationSet(GameEngine, bool),
+
+		/// Player confirmed the result of a finished game. [game_hash, player]
+		ResultConfirmed(T::Hash, T::AccountId),
+
+		/// Running game resumed by its restarted AjunaTEE, the players reconnect once it's started
+		/// again. [game_hash, tee, resume_count]
+		GameResuming(T::Hash, T::AccountId, u32),
+
+		/// Players of a settled game earned reward points. [game_hash, rewards]
+		PlayersRewarded(T::Hash, Vec<(T::AccountId, u32)>),
+
+		/// AjunaTEE used up its lifecycle calls of a block, further calls of it were rejected.
+		/// [tee, throttles]
+		TeeThrottled(T::AccountId, u32),
+
+		/// AjunaTEE suspended for being throttled too many blocks in a row. [tee]
+		TeeSuspended(T::AccountId),
+
+		/// Suspension of an AjunaTEE lifted by governance. [tee]
+		TeeUnsuspended(T::AccountId),
+
+		/// Result flagged by a player, an AjunaTEE has to re-confirm it. [game_hash, player]
+		ResultFlagged(T::Hash, T::AccountId),
+
+		/// Flagged result re-confirmed by an AjunaTEE. [game_hash, tee]
+		ResultReconfirmed(T::Hash, T::AccountId),
+
+		/// Flagged result contested or not re-confirmed in time, suspended for arbitration.
+		/// [game_hash]
+		ResultEscalated(T::Hash),
+
+		/// Matches of a bracket take longer than `MatchWaitSla`, more AjunaTEE capacity or wider
+		/// brackets are needed. [game_engine, bracket, estimated_wait, longest_wait]
+		MatchmakingDegraded(GameEngine, u8, T::BlockNumber, T::BlockNumber),
+
+		/// Matches of a degraded bracket are back within `MatchWaitSla`.
+		/// [game_engine, bracket, estimated_wait]
+		MatchmakingRecovered(GameEngine, u8, T::BlockNumber),
+
+		/// Health of the registry, sent every `HeartbeatInterval` blocks.
+		/// [(game_engine, queue_depth, active_games)]
+		RegistryHeartbeat(Vec<(GameEngine, u32, u32)>),
+
+		/// Season ended, the next one started. [season, champions]
+		SeasonEnded(u32, Vec<(T::AccountId, u32)>),
+
+		/// Replay of a finished game anchored by its AjunaTEE. [game_hash, replay]
+		ReplayAnchored(T::Hash, Vec<u8>),
+
+		/// Hash of the transcript of a finished game anchored by its AjunaTEE.
+		/// [game_hash, result_proof]
+		ResultProofAnchored(T::Hash, T::Hash),
+
+		/// Transcript of a finished game verified against its result proof. [game_hash, who]
+		ResultVerified(T::Hash, T::AccountId),
+
+		/// State checkpoint of a running game posted by its AjunaTEE. [game_hash, state_root, turn]
+		GameCheckpointed(T::Hash, T::Hash, u32),
+
+		/// Game dropped by its AjunaTEE before it was finished, players of private games are left
+		/// out.
+		GameDropped {
+			game: T::Hash,
+			engine: GameEngine,
+			reason: DropReason,
+			tee: T::AccountId,
+			players: Vec<T::AccountId>,
+		},
+
+		/// Games dropped in a batch by their AjunaTEE or the operator, no account for origins
+		/// without one. [game_hashes, reason, by]
+		GamesDropped(Vec<T::Hash>, DropReason, Option<T::AccountId>),
+
+		/// Game queued in waiting queue, players of private games are left out.
+		GameQueued {
+			game: T::Hash,
+			engine: GameEngine,
+			players: Vec<T::AccountId>,
+			bracket: u8,
+			time_control: Option<TimeControl>,
+			init_data: Vec<u8>,
+		},
+
+		/// Stepped migration of the game registry finished.
+		RegistryMigrated,
+
+		/// Game expired past a timeout, accepted games are queued again, others are dropped.
+		/// [game_hash, stage, reason]
+		GameExpired(T::Hash, GameStateOf<T>, ExpiryReason),
+
+		/// Accepted game never got ready or a checkpointed game lost its AjunaTEE, it's queued
+		/// again for another AjunaTEE. [game_hash]
+		GameRequeued(T::Hash),
+
+		/// Game of an AjunaTEE gone offline queued again for another AjunaTEE. [game_hash, tee]
+		GameReassigned(T::Hash, T::AccountId),
+
+		/// Game assigned to an AjunaTEE when it was queued. [game_hash, tee]
+		GameAssigned(T::Hash, T::AccountId),
+
+		/// Waiting game moved into the priority lane of its game queue. [game_hash, game_engine]
+		GamePrioritized(T::Hash, GameEngine),
+
+		/// AjunaTEE sent a heartbeat. [tee]
+		TeeHeartbeat(T::AccountId),
+
+		/// Game acknowledged by an AjunaTEE.
+		GameAccepted {
+			game: T::Hash,
+			tee: T::AccountId,
+		},
+
+		/// Batch of results submitted by an AjunaTEE, with the games that failed to finish.
+		/// [tee, finished, failed]
+		GamesFinished(T::AccountId, u32, Vec<T::Hash>),
+
+		/// Game started by its AjunaTEE, players of private games are left out.
+		GameStarted {
+			game: T::Hash,
+			tee: T::AccountId,
+			players: Vec<T::AccountId>,
+		},
+
+		/// Connection details of a ready game, so clients can connect to the AjunaTEE.
+		/// [game_hash, game_engine, players, tee, endpoint]
+		GameConnection(T::Hash, GameEngine, Vec<T::AccountId>, T::AccountId, Vec<u8>),
+
+		/// Game finished with its outcome, after running for `duration_blocks` blocks.
+		GameFinished {
+			game: T::Hash,
+			engine: GameEngine,
+			players: Vec<T::AccountId>,
+			outcome: GameOutcomeOf<T>,
+			duration_blocks: T::BlockNumber,
+		},
+
+		/// Game changed its state, `None` if it's created or removed. [game_hash, from, to, by]
+		GameStateChanged(T::Hash, GameStateOf<T>, GameStateOf<T>, Option<T::AccountId>),
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// To many games trying to acknowledge at once.
+		AckToMany,
+		/// The AjunaTEE can't take more games.
+		TeeAtCapacity,
+		/// To many results submitted at once.
+		FinishToMany,
+		/// To many games dropped at once.
+		DropToMany,
+		/// The acknowledged game isn't the next game of the game queue.
+		AckHashMismatch,
+		/// There are no games waiting in the game queue.
+		QueueEmpty,
+		/// There is no game queue for the game engine version.
+		NoGameQueue,
+		/// There is no such game entry
+		NoGameEntry,
+		/// Player is already queued for a match.
+		AlreadyQueued,
+		/// The player isn't queued.
+		NotQueued,
+		/// There is no such game engine registered.
+		EngineNotRegistered,
+		/// The game engine version is already registered.
+		EngineAlreadyRegistered,
+		/// The matchmaking bracket is already used by another game engine version.
+		BracketInUse,
+		/// The brackets of all rating tiers don't fit behind the first bracket.
+		BracketOutOfRange,
+		/// The game engine version has been drained, no new games are accepted.
+		EngineDrained,
+		/// The game engine version is deprecated, no new games are accepted.
+		EngineDeprecated,
+		/// The game rules of both game engine versions are not the same.
+		IncompatibleEngineRules,
+		/// The game queue can't hold all the games.
+		QueueFull,
+		/// The game engine is in maintenance, no new games are accepted.
+		EngineInMaintenance,
+		/// The game engine is paused, no new games are accepted.
+		EngineDisabled,
+		/// The maintenance window is invalid or couldn't be scheduled.
+		MaintenanceScheduleFailed,
+		/// The sender is not a registered AjunaTEE.
+		NotRegisteredTee,
+		/// The AjunaTEE doesn't support the game engine.
+		EngineNotSupported,
+		/// Not enough AjunaTEEs support the game engine to host its games.
+		EngineUnavailable,
+		/// Player doesn't meet the game rules of the game engine.
+		RequirementNotMet,
+		/// Player can't afford the queue bond.
+		InsufficientBalance,
+		/// Player can't afford the stake of the game engine.
+		InsufficientStake,
+		/// Staked games can only be queued by the account of the player.
+		StakeRequiresAccount,
+		/// The sender is not a player of the game.
+		NotAPlayer,
+		/// The winner is not a player of the game.
+		WinnerNotAPlayer,
+		/// The ranking doesn't list every player of the game once.
+		InvalidRanking,
+		/// The game is already running or finished.
+		GameAlreadyStarted,
+		/// There is no such time control preset for the game engine.
+		NoTimeControl,
+		/// The game engine has too many time control presets.
+		TooManyTimeControls,
+		/// A game needs at least one player.
+		NoPlayers,
+		/// A game has at most `MaxPlayersPerGame` players.
+		TooManyPlayers,
+		/// Amount of players doesn't fit the players per game of the game engine.
+		PlayerCountMismatch,
+		/// Game rules with an empty or inverted range, or more players than fit into a game.
+		InvalidGameRules,
+		/// More game rules than `MaxRulesPerEngine`.
+		TooManyGameRules,
+		/// Initialization data longer than `MaxInitData`.
+		InitDataTooLong,
+		/// The player is in as many ongoing games of the game engine as it allows.
+		TooManyGames,
+		/// The player is in an ongoing game and the game engine doesn't allow parallel games.
+		AlreadyInGame,
+		/// Opponents of a match must be distinct other players.
+		InvalidOpponents,
+		/// No match invite with this id.
+		NoMatchInvite,
+		/// Player isn't invited to the match or accepted already.
+		NotInvitedToMatch,
+		/// The match invite expired.
+		MatchInviteExpired,
+		/// The session key already plays on behalf of a main account.
+		SessionKeyInUse,
+		/// The session key doesn't play on behalf of the sender.
+		NotSessionKeyOwner,
+		/// The main account didn't propose the sender as its session key.
+		NoSessionKeyProposal,
+		/// Session keys can't be the main account itself or play on behalf of a session key.
+		InvalidSessionKey,
+		/// The session key is a main account or has queued, bonded or ongoing games itself.
+		SessionKeyIsPlayer,
+		/// The game is not running.
+		GameNotRunning,
+		/// The game isn't acknowledged by an AjunaTEE.
+		GameNotAccepted,
+		/// The game can't move from its current state to the requested one.
+		InvalidStateTransition,
+		/// The game is not past its timeout.
+		GameNotExpired,
+		/// The liveness of the AjunaTEEs was already noted in this block.
+		LivenessAlreadyNoted,
+		/// Players can't queue for games currently.
+		RegistrationsClosed,
+		/// The registry is paused.
+		RegistryPaused,
+		/// The entry point is disabled.
+		CallDisabled,
+		/// The account is banned from playing.
+		AccountBanned,
+		/// The AjunaTEE landed all lifecycle calls it may land in this block.
+		TeeRateLimited,
+		/// The enclave of the AjunaTEE has to sign the result.
+		MissingResultSignature,
+		/// The result isn't signed by the enclave of the AjunaTEE.
+		InvalidResultSignature,
+		/// Only allowlisted accounts can queue currently.
+		NotAllowlisted,
+		/// Too many accounts in one allowlist batch.
+		AllowlistBatchTooLarge,
+		/// Players can only report their opponents.
+		CannotReportSelf,
+		/// The moderation queue is full.
+		ReportQueueFull,
+		/// There is no report with this id.
+		NoReport,
+		/// The player is sanctioned.
+		PlayerSanctioned,
+		/// The player has no active sanction of this kind.
+		NoSanction,
+		/// The sanction is appealed already.
+		AlreadyAppealed,
+		/// There is no appeal of this sanction.
+		NoAppeal,
+		/// The game isn't finished.
+		GameNotFinished,
+		/// Fraud proofs can't be submitted against the game anymore.
+		FraudProofWindowClosed,
+		/// The fraud proof is too long.
+		FraudProofTooLong,
+		/// There is no fraud proof against the game.
+		NoFraudProof,
+		/// The result isn't waiting for re-confirmation.
+		ResultNotFlagged,
+		/// The sender is not the AjunaTEE running the game.
+		NotGameTee,
+		/// The checkpoint isn't past the last checkpoint of the game.
+		StaleCheckpoint,
+		/// The replay content hash is longer than the maximum replay length.
+		ReplayTooLong,
+		/// The endpoint is longer than the maximum endpoint length.
+		EndpointTooLong,
+		/// The game registry is being migrated.
+		MigrationOngoing,
+		/// The AjunaTEE has no attested enclave.
+		TeeNotAttested,
+		/// The attestation of the AjunaTEE is older than `AttestationValidity`.
+		AttestationExpired,
+		/// The bond of the AjunaTEE is too low for the wager of the game.
+		TeeBondTooLow,
+		/// The AjunaTEE hasn't bonded that much.
+		InsufficientTeeBond,
+		/// The game has reached the maximum of spectators.
+		TooManySpectators,
+		/// The sender is already spectating the game.
+		AlreadySpectating,
+		/// The game is already finished.
+		GameAlreadyFinished,
+		/// The spectator cap is above `MaxSpectators`.
+		SpectatorCapTooHigh,
+		/// Spectating is disabled for the game.
+		SpectatingDisabled,
+		/// Spectating the game needs an invite of a player.
+		NotInvited,
+		/// The no-show grace period of the game hasn't passed yet.
+		NoShowTooEarly,
+		/// The game has no result proof.
+		NoResultProof,
+		/// The transcript doesn't hash to the result proof of the game.
+		ResultProofMismatch,
+		/// The transcript is longer than the maximum transcript length.
+		TranscriptTooLong,
+		/// The rematch window of the game is closed.
+		RematchWindowClosed,
+		/// The player already requested a rematch of the game.
+		RematchAlreadyRequested,
+		/// Games of game engine versions with a stake can't be rematched, players queue again.
+		StakedRematch,
+		/// The player already agreed to abort the game.
+		AbortAlreadyProposed,
+		/// To many games handed over at once.
+		HandoverToMany,
+		/// Only games acknowledged or run by an AjunaTEE can be handed over.
+		GameNotActive,
+		/// Games can only be handed over to another AjunaTEE.
+		InvalidHandover,
+		/// Only the operator drops games as an administrative action.
+		InvalidDropReason,
+		/// The lowest client version is above the highest one.
+		InvalidClientVersions,
+		/// The game client of the player shares no version with the game engine version.
+		ClientNotSupported,
+		/// The AjunaTEE has no enclave signing key to submit unsigned.
+		NoSigningKey,
+		/// The nonce isn't the next one of the AjunaTEE.
+		InvalidNonce,
+		/// The start block of a game isn't in the future.
+		InvalidStartBlock,
+		/// The scheduler didn't take the start of the game.
+		GameScheduleFailed,
+		/// The game isn't scheduled to start.
+		GameNotScheduled,
+		/// A party needs distinct members, up to `MaxPartySize`, and two teams of its size have
+		/// to fit the games of its game engine.
+		InvalidParty,
+		/// The player leads no party.
+		NoParty,
+		/// Player isn't invited to the party or accepted already.
+		NotInvitedToParty,
+		/// The id generated for the game is already taken by another game.
+		DuplicateGameId,
+		/// There is no cluster with the id.
+		NoCluster,
+		/// The sender isn't the operator of the cluster.
+		NotClusterOperator,
+		/// The AjunaTEE belongs to a cluster, its game engine versions are the ones of the
+		/// cluster.
+		TeeInCluster,
+		/// The AjunaTEE doesn't belong to the cluster.
+		TeeNotInCluster,
+		/// The cluster has reached `MAX_CLUSTER_TEES` AjunaTEEs.
+		TooManyClusterTees,
+		/// The result of the game isn't waiting for its settlement.
+		ResultNotPending,
+		/// The player confirmed the result already.
+		ResultAlreadyConfirmed,
+		/// The AjunaTEE is suspended for flooding the registry.
+		TeeSuspended,
+		/// The AjunaTEE isn't suspended.
+		TeeNotSuspended,
+		/// There is no tournament with the id.
+		NoTournament,
+		/// A tournament needs room for two players, up to the maximum, and a future start block.
+		InvalidTournament,
+		/// The tournament started already.
+		TournamentClosed,
+		/// The tournament has no room for more players.
+		TournamentFull,
+		/// The player already joined the tournament.
+		AlreadyJoined,
+		/// The ban ends at or before the current block.
+		BanAlreadyOver,
+		/// `MaxFinishedPerBlock` games finished in this block already.
+		TooManyFinishedGames,
+		/// More game engine versions than an AjunaTEE or a cluster can run.
+		TooManyEngines,
+		/// A game engine version can't be upgraded to itself.
+		SameGameEngine,
+	}
+
+	// Pallet implements [`Hooks`] trait to define some logic to execute in some context.
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		// `on_initialize` is executed at the beginning of the block before any extrinsic are
+		// dispatched.
+		//
+		// This function must return the weight consumed by `on_initialize` and `on_finalize`.
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			// game entries are not readable till the stepped migration is done, so matching in
+			// `on_idle` and the deadlines of the passed blocks wait for it
+			if let Some(cursor) = Self::migration_cursor() {
+				let (cursor, weight) = migrations::v5::migrate_step::<T>(
+					cursor.into_inner(),
+					T::MigrationBatchSize::get(),
+				);
+				match cursor {
+					Some(cursor) => <MigrationCursor<T>>::put(Self::storage_cursor(cursor)),
+					None => {
+						<MigrationCursor<T>>::kill();
+						Self::deposit_event(Event::RegistryMigrated);
+					},
+				}
+				if Self::deadline_cursor().is_none() {
+					<DeadlineCursor<T>>::put(n);
+				}
+				return T::WeightInfo::on_initialize_migration().saturating_add(weight)
+			}
+
+			// initial weights, including the commitments of on_finalize
+			let mut tot_weights = T::WeightInfo::on_initialize_idle();
+
+			// blocks passed during a stepped migration are caught up on, a few per block
+			if let Some(mut block) = Self::deadline_cursor() {
+				let mut missed = 0;
+				while block < n && missed < MAX_MISSED_BLOCKS {
+					tot_weights = tot_weights.saturating_add(Self::process_block_deadlines(block));
+					block = block.saturating_add(One::one());
+					missed += 1;
+				}
+				if block < n {
+					<DeadlineCursor<T>>::put(block);
+				} else {
+					<DeadlineCursor<T>>::kill();
+				}
+				tot_weights = tot_weights.saturating_add(T::DbWeight::get().writes(1));
+			}
+			tot_weights = tot_weights.saturating_add(Self::process_block_deadlines(n));
+
+			// games of AjunaTEEs not seen for too long go to another AjunaTEE
+			tot_weights = tot_weights.saturating_add(Self::reassign_offline_games(n));
+
+			// heartbeat of the registry health, for dashboards without storage polling
+			let interval = T::HeartbeatInterval::get();
+			if !interval.is_zero() && (n % interval).is_zero() {
+				let heartbeat: Vec<_> = <SupportedEngines<T>>::iter_keys()
+					.map(|game_engine| {
+						let queue_depth = Self::game_queues(&game_engine).length();
+						let active_games = Self::active_games(&game_engine);
+						(game_engine, queue_depth, active_games)
+					})
+					.collect();
+				tot_weights =
+					tot_weights.saturating_add(T::WeightInfo::heartbeat(heartbeat.len() as u32));
+				Self::deposit_event(Event::RegistryHeartbeat(heartbeat));
+			}
+
+			let season_length = T::SeasonLength::get();
+			if !season_length.is_zero() && (n % season_length).is_zero() {
+				Self::end_season();
+				tot_weights = tot_weights.saturating_add(T::WeightInfo::end_season());
+			}
+
+			return tot_weights
+		}
+
+		// `on_idle` is executed at the end of the block with the weight left over by the
+		// extrinsics, matchmaking only uses that weight up to its budget, so a busy block is
+		// never pushed over its limit by new games.
+		fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			// migration cursor, settings and parameters
+			let mut tot_weights = T::DbWeight::get().reads(3);
+			if remaining_weight < tot_weights {
+				return 0
+			}
+			// game entries are not readable till the stepped migration is done
+			if Self::migration_cursor().is_some() {
+				return T::DbWeight::get().reads(1)
+			}
+			// finished games are settled before new ones are matched
+			tot_weights = tot_weights.saturating_add(Self::settle_queued_results(
+				remaining_weight.saturating_sub(tot_weights),
+			));
+			let budget = remaining_weight.min(Self::parameters().matchmaking_weight);
+
+			// players matched for an engine in maintenance or paused, returned to matchmaking
+			// afterwards
+			let mut postponed: Vec<(QueueInfo, Vec<T::AccountId>)> = Vec::new();
+			// matching is switched off or paused, players stay in matchmaking
+			let max_games = if Self::settings().auto_matchmaking && !Self::paused() {
+				Self::parameters().games_per_block
+			} else {
+				0
+			};
+			// every match is metered at its worst case, matching stops before it would exceed
+			// the budget
+			let match_weight = T::WeightInfo::match_game();
+			for _i in 0..max_games {
+				if tot_weights.saturating_add(match_weight) > budget {
+					break
+				}
+				tot_weights = tot_weights.saturating_add(match_weight);
+
+				// try to create a match till we reached max games or no more matches available
+				let result = T::MatchMaker::try_match();
+				// if result is not empty we have a valid match
+				if !result.is_empty() {
+					// brackets are owned by a single engine version and time control,
+					// so all players share it
+					let mut queue_info = None;
+					let mut players = Vec::new();
+					for player in result.iter() {
+						match <QueuedPlayers<T>>::take(player) {
+							// players banned while queued are taken out of matchmaking
+							Some(_)
+								if Self::is_banned(player) ||
+									Self::is_sanctioned(player, SanctionKind::QueueBan) =>
+							{
+								<QueuedSince<T>>::remove(player);
+								Self::release_queue_bond(player);
+								Self::deposit_event(Event::PlayerDequeued(
+									player.clone(),
+									DequeueReason::Banned,
+								));
+							},
+							// so are players no longer meeting the game rules, e.g. after a
+							// transfer
+							Some(info)
+								if Self::check_game_rules(&info.game_engine, player).is_err() =>
+							{
+								<QueuedSince<T>>::remove(player);
+								Self::release_queue_bond(player);
+								Self::deposit_event(Event::PlayerDequeued(
+									player.clone(),
+									DequeueReason::RequirementNotMet,
+								));
+							},
+							Some(info) => {
+								queue_info = Some(info);
+								players.push(player.clone());
+							},
+							// players that left the queue are skipped
+							None => <LeftPlayers<T>>::remove(player),
+						}
+					}
+					if let Some(queue_info) = queue_info {
+						// the rest of an incomplete match goes back into matchmaking
+						if players.len() < result.len() {
+							postponed.push((queue_info, players));
+							continue
+						}
+						let result = players;
+						// drained engine versions hand their matches to the successor
+						let current_engine = Self::current_engine(queue_info.game_engine.clone());
+						// players of deprecated engine versions are taken out of matchmaking
+						if Self::is_deprecated(&current_engine) {
+							for player in result.iter() {
+								<QueuedSince<T>>::remove(player);
+								Self::release_queue_bond(player);
+								Self::deposit_event(Event::PlayerDequeued(
+									player.clone(),
+									DequeueReason::EngineDeprecated,
+								));
+							}
+							continue
+						}
+						if Self::in_maintenance(&current_engine) ||
+							Self::paused_engines(&current_engine)
+						{
+							postponed.push((queue_info, result));
+							continue
+						}
+						// games for more players are formed out of several matches
+						let party_size = Self::players_per_game(&current_engine)
+							.map_or(result.len(), |(_, max)| max as usize);
+						let result = if result.len() != party_size {
+							match Self::form_party(&queue_info, result, party_size) {
+								Some(party) => party,
+								None => continue,
+							}
+						} else {
+							result
+						};
+						// players whose clients share no version go back into matchmaking, to
+						// be matched with other players
+						let client_version =
+							match Self::shared_client_version(&current_engine, &result) {
+								Ok(client_version) => client_version,
+								Err(_) => {
+									postponed.push((queue_info, result));
+									continue
+								},
+							};
+						let time_control = queue_info.time_control.and_then(|index| {
+							Self::time_controls(&queue_info.game_engine)
+								.get(index as usize)
+								.cloned()
+						});
+						// Create new game, holding the stakes of the players for its pot
+						let game_hash = match Self::queue_game(
+							current_engine,
+							result.clone(),
+							time_control,
+							false,
+							None,
+						) {
+							Ok(game_hash) => game_hash,
+							// players of games that can't be created go back into matchmaking
+							Err(_) => {
+								postponed.push((queue_info, result));
+								continue
+							},
+						};
+						Self::hold_stakes(&game_hash, &result);
+						if let Some(client_version) = client_version {
+							<GameClientVersions<T>>::insert(&game_hash, client_version);
+						}
+						// the average wait of the matched players goes into the estimate
+						let mut total_wait = T::BlockNumber::zero();
+						let mut longest_wait = T::BlockNumber::zero();
+						for player in result.iter() {
+							let since = <QueuedSince<T>>::take(player).unwrap_or(n);
+							let wait = n.saturating_sub(since);
+							total_wait = total_wait.saturating_add(wait);
+							longest_wait = longest_wait.max(wait);
+							Self::deposit_event(Event::PlayerDequeued(
+								player.clone(),
+								DequeueReason::Matched,
+							));
+						}
+						Self::note_wait(
+							&queue_info.game_engine,
+							queue_info.bracket,
+							total_wait / (result.len() as u32).into(),
+							longest_wait,
+						);
+					}
+					continue
+				}
+				// nothing left to match, only the lookup of the metered match was used
+				tot_weights = tot_weights
+					.saturating_sub(match_weight)
+					.saturating_add(T::WeightInfo::no_match());
+				break
+			}
+
+			// put players postponed by a maintenance window, a pause or a failed game creation
+			// back into matchmaking
+			for (queue_info, players) in postponed {
+				for player in players {
+					if T::MatchMaker::add_queue(player.clone(), queue_info.bracket) {
+						<QueuedPlayers<T>>::insert(&player, queue_info.clone());
+					}
+				}
+				tot_weights = tot_weights.saturating_add(T::DbWeight::get().writes(1));
+			}
+
+			// premade parties are matched against teams of the same size
+			if max_games > 0 {
+				let engines: Vec<_> = <PartyQueues<T>>::iter_keys().collect();
+				for game_engine in engines {
+					let parties = <PartyQueues<T>>::decode_len(&game_engine).unwrap_or_default();
+					if tot_weights.saturating_add(T::WeightInfo::match_parties(parties as u32)) >
+						budget
+					{
+						break
+					}
+					tot_weights = tot_weights.saturating_add(Self::match_parties(&game_engine));
+				}
+			}
+
+			// expiry, pruning and clean ups run with the weight left over
+			let maintenance_budget = remaining_weight
+				.saturating_sub(tot_weights)
+				.min(Self::parameters().maintenance_weight);
+			let maintenance_weight = Self::run_maintenance(n, maintenance_budget);
+			return tot_weights.saturating_add(maintenance_weight)
+		}
+
+		// `on_runtime_upgrade` starts the storage migrations, stepped in `on_initialize`.
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::on_runtime_upgrade::<T>()
+				.saturating_add(migrations::v2::on_runtime_upgrade::<T>())
+				.saturating_add(migrations::v3::on_runtime_upgrade::<T>())
+				.saturating_add(migrations::v4::on_runtime_upgrade::<T>())
+				.saturating_add(migrations::v5::on_runtime_upgrade::<T>())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let games = <GameRegistry<T>>::iter_keys().count() as u32;
+			Self::set_temp_storage(games, "games");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::{GetStorageVersion, OnRuntimeUpgradeHelpersExt};
+
+			ensure!(Self::on_chain_storage_version() == STORAGE_VERSION, "storage version not set");
+			let games = Self::get_temp_storage::<u32>("games").ok_or("games not counted")?;
+			ensure!(
+				<GameRegistry<T>>::iter_keys().count() as u32 == games,
+				"game entries lost in the upgrade"
+			);
+			Self::do_try_state()
+		}
+
+		// `on_finalize` is executed at the end of block after all extrinsic are dispatched.
+		fn on_finalize(n: BlockNumberFor<T>) {
+			<TeeLivenessNoted<T>>::kill();
+			Self::throttle_tees();
+
+			// commit to the results finalized in this block, for the next MMR leaf
+			let leaves = <BlockResultLeaves<T>>::take();
+			if leaves.is_empty() {
+				<ResultsCommitment<T>>::kill();
+			} else {
+				<ResultsCommitment<T>>::put(T::Hashing::hash_of(&leaves));
+			}
+
+			// export the games finalized in this block to the offchain index
+			let finished_games = <BlockFinishedGames<T>>::take();
+			if !finished_games.is_empty() {
+				sp_io::offchain_index::set(&Self::block_record_key(n), &finished_games.encode());
+			}
+		}
+
+		// A runtime code run after every block and have access to extended set of APIs.
+		//
+		// The full leaderboard is too heavy for the chain, it's built here periodically into the
+		// offchain storage and can be read through the `offchain_localStorageGet` rpc.
+		fn offchain_worker(n: T::BlockNumber) {
+			let interval = T::LeaderboardInterval::get();
+			if !interval.is_zero() && (n % interval).is_zero() {
+				let leaderboard = Self::build_leaderboard();
+				StorageValueRef::persistent(OFFCHAIN_LEADERBOARD_KEY).set(&leaderboard);
+			}
+
+			// clean up games past their timeouts, so it doesn't depend on external bots, one page
+			// of the game registry per block
+			let cursor = StorageValueRef::persistent(OFFCHAIN_EXPIRY_CURSOR_KEY);
+			let (expired_games, next) = Self::expired_games(cursor.get().ok().flatten());
+			match next {
+				Some(next) => cursor.set(&next),
+				None => cursor.clear(),
+			}
+			for game_hash in expired_games {
+				// the expiry is only submitted again once the last one left the transaction pool,
+				// the lock holds across concurrent offchain workers of the node
+				let lock = StorageValueRef::persistent(&Self::expiry_lock_key(&game_hash));
+				let locked = lock.mutate(|sent: Result<Option<T::BlockNumber>, _>| match sent {
+					Ok(Some(sent)) if n < sent.saturating_add(EXPIRY_RESUBMIT_BLOCKS.into()) =>
+						Err(()),
+					_ => Ok(n),
+				});
+				if locked.is_err() {
+					continue
+				}
+				let call = Call::expire_game { game_hash };
+				if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+					.is_err()
+				{
+					log::warn!("failed to submit expire_game for {:?}", game_hash);
+				}
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let game_hash = match call {
+				Call::expire_game { game_hash } => game_hash,
+				Call::finish_game_unsigned { tee, result, nonce } => {
+					let signature = match result.signature.as_ref() {
+						Some(signature) => signature,
+						None => return InvalidTransaction::BadProof.into(),
+					};
+					let payload = (
+						result.game_hash,
+						&result.outcome,
+						result.final_state,
+						nonce,
+						&result.replay,
+						result.result_proof,
+					)
+						.encode();
+					return Self::validate_tee_submission(tee, *nonce, &payload, signature)
+				},
+				Call::ack_game_unsigned { tee, cluster, games, nonce, signature } => {
+					let payload = (cluster, games, nonce).encode();
+					return Self::validate_tee_submission(tee, *nonce, &payload, signature)
+				},
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			// only games past their timeout can be expired
+			let expired = Self::game_entry(game_hash)
+				.map_or(false, |game_entry| Self::is_expired(&game_entry));
+			if !expired {
+				return InvalidTransaction::Stale.into()
+			}
+
+			ValidTransaction::with_tag_prefix("GameRegistryExpire")
+				.priority(TransactionPriority::max_value())
+				.and_provides(game_hash)
+				.longevity(EXPIRY_RESUBMIT_BLOCKS.into())
+				.propagate(true)
+				.build()
+		}
+	}
+
+	#[pallet::inherent]
+	impl<T: Config> ProvideInherent for Pallet<T> {
+		type Call = Call<T>;
+		type Error = MakeFatalError<()>;
+		const INHERENT_IDENTIFIER: InherentIdentifier = inherent::INHERENT_IDENTIFIER;
+
+		fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+			let tees: Vec<T::AccountId> = data.get_data(&Self::INHERENT_IDENTIFIER).ok()??;
+
+			// only the liveness of registered AjunaTEEs is kept
+			let tees: Vec<_> =
+				tees.into_iter().filter(|tee| <RegisteredTees<T>>::contains_key(tee)).collect();
+			if tees.is_empty() {
+				return None
+			}
+			Some(Call::note_tee_liveness { tees })
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			matches!(call, Call::note_tee_liveness { .. })
+		}
+	}
+
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the operational toggles of the registry.
+		#[pallet::weight(T::WeightInfo::set_settings())]
+		pub fn set_settings(origin: OriginFor<T>, settings: PalletSettings) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			<Settings<T>>::put(&settings);
+
+			Self::log_admin_action(actor, AdminAction::SetSettings, &settings);
+
+			// Emit an event.
+			Self::deposit_event(Event::SettingsSet(settings));
+
+			Ok(())
+		}
+
+		/// Pause the registry, to contain an incident without a runtime upgrade.
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			<Paused<T>>::put(true);
+
+			Self::log_admin_action(actor, AdminAction::Pause, &());
+
+			// Emit an event.
+			Self::deposit_event(Event::RegistryPaused);
+
+			Ok(())
+		}
+
+		/// Unpause the registry.
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			<Paused<T>>::kill();
+
+			Self::log_admin_action(actor, AdminAction::Unpause, &());
+
+			// Emit an event.
+			Self::deposit_event(Event::RegistryUnpaused);
+
+			Ok(())
+		}
+
+		/// Disable or enable a single entry point of the registry, for partial degradation.
+		#[pallet::weight(T::WeightInfo::set_call_disabled())]
+		pub fn set_call_disabled(
+			origin: OriginFor<T>,
+			call: RegistryCall,
+			disabled: bool,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			if disabled {
+				<DisabledCalls<T>>::insert(call, true);
+			} else {
+				<DisabledCalls<T>>::remove(call);
+			}
+
+			Self::log_admin_action(actor, AdminAction::SetCallDisabled, &(call, disabled));
+
+			// Emit an event.
+			Self::deposit_event(Event::CallDisabledSet(call, disabled));
+
+			Ok(())
+		}
+
+		/// Set a numeric parameter of the registry.
+		#[pallet::weight(T::WeightInfo::set_parameter())]
+		pub fn set_parameter(
+			origin: OriginFor<T>,
+			parameter: RegistryParameter<T::BlockNumber>,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			let mut parameters = Self::parameters();
+			match parameter.clone() {
+				RegistryParameter::WaitingTimeout(blocks) => parameters.waiting_timeout = blocks,
+				RegistryParameter::AcceptedTimeout(blocks) => parameters.accepted_timeout = blocks,
+				RegistryParameter::MaxGameDuration(blocks) => parameters.max_game_duration = blocks,
+				RegistryParameter::AckBatchLimit(limit) => parameters.ack_batch_limit = limit,
+				RegistryParameter::GamesPerBlock(games) => parameters.games_per_block = games,
+				RegistryParameter::TeeCallsPerBlock(calls) =>
+					parameters.tee_calls_per_block = calls,
+				RegistryParameter::AbandonmentSlash(slash) => parameters.abandonment_slash = slash,
+				RegistryParameter::FrivolousReportSlash(slash) =>
+					parameters.frivolous_report_slash = slash,
+				RegistryParameter::MatchmakingWeight(weight) =>
+					parameters.matchmaking_weight = weight,
+				RegistryParameter::MaintenanceWeight(weight) =>
+					parameters.maintenance_weight = weight,
+			}
+			<Parameters<T>>::put(parameters);
+
+			Self::log_admin_action(actor, AdminAction::SetParameter, &parameter);
+
+			// Emit an event.
+			Self::deposit_event(Event::ParameterSet(parameter));
+
+			Ok(())
+		}
+
+		/// Register a game engine version, with its own matchmaking bracket.
+		#[pallet::weight(T::WeightInfo::register_game_engine())]
+		pub fn register_game_engine(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			bracket: u8,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			Self::do_register_game_engine(game_engine.clone(), bracket)?;
+
+			Self::log_admin_action(
+				actor,
+				AdminAction::RegisterGameEngine,
+				&(&game_engine, bracket),
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::GameEngineRegistered(game_engine, bracket));
+
+			Ok(())
+		}
+
+		/// Deprecate a game engine version without a successor, no new games are queued or
+		/// matched for it. Games already created are still played out.
+		#[pallet::weight(T::WeightInfo::deprecate_game_engine())]
+		pub fn deprecate_game_engine(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			ensure!(!Self::is_deprecated(&game_engine), Error::<T>::EngineDeprecated);
+			<DeprecatedEngines<T>>::insert(&game_engine, <frame_system::Pallet<T>>::block_number());
+
+			Self::log_admin_action(actor, AdminAction::DeprecateGameEngine, &game_engine);
+
+			// Emit an event.
+			Self::deposit_event(Event::GameEngineDeprecated(game_engine));
+
+			Ok(())
+		}
+
+		/// Upgrade game engine will stop new games on the old version and move its waiting
+		/// games to the new version, games already accepted by a TEE finish on the old version.
+		#[pallet::weight(T::WeightInfo::upgrade_game_engine())]
+		pub fn upgrade_game_engine(
+			origin: OriginFor<T>,
+			from: GameEngine,
+			to: GameEngine,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			ensure!(from != to, Error::<T>::SameGameEngine);
+			let mut from_info =
+				Self::supported_engines(&from).ok_or(Error::<T>::EngineNotRegistered)?;
+			ensure!(from_info.successor.is_none(), Error::<T>::EngineDrained);
+			let to_info = Self::supported_engines(&to).ok_or(Error::<T>::EngineNotRegistered)?;
+			ensure!(to_info.successor.is_none(), Error::<T>::EngineDrained);
+			ensure!(!Self::is_deprecated(&to), Error::<T>::EngineDeprecated);
+			ensure!(
+				Self::game_requirements(&from) == Self::game_requirements(&to),
+				Error::<T>::IncompatibleEngineRules
+			);
+
+			// retrieve both game queues
+			let mut from_queue = Self::game_queues(&from);
+			let mut to_queue = Self::game_queues(&to);
+			ensure!(from_queue.length() <= to_queue.remaining_capacity(), Error::<T>::QueueFull);
+
+			// move all waiting games over to the new version
+			let mut games_moved = 0;
+			while let Some(game_hash) = from_queue.dequeue() {
+				<GameRegistry<T>>::mutate(&game_hash, |game_entry| {
+					game_entry.game_engine = to.clone();
+					// ongoing games are counted per game engine id
+					if from.id != to.id {
+						Self::count_player_games(&game_entry.players, from.id, false);
+						Self::count_player_games(&game_entry.players, to.id, true);
+					}
+				});
+				Self::enqueue_game(&mut to_queue, game_hash);
+				games_moved += 1;
+			}
+			from_queue.clear();
+
+			// players still in matchmaking for the old version, get their games on the new one
+			from_info.successor = Some(to.clone());
+			<SupportedEngines<T>>::insert(&from, from_info);
+
+			Self::log_admin_action(actor, AdminAction::UpgradeGameEngine, &(&from, &to));
+
+			// Emit an event.
+			Self::deposit_event(Event::GameEngineUpgraded(from, to, games_moved));
+
+			Ok(())
+		}
+
+		/// Set the game rules players have to meet before they can queue for a game engine.
+		#[pallet::weight(T::WeightInfo::set_game_rules())]
+		pub fn set_game_rules(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			game_rules: Vec<GameRule<GameRuleType>>,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			for game_rule in game_rules.iter() {
+				Self::ensure_valid_rule(game_rule)?;
+			}
+			let game_rules = BoundedVec::<_, T::MaxRulesPerEngine>::try_from(game_rules)
+				.map_err(|_| Error::<T>::TooManyGameRules)?;
+			<GameRequirments<T>>::insert(&game_engine, &game_rules);
+
+			Self::log_admin_action(actor, AdminAction::SetGameRules, &(&game_engine, &game_rules));
+
+			// Emit an event.
+			Self::deposit_event(Event::GameRulesSet(game_engine));
+
+			Ok(())
+		}
+
+		/// Set the default spectator settings of the games of a game engine.
+		#[pallet::weight(T::WeightInfo::set_engine_spectator_settings())]
+		pub fn set_engine_spectator_settings(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			settings: SpectatorSettings,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			ensure!(
+				settings.max_spectators <= T::MaxSpectators::get(),
+				Error::<T>::SpectatorCapTooHigh
+			);
+			<EngineSpectatorSettings<T>>::insert(&game_engine, settings);
+
+			// Emit an event.
+			Self::deposit_event(Event::EngineSpectatorSettingsSet(game_engine));
+
+			Ok(())
+		}
+
+		/// Set the ongoing games a player can be in for a game engine, `None` for the default
+		/// `MaxGamesPerPlayer`.
+		#[pallet::weight(T::WeightInfo::set_max_games_per_player())]
+		pub fn set_max_games_per_player(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			max_games: Option<u32>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			match max_games {
+				Some(max_games) => <EngineMaxGamesPerPlayer<T>>::insert(&game_engine, max_games),
+				None => <EngineMaxGamesPerPlayer<T>>::remove(&game_engine),
+			}
+
+			// Emit an event.
+			Self::deposit_event(Event::MaxGamesPerPlayerSet(game_engine, max_games));
+
+			Ok(())
+		}
+
+		/// Set the stake every player puts into the pot of a game of a game engine version,
+		/// `None` for games without stakes.
+		#[pallet::weight(T::WeightInfo::set_engine_stake())]
+		pub fn set_engine_stake(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			stake: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			<EngineStakes<T>>::set(&game_engine, stake);
+
+			Self::log_admin_action(actor, AdminAction::SetEngineStake, &(&game_engine, stake));
+
+			// Emit an event.
+			Self::deposit_event(Event::EngineStakeSet(game_engine, stake));
+
+			Ok(())
+		}
+
+		/// Add a time control preset to a game engine, matched in its own bracket.
+		#[pallet::weight(T::WeightInfo::add_time_control())]
+		pub fn add_time_control(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			time_control: TimeControl,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			let mut time_controls = Self::time_controls(&game_engine);
+			ensure!(time_controls.len() <= u8::MAX as usize, Error::<T>::TooManyTimeControls);
+			let index = time_controls.len() as u8;
+			Self::reserve_brackets(time_control.bracket, &game_engine)?;
+			time_controls
+				.try_push(time_control)
+				.map_err(|_| Error::<T>::TooManyTimeControls)?;
+			<TimeControls<T>>::insert(&game_engine, time_controls);
+
+			// Emit an event.
+			Self::deposit_event(Event::TimeControlAdded(game_engine, index));
+
+			Ok(())
+		}
+
+		/// Register an AjunaTEE with the game engine versions it is able to run.
+		///
+		/// The AjunaTEE must present an attestation quote, or be attested by the runtime already.
+		#[pallet::weight(T::WeightInfo::register_tee())]
+		pub fn register_tee(
+			origin: OriginFor<T>,
+			tee: T::AccountId,
+			supported_engines: Vec<GameEngine>,
+			quote: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+			ensure!(Self::tee_cluster(&tee).is_none(), Error::<T>::TeeInCluster);
+
+			let enclave = Self::verify_attestation(&tee, quote)?;
+
+			let supported_engines = Self::dedup_engines(supported_engines)?;
+			let old_info = Self::registered_tees(&tee).unwrap_or_default();
+			Self::update_engine_tee_count(&old_info.supported_engines, &supported_engines);
+
+			let signing_key = T::TeeAttestation::signing_key_of(&tee);
+			<RegisteredTees<T>>::insert(
+				&tee,
+				TeeInfo { supported_engines, enclave, endpoint: old_info.endpoint, signing_key },
+			);
+			<AttestedAt<T>>::insert(&tee, <frame_system::Pallet<T>>::block_number());
+
+			Self::log_admin_action(actor, AdminAction::RegisterTee, &tee);
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeRegistered(tee));
+
+			Ok(())
+		}
+
+		/// Update the game engine versions the sending AjunaTEE is able to run.
+		#[pallet::weight(T::WeightInfo::update_tee_engines())]
+		pub fn update_tee_engines(
+			origin: OriginFor<T>,
+			supported_engines: Vec<GameEngine>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::tee_cluster(&who).is_none(), Error::<T>::TeeInCluster);
+
+			let supported_engines = Self::dedup_engines(supported_engines)?;
+			<RegisteredTees<T>>::try_mutate(&who, |maybe_info| -> DispatchResult {
+				let tee_info = maybe_info.as_mut().ok_or(Error::<T>::NotRegisteredTee)?;
+				Self::update_engine_tee_count(&tee_info.supported_engines, &supported_engines);
+				tee_info.supported_engines = supported_engines;
+				Ok(())
+			})?;
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeRegistered(who));
+
+			Ok(())
+		}
+
+		/// Publish the endpoint clients connect to for the games of the sending AjunaTEE.
+		#[pallet::weight(T::WeightInfo::set_tee_endpoint())]
+		pub fn set_tee_endpoint(origin: OriginFor<T>, endpoint: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bounded_endpoint =
+				BoundedVec::try_from(endpoint.clone()).map_err(|_| Error::<T>::EndpointTooLong)?;
+			<RegisteredTees<T>>::try_mutate(&who, |maybe_info| -> DispatchResult {
+				let tee_info = maybe_info.as_mut().ok_or(Error::<T>::NotRegisteredTee)?;
+				tee_info.endpoint = bounded_endpoint;
+				Ok(())
+			})?;
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeEndpointSet(who, endpoint));
+
+			Ok(())
+		}
+
+		/// Attest the enclave of the sending AjunaTEE again, before its attestation expires.
+		///
+		/// Without a fresh quote the enclave must be attested by the runtime.
+		#[pallet::weight(T::WeightInfo::reattest_tee())]
+		pub fn reattest_tee(origin: OriginFor<T>, quote: Option<Vec<u8>>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<RegisteredTees<T>>::contains_key(&who), Error::<T>::NotRegisteredTee);
+
+			let enclave = Self::verify_attestation(&who, quote)?;
+			<RegisteredTees<T>>::mutate(&who, |maybe_info| {
+				if let Some(tee_info) = maybe_info {
+					tee_info.enclave = enclave;
+					tee_info.signing_key = T::TeeAttestation::signing_key_of(&who);
+				}
+			});
+			<AttestedAt<T>>::insert(&who, <frame_system::Pallet<T>>::block_number());
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeReattested(who, enclave));
+
+			Ok(())
+		}
+
+		/// Reserve more bond for the sending AjunaTEE, qualifying it for games of higher value.
+		#[pallet::weight(T::WeightInfo::bond_tee())]
+		pub fn bond_tee(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<RegisteredTees<T>>::contains_key(&who), Error::<T>::NotRegisteredTee);
+
+			T::Currency::reserve(&who, amount).map_err(|_| Error::<T>::InsufficientBalance)?;
+			let bond = <TeeBonds<T>>::mutate(&who, |bond| {
+				*bond = bond.saturating_add(amount);
+				*bond
+			});
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeBondChanged(who, bond));
+
+			Ok(())
+		}
+
+		/// Release bond of the sending AjunaTEE, games already acknowledged keep running.
+		#[pallet::weight(T::WeightInfo::unbond_tee())]
+		pub fn unbond_tee(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bond = Self::tee_bonds(&who);
+			ensure!(bond >= amount, Error::<T>::InsufficientTeeBond);
+			T::Currency::unreserve(&who, amount);
+			let bond = bond - amount;
+			if bond.is_zero() {
+				<TeeBonds<T>>::remove(&who);
+			} else {
+				<TeeBonds<T>>::insert(&who, bond);
+			}
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeBondChanged(who, bond));
+
+			Ok(())
+		}
+
+		/// Remove an AjunaTEE from the registry.
+		#[pallet::weight(T::WeightInfo::deregister_tee())]
+		pub fn deregister_tee(origin: OriginFor<T>, tee: T::AccountId) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			let tee_info = <RegisteredTees<T>>::take(&tee).ok_or(Error::<T>::NotRegisteredTee)?;
+			<TeeLastSeen<T>>::remove(&tee);
+			<AttestedAt<T>>::remove(&tee);
+			<TeeThrottles<T>>::remove(&tee);
+			<SuspendedTees<T>>::remove(&tee);
+			Self::update_engine_tee_count(&tee_info.supported_engines, &[]);
+			if let Some(cluster_id) = <TeeClusterIds<T>>::take(&tee) {
+				<Clusters<T>>::mutate(cluster_id, |cluster| {
+					if let Some(cluster) = cluster {
+						cluster.tees.retain(|member| *member != tee);
+					}
+				});
+			}
+			// a removed AjunaTEE gets its bond back
+			T::Currency::unreserve(&tee, <TeeBonds<T>>::take(&tee));
+
+			Self::log_admin_action(actor, AdminAction::DeregisterTee, &tee);
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeDeregistered(tee));
+
+			Ok(())
+		}
+
+		/// Emergency drain of a game queue, dropping all waiting games of the game engine.
+		#[pallet::weight(T::WeightInfo::drain_game_queue())]
+		pub fn drain_game_queue(origin: OriginFor<T>, game_engine: GameEngine) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::OperatorOrigin>(origin)?;
+
+			ensure!(Self::has_game_queue(&game_engine), Error::<T>::NoGameQueue);
+			let mut game_queue = Self::game_queues(&game_engine);
+
+			let mut games_dropped = 0;
+			while let Some(game_hash) = game_queue.dequeue() {
+				// only games still waiting for a TEE are dropped
+				let game_entry = Self::game_registry(&game_hash);
+				if game_entry.game_state != GameState::Waiting {
+					continue
+				}
+				<GameRegistry<T>>::remove(&game_hash);
+				<PrivateGames<T>>::remove(&game_hash);
+				Self::advance_tournament(&game_hash, None);
+				<GameWagers<T>>::remove(&game_hash);
+				Self::refund_stakes(&game_hash);
+				<GameCheckpoints<T>>::remove(&game_hash);
+				<GameResumes<T>>::remove(&game_hash);
+				Self::remove_match_code(&game_hash);
+				<GameSeeds<T>>::remove(&game_hash);
+				<GameInitData<T>>::remove(&game_hash);
+				<GameClientVersions<T>>::remove(&game_hash);
+				<GameTeams<T>>::remove(&game_hash);
+				Self::change_game_state(
+					&game_hash,
+					&game_entry,
+					&game_entry.game_state,
+					&GameState::None,
+					actor.clone(),
+				);
+				T::OnGameResult::on_game_result(
+					&game_hash,
+					&game_entry.game_engine,
+					&game_entry.players,
+					&GameResult::Dropped,
+				);
+
+				// refund bonds still held for the game
+				for player in game_entry.players.iter() {
+					Self::release_queue_bond(player);
+				}
+
+				Self::count_game(&game_engine, |counters| &mut counters.dropped);
+
+				// Emit an event.
+				Self::deposit_event(Event::GameDrained(game_engine.clone(), game_hash));
+				games_dropped += 1;
+			}
+			game_queue.clear();
+
+			Self::log_admin_action(actor, AdminAction::DrainGameQueue, &game_engine);
+
+			// Emit an event.
+			Self::deposit_event(Event::GameQueueDrained(game_engine, games_dropped));
+
+			Ok(())
+		}
+
+		/// Schedule a maintenance window for a game engine, starting and ending automatically.
+		///
+		/// Windows are only scheduled as a whole, a window starting or ending in the block another
+		/// one starts or ends in fails.
+		#[pallet::weight(T::WeightInfo::schedule_maintenance())]
+		#[transactional]
+		pub fn schedule_maintenance(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			start: T::BlockNumber,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::OperatorOrigin>(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			let end = start.saturating_add(duration);
+			ensure!(
+				start > <frame_system::Pallet<T>>::block_number() && end > start,
+				Error::<T>::MaintenanceScheduleFailed
+			);
+
+			for (when, maintenance) in [(start, true), (end, false)] {
+				let call = Call::<T>::set_engine_maintenance {
+					game_engine: game_engine.clone(),
+					maintenance,
+				};
+				T::Scheduler::schedule_named(
+					(GAMEREGISTRY_ID, b"maintenance", &game_engine, when).encode(),
+					DispatchTime::At(when),
+					None,
+					HARD_DEADLINE,
+					frame_system::RawOrigin::Root.into(),
+					call.into(),
+				)
+				.map_err(|_| Error::<T>::MaintenanceScheduleFailed)?;
+			}
+
+			Self::log_admin_action(
+				actor,
+				AdminAction::ScheduleMaintenance,
+				&(&game_engine, start, end),
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::MaintenanceScheduled(game_engine, start, end));
+
+			Ok(())
+		}
+
+		/// Start or end the maintenance of a game engine, dispatched by the scheduler.
+		#[pallet::weight(T::WeightInfo::set_engine_maintenance())]
+		pub fn set_engine_maintenance(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			maintenance: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			<SupportedEngines<T>>::try_mutate(&game_engine, |maybe_info| -> DispatchResult {
+				let engine_info = maybe_info.as_mut().ok_or(Error::<T>::EngineNotRegistered)?;
+				engine_info.maintenance = maintenance;
+				Ok(())
+			})?;
+
+			// Emit an event.
+			Self::deposit_event(Event::EngineMaintenance(game_engine, maintenance));
+
+			Ok(())
+		}
+
+		/// Ban an account from playing, its running games are forfeited.
+		///
+		/// The running games are finished in favour of the opponents, like a no-show of the
+		/// account. Games with more than one opponent are aborted and their stakes refunded.
+		#[pallet::weight(T::WeightInfo::ban_account(T::MaxGamesPerPlayer::get()))]
+		#[transactional]
+		pub fn ban_account(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let actor = Self::ensure_admin::<T::ModerationOrigin>(origin)?;
+
+			<BannedAccounts<T>>::insert(&account, true);
+
+			let mut forfeited = 0;
+			for game_hash in Self::current_games(&account) {
+				let game_entry = Self::game_registry(&game_hash);
+				if game_entry.game_state != GameState::Running {
+					continue
+				}
+				let outcome = Self::forfeit_outcome(&game_entry.players, &account);
+
+				// Emit an event, private games keep their players out of events.
+				if !Self::is_private_game(&game_hash) {
+					Self::deposit_event(Event::GameConceded(game_hash, account.clone()));
+				}
+
+				Self::conclude_game(
+					&account,
+					game_hash,
+					game_entry,
+					outcome,
+					T::Hash::default(),
+					None,
+					None,
+				)?;
+				forfeited += 1;
+			}
+
+			Self::log_admin_action(actor, AdminAction::BanAccount, &account);
+
+			// Emit an event.
+			Self::deposit_event(Event::AccountBanned(account));
+
+			Ok(Some(T::WeightInfo::ban_account(forfeited)).into())
+		}
+
+		/// Unban an account.
+		#[pallet::weight(T::WeightInfo::unban_account())]
+		pub fn unban_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ModerationOrigin>(origin)?;
+
+			<BannedAccounts<T>>::remove(&account);
+
+			Self::log_admin_action(actor, AdminAction::UnbanAccount, &account);
+
+			// Emit an event.
+			Self::deposit_event(Event::AccountUnbanned(account));
+
+			Ok(())
+		}
+
+		/// Ban a player from queueing till a block, e.g. after a confirmed cheating dispute.
+		///
+		/// The ban is a queue ban sanction, it ends by itself once the block is reached.
+		#[pallet::weight(T::WeightInfo::ban_player())]
+		pub fn ban_player(
+			origin: OriginFor<T>,
+			player: T::AccountId,
+			until: T::BlockNumber,
+			reason: ReportCategory,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ModerationOrigin>(origin)?;
+			ensure!(until > <frame_system::Pallet<T>>::block_number(), Error::<T>::BanAlreadyOver);
+
+			<Sanctions<T>>::insert(&player, SanctionKind::QueueBan, until);
+			<Appeals<T>>::remove(&player, SanctionKind::QueueBan);
+
+			Self::log_admin_action(actor, AdminAction::BanPlayer, &(&player, until, &reason));
+
+			// Emit an event.
+			Self::deposit_event(Event::PlayerBanned(player, until, reason));
+
+			Ok(())
+		}
+
+		/// Lift the temporary ban of a player before it ends.
+		#[pallet::weight(T::WeightInfo::unban_player())]
+		pub fn unban_player(origin: OriginFor<T>, player: T::AccountId) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ModerationOrigin>(origin)?;
+			ensure!(Self::is_sanctioned(&player, SanctionKind::QueueBan), Error::<T>::NoSanction);
+
+			<Sanctions<T>>::remove(&player, SanctionKind::QueueBan);
+			<Appeals<T>>::remove(&player, SanctionKind::QueueBan);
+
+			Self::log_admin_action(actor, AdminAction::UnbanPlayer, &player);
+
+			// Emit an event.
+			Self::deposit_event(Event::PlayerUnbanned(player));
+
+			Ok(())
+		}
+
+		/// Allow accounts to queue, while the allowlist mode is on.
+		#[pallet::weight(T::WeightInfo::add_to_allowlist(accounts.len() as u32))]
+		pub fn add_to_allowlist(
+			origin: OriginFor<T>,
+			accounts: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ModerationOrigin>(origin)?;
+			ensure!(accounts.len() <= MAX_ALLOWLIST_BATCH, Error::<T>::AllowlistBatchTooLarge);
+
+			for account in accounts.iter() {
+				<Allowlist<T>>::insert(account, true);
+			}
+
+			Self::log_admin_action(actor, AdminAction::AddToAllowlist, &accounts);
+
+			// Emit an event.
+			Self::deposit_event(Event::AddedToAllowlist(accounts));
+
+			Ok(())
+		}
+
+		/// Remove accounts from the allowlist.
+		#[pallet::weight(T::WeightInfo::remove_from_allowlist(accounts.len() as u32))]
+		pub fn remove_from_allowlist(
+			origin: OriginFor<T>,
+			accounts: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ModerationOrigin>(origin)?;
+			ensure!(accounts.len() <= MAX_ALLOWLIST_BATCH, Error::<T>::AllowlistBatchTooLarge);
+
+			for account in accounts.iter() {
+				<Allowlist<T>>::remove(account);
+			}
+
+			Self::log_admin_action(actor, AdminAction::RemoveFromAllowlist, &accounts);
+
+			// Emit an event.
+			Self::deposit_event(Event::RemovedFromAllowlist(accounts));
+
+			Ok(())
+		}
+
+		/// Resolve a report, the deposit is refunded if upheld and partly slashed otherwise.
+		#[pallet::weight(T::WeightInfo::resolve_report())]
+		pub fn resolve_report(
+			origin: OriginFor<T>,
+			report_id: u32,
+			upheld: bool,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ModerationOrigin>(origin)?;
+
+			let report = <Reports<T>>::take(report_id).ok_or(Error::<T>::NoReport)?;
+			<ReportQueue<T>>::mutate(|queue| queue.retain(|id| *id != report_id));
+
+			let slash = if upheld {
+				Zero::zero()
+			} else {
+				Self::parameters().frivolous_report_slash * report.deposit
+			};
+			let (_imbalance, _remaining) = T::Currency::slash_reserved(&report.reporter, slash);
+			T::Currency::unreserve(&report.reporter, report.deposit.saturating_sub(slash));
+
+			Self::log_admin_action(actor, AdminAction::ResolveReport, &(report_id, upheld));
+
+			// Emit an event.
+			Self::deposit_event(Event::ReportResolved(report_id, upheld));
+
+			Ok(())
+		}
+
+		/// Sanction a player for a number of blocks, enforced automatically until it ends.
+		#[pallet::weight(T::WeightInfo::sanction_player())]
+		pub fn sanction_player(
+			origin: OriginFor<T>,
+			player: T::AccountId,
+			kind: SanctionKind,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ModerationOrigin>(origin)?;
+
+			let until = <frame_system::Pallet<T>>::block_number().saturating_add(duration);
+			<Sanctions<T>>::insert(&player, kind, until);
+			<Appeals<T>>::remove(&player, kind);
+
+			Self::log_admin_action(actor, AdminAction::SanctionPlayer, &(&player, kind, until));
+
+			// Emit an event.
+			Self::deposit_event(Event::PlayerSanctioned(player, kind, until));
+
+			Ok(())
+		}
+
+		/// Appeal an active sanction, escalating it to governance.
+		#[pallet::weight(T::WeightInfo::appeal_sanction())]
+		pub fn appeal_sanction(origin: OriginFor<T>, kind: SanctionKind) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(Self::is_sanctioned(&sender, kind), Error::<T>::NoSanction);
+			ensure!(!<Appeals<T>>::contains_key(&sender, kind), Error::<T>::AlreadyAppealed);
+
+			<Appeals<T>>::insert(&sender, kind, ());
+
+			// Emit an event.
+			Self::deposit_event(Event::SanctionAppealed(sender, kind));
+
+			Ok(())
+		}
+
+		/// Resolve the appeal of a sanction, lifting the sanction or upholding it.
+		#[pallet::weight(T::WeightInfo::resolve_appeal())]
+		pub fn resolve_appeal(
+			origin: OriginFor<T>,
+			player: T::AccountId,
+			kind: SanctionKind,
+			lift: bool,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+			ensure!(<Appeals<T>>::contains_key(&player, kind), Error::<T>::NoAppeal);
+
+			<Appeals<T>>::remove(&player, kind);
+			if lift {
+				<Sanctions<T>>::remove(&player, kind);
+			}
+
+			Self::log_admin_action(actor, AdminAction::ResolveAppeal, &(&player, kind, lift));
+
+			// Emit an event.
+			Self::deposit_event(Event::AppealResolved(player, kind, lift));
+
+			Ok(())
+		}
+
+		/// Rule on a fraud proof, a fraudulent result is voided and the game removed. The deposit
+		/// of the proof is returned if the result is fraudulent and slashed otherwise.
+		#[pallet::weight(T::WeightInfo::rule_on_fraud_proof())]
+		pub fn rule_on_fraud_proof(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			fraudulent: bool,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ArbitrationOrigin>(origin)?;
+
+			ensure!(<FraudProofs<T>>::contains_key(&game_hash), Error::<T>::NoFraudProof);
+			let mut game_entry = Self::game_entry(&game_hash).unwrap_or_default();
+			let outcome = match game_entry.game_state.clone() {
+				GameState::Suspended(outcome) => outcome,
+				_ => return Err(Error::<T>::NoFraudProof)?,
+			};
+			if let Some(fraud_proof) = <FraudProofs<T>>::take(&game_hash) {
+				Self::release_fraud_proof_deposit(&fraud_proof, fraudulent);
+			}
+
+			if fraudulent {
+				<GameRegistry<T>>::remove(&game_hash);
+				<ArchivedGames<T>>::remove(&game_hash);
+				Self::refund_stakes(&game_hash);
+				<GameReplays<T>>::remove(&game_hash);
+				<FinalStates<T>>::remove(&game_hash);
+				Self::remove_match_code(&game_hash);
+				<GameSeeds<T>>::remove(&game_hash);
+				<GameInitData<T>>::remove(&game_hash);
+				<GameClientVersions<T>>::remove(&game_hash);
+				<GameTeams<T>>::remove(&game_hash);
+				Self::count_game(&game_entry.game_engine, |counters| &mut counters.dropped);
+				Self::count_win(&outcome, false);
+				Self::change_game_state(
+					&game_hash,
+					&game_entry,
+					&game_entry.game_state,
+					&GameState::None,
+					actor.clone(),
+				);
+				T::OnGameResult::on_game_result(
+					&game_hash,
+					&game_entry.game_engine,
+					&game_entry.players,
+					&GameResult::Dropped,
+				);
+			} else {
+				let previous_state = game_entry.game_state.clone();
+				game_entry.game_state = GameState::Finished(outcome.clone());
+				Self::change_game_state(
+					&game_hash,
+					&game_entry,
+					&previous_state,
+					&game_entry.game_state,
+					actor.clone(),
+				);
+				Self::archive_game(&game_hash, &game_entry);
+				Self::settle_ruled_result(&game_hash, &game_entry, &outcome);
+			}
+
+			Self::log_admin_action(actor, AdminAction::RuleOnFraudProof, &(game_hash, fraudulent));
+
+			// Emit an event.
+			Self::deposit_event(Event::FraudProofRuled(game_hash, fraudulent));
+
+			Ok(())
+		}
+
+		/// Queue sender up for a game, ranking brackets
+		///
+		/// Without a version the player is queued for the latest version of the game engine.
+		#[pallet::weight(T::WeightInfo::queue())]
+		pub fn queue(
+			origin: OriginFor<T>,
+			engine_id: u8,
+			version: Option<u8>,
+			time_control: Option<u8>,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+
+			Self::do_queue(sender.clone(), &sender, engine_id, version, time_control)
+		}
+
+		/// Leave the queue before being matched, the queue bond is released.
+		///
+		/// With a matchmaker that can't remove players, the player stays in the matchmaker till
+		/// it's matched and skipped, or queues again for the same bracket.
+		#[pallet::weight(T::WeightInfo::leave_queue())]
+		pub fn leave_queue(origin: OriginFor<T>) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+
+			let queue_info = <QueuedPlayers<T>>::take(&sender).ok_or(Error::<T>::NotQueued)?;
+			if !T::MatchMaker::remove_queue(&sender, queue_info.bracket) {
+				<LeftPlayers<T>>::insert(&sender, queue_info.bracket);
+			}
+			<QueuedSince<T>>::remove(&sender);
+			Self::release_queue_bond(&sender);
+
+			// Emit an event.
+			Self::deposit_event(Event::PlayerDequeued(sender, DequeueReason::Left));
+
+			Ok(())
+		}
+
+		/// Set the matchmaking preferences of the sender, `None` clears them.
+		#[pallet::weight(T::WeightInfo::set_preferences())]
+		pub fn set_preferences(
+			origin: OriginFor<T>,
+			preferences: Option<PlayerPreferences>,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+
+			<Preferences<T>>::set(&sender, preferences);
+
+			// Emit an event.
+			Self::deposit_event(Event::PreferencesSet(sender));
+
+			Ok(())
+		}
+
+		/// Challenge friends to a match of a game engine, bypassing matchmaking. The game is
+		/// queued for an AjunaTEE once all opponents accepted with `accept_invite`, invites not
+		/// accepted within `InviteTimeout` blocks expire.
+		///
+		/// The game starts from `init_data`, or the default initialization data of the game
+		/// engine without it. With `start_at` the game is only queued at that block, matches
+		/// accepted after it are queued right away.
+		#[pallet::weight(T::WeightInfo::create_match(opponents.len() as u32))]
+		pub fn create_match(
+			origin: OriginFor<T>,
+			opponents: Vec<T::AccountId>,
+			game_engine: GameEngine,
+			init_data: Option<Vec<u8>>,
+			start_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::CreateMatch)?;
+
+			Self::ensure_engine_open(&game_engine)?;
+			ensure!(
+				Self::engine_tee_count(&game_engine) >= Self::min_tees(),
+				Error::<T>::EngineUnavailable
+			);
+			let mut players = opponents.clone();
+			players.push(sender.clone());
+			players.sort();
+			players.dedup();
+			ensure!(
+				!opponents.is_empty() && players.len() == opponents.len() + 1,
+				Error::<T>::InvalidOpponents
+			);
+			ensure!(
+				players.len() as u32 <= T::MaxPlayersPerGame::get(),
+				Error::<T>::TooManyPlayers
+			);
+			if let Some((min, max)) = Self::players_per_game(&game_engine) {
+				ensure!(
+					(min as usize..=max as usize).contains(&players.len()),
+					Error::<T>::PlayerCountMismatch
+				);
+			}
+			Self::ensure_can_play(&sender, &game_engine)?;
+			let init_data = init_data
+				.map(InitDataOf::<T>::try_from)
+				.transpose()
+				.map_err(|_| Error::<T>::InitDataTooLong)?;
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				start_at.map_or(true, |start_at| start_at > current_block),
+				Error::<T>::InvalidStartBlock
+			);
+
+			let match_id =
+				T::GameIdGenerator::generate_game_id(&game_engine, &players, Self::next_nonce());
+			<InviteInitData<T>>::set(&match_id, init_data);
+			<InviteStarts<T>>::set(&match_id, start_at);
+			let expires = current_block.saturating_add(T::InviteTimeout::get());
+			let pending =
+				BoundedVec::try_from(opponents.clone()).map_err(|_| Error::<T>::TooManyPlayers)?;
+			let accepted = BoundedVec::try_from(sp_std::vec![sender.clone()])
+				.map_err(|_| Error::<T>::TooManyPlayers)?;
+			<MatchInvites<T>>::insert(
+				&match_id,
+				MatchInvite { game_engine, creator: sender.clone(), accepted, pending, expires },
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::MatchCreated(match_id, sender, opponents));
+
+			Ok(())
+		}
+
+		/// Accept the invite to a match, the last player accepting queues its game.
+		#[pallet::weight(T::WeightInfo::accept_invite())]
+		#[transactional]
+		pub fn accept_invite(origin: OriginFor<T>, match_id: T::Hash) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::CreateMatch)?;
+
+			let mut invite = Self::match_invites(&match_id).ok_or(Error::<T>::NoMatchInvite)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() < invite.expires,
+				Error::<T>::MatchInviteExpired
+			);
+			let index = invite
+				.pending
+				.iter()
+				.position(|player| *player == sender)
+				.ok_or(Error::<T>::NotInvitedToMatch)?;
+			Self::ensure_can_play(&sender, &invite.game_engine)?;
+			invite.pending.swap_remove(index);
+			invite
+				.accepted
+				.try_push(sender.clone())
+				.map_err(|_| Error::<T>::TooManyPlayers)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::InviteAccepted(match_id, sender));
+
+			if !invite.pending.is_empty() {
+				<MatchInvites<T>>::insert(&match_id, invite);
+				return Ok(())
+			}
+
+			<MatchInvites<T>>::remove(&match_id);
+			let init_data = <InviteInitData<T>>::take(&match_id);
+			let start_at = <InviteStarts<T>>::take(&match_id)
+				.filter(|start_at| *start_at > <frame_system::Pallet<T>>::block_number());
+			Self::ensure_engine_open(&invite.game_engine)?;
+			let game_hash = Self::new_game(
+				invite.game_engine,
+				invite.accepted.into_inner(),
+				None,
+				false,
+				init_data,
+				start_at,
+			)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::MatchStarted(match_id, game_hash));
+
+			Ok(())
+		}
+
+		/// Queue up anonymously with a commitment `hash(player, salt)`, from an account paying
+		/// the queue bond that isn't linked to the player.
+		///
+		/// The player takes part in matchmaking and games under an account derived from the
+		/// commitment, till revealing its identity with `reveal_identity`.
+		#[pallet::weight(T::WeightInfo::queue_committed())]
+		pub fn queue_committed(
+			origin: OriginFor<T>,
+			engine_id: u8,
+			version: Option<u8>,
+			time_control: Option<u8>,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+
+			Self::do_queue(
+				Self::committed_player(&commitment),
+				&payer,
+				engine_id,
+				version,
+				time_control,
+			)
+		}
+
+		/// Reveal the identity behind a commitment of a matched game, accepting the match.
+		#[pallet::weight(T::WeightInfo::reveal_identity())]
+		pub fn reveal_identity(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::is_banned(&sender), Error::<T>::AccountBanned);
+			ensure!(
+				!Self::is_sanctioned(&sender, SanctionKind::QueueBan),
+				Error::<T>::PlayerSanctioned
+			);
+
+			// retrieve game entry
+			let mut game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(
+				matches!(game_entry.game_state, GameState::Waiting | GameState::Accepted),
+				Error::<T>::GameAlreadyStarted
+			);
+			let committed = Self::committed_player(&T::Hashing::hash_of(&(&sender, salt)));
+			let mut players = game_entry.players.into_inner();
+			let player = players
+				.iter_mut()
+				.find(|player| **player == committed)
+				.ok_or(Error::<T>::NotAPlayer)?;
+			*player = sender.clone();
+			// committed players queue without their account, so its games are checked now
+			Self::ensure_game_slot(&sender, &game_entry.game_engine)?;
+			let engine_id = game_entry.game_engine.id;
+			<PlayerGames<T>>::remove(&committed, engine_id);
+			<CurrentGames<T>>::remove(&committed);
+			Self::count_player_games(&[sender.clone()], engine_id, true);
+			Self::note_current_game(&[sender.clone()], &game_hash, true);
+			game_entry.players =
+				BoundedVec::try_from(players).map_err(|_| Error::<T>::TooManyPlayers)?;
+			<GameRegistry<T>>::insert(&game_hash, game_entry);
+
+			Self::release_queue_bond(&committed);
+
+			// Emit an event.
+			Self::deposit_event(Event::IdentityRevealed(game_hash, sender.clone()));
+			Self::deposit_event(Event::MatchAccepted(game_hash, sender));
+
+			Ok(())
+		}
+
+		/// Queue a player from another chain up for a game, e.g. through an XCM `Transact`.
+		///
+		/// The location is kept on the game entry, so results can be reported back.
+		#[pallet::weight(T::WeightInfo::queue_foreign())]
+		pub fn queue_foreign(
+			origin: OriginFor<T>,
+			engine_id: u8,
+			version: Option<u8>,
+			time_control: Option<u8>,
+		) -> DispatchResult {
+			let location = T::ForeignOrigin::ensure_origin(origin)?;
+			let player = T::LocationToAccountId::convert(location.clone());
+
+			Self::do_queue(player.clone(), &player, engine_id, version, time_control)?;
+			<ForeignPlayers<T>>::insert(&player, location.clone());
+
+			// Emit an event.
+			Self::deposit_event(Event::ForeignPlayerQueued(player, location));
+
+			Ok(())
+		}
+
+		/// Accept match is the ready-check of a player, releasing the queue bond.
+		#[pallet::weight(T::WeightInfo::accept_match())]
+		pub fn accept_match(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			Self::ensure_enabled(RegistryCall::AcceptMatch)?;
+			ensure!(!Self::is_banned(&sender), Error::<T>::AccountBanned);
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.players.contains(&sender), Error::<T>::NotAPlayer);
+			ensure!(
+				matches!(game_entry.game_state, GameState::Waiting | GameState::Accepted),
+				Error::<T>::GameAlreadyStarted
+			);
+
+			Self::release_queue_bond(&sender);
+
+			// Emit an event, private games keep their players out of events.
+			if !Self::is_private_game(&game_hash) {
+				Self::deposit_event(Event::MatchAccepted(game_hash, sender));
+			}
+
+			Ok(())
+		}
+
+		/// Concede a running game, the AjunaTEE running it finishes the game.
+		#[pallet::weight(T::WeightInfo::concede())]
+		pub fn concede(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			Self::ensure_enabled(RegistryCall::Concede)?;
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.players.contains(&sender), Error::<T>::NotAPlayer);
+			ensure!(game_entry.game_state == GameState::Running, Error::<T>::GameNotRunning);
+
+			// Emit an event, private games keep their players out of events.
+			if !Self::is_private_game(&game_hash) {
+				Self::deposit_event(Event::GameConceded(game_hash, sender));
+			}
+
+			Ok(())
+		}
+
+		/// Report an opponent of a game to moderation, backed by a deposit.
+		#[pallet::weight(T::WeightInfo::report_player())]
+		pub fn report_player(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			offender: T::AccountId,
+			category: ReportCategory,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(sender != offender, Error::<T>::CannotReportSelf);
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(
+				game_entry.players.contains(&sender) && game_entry.players.contains(&offender),
+				Error::<T>::NotAPlayer
+			);
+
+			let report_id = Self::next_report_id();
+			let mut queue = Self::report_queue();
+			queue.try_push(report_id).map_err(|_| Error::<T>::ReportQueueFull)?;
+
+			let deposit = T::ReportDeposit::get();
+			T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			<ReportQueue<T>>::put(queue);
+			<NextReportId<T>>::put(report_id.wrapping_add(1));
+			<Reports<T>>::insert(
+				report_id,
+				PlayerReport {
+					reporter: sender.clone(),
+					offender: offender.clone(),
+					game_hash,
+					category,
+					deposit,
+				},
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::PlayerReported(report_id, sender, offender, game_hash));
+
+			Ok(())
+		}
+
+		/// Flag the result of a finished game as suspicious, within the fraud proof window, an
+		/// AjunaTEE has to re-confirm it within the re-confirmation period.
+		#[pallet::weight(T::WeightInfo::flag_result())]
+		pub fn flag_result(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+
+			// retrieve game entry
+			let mut game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.players.contains(&sender), Error::<T>::NotAPlayer);
+			let outcome = match game_entry.game_state.clone() {
+				GameState::Finished(outcome) => outcome,
+				_ => return Err(Error::<T>::GameNotFinished)?,
+			};
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				current_block <=
+					game_entry.timestamps.finished.saturating_add(T::FraudProofWindow::get()) &&
+					!Self::result_confirmed(&game_hash, &game_entry),
+				Error::<T>::FraudProofWindowClosed
+			);
+
+			<ResultFlags<T>>::insert(&game_hash, &sender);
+			let deadline = current_block.saturating_add(T::ReconfirmationPeriod::get());
+			Self::schedule_in_block(deadline, |block| {
+				<ReconfirmationDeadlines<T>>::try_mutate(block, |games| games.try_push(game_hash))
+			});
+			let previous_state = game_entry.game_state.clone();
+			game_entry.game_state = GameState::Reconfirming(outcome);
+			Self::change_game_state(
+				&game_hash,
+				&game_entry,
+				&previous_state,
+				&game_entry.game_state,
+				Some(sender.clone()),
+			);
+			Self::archive_game(&game_hash, &game_entry);
+
+			// Emit an event.
+			Self::deposit_event(Event::ResultFlagged(game_hash, sender));
+
+			Ok(())
+		}
+
+		/// Re-confirm a flagged result, by the AjunaTEE that ran the game or a second one running
+		/// its game engine. A different result sends the game to arbitration.
+		#[pallet::weight(T::WeightInfo::reconfirm_result())]
+		#[transactional]
+		pub fn reconfirm_result(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			outcome: GameOutcomeOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::note_tee_call(&who)?;
+
+			let game_entry = Self::game_entry(&game_hash).unwrap_or_default();
+			let claimed = match game_entry.game_state.clone() {
+				GameState::Reconfirming(claimed) => claimed,
+				_ => return Err(Error::<T>::ResultNotFlagged)?,
+			};
+			let tee_info = Self::registered_tees(&who).ok_or(Error::<T>::NotRegisteredTee)?;
+			ensure!(
+				tee_info.supported_engines.contains(&game_entry.game_engine),
+				Error::<T>::EngineNotSupported
+			);
+
+			if outcome == claimed {
+				let mut game_entry = game_entry;
+				let previous_state = game_entry.game_state.clone();
+				game_entry.game_state = GameState::Finished(claimed.clone());
+				Self::change_game_state(
+					&game_hash,
+					&game_entry,
+					&previous_state,
+					&game_entry.game_state,
+					Some(who.clone()),
+				);
+				Self::archive_game(&game_hash, &game_entry);
+				<ResultFlags<T>>::remove(&game_hash);
+				Self::settle_ruled_result(&game_hash, &game_entry, &claimed);
+
+				// Emit an event.
+				Self::deposit_event(Event::ResultReconfirmed(game_hash, who));
+			} else {
+				Self::escalate_result(game_hash, game_entry, claimed, (who, outcome).encode());
+			}
+
+			Ok(())
+		}
+
+		/// Submit a fraud proof against the result of a finished game, within the fraud proof
+		/// window, the result is suspended till the arbitration rules on it. `FraudProofDeposit`
+		/// is reserved from the sender till then.
+		#[pallet::weight(T::WeightInfo::submit_fraud_proof())]
+		pub fn submit_fraud_proof(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			proof: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let proof = BoundedVec::try_from(proof).map_err(|_| Error::<T>::FraudProofTooLong)?;
+
+			// retrieve game entry
+			let mut game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			let outcome = match game_entry.game_state.clone() {
+				GameState::Finished(outcome) => outcome,
+				_ => return Err(Error::<T>::GameNotFinished)?,
+			};
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				current_block <=
+					game_entry.timestamps.finished.saturating_add(T::FraudProofWindow::get()) &&
+					!Self::result_confirmed(&game_hash, &game_entry),
+				Error::<T>::FraudProofWindowClosed
+			);
+
+			let deposit = T::FraudProofDeposit::get();
+			T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			<FraudProofs<T>>::insert(
+				&game_hash,
+				FraudProof { submitter: sender.clone(), proof, submitted: current_block, deposit },
+			);
+			let previous_state = game_entry.game_state.clone();
+			game_entry.game_state = GameState::Suspended(outcome);
+			Self::change_game_state(
+				&game_hash,
+				&game_entry,
+				&previous_state,
+				&game_entry.game_state,
+				Some(sender.clone()),
+			);
+			Self::archive_game(&game_hash, &game_entry);
+
+			// Emit an event.
+			Self::deposit_event(Event::FraudProofSubmitted(game_hash, sender));
+
+			Ok(())
+		}
+
+		/// Register as spectator of a game, to stream it read-only from the AjunaTEE.
+		#[pallet::weight(T::WeightInfo::register_spectator())]
+		pub fn register_spectator(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_enabled(RegistryCall::RegisterSpectator)?;
+			ensure!(!Self::is_banned(&sender), Error::<T>::AccountBanned);
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(
+				!matches!(
+					game_entry.game_state,
+					GameState::Finished(_) | GameState::Suspended(_) | GameState::Reconfirming(_)
+				),
+				Error::<T>::GameAlreadyFinished
+			);
+
+			ensure!(Self::settings().spectators_enabled, Error::<T>::SpectatingDisabled);
+			let settings = Self::spectator_settings(&game_hash, &game_entry.game_engine);
+			match settings.policy {
+				SpectatorPolicy::Open => (),
+				SpectatorPolicy::Invited => ensure!(
+					<SpectatorInvites<T>>::contains_key(&game_hash, &sender),
+					Error::<T>::NotInvited
+				),
+				SpectatorPolicy::Disabled => Err(Error::<T>::SpectatingDisabled)?,
+			}
+
+			<Spectators<T>>::try_mutate(&game_hash, |spectators| -> DispatchResult {
+				ensure!(!spectators.contains(&sender), Error::<T>::AlreadySpectating);
+				ensure!(
+					(spectators.len() as u32) < settings.max_spectators,
+					Error::<T>::TooManySpectators
+				);
+				spectators.try_push(sender.clone()).map_err(|_| Error::<T>::TooManySpectators)?;
+				Ok(())
+			})?;
+
+			// Emit an event.
+			Self::deposit_event(Event::SpectatorRegistered(game_hash, sender));
+
+			Ok(())
+		}
+
+		/// Set the spectator settings of a game, as a player of the game.
+		#[pallet::weight(T::WeightInfo::set_spectator_settings())]
+		pub fn set_spectator_settings(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			settings: SpectatorSettings,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.players.contains(&sender), Error::<T>::NotAPlayer);
+			ensure!(
+				settings.max_spectators <= T::MaxSpectators::get(),
+				Error::<T>::SpectatorCapTooHigh
+			);
+			<GameSpectatorSettings<T>>::insert(&game_hash, settings);
+
+			// Emit an event.
+			Self::deposit_event(Event::GameSpectatorSettingsSet(game_hash, sender));
+
+			Ok(())
+		}
+
+		/// Invite a spectator to a game, as a player of the game.
+		#[pallet::weight(T::WeightInfo::invite_spectator())]
+		pub fn invite_spectator(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			spectator: T::AccountId,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.players.contains(&sender), Error::<T>::NotAPlayer);
+			<SpectatorInvites<T>>::insert(&game_hash, &spectator, ());
+
+			// Emit an event.
+			Self::deposit_event(Event::SpectatorInvited(game_hash, spectator));
+
+			Ok(())
+		}
+
+		/// Propose a session key to queue, accept matches and concede on behalf of the sender,
+		/// set once the session key accepts it with `accept_session_key`.
+		///
+		/// Bonds are still reserved from the main account, so the session key holds no funds.
+		#[pallet::weight(T::WeightInfo::set_session_key())]
+		pub fn set_session_key(origin: OriginFor<T>, session: T::AccountId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				session != sender && !<SessionKeys<T>>::contains_key(&sender),
+				Error::<T>::InvalidSessionKey
+			);
+			ensure!(!<SessionKeys<T>>::contains_key(&session), Error::<T>::SessionKeyInUse);
+			<SessionKeyProposals<T>>::insert(&session, &sender);
+
+			// Emit an event.
+			Self::deposit_event(Event::SessionKeyProposed(sender, session));
+
+			Ok(())
+		}
+
+		/// Accept to play on behalf of `main` as its session key, proposed with
+		/// `set_session_key`. Accounts that play themselves can't become session keys.
+		#[pallet::weight(T::WeightInfo::accept_session_key())]
+		pub fn accept_session_key(origin: OriginFor<T>, main: T::AccountId) -> DispatchResult {
+			let session = ensure_signed(origin)?;
+
+			ensure!(
+				Self::session_key_proposal(&session).as_ref() == Some(&main),
+				Error::<T>::NoSessionKeyProposal
+			);
+			ensure!(!<SessionKeys<T>>::contains_key(&main), Error::<T>::InvalidSessionKey);
+			ensure!(!<SessionKeys<T>>::contains_key(&session), Error::<T>::SessionKeyInUse);
+			ensure!(!Self::has_player_state(&session), Error::<T>::SessionKeyIsPlayer);
+
+			<SessionKeyProposals<T>>::remove(&session);
+			<SessionKeys<T>>::insert(&session, &main);
+			<SessionKeyCount<T>>::mutate(&main, |count| *count = count.saturating_add(1));
+
+			// Emit an event.
+			Self::deposit_event(Event::SessionKeySet(main, session));
+
+			Ok(())
+		}
+
+		/// Remove a session key, by its main account or by the session key itself.
+		#[pallet::weight(T::WeightInfo::remove_session_key())]
+		pub fn remove_session_key(origin: OriginFor<T>, session: T::AccountId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let main = Self::session_keys(&session).ok_or(Error::<T>::NotSessionKeyOwner)?;
+			ensure!(sender == main || sender == session, Error::<T>::NotSessionKeyOwner);
+			<SessionKeys<T>>::remove(&session);
+			<SessionKeyCount<T>>::mutate_exists(&main, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+			});
+
+			// Emit an event.
+			Self::deposit_event(Event::SessionKeyRemoved(main, session));
+
+			Ok(())
+		}
+
+		/// Drop game will remove the game from the queue and the registry.
+		///
+		/// Running games with a checkpoint, dropped for a failing AjunaTEE, are queued again
+		/// instead, so another AjunaTEE resumes them from their last checkpoint.
+		///
+		/// The weight is charged for a full game queue, the difference to the actual queue is
+		/// refunded.
+		#[pallet::weight(T::WeightInfo::drop_game(T::MaxQueueSize::get()))]
+		pub fn drop_game(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			game_engine: GameEngine,
+			reason: DropReason,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_tee(&who)?;
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::DropGame)?;
+
+			ensure!(reason != DropReason::AdminAction, Error::<T>::InvalidDropReason);
+
+			// retrieve game entry
+			if let Some(game_entry) = Self::game_entry(&game_hash) {
+				Self::ensure_droppable(&game_entry, reason)?;
+				let dropped_engine = game_entry.game_engine.clone();
+				let players = if Self::is_private_game(&game_hash) {
+					Vec::new()
+				} else {
+					game_entry.players.to_vec()
+				};
+				let (requeued, queue_length) = Self::do_drop_game(
+					game_hash,
+					game_entry,
+					&game_engine,
+					reason,
+					Some(who.clone()),
+				);
+
+				// Emit an event.
+				Self::deposit_event(Event::GameDropped {
+					game: game_hash,
+					engine: dropped_engine,
+					reason,
+					tee: who,
+					players,
+				});
+				if requeued {
+					Self::deposit_event(Event::GameRequeued(game_hash));
+				}
+				return Ok(Some(T::WeightInfo::drop_game(queue_length)).into())
+			}
+
+			Ok(Some(T::WeightInfo::drop_game(0)).into())
+		}
+
+		/// Drop many dead games at once, e.g. games orphaned by an incident, up to the
+		/// acknowledgement batch limit.
+		///
+		/// AjunaTEEs drop the games assigned to them, `OperatorOrigin` drops any game. Games
+		/// already gone are skipped and not charged.
+		#[pallet::weight(T::WeightInfo::batch_drop_games(game_hashes.len() as u32))]
+		#[transactional]
+		pub fn batch_drop_games(
+			origin: OriginFor<T>,
+			game_hashes: Vec<T::Hash>,
+			reason: DropReason,
+		) -> DispatchResultWithPostInfo {
+			let (who, operator) = match Self::ensure_admin::<T::OperatorOrigin>(origin.clone()) {
+				Ok(actor) => (actor, true),
+				Err(_) => {
+					let who = ensure_signed(origin)?;
+					Self::ensure_tee(&who)?;
+					Self::note_tee_call(&who)?;
+					ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+					Self::ensure_enabled(RegistryCall::DropGame)?;
+					(Some(who), false)
+				},
+			};
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(
+				game_hashes.len() <= Self::parameters().ack_batch_limit as usize,
+				Error::<T>::DropToMany
+			);
+			ensure!(operator || reason != DropReason::AdminAction, Error::<T>::InvalidDropReason);
+			if operator {
+				Self::log_admin_action(
+					who.clone(),
+					AdminAction::DropGames,
+					&(&game_hashes, reason),
+				);
+			}
+
+			let mut dropped = Vec::new();
+			for game_hash in game_hashes {
+				let game_entry = match Self::game_entry(&game_hash) {
+					Some(game_entry) => game_entry,
+					None => continue,
+				};
+				ensure!(operator || game_entry.tee_id == who, Error::<T>::NotGameTee);
+				Self::ensure_droppable(&game_entry, reason)?;
+				let game_engine = game_entry.game_engine.clone();
+				let (requeued, _) =
+					Self::do_drop_game(game_hash, game_entry, &game_engine, reason, who.clone());
+				if requeued {
+					Self::deposit_event(Event::GameRequeued(game_hash));
+				}
+				dropped.push(game_hash);
+			}
+			let weight = T::WeightInfo::batch_drop_games(dropped.len() as u32);
+
+			// Emit an event.
+			Self::deposit_event(Event::GamesDropped(dropped, reason, who));
+
+			Ok(Some(weight).into())
+		}
+
+		/// Note the AjunaTEEs the block author observed alive, included as inherent.
+		#[pallet::weight((T::WeightInfo::note_tee_liveness(tees.len() as u32), DispatchClass::Mandatory))]
+		pub fn note_tee_liveness(origin: OriginFor<T>, tees: Vec<T::AccountId>) -> DispatchResult {
+			ensure_none(origin)?;
+			ensure!(!<TeeLivenessNoted<T>>::get(), Error::<T>::LivenessAlreadyNoted);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			for tee in tees.iter().filter(|tee| <RegisteredTees<T>>::contains_key(tee)) {
+				<TeeLastSeen<T>>::insert(tee, now);
+			}
+			<TeeLivenessNoted<T>>::put(true);
+
+			Ok(())
+		}
+
+		/// Expire a game past its timeout, dispatched by the scheduler at the deadline of the game
+		/// or submitted unsigned by the offchain worker, in case the maintenance in `on_idle`
+		/// doesn't get to it.
+		///
+		/// Waiting games and games running past the maximum game duration are dropped, accepted
+		/// games are queued again for another AjunaTEE.
+		#[pallet::weight(T::WeightInfo::expire_game())]
+		pub fn expire_game(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			ensure_none(origin.clone()).or_else(|_| ensure_root(origin))?;
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			let reason = Self::expiry_reason(&game_entry).ok_or(Error::<T>::GameNotExpired)?;
+			Self::do_expire_game(game_hash, game_entry, reason);
+
+			Ok(())
+		}
+
+		/// Acknowledge game will remove from queue and set state to accepted.
+		///
+		/// Without an assignment of AjunaTEEs the games are acknowledged from the head of the
+		/// queue, otherwise an AjunaTEE confirms the games assigned to it in any order.
+		///
+		/// Batches failing part way are only charged for the games up to the failing one.
+		#[pallet::weight(T::WeightInfo::ack_game(games.len() as u32))]
+		#[transactional]
+		pub fn ack_game(
+			origin: OriginFor<T>,
+			cluster: GameEngine,
+			games: Vec<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_ack_game(who, cluster, games)
+		}
+
+		/// Drop game will remove the game from the queue and the registry.
+		#[pallet::weight(T::WeightInfo::ready_game())]
+		#[transactional]
+		pub fn ready_game(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_tee(&who)?;
+			Self::note_tee_call(&who)?;
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::ReadyGame)?;
+
+			// retrieve game entry
+			let mut game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.game_state == GameState::Accepted, Error::<T>::GameNotAccepted);
+			// games acknowledged before the AjunaTEE was noted can be started by any AjunaTEE
+			ensure!(
+				game_entry.tee_id.as_ref().map_or(true, |tee| *tee == who),
+				Error::<T>::NotGameTee
+			);
+
+			// initialize the game in the game pallet, if the runtime configured one
+			if let Some(call) = T::GameStartCall::game_start_call(
+				&game_hash,
+				&game_entry.game_engine,
+				&game_entry.players,
+			) {
+				call.dispatch(frame_system::RawOrigin::Root.into()).map_err(|e| e.error)?;
+			}
+
+			let previous_state = game_entry.game_state.clone();
+			game_entry.tee_id = Some(who.clone());
+			game_entry.timestamps.started = <frame_system::Pallet<T>>::block_number();
+			game_entry.game_state = GameState::Running;
+
+			Self::change_game_state(
+				&game_hash,
+				&game_entry,
+				&previous_state,
+				&game_entry.game_state,
+				Some(who.clone()),
+			);
+
+			// insert changed game entry back
+			<GameRegistry<T>>::insert(game_hash, game_entry.clone());
+			T::GameObserver::on_game_started(&game_hash, &game_entry.players);
+
+			// the AjunaTEE reads the players of private games from the game entry
+			let players = if Self::is_private_game(&game_hash) {
+				Vec::new()
+			} else {
+				game_entry.players.into_inner()
+			};
+
+			// Emit an event.
+			Self::deposit_event(Event::GameStarted {
+				game: game_hash,
+				tee: who.clone(),
+				players: players.clone(),
+			});
+			let endpoint = Self::registered_tees(&who)
+				.map(|tee_info| tee_info.endpoint.into_inner())
+				.unwrap_or_default();
+			Self::deposit_event(Event::GameConnection(
+				game_hash,
+				game_entry.game_engine,
+				players,
+				who,
+				endpoint,
+			));
+
+			Ok(())
+		}
+
+		/// Post a state commitment of a running game, by the AjunaTEE running it.
+		///
+		/// Long games are checkpointed periodically, so a game losing its AjunaTEE is resumed or
+		/// adjudicated from its last checkpoint, instead of being voided.
+		#[pallet::weight(T::WeightInfo::checkpoint_game())]
+		#[transactional]
+		pub fn checkpoint_game(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			state_root: T::Hash,
+			turn: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_tee(&who)?;
+			Self::note_tee_call(&who)?;
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::CheckpointGame)?;
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.game_state == GameState::Running, Error::<T>::GameNotRunning);
+			ensure!(game_entry.tee_id.as_ref() == Some(&who), Error::<T>::NotGameTee);
+			ensure!(
+				Self::game_checkpoint(&game_hash).map_or(true, |last| turn > last.turn),
+				Error::<T>::StaleCheckpoint
+			);
+
+			let block = <frame_system::Pallet<T>>::block_number();
+			<GameCheckpoints<T>>::insert(&game_hash, GameCheckpoint { state_root, turn, block });
+
+			// Emit an event.
+			Self::deposit_event(Event::GameCheckpointed(game_hash, state_root, turn));
+
+			Ok(())
+		}
+
+		/// Finish a running game with its outcome, a winner, a draw, a ranking of the players or
+		/// an aborted game. Results of enclaves with a signing key have to be signed over the game
+		/// hash, outcome, final state, the nonce of the AjunaTEE, the replay and the result proof.
+		///
+		/// The final state is the hash of the terminal game state, e.g. the final board, the
+		/// replay is a content hash or CID of the full game replay kept off-chain and the result
+		/// proof is the hash of the game transcript signed by the enclave, checked with
+		/// `verify_result`.
+		#[pallet::weight(Self::finish_weight(T::WeightInfo::finish_game(), 1))]
+		#[transactional]
+		pub fn finish_game(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			outcome: GameOutcomeOf<T>,
+			final_state: T::Hash,
+			signature: Option<MultiSignature>,
+			replay: Option<Vec<u8>>,
+			result_proof: Option<T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_tee(&who)?;
+			Self::note_tee_call(&who)?;
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::FinishGame)?;
+
+			Self::do_finish_game(
+				&who,
+				game_hash,
+				outcome,
+				final_state,
+				signature,
+				replay,
+				result_proof,
+			)
+		}
+
+		/// Finish many games in one call, up to the acknowledgement batch limit.
+		///
+		/// Every result is applied on its own, results that fail to finish a game are left out,
+		/// listed in the `GamesFinished` event and not charged.
+		#[pallet::weight(Self::finish_weight(
+			T::WeightInfo::batch_finish_games(results.len() as u32),
+			results.len() as u32
+		))]
+		#[transactional]
+		pub fn batch_finish_games(
+			origin: OriginFor<T>,
+			results: Vec<GameSubmission<T::AccountId, T::Hash, T::MaxPlayersPerGame>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_tee(&who)?;
+			Self::note_tee_call(&who)?;
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::FinishGame)?;
+			ensure!(
+				results.len() <= Self::parameters().ack_batch_limit as usize,
+				Error::<T>::FinishToMany
+			);
+
+			let mut finished = 0u32;
+			let mut failed = Vec::new();
+			for result in results {
+				let game_hash = result.game_hash;
+				let outcome = with_transaction(|| {
+					match Self::do_finish_game(
+						&who,
+						result.game_hash,
+						result.outcome,
+						result.final_state,
+						result.signature,
+						result.replay,
+						result.result_proof,
+					) {
+						Ok(()) => TransactionOutcome::Commit(Ok(())),
+						Err(e) => TransactionOutcome::Rollback(Err(e)),
+					}
+				});
+				match outcome {
+					Ok(()) => finished += 1,
+					Err(_) => failed.push(game_hash),
+				}
+			}
+
+			let weight = Self::finish_weight(T::WeightInfo::batch_finish_games(finished), finished);
+
+			// Emit an event.
+			Self::deposit_event(Event::GamesFinished(who, finished, failed));
+
+			Ok(Some(weight).into())
+		}
+
+		/// Overturn a suspended result with another outcome of the game, ruling on its fraud
+		/// proof.
+		#[pallet::weight(T::WeightInfo::overturn_result())]
+		pub fn overturn_result(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			outcome: GameOutcomeOf<T>,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::ArbitrationOrigin>(origin)?;
+
+			ensure!(<FraudProofs<T>>::contains_key(&game_hash), Error::<T>::NoFraudProof);
+			let mut game_entry = Self::game_entry(&game_hash).unwrap_or_default();
+			let overturned = match game_entry.game_state.clone() {
+				GameState::Suspended(overturned) => overturned,
+				_ => return Err(Error::<T>::NoFraudProof)?,
+			};
+			Self::ensure_outcome(&outcome, &game_entry.players)?;
+			if let Some(fraud_proof) = <FraudProofs<T>>::take(&game_hash) {
+				Self::release_fraud_proof_deposit(&fraud_proof, true);
+			}
+
+			// the win moves over to the new winner
+			Self::count_win(&overturned, false);
+			Self::count_win(&outcome, true);
+
+			let previous_state = game_entry.game_state.clone();
+			game_entry.game_state = GameState::Finished(outcome.clone());
+			game_entry.result = Some(outcome.clone().into());
+			Self::change_game_state(
+				&game_hash,
+				&game_entry,
+				&previous_state,
+				&game_entry.game_state,
+				actor.clone(),
+			);
+			T::OnGameResult::on_game_result(
+				&game_hash,
+				&game_entry.game_engine,
+				&game_entry.players,
+				&outcome.clone().into(),
+			);
+			Self::archive_game(&game_hash, &game_entry);
+			Self::settle_ruled_result(&game_hash, &game_entry, &outcome);
+
+			Self::log_admin_action(actor, AdminAction::OverturnResult, &(game_hash, &outcome));
+
+			// Emit an event.
+			Self::deposit_event(Event::ResultOverturned(game_hash, outcome));
+
+			Ok(())
+		}
+
+		/// Heartbeat of an AjunaTEE, its games are reassigned if it's not seen for `TeeTimeout`
+		/// blocks.
+		#[pallet::weight(T::WeightInfo::tee_heartbeat())]
+		pub fn tee_heartbeat(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_tee(&who)?;
+			Self::note_tee_call(&who)?;
+
+			<TeeLastSeen<T>>::insert(&who, <frame_system::Pallet<T>>::block_number());
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeHeartbeat(who));
+
+			Ok(())
+		}
+
+		/// Pause a game engine for an incident, or unpause it. A paused game engine takes no new
+		/// games and its players aren't matched, games already created are still played and
+		/// finished.
+		#[pallet::weight(T::WeightInfo::set_engine_paused())]
+		pub fn set_engine_paused(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			paused: bool,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::OperatorOrigin>(origin)?;
+			ensure!(
+				<SupportedEngines<T>>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+
+			if paused {
+				<PausedEngines<T>>::insert(&game_engine, true);
+			} else {
+				<PausedEngines<T>>::remove(&game_engine);
+			}
+
+			Self::log_admin_action(actor, AdminAction::PauseEngine, &(&game_engine, paused));
+
+			// Emit an event.
+			Self::deposit_event(Event::EnginePaused(game_engine, paused));
+
+			Ok(())
+		}
+
+		/// Set the default initialization data of the games of a game engine, `None` clears it.
+		#[pallet::weight(T::WeightInfo::set_engine_init_data())]
+		pub fn set_engine_init_data(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			init_data: Option<Vec<u8>>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			let init_data = init_data
+				.map(InitDataOf::<T>::try_from)
+				.transpose()
+				.map_err(|_| Error::<T>::InitDataTooLong)?;
+			<EngineInitData<T>>::set(&game_engine, init_data);
+
+			// Emit an event.
+			Self::deposit_event(Event::EngineInitDataSet(game_engine));
+
+			Ok(())
+		}
+
+		/// Report a player that never showed up for a running game, by the AjunaTEE running it
+		/// once `NoShowGracePeriod` blocks passed since the game started.
+		///
+		/// The game is finished right away, won by the player left or aborted with the stakes
+		/// refunded when more players are left. The absent player gets a no-show counted against
+		/// it, `MaxNoShows` no-shows ban it from queueing for a while.
+		#[pallet::weight(T::WeightInfo::report_no_show())]
+		#[transactional]
+		pub fn report_no_show(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			absent_player: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_tee(&who)?;
+			Self::note_tee_call(&who)?;
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::FinishGame)?;
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.game_state == GameState::Running, Error::<T>::GameNotRunning);
+			ensure!(game_entry.tee_id.as_ref() == Some(&who), Error::<T>::NotGameTee);
+			ensure!(game_entry.players.contains(&absent_player), Error::<T>::NotAPlayer);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >=
+					game_entry.timestamps.started.saturating_add(T::NoShowGracePeriod::get()),
+				Error::<T>::NoShowTooEarly
+			);
+
+			let outcome = Self::forfeit_outcome(&game_entry.players, &absent_player);
+			let strikes = Self::note_no_show(&absent_player);
+
+			// Emit an event.
+			Self::deposit_event(Event::NoShowReported(game_hash, absent_player, strikes));
+
+			Self::conclude_game(
+				&who,
+				game_hash,
+				game_entry,
+				outcome,
+				T::Hash::default(),
+				None,
+				None,
+			)
+		}
+
+		/// Set a single game rule of a game engine, replacing its rule of the same kind.
+		#[pallet::weight(T::WeightInfo::set_game_rule())]
+		pub fn set_game_rule(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			game_rule: GameRule<GameRuleType>,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			Self::ensure_valid_rule(&game_rule)?;
+			<GameRequirments<T>>::try_mutate(&game_engine, |game_rules| {
+				let kind = sp_std::mem::discriminant(&game_rule.game_rule_type);
+				match game_rules
+					.iter_mut()
+					.find(|existing| sp_std::mem::discriminant(&existing.game_rule_type) == kind)
+				{
+					Some(existing) => *existing = game_rule.clone(),
+					None => game_rules
+						.try_push(game_rule.clone())
+						.map_err(|_| Error::<T>::TooManyGameRules)?,
+				}
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::log_admin_action(actor, AdminAction::SetGameRule, &(&game_engine, &game_rule));
+
+			// Emit an event.
+			Self::deposit_event(Event::GameRulesSet(game_engine));
+
+			Ok(())
+		}
+
+		/// Clear all game rules of a game engine.
+		#[pallet::weight(T::WeightInfo::clear_game_rules())]
+		pub fn clear_game_rules(origin: OriginFor<T>, game_engine: GameEngine) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			<GameRequirments<T>>::remove(&game_engine);
+
+			Self::log_admin_action(actor, AdminAction::ClearGameRules, &game_engine);
+
+			// Emit an event.
+			Self::deposit_event(Event::GameRulesCleared(game_engine));
+
+			Ok(())
+		}
+
+		/// Verify the transcript of a finished game against the result proof its AjunaTEE
+		/// reported, anyone can prove what the AjunaTEE reported this way.
+		#[pallet::weight(T::WeightInfo::verify_result(transcript.len() as u32))]
+		pub fn verify_result(
+			origin: OriginFor<T>,
+			game_hash: T::Hash,
+			transcript: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				transcript.len() <= MAX_TRANSCRIPT_LENGTH as usize,
+				Error::<T>::TranscriptTooLong
+			);
+			let result_proof = Self::result_proof(&game_hash).ok_or(Error::<T>::NoResultProof)?;
+			ensure!(T::Hashing::hash(&transcript) == result_proof, Error::<T>::ResultProofMismatch);
+
+			// Emit an event.
+			Self::deposit_event(Event::ResultVerified(game_hash, who));
+
+			Ok(())
+		}
+
+		/// Request a rematch of a finished game, within `RematchWindow` blocks after it finished.
+		///
+		/// Once all of its players requested it, a game between them is queued with the same game
+		/// engine version and time control, skipping the matchmaking.
+		#[pallet::weight(T::WeightInfo::request_rematch())]
+		#[transactional]
+		pub fn request_rematch(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::is_banned(&sender), Error::<T>::AccountBanned);
+			ensure!(
+				!Self::is_sanctioned(&sender, SanctionKind::QueueBan),
+				Error::<T>::PlayerSanctioned
+			);
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.players.contains(&sender), Error::<T>::NotAPlayer);
+			ensure!(
+				matches!(game_entry.game_state, GameState::Finished(_)),
+				Error::<T>::GameNotFinished
+			);
+			let deadline = game_entry.timestamps.finished.saturating_add(T::RematchWindow::get());
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= deadline,
+				Error::<T>::RematchWindowClosed
+			);
+
+			let mut requests = Self::rematch_requests(&game_hash);
+			ensure!(!requests.contains(&sender), Error::<T>::RematchAlreadyRequested);
+			if requests.is_empty() {
+				Self::schedule_in_block(deadline.saturating_add(One::one()), |block| {
+					<RematchDeadlines<T>>::try_mutate(block, |games| games.try_push(game_hash))
+				});
+			}
+			requests.try_push(sender.clone()).map_err(|_| Error::<T>::TooManyPlayers)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::RematchRequested(game_hash, sender));
+
+			if requests.len() < game_entry.players.len() {
+				<RematchRequests<T>>::insert(&game_hash, requests);
+				return Ok(())
+			}
+
+			<RematchRequests<T>>::remove(&game_hash);
+			Self::ensure_engine_open(&game_entry.game_engine)?;
+			ensure!(
+				Self::engine_stake(&game_entry.game_engine).is_none(),
+				Error::<T>::StakedRematch
+			);
+			for player in game_entry.players.iter() {
+				ensure!(!Self::is_banned(player), Error::<T>::AccountBanned);
+				Self::ensure_game_slot(player, &game_entry.game_engine)?;
+			}
+			let rematch = Self::queue_game(
+				game_entry.game_engine,
+				game_entry.players.into_inner(),
+				game_entry.time_control,
+				false,
+				None,
+			)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::RematchCreated(game_hash, rematch));
+
+			Ok(())
+		}
+
+		/// Create a single-elimination tournament of a game engine version for up to
+		/// `max_players` players, each reserving `entry_fee` to join.
+		///
+		/// The first round starts in `start_block`, the winners of a round meet in the next one
+		/// and the last player left wins the entry fees of all players.
+		#[pallet::weight(T::WeightInfo::create_tournament())]
+		pub fn create_tournament(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			max_players: u32,
+			entry_fee: BalanceOf<T>,
+			start_block: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_engine_open(&game_engine)?;
+			ensure!(
+				(2..=T::MaxTournamentPlayers::get()).contains(&max_players) &&
+					start_block > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidTournament
+			);
+
+			let tournament_id = Self::next_tournament_id();
+			<NextTournamentId<T>>::put(tournament_id.wrapping_add(1));
+			<Tournaments<T>>::insert(
+				tournament_id,
+				Tournament {
+					game_engine: game_engine.clone(),
+					creator: sender.clone(),
+					max_players,
+					entry_fee,
+					start_block,
+					players: Default::default(),
+					round: 0,
+					bracket: Default::default(),
+					advancing: Default::default(),
+					state: TournamentState::Open,
+				},
+			);
+			Self::schedule_in_block(start_block, |block| {
+				<TournamentRounds<T>>::try_mutate(block, |rounds| rounds.try_push(tournament_id))
+			});
+
+			// Emit an event.
+			Self::deposit_event(Event::TournamentCreated(tournament_id, game_engine, sender));
+
+			Ok(())
+		}
+
+		/// Join a tournament before it starts, reserving its entry fee.
+		#[pallet::weight(T::WeightInfo::join_tournament())]
+		pub fn join_tournament(origin: OriginFor<T>, tournament_id: u32) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			ensure!(!Self::is_banned(&sender), Error::<T>::AccountBanned);
+			ensure!(
+				!Self::is_sanctioned(&sender, SanctionKind::QueueBan),
+				Error::<T>::PlayerSanctioned
+			);
+
+			let mut tournament =
+				Self::tournaments(tournament_id).ok_or(Error::<T>::NoTournament)?;
+			ensure!(tournament.state == TournamentState::Open, Error::<T>::TournamentClosed);
+			ensure!(!tournament.players.contains(&sender), Error::<T>::AlreadyJoined);
+			ensure!(
+				(tournament.players.len() as u32) < tournament.max_players,
+				Error::<T>::TournamentFull
+			);
+			Self::check_game_rules(&tournament.game_engine, &sender)?;
+			tournament
+				.players
+				.try_push(sender.clone())
+				.map_err(|_| Error::<T>::TournamentFull)?;
+			T::Currency::reserve(&sender, tournament.entry_fee)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			<Tournaments<T>>::insert(tournament_id, tournament);
+
+			// Emit an event.
+			Self::deposit_event(Event::TournamentJoined(tournament_id, sender));
+
+			Ok(())
+		}
+
+		/// Agree to abort a running game without a winner.
+		///
+		/// Once all of its players agreed, the game is finished as aborted: the stakes are
+		/// refunded, ratings are left untouched and `GameAborted` tells the AjunaTEE running it to
+		/// tear down its session.
+		#[pallet::weight(T::WeightInfo::propose_abort())]
+		#[transactional]
+		pub fn propose_abort(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::Concede)?;
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.players.contains(&sender), Error::<T>::NotAPlayer);
+			ensure!(game_entry.game_state == GameState::Running, Error::<T>::GameNotRunning);
+
+			let mut proposals = Self::abort_proposals(&game_hash);
+			ensure!(!proposals.contains(&sender), Error::<T>::AbortAlreadyProposed);
+			proposals.try_push(sender.clone()).map_err(|_| Error::<T>::TooManyPlayers)?;
+
+			// Emit an event, private games keep their players out of events.
+			if !Self::is_private_game(&game_hash) {
+				Self::deposit_event(Event::AbortProposed(game_hash, sender.clone()));
+			}
+
+			if proposals.len() < game_entry.players.len() {
+				<AbortProposals<T>>::insert(&game_hash, proposals);
+				return Ok(())
+			}
+
+			let tee = game_entry.tee_id.clone();
+			Self::conclude_game(
+				&sender,
+				game_hash,
+				game_entry,
+				GameOutcome::Aborted,
+				T::Hash::default(),
+				None,
+				None,
+			)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::GameAborted(game_hash, tee));
+
+			Ok(())
+		}
+
+		/// Hand games over to another AjunaTEE, e.g. to drain an AjunaTEE before its upgrade, up
+		/// to the acknowledgement batch limit.
+		///
+		/// AjunaTEEs hand over the games assigned to them, `OperatorOrigin` any game. The games are
+		/// accepted by the receiving AjunaTEE, which gets them ready to resume their sessions.
+		#[pallet::weight(T::WeightInfo::handover_games(game_hashes.len() as u32))]
+		#[transactional]
+		pub fn handover_games(
+			origin: OriginFor<T>,
+			to_tee: T::AccountId,
+			game_hashes: Vec<T::Hash>,
+		) -> DispatchResult {
+			let (who, operator) = match Self::ensure_admin::<T::OperatorOrigin>(origin.clone()) {
+				Ok(actor) => (actor, true),
+				Err(_) => {
+					let who = ensure_signed(origin)?;
+					Self::ensure_tee(&who)?;
+					Self::note_tee_call(&who)?;
+					ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+					(Some(who), false)
+				},
+			};
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(
+				game_hashes.len() <= Self::parameters().ack_batch_limit as usize,
+				Error::<T>::HandoverToMany
+			);
+			let tee_info = Self::registered_tees(&to_tee).ok_or(Error::<T>::NotRegisteredTee)?;
+			ensure!(
+				Self::tee_loads(&to_tee).total().saturating_add(game_hashes.len() as u32) <=
+					T::MaxGamesPerTee::get(),
+				Error::<T>::TeeAtCapacity
+			);
+			if operator {
+				Self::log_admin_action(
+					who.clone(),
+					AdminAction::HandoverGames,
+					&(&to_tee, &game_hashes),
+				);
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			for game_hash in game_hashes {
+				let mut game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+				ensure!(operator || game_entry.tee_id == who, Error::<T>::NotGameTee);
+				ensure!(
+					matches!(game_entry.game_state, GameState::Accepted | GameState::Running),
+					Error::<T>::GameNotActive
+				);
+				ensure!(game_entry.tee_id.as_ref() != Some(&to_tee), Error::<T>::InvalidHandover);
+				ensure!(
+					tee_info.supported_engines.contains(&game_entry.game_engine),
+					Error::<T>::EngineNotSupported
+				);
+				// games above the high-value wager only go to AjunaTEEs of the high-value tier
+				ensure!(
+					Self::dev_mode() ||
+						Self::game_wagers(&game_hash) <= T::HighValueWager::get() ||
+						Self::tee_bonds(&to_tee) >= T::HighValueTeeBond::get(),
+					Error::<T>::TeeBondTooLow
+				);
+
+				// the load of the game moves over to the receiving AjunaTEE
+				let previous_state = game_entry.game_state.clone();
+				let from_tee = game_entry.tee_id.take();
+				if let Some(from_tee) = from_tee.as_ref() {
+					Self::note_tee_load(from_tee, &game_hash, &previous_state, &GameState::Waiting);
+				}
+				game_entry.game_state = GameState::Accepted;
+				// the receiving AjunaTEE starts the session again
+				game_entry.timestamps.accepted = now;
+				game_entry.timestamps.started = Zero::zero();
+				Self::change_game_state(
+					&game_hash,
+					&game_entry,
+					&previous_state,
+					&game_entry.game_state,
+					who.clone(),
+				);
+				Self::note_tee_load(&to_tee, &game_hash, &GameState::Waiting, &GameState::Accepted);
+				game_entry.tee_id = Some(to_tee.clone());
+				<GameRegistry<T>>::insert(&game_hash, game_entry);
+
+				// Emit an event.
+				Self::deposit_event(Event::GameHandedOver(game_hash, from_tee, to_tee.clone()));
+			}
+
+			Ok(())
+		}
+
+		/// Set the game client versions a game engine version supports, `None` for every client.
+		///
+		/// Players are only queued with a client sharing a version with the game engine version.
+		#[pallet::weight(T::WeightInfo::set_engine_client_versions())]
+		pub fn set_engine_client_versions(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			client_versions: Option<ClientVersions>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			match client_versions {
+				Some(versions) => {
+					ensure!(versions.min <= versions.max, Error::<T>::InvalidClientVersions);
+					<EngineClientVersions<T>>::insert(&game_engine, versions);
+				},
+				None => <EngineClientVersions<T>>::remove(&game_engine),
+			}
+
+			// Emit an event.
+			Self::deposit_event(Event::EngineClientVersionsSet(game_engine, client_versions));
+
+			Ok(())
+		}
+
+		/// Set the versions the game client of the sender supports, `None` clears them.
+		///
+		/// Matchmaking only pairs players whose clients share a version, the highest one they
+		/// share is kept for the game in `GameClientVersions`.
+		#[pallet::weight(T::WeightInfo::set_client_versions())]
+		pub fn set_client_versions(
+			origin: OriginFor<T>,
+			client_versions: Option<ClientVersions>,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+
+			if let Some(versions) = client_versions {
+				ensure!(versions.min <= versions.max, Error::<T>::InvalidClientVersions);
+			}
+			<PlayerClients<T>>::set(&sender, client_versions);
+
+			// Emit an event.
+			Self::deposit_event(Event::ClientVersionsSet(sender, client_versions));
+
+			Ok(())
+		}
+
+		/// Finish a running game without a funded account for the AjunaTEE. The result is signed
+		/// by the enclave signing key of the AjunaTEE like for `finish_game`, over its next result
+		/// nonce.
+		///
+		/// Submitted unsigned, the submission is checked in `validate_unsigned` and costs no fee.
+		#[pallet::weight(Self::finish_weight(T::WeightInfo::finish_game_unsigned(), 1))]
+		#[transactional]
+		pub fn finish_game_unsigned(
+			origin: OriginFor<T>,
+			tee: T::AccountId,
+			result: GameSubmission<T::AccountId, T::Hash, T::MaxPlayersPerGame>,
+			nonce: u64,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::ensure_unsigned_tee(&tee, nonce)?;
+			Self::note_tee_call(&tee)?;
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::FinishGame)?;
+
+			Self::do_finish_game(
+				&tee,
+				result.game_hash,
+				result.outcome,
+				result.final_state,
+				result.signature,
+				result.replay,
+				result.result_proof,
+			)
+		}
+
+		/// Acknowledge games without a funded account for the AjunaTEE, signed by the enclave
+		/// signing key of the AjunaTEE over the games and its next result nonce.
+		///
+		/// Submitted unsigned, the submission is checked in `validate_unsigned` and costs no fee.
+		#[pallet::weight(T::WeightInfo::ack_game_unsigned(games.len() as u32))]
+		#[transactional]
+		pub fn ack_game_unsigned(
+			origin: OriginFor<T>,
+			tee: T::AccountId,
+			cluster: GameEngine,
+			games: Vec<T::Hash>,
+			nonce: u64,
+			signature: MultiSignature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let signing_key = Self::ensure_unsigned_tee(&tee, nonce)?;
+			let payload = (&cluster, &games, nonce).encode();
+			ensure!(
+				signature.verify(&payload[..], &signing_key),
+				Error::<T>::InvalidResultSignature
+			);
+			<TeeResultNonce<T>>::insert(&tee, nonce.wrapping_add(1));
+
+			Self::do_ack_game(tee, cluster, games)
+		}
+
+		/// Queue a scheduled game for the AjunaTEEs at its start block, dispatched by the
+		/// scheduler.
+		#[pallet::weight(T::WeightInfo::start_scheduled_game())]
+		#[transactional]
+		pub fn start_scheduled_game(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let mut game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.game_state == GameState::Scheduled, Error::<T>::GameNotScheduled);
+			game_entry.game_state = GameState::Waiting;
+			game_entry.timestamps.queued = <frame_system::Pallet<T>>::block_number();
+			game_entry.tee_id = Self::assign_tee(&game_entry.game_engine, &game_hash, None);
+			<GameRegistry<T>>::insert(&game_hash, &game_entry);
+			Self::change_game_state(
+				&game_hash,
+				&game_entry,
+				&GameState::Scheduled,
+				&GameState::Waiting,
+				None,
+			);
+
+			Self::enqueue_new_game(&game_entry)
+		}
+
+		/// Form a premade party for a game engine version, inviting `members` to queue together
+		/// with the sender as its leader. Every member reserves the queue bond on accepting with
+		/// `accept_party_invite`, the party is queued once all accepted.
+		///
+		/// Queued parties are matched against a team of the parties queued after them with the
+		/// same amount of players, a party without members queues a solo player.
+		#[pallet::weight(T::WeightInfo::queue_party(members.len() as u32))]
+		#[transactional]
+		pub fn queue_party(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			members: BoundedVec<T::AccountId, T::MaxPartySize>,
+		) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::Queue)?;
+
+			Self::ensure_engine_open(&game_engine)?;
+			ensure!(
+				Self::engine_tee_count(&game_engine) >= Self::min_tees(),
+				Error::<T>::EngineUnavailable
+			);
+			let mut players = members.to_vec();
+			players.push(sender.clone());
+			players.sort();
+			players.dedup();
+			let game_size = 2 * players.len();
+			ensure!(
+				players.len() == members.len() + 1 &&
+					players.len() as u32 <= T::MaxPartySize::get() &&
+					game_size as u32 <= T::MaxPlayersPerGame::get() &&
+					Self::players_per_game(&game_engine).map_or(true, |(min, max)| {
+						(min as usize..=max as usize).contains(&game_size)
+					}),
+				Error::<T>::InvalidParty
+			);
+			Self::ensure_can_play(&sender, &game_engine)?;
+			Self::reserve_party_bond(&sender)?;
+
+			let leader = BoundedVec::try_from(sp_std::vec![sender.clone()])
+				.map_err(|_| Error::<T>::InvalidParty)?;
+			<Parties<T>>::insert(
+				&sender,
+				Party {
+					game_engine: game_engine.clone(),
+					members: leader,
+					pending: members.clone(),
+				},
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::PartyCreated(
+				sender.clone(),
+				game_engine.clone(),
+				members.to_vec(),
+			));
+
+			if members.is_empty() {
+				Self::enqueue_party(&sender, &game_engine, 1)?;
+			}
+
+			Ok(())
+		}
+
+		/// Accept the invite to the party of `leader`, reserving the queue bond. The last member
+		/// accepting queues the party.
+		#[pallet::weight(T::WeightInfo::accept_party_invite())]
+		#[transactional]
+		pub fn accept_party_invite(origin: OriginFor<T>, leader: T::AccountId) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::Queue)?;
+
+			let mut party = Self::parties(&leader).ok_or(Error::<T>::NoParty)?;
+			let index = party
+				.pending
+				.iter()
+				.position(|player| *player == sender)
+				.ok_or(Error::<T>::NotInvitedToParty)?;
+			Self::ensure_can_play(&sender, &party.game_engine)?;
+			Self::reserve_party_bond(&sender)?;
+			party.pending.swap_remove(index);
+			party.members.try_push(sender.clone()).map_err(|_| Error::<T>::InvalidParty)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::PartyInviteAccepted(leader.clone(), sender));
+
+			if party.pending.is_empty() {
+				Self::enqueue_party(&leader, &party.game_engine, party.members.len() as u32)?;
+			}
+			<Parties<T>>::insert(&leader, party);
+
+			Ok(())
+		}
+
+		/// Disband the party of the sender before it's matched, releasing the bonds of its
+		/// members.
+		#[pallet::weight(T::WeightInfo::disband_party(T::MaxPartySize::get()))]
+		pub fn disband_party(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let sender = Self::ensure_player(origin)?;
+
+			let party = Self::parties(&sender).ok_or(Error::<T>::NoParty)?;
+			let members = party.members.len() as u32;
+			<PartyQueues<T>>::mutate(&party.game_engine, |queue| {
+				queue.retain(|leader| *leader != sender)
+			});
+			Self::dissolve_party(&sender, party);
+
+			Ok(Some(T::WeightInfo::disband_party(members)).into())
+		}
+
+		/// Register a cluster of AjunaTEEs serving game engine versions, run by an operator.
+		///
+		/// The operator adds registered AjunaTEEs to the cluster with `add_cluster_tee`, they
+		/// run the game engine versions of the cluster instead of their own.
+		#[pallet::weight(T::WeightInfo::register_cluster())]
+		pub fn register_cluster(
+			origin: OriginFor<T>,
+			engines: Vec<GameEngine>,
+			operator: T::AccountId,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			let engines = Self::dedup_engines(engines)?;
+			let cluster_id = Self::next_cluster_id();
+			<NextClusterId<T>>::put(cluster_id.wrapping_add(1));
+			<Clusters<T>>::insert(
+				cluster_id,
+				TeeCluster { operator: operator.clone(), engines, tees: Default::default() },
+			);
+
+			Self::log_admin_action(actor, AdminAction::RegisterCluster, &(cluster_id, &operator));
+
+			// Emit an event.
+			Self::deposit_event(Event::ClusterRegistered(cluster_id, operator));
+
+			Ok(())
+		}
+
+		/// Add a registered AjunaTEE to a cluster of the sending operator, running the game
+		/// engine versions of the cluster from now on.
+		#[pallet::weight(T::WeightInfo::add_cluster_tee())]
+		pub fn add_cluster_tee(
+			origin: OriginFor<T>,
+			cluster_id: u32,
+			tee: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut cluster = Self::clusters(cluster_id).ok_or(Error::<T>::NoCluster)?;
+			ensure!(cluster.operator == who, Error::<T>::NotClusterOperator);
+			ensure!(<RegisteredTees<T>>::contains_key(&tee), Error::<T>::NotRegisteredTee);
+			ensure!(Self::tee_cluster(&tee).is_none(), Error::<T>::TeeInCluster);
+			cluster.tees.try_push(tee.clone()).map_err(|_| Error::<T>::TooManyClusterTees)?;
+
+			Self::set_tee_engines(&tee, &cluster.engines);
+			<Clusters<T>>::insert(cluster_id, cluster);
+			<TeeClusterIds<T>>::insert(&tee, cluster_id);
+
+			// Emit an event.
+			Self::deposit_event(Event::ClusterTeeAdded(cluster_id, tee));
+
+			Ok(())
+		}
+
+		/// Remove an AjunaTEE from a cluster of the sending operator. The AjunaTEE runs no game
+		/// engine versions till it updates them, games it acknowledged keep running.
+		#[pallet::weight(T::WeightInfo::remove_cluster_tee())]
+		pub fn remove_cluster_tee(
+			origin: OriginFor<T>,
+			cluster_id: u32,
+			tee: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut cluster = Self::clusters(cluster_id).ok_or(Error::<T>::NoCluster)?;
+			ensure!(cluster.operator == who, Error::<T>::NotClusterOperator);
+			ensure!(Self::tee_cluster(&tee) == Some(cluster_id), Error::<T>::TeeNotInCluster);
+
+			Self::set_tee_engines(&tee, &Default::default());
+			cluster.tees.retain(|member| *member != tee);
+			<Clusters<T>>::insert(cluster_id, cluster);
+			<TeeClusterIds<T>>::remove(&tee);
+
+			// Emit an event.
+			Self::deposit_event(Event::ClusterTeeRemoved(cluster_id, tee));
+
+			Ok(())
+		}
+
+		/// Rebalance the game engine versions served by a cluster, moving all of its AjunaTEEs
+		/// over to them.
+		#[pallet::weight(T::WeightInfo::set_cluster_engines(MAX_CLUSTER_TEES))]
+		pub fn set_cluster_engines(
+			origin: OriginFor<T>,
+			cluster_id: u32,
+			engines: Vec<GameEngine>,
+		) -> DispatchResultWithPostInfo {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			let mut cluster = Self::clusters(cluster_id).ok_or(Error::<T>::NoCluster)?;
+			cluster.engines = Self::dedup_engines(engines)?;
+			for tee in cluster.tees.iter() {
+				Self::set_tee_engines(tee, &cluster.engines);
+			}
+			let tees = cluster.tees.len() as u32;
+			let engines = cluster.engines.to_vec();
+			<Clusters<T>>::insert(cluster_id, cluster);
+
+			Self::log_admin_action(actor, AdminAction::SetClusterEngines, &(cluster_id, &engines));
+
+			// Emit an event.
+			Self::deposit_event(Event::ClusterEnginesSet(cluster_id, engines));
+
+			Ok(Some(T::WeightInfo::set_cluster_engines(tees)).into())
+		}
+
+		/// Switch result confirmation of a game engine version on or off. The results of its
+		/// games wait for the fraud proof window to close, unless all players confirm them
+		/// earlier with `confirm_result`.
+		#[pallet::weight(T::WeightInfo::set_result_confirmation())]
+		pub fn set_result_confirmation(
+			origin: OriginFor<T>,
+			game_engine: GameEngine,
+			enabled: bool,
+		) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			ensure!(
+				SupportedEngines::<T>::contains_key(&game_engine),
+				Error::<T>::EngineNotRegistered
+			);
+			<ResultConfirmationEngines<T>>::set(&game_engine, enabled);
+
+			Self::log_admin_action(
+				actor,
+				AdminAction::SetResultConfirmation,
+				&(&game_engine, enabled),
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::ResultConfirmationSet(game_engine, enabled));
+
+			Ok(())
+		}
+
+		/// Confirm the result of a finished game waiting for its settlement, within the fraud
+		/// proof window. The last player confirming settles the result right away, it can't be
+		/// disputed anymore.
+		#[pallet::weight(T::WeightInfo::confirm_result())]
+		pub fn confirm_result(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
+			let sender = Self::ensure_player(origin)?;
+
+			// retrieve game entry
+			let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+			ensure!(game_entry.players.contains(&sender), Error::<T>::NotAPlayer);
+			let outcome = match game_entry.game_state.clone() {
+				GameState::Finished(outcome) => outcome,
+				_ => return Err(Error::<T>::GameNotFinished)?,
+			};
+			let window_end =
+				game_entry.timestamps.finished.saturating_add(T::FraudProofWindow::get());
+			ensure!(
+				Self::defers_settlement(&game_entry.game_engine) &&
+					<frame_system::Pallet<T>>::block_number() <= window_end &&
+					!Self::result_confirmed(&game_hash, &game_entry),
+				Error::<T>::ResultNotPending
+			);
+
+			let confirmations = <ResultConfirmations<T>>::try_mutate(
+				&game_hash,
+				|confirmations| -> Result<usize, DispatchError> {
+					ensure!(!confirmations.contains(&sender), Error::<T>::ResultAlreadyConfirmed);
+					confirmations.try_push(sender.clone()).map_err(|_| Error::<T>::NotAPlayer)?;
+					Ok(confirmations.len())
+				},
+			)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::ResultConfirmed(game_hash, sender));
+
+			// results confirmed by all players don't wait for the window to close
+			if confirmations == game_entry.players.len() {
+				<PendingSettlements<T>>::mutate(window_end.saturating_add(One::one()), |games| {
+					games.retain(|pending| *pending != game_hash)
+				});
+				Self::settle_result(&game_hash, &game_entry, &outcome);
+			}
+
+			Ok(())
+		}
+
+		/// Resume running games after a restart of their AjunaTEE lost their sessions, by the
+		/// AjunaTEE running them. The games are accepted again, for the AjunaTEE to start them
+		/// once more with `ready_game` and the players to reconnect. Games resumed more than
+		/// `MaxGameResumes` times are dropped instead.
+		#[pallet::weight(T::WeightInfo::resume_games(games.len() as u32))]
+		#[transactional]
+		pub fn resume_games(
+			origin: OriginFor<T>,
+			games: BoundedVec<T::Hash, T::MaxAckBatch>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_tee(&who)?;
+			Self::note_tee_call(&who)?;
+			ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+			ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+			Self::ensure_enabled(RegistryCall::ReadyGame)?;
+
+			for game_hash in games {
+				// retrieve game entry
+				let mut game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+				ensure!(game_entry.game_state == GameState::Running, Error::<T>::GameNotRunning);
+				ensure!(game_entry.tee_id.as_ref() == Some(&who), Error::<T>::NotGameTee);
+
+				let resume_count = Self::resume_count(&game_hash).saturating_add(1);
+				if resume_count > T::MaxGameResumes::get() {
+					let game_engine = game_entry.game_engine.clone();
+					let players = if Self::is_private_game(&game_hash) {
+						Vec::new()
+					} else {
+						game_entry.players.to_vec()
+					};
+					Self::do_drop_game(
+						game_hash,
+						game_entry,
+						&game_engine,
+						DropReason::ResumeLimit,
+						Some(who.clone()),
+					);
+
+					// Emit an event.
+					Self::deposit_event(Event::GameDropped {
+						game: game_hash,
+						engine: game_engine,
+						reason: DropReason::ResumeLimit,
+						tee: who.clone(),
+						players,
+					});
+					continue
+				}
+				<GameResumes<T>>::insert(&game_hash, resume_count);
+
+				game_entry.game_state = GameState::Accepted;
+				// the AjunaTEE starts the session again
+				game_entry.timestamps.accepted = <frame_system::Pallet<T>>::block_number();
+				game_entry.timestamps.started = Zero::zero();
+				Self::change_game_state(
+					&game_hash,
+					&game_entry,
+					&GameState::Running,
+					&game_entry.game_state,
+					Some(who.clone()),
+				);
+				<GameRegistry<T>>::insert(&game_hash, game_entry);
+
+				// Emit an event.
+				Self::deposit_event(Event::GameResuming(game_hash, who.clone(), resume_count));
+			}
+
+			Ok(())
+		}
+
+		/// Lift the suspension of an AjunaTEE suspended for flooding the registry, once
+		/// governance reviewed it.
+		#[pallet::weight(T::WeightInfo::unsuspend_tee())]
+		pub fn unsuspend_tee(origin: OriginFor<T>, tee: T::AccountId) -> DispatchResult {
+			let actor = Self::ensure_admin::<T::GovernanceOrigin>(origin)?;
+
+			<SuspendedTees<T>>::take(&tee).ok_or(Error::<T>::TeeNotSuspended)?;
+
+			Self::log_admin_action(actor, AdminAction::UnsuspendTee, &tee);
+
+			// Emit an event.
+			Self::deposit_event(Event::TeeUnsuspended(tee));
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Drop a game, running games with a checkpoint dropped for a failing AjunaTEE are queued
+	/// again instead. Returns whether the game is queued again and the length of the game
+	/// queue, for the weight.
+	fn do_drop_game(
+		game_hash: T::Hash,
+		mut game_entry: GameEntryOf<T>,
+		game_engine: &GameEngine,
+		reason: DropReason,
+		by: Option<T::AccountId>,
+	) -> (bool, u32) {
+		let mut game_queue = Self::game_queues(&game_entry.game_engine);
+		if reason == DropReason::TeeFailure &&
+			game_entry.game_state == GameState::Running &&
+			<GameCheckpoints<T>>::contains_key(&game_hash) &&
+			Self::enqueue_game(&mut game_queue, game_hash)
+		{
+			game_entry.game_state = GameState::Waiting;
+			game_entry.timestamps.queued = <frame_system::Pallet<T>>::block_number();
+			game_entry.timestamps.accepted = 0u8.into();
+
+			// the failing AjunaTEE is released of the game
+			Self::change_game_state(
+				&game_hash,
+				&game_entry,
+				&GameState::Running,
+				&game_entry.game_state,
+				by.clone(),
+			);
+			game_entry.tee_id =
+				Self::assign_tee(&game_entry.game_engine, &game_hash, game_entry.tee_id.as_ref());
+
+			let queue_length = game_queue.length();
+			<GameRegistry<T>>::insert(&game_hash, game_entry);
+
+			return (true, queue_length)
+		}
+
+		<GameRegistry<T>>::remove(&game_hash);
+		<ArchivedGames<T>>::remove(&game_hash);
+		Self::remove_spectators(&game_hash);
+		<PrivateGames<T>>::remove(&game_hash);
+		Self::advance_tournament(&game_hash, None);
+		<AbortProposals<T>>::remove(&game_hash);
+		<GameWagers<T>>::remove(&game_hash);
+		Self::refund_stakes(&game_hash);
+		<GameCheckpoints<T>>::remove(&game_hash);
+		<GameResumes<T>>::remove(&game_hash);
+		Self::remove_match_code(&game_hash);
+		<GameSeeds<T>>::remove(&game_hash);
+		<GameInitData<T>>::remove(&game_hash);
+		<GameClientVersions<T>>::remove(&game_hash);
+		<GameTeams<T>>::remove(&game_hash);
+		if game_entry.game_state == GameState::Scheduled {
+			let _ = T::Scheduler::cancel_named((GAMEREGISTRY_ID, b"start", &game_hash).encode());
+		}
+
+		// clients looking up the game learn why it's gone, till the games of the block are pruned
+		let now = <frame_system::Pallet<T>>::block_number();
+		<DroppedGames<T>>::insert(&game_hash, DroppedGame { reason, by: by.clone(), dropped: now });
+		Self::schedule_pruning(now, game_hash);
+
+		Self::count_game(&game_entry.game_engine, |counters| &mut counters.dropped);
+
+		// players that never accepted the match failed the ready-check
+		for player in game_entry.players.iter() {
+			match reason {
+				DropReason::NoShow => Self::slash_queue_bond(player),
+				_ => Self::release_queue_bond(player),
+			}
+		}
+
+		Self::change_game_state(
+			&game_hash,
+			&game_entry,
+			&game_entry.game_state,
+			&GameState::None,
+			by,
+		);
+
+		let mut game_queue = Self::game_queues(game_engine);
+		let queue_length = game_queue.length();
+
+		// check if there is any elements queued
+		if queue_length > 0 {
+			// remove element
+			game_queue.remove(game_hash);
+		}
+
+		(false, queue_length)
+	}
+
+	/// Ensure an AjunaTEE submitting unsigned has an enclave signing key and submits its next
+	/// nonce. Returns the signing key.
+	fn ensure_unsigned_tee(tee: &T::AccountId, nonce: u64) -> Result<AccountId32, DispatchError> {
+		Self::ensure_tee(tee)?;
+		let signing_key = Self::registered_tees(tee)
+			.and_then(|tee_info| tee_info.signing_key)
+			.ok_or(Error::<T>::NoSigningKey)?;
+		ensure!(nonce == Self::tee_result_nonce(tee), Error::<T>::InvalidNonce);
+		Ok(signing_key)
+	}
+
+	/// Check an unsigned submission of an AjunaTEE is signed by its enclave, submissions with
+	/// later nonces wait in the transaction pool for the ones before them.
+	fn validate_tee_submission(
+		tee: &T::AccountId,
+		nonce: u64,
+		payload: &[u8],
+		signature: &MultiSignature,
+	) -> TransactionValidity {
+		let signing_key = match Self::registered_tees(tee).and_then(|info| info.signing_key) {
+			Some(signing_key) => signing_key,
+			None => return InvalidTransaction::BadSigner.into(),
+		};
+		let next_nonce = Self::tee_result_nonce(tee);
+		if nonce < next_nonce {
+			return InvalidTransaction::Stale.into()
+		}
+		if !signature.verify(payload, &signing_key) {
+			return InvalidTransaction::BadProof.into()
+		}
+
+		let mut validity = ValidTransaction::with_tag_prefix("GameRegistryTee")
+			.priority(TransactionPriority::max_value())
+			.and_provides((tee, nonce))
+			.longevity(TEE_SUBMISSION_LONGEVITY.into())
+			.propagate(true);
+		if nonce > next_nonce {
+			validity = validity.and_requires((tee, nonce - 1));
+		}
+		validity.build()
+	}
+
+	/// Acknowledge games of a game engine for an AjunaTEE, shared by signed and unsigned
+	/// acknowledgements.
+	fn do_ack_game(
+		who: T::AccountId,
+		cluster: GameEngine,
+		games: Vec<T::Hash>,
+	) -> DispatchResultWithPostInfo {
+		Self::note_tee_call(&who)?;
+		ensure!(Self::migration_cursor().is_none(), Error::<T>::MigrationOngoing);
+		ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+		Self::ensure_enabled(RegistryCall::AckGame)?;
+
+		// only registered AjunaTEEs running the game engine can acknowledge its games,
+		// in dev mode any account stands in for an AjunaTEE.
+		if !Self::dev_mode() {
+			let tee_info = Self::registered_tees(&who).ok_or(Error::<T>::NotRegisteredTee)?;
+			ensure!(tee_info.supported_engines.contains(&cluster), Error::<T>::EngineNotSupported);
+			// no new games for AjunaTEEs with an expired attestation
+			let attested_at = Self::attested_at(&who).unwrap_or_default();
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <=
+					attested_at.saturating_add(T::AttestationValidity::get()),
+				Error::<T>::AttestationExpired
+			);
+		}
+
+		// only up to the batch limit games allowed to acknowledge in one batch.
+		if games.len() > Self::parameters().ack_batch_limit as usize {
+			return Err(Error::<T>::AckToMany.with_weight(T::WeightInfo::ack_game(0)))
+		}
+
+		// retrieve game queue for asked cluster
+		ensure!(Self::has_game_queue(&cluster), Error::<T>::NoGameQueue);
+		let mut game_queue = Self::game_queues(&cluster);
+		// the AjunaTEE is alive as long as it takes games
+		<TeeLastSeen<T>>::insert(&who, <frame_system::Pallet<T>>::block_number());
+
+		let assigned = T::TeeAssignment::get() != TeeAssignment::FirstCome;
+		let mut games_count = 0;
+		for game_hash_tee in games.iter() {
+			// weight of the games up to this one, if the batch fails here
+			let weight = T::WeightInfo::ack_game(games_count as u32 + 1);
+			let game_hash = if !assigned {
+				game_queue.peek().ok_or(Error::<T>::QueueEmpty.with_weight(weight))?
+			} else {
+				ensure!(
+					game_queue.contains(*game_hash_tee),
+					Error::<T>::AckHashMismatch.with_weight(weight)
+				);
+				// games of AjunaTEEs deregistered since are open to every AjunaTEE
+				let assigned_tee = Self::game_registry(game_hash_tee).tee_id;
+				ensure!(
+					assigned_tee.as_ref().map_or(true, |tee| {
+						*tee == who || !<RegisteredTees<T>>::contains_key(tee)
+					}),
+					Error::<T>::NotGameTee.with_weight(weight)
+				);
+				*game_hash_tee
+			};
+
+			// check if peeked game matches acknowledge
+			if &game_hash == game_hash_tee {
+				// games above the high-value wager only go to AjunaTEEs of the high-value tier
+				ensure!(
+					Self::dev_mode() ||
+						Self::game_wagers(game_hash_tee) <= T::HighValueWager::get() ||
+						Self::tee_bonds(&who) >= T::HighValueTeeBond::get(),
+					Error::<T>::TeeBondTooLow.with_weight(weight)
+				);
+
+				ensure!(
+					Self::tee_loads(&who).total() < T::MaxGamesPerTee::get(),
+					Error::<T>::TeeAtCapacity.with_weight(weight)
+				);
+
+				// dequeue game hash from waiting queue cluster
+				if assigned {
+					game_queue.remove(game_hash);
+				} else {
+					let _ = game_queue.dequeue();
+				}
+
+				// retrieve game entry to change state
+				let mut game_entry = Self::game_registry(game_hash_tee.clone());
+				ensure!(
+					game_entry.game_state.can_transition_to(&GameState::Accepted),
+					Error::<T>::InvalidStateTransition.with_weight(weight)
+				);
+
+				let previous_state = game_entry.game_state.clone();
+				game_entry.tee_id = Some(who.clone());
+				game_entry.timestamps.accepted = <frame_system::Pallet<T>>::block_number();
+				game_entry.game_state = GameState::Accepted;
+
+				Self::change_game_state(
+					game_hash_tee,
+					&game_entry,
+					&previous_state,
+					&game_entry.game_state,
+					Some(who.clone()),
+				);
+
+				Self::count_game(&game_entry.game_engine, |counters| &mut counters.acknowledged);
+				// insert changed game entry back
+				<GameRegistry<T>>::insert(game_hash_tee, game_entry);
+
+				// Emit an event.
+				Self::deposit_event(Event::GameAccepted { game: game_hash, tee: who.clone() });
+
+				// Increase counter
+				games_count += 1;
+			} else {
+				return Err(Error::<T>::AckHashMismatch.with_weight(weight))
+			}
+		}
+
+		// Return a successful DispatchResultWithPostInfo
+		Ok(Some(T::WeightInfo::ack_game(games_count as u32)).into())
+	}
+
+	/// Finish a game with the result of an AjunaTEE, shared by single and batched results.
+	fn do_finish_game(
+		who: &T::AccountId,
+		game_hash: T::Hash,
+		outcome: GameOutcomeOf<T>,
+		final_state: T::Hash,
+		signature: Option<MultiSignature>,
+		replay: Option<Vec<u8>>,
+		result_proof: Option<T::Hash>,
+	) -> DispatchResult {
+		let replay = replay
+			.map(ReplayOf::try_from)
+			.transpose()
+			.map_err(|_| Error::<T>::ReplayTooLong)?;
+
+		// retrieve game entry
+		let game_entry = Self::game_entry(&game_hash).ok_or(Error::<T>::NoGameEntry)?;
+		ensure!(game_entry.game_state == GameState::Running, Error::<T>::GameNotRunning);
+		ensure!(game_entry.tee_id.as_ref() == Some(who), Error::<T>::NotGameTee);
+		Self::ensure_outcome(&outcome, &game_entry.players)?;
+
+		// results of enclaves with a signing key are bound to the enclave, not the account
+		if let Some(signing_key) = Self::registered_tees(who).and_then(|info| info.signing_key) {
+			let signature = signature.ok_or(Error::<T>::MissingResultSignature)?;
+			let nonce = Self::tee_result_nonce(who);
+			let payload = (game_hash, &outcome, final_state, nonce, &replay, result_proof).encode();
+			ensure!(
+				signature.verify(&payload[..], &signing_key),
+				Error::<T>::InvalidResultSignature
+			);
+			<TeeResultNonce<T>>::insert(who, nonce.wrapping_add(1));
+		}
+
+		Self::conclude_game(who, game_hash, game_entry, outcome, final_state, replay, result_proof)
+	}
+
+	/// Finish a running game with a checked outcome, recording, reporting and settling its
+	/// result. At most `MaxFinishedPerBlock` games finish in a block.
+	fn conclude_game(
+		who: &T::AccountId,
+		game_hash: T::Hash,
+		mut game_entry: GameEntryOf<T>,
+		outcome: GameOutcomeOf<T>,
+		final_state: T::Hash,
+		replay: Option<ReplayOf>,
+		result_proof: Option<T::Hash>,
+	) -> DispatchResult {
+		// the finished games of the block bound its result leaves as well
+		<BlockFinishedGames<T>>::try_mutate(|games| games.try_push(game_hash))
+			.map_err(|_| Error::<T>::TooManyFinishedGames)?;
+
+		let previous_state = game_entry.game_state.clone();
+		game_entry.timestamps.finished = <frame_system::Pallet<T>>::block_number();
+		game_entry.game_state = GameState::Finished(outcome.clone());
+		game_entry.result = Some(outcome.clone().into());
+
+		Self::change_game_state(
+			&game_hash,
+			&game_entry,
+			&previous_state,
+			&game_entry.game_state,
+			Some(who.clone()),
+		);
+		T::GameObserver::on_game_finished(&game_hash, &outcome);
+		T::OnGameResult::on_game_result(
+			&game_hash,
+			&game_entry.game_engine,
+			&game_entry.players,
+			&outcome.clone().into(),
+		);
+
+		// append the compact result leaf to the results of this block
+		let result_leaf = GameResultLeaf {
+			game_hash,
+			game_engine: game_entry.game_engine.clone(),
+			outcome: outcome.clone(),
+			block: game_entry.timestamps.finished,
+		};
+		let leaf_hash = T::Hashing::hash_of(&result_leaf);
+		// the finished games of the block leave room for their leaves
+		<BlockResultLeaves<T>>::mutate(|leaves| {
+			let _ = leaves.try_push(leaf_hash);
+		});
+		Self::deposit_event(Event::ResultLeafAppended(game_hash, leaf_hash));
+
+		// canonical result encoding for consumers outside of substrate
+		let canonical_result = Self::canonical_result(&result_leaf);
+		<CanonicalResultHashes<T>>::insert(
+			&game_hash,
+			sp_io::hashing::keccak_256(&canonical_result.encode()),
+		);
+		Self::deposit_event(Event::GameResultEncoded(game_hash, canonical_result));
+
+		// export the game record to the offchain index, for indexer nodes
+		let game_record = GameRecord {
+			game_hash,
+			game_engine: game_entry.game_engine.clone(),
+			players: game_entry.players.to_vec(),
+			outcome: outcome.clone(),
+			block: game_entry.timestamps.finished,
+		};
+		sp_io::offchain_index::set(&Self::game_record_key(&game_hash), &game_record.encode());
+
+		// report the result back to the chains of foreign players
+		for (_, location) in game_entry.foreign_players.iter() {
+			let event = match T::ResultNotifier::notify_result(location, &game_hash, &outcome) {
+				Ok(()) => Event::ForeignResultSent(game_hash, location.clone()),
+				Err(_) => Event::ForeignResultFailed(game_hash, location.clone()),
+			};
+			Self::deposit_event(event);
+		}
+
+		Self::count_win(&outcome, true);
+		Self::tally_season(&game_entry.players, &outcome);
+
+		for player in game_entry.players.iter() {
+			<FinishedGames<T>>::mutate(player, |count| *count = count.saturating_add(1));
+			Self::record_match(player, &game_record);
+			// players showed up for the game, bonds not released yet are given back
+			Self::release_queue_bond(player);
+		}
+
+		// games finished right after matching are counted, to surface win-trading
+		if game_entry.timestamps.finished <=
+			game_entry.timestamps.queued.saturating_add(T::CollusionWindow::get())
+		{
+			Self::note_pairing(&game_entry.players);
+		}
+
+		Self::count_game(&game_entry.game_engine, |counters| &mut counters.finished);
+		// the registry only holds the games still in play
+		Self::archive_game(&game_hash, &game_entry);
+		// nothing left to stream
+		Self::remove_spectators(&game_hash);
+
+		<GameWagers<T>>::remove(&game_hash);
+		if Self::defers_settlement(&game_entry.game_engine) {
+			// disputes can still void or overturn the result till the fraud proof window closes
+			let settlement = game_entry
+				.timestamps
+				.finished
+				.saturating_add(T::FraudProofWindow::get())
+				.saturating_add(One::one());
+			// the games finished in a block fit into the block they're settled in, unless the
+			// fraud proof window changed in between
+			if <PendingSettlements<T>>::try_mutate(settlement, |games| games.try_push(game_hash))
+				.is_err()
+			{
+				Self::settle_result(&game_hash, &game_entry, &outcome);
+			}
+		} else if T::SettlementsPerBlock::get().is_zero() {
+			Self::settle_result(&game_hash, &game_entry, &outcome);
+		} else if <SettlementQueue<T>>::try_mutate(|queue| queue.try_push(game_hash)).is_err() {
+			// the stakes, ratings and rewards are settled in a later `on_idle`, games finished
+			// with a full settlement queue right away
+			Self::settle_result(&game_hash, &game_entry, &outcome);
+		}
+		<GameCheckpoints<T>>::remove(&game_hash);
+		<GameResumes<T>>::remove(&game_hash);
+		<FinalStates<T>>::insert(&game_hash, final_state);
+		Self::schedule_pruning(game_entry.timestamps.finished, game_hash);
+
+		// Emit an event.
+		if let Some(replay) = replay {
+			<GameReplays<T>>::insert(&game_hash, &replay);
+			Self::deposit_event(Event::ReplayAnchored(game_hash, replay.into_inner()));
+		}
+		if let Some(result_proof) = result_proof {
+			<ResultProofs<T>>::insert(&game_hash, result_proof);
+			Self::deposit_event(Event::ResultProofAnchored(game_hash, result_proof));
+		}
+		Self::advance_tournament(&game_hash, outcome.winner());
+		<AbortProposals<T>>::remove(&game_hash);
+		if <PrivateGames<T>>::take(&game_hash) {
+			Self::deposit_event(Event::PrivatePlayersRevealed(
+				game_hash,
+				game_entry.players.to_vec(),
+			));
+		}
+		Self::requeue_players(&game_entry);
+
+		let duration = game_entry.timestamps.finished.saturating_sub(game_entry.timestamps.started);
+		Self::deposit_event(Event::GameFinished {
+			game: game_hash,
+			engine: game_entry.game_engine,
+			players: game_entry.players.into_inner(),
+			outcome,
+			duration_blocks: duration,
+		});
+
+		Ok(())
+	}
+
+	/// Outcome of a running game a player forfeits, won by the only opponent and aborted with
+	/// more opponents.
+	fn forfeit_outcome(players: &[T::AccountId], player: &T::AccountId) -> GameOutcomeOf<T> {
+		let opponents: Vec<_> = players.iter().filter(|other| *other != player).collect();
+		match opponents.as_slice() {
+			[opponent] => GameOutcome::Winner((*opponent).clone()),
+			_ => GameOutcome::Aborted,
+		}
+	}
+
+	/// Count a no-show against a player, `MaxNoShows` no-shows ban it from queueing for
+	/// `NoShowBanDuration` blocks. Returns the no-shows since its last ban.
+	fn note_no_show(player: &T::AccountId) -> u32 {
+		let strikes = <NoShowStrikes<T>>::mutate(player, |strikes| {
+			*strikes = strikes.saturating_add(1);
+			*strikes
+		});
+		if strikes < T::MaxNoShows::get() {
+			return strikes
+		}
+
+		<NoShowStrikes<T>>::remove(player);
+		// a longer ban already in place is kept
+		let until = <frame_system::Pallet<T>>::block_number()
+			.saturating_add(T::NoShowBanDuration::get())
+			.max(Self::sanctions(player, SanctionKind::QueueBan).unwrap_or_default());
+		<Sanctions<T>>::insert(player, SanctionKind::QueueBan, until);
+		Self::deposit_event(Event::PlayerSanctioned(player.clone(), SanctionKind::QueueBan, until));
+		strikes
+	}
+
+	/// Ensure a game can be dropped, running games aren't dropped for players not showing up.
+	fn ensure_droppable(game_entry: &GameEntryOf<T>, reason: DropReason) -> DispatchResult {
+		ensure!(
+			game_entry.game_state.can_transition_to(&GameState::None) &&
+				(game_entry.game_state != GameState::Running || reason != DropReason::NoShow),
+			Error::<T>::InvalidStateTransition
+		);
+		Ok(())
+	}
+
+	/// Expire a game past its timeout, requeueing accepted games and dropping the others.
+	fn do_expire_game(game_hash: T::Hash, mut game_entry: GameEntryOf<T>, reason: ExpiryReason) {
+		let stage = game_entry.game_state.clone();
+		Self::count_game(&game_entry.game_engine, |counters| &mut counters.expired);
+
+		let mut game_queue = Self::game_queues(&game_entry.game_engine);
+		if game_entry.game_state == GameState::Accepted &&
+			Self::enqueue_game(&mut game_queue, game_hash)
+		{
+			game_entry.game_state = GameState::Waiting;
+			game_entry.timestamps.queued = <frame_system::Pallet<T>>::block_number();
+			game_entry.timestamps.accepted = 0u8.into();
+
+			Self::change_game_state(
+				&game_hash,
+				&game_entry,
+				&GameState::Accepted,
+				&game_entry.game_state,
+				None,
+			);
+			game_entry.tee_id =
+				Self::assign_tee(&game_entry.game_engine, &game_hash, game_entry.tee_id.as_ref());
+
+			<GameRegistry<T>>::insert(&game_hash, game_entry);
+
+			// Emit an event.
+			Self::deposit_event(Event::GameExpired(game_hash, stage, reason));
+			Self::deposit_event(Event::GameRequeued(game_hash));
+		} else {
+			// no AjunaTEE picked the game up, the players are not to blame
+			for player in game_entry.players.iter() {
+				Self::release_queue_bond(player);
+			}
+
+			Self::change_game_state(
+				&game_hash,
+				&game_entry,
+				&game_entry.game_state,
+				&GameState::None,
+				None,
+			);
+			T::OnGameResult::on_game_result(
+				&game_hash,
+				&game_entry.game_engine,
+				&game_entry.players,
+				&GameResult::Dropped,
+			);
+
+			game_queue.remove(game_hash);
+			<GameRegistry<T>>::remove(&game_hash);
+			Self::remove_spectators(&game_hash);
+			<PrivateGames<T>>::remove(&game_hash);
+			Self::advance_tournament(&game_hash, None);
+			<AbortProposals<T>>::remove(&game_hash);
+			<GameWagers<T>>::remove(&game_hash);
+			Self::refund_stakes(&game_hash);
+			<GameCheckpoints<T>>::remove(&game_hash);
+			<GameResumes<T>>::remove(&game_hash);
+			Self::remove_match_code(&game_hash);
+			<GameSeeds<T>>::remove(&game_hash);
+			<GameInitData<T>>::remove(&game_hash);
+			<GameClientVersions<T>>::remove(&game_hash);
+			<GameTeams<T>>::remove(&game_hash);
+
+			// Emit an event.
+			Self::deposit_event(Event::GameExpired(game_hash, stage, reason));
+		}
+	}
+
+	/// Run the maintenance tasks with the weight left over by matchmaking, till one runs out of
+	/// weight. The task to start with rotates every block, so a task with a lot of work doesn't
+	/// starve the others, and each task resumes where it stopped. Returns the weight used.
+	fn run_maintenance(n: T::BlockNumber, weight_limit: Weight) -> Weight {
+		// the maintenance step
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		if weight > weight_limit {
+			return 0
+		}
+
+		let first = Self::maintenance_step();
+		let mut task = first;
+		for _ in 0..MaintenanceTask::COUNT {
+			let task_limit = weight_limit.saturating_sub(weight);
+			let (task_weight, done) = match task {
+				MaintenanceTask::Expiry => Self::expire_games(task_limit),
+				MaintenanceTask::Pruning => Self::prune_finished_games(n, task_limit),
+				MaintenanceTask::StaleQueues => Self::clean_game_queues(task_limit),
+				MaintenanceTask::Compaction => Self::remove_lapsed_sanctions(n, task_limit),
+				MaintenanceTask::Invites => Self::remove_expired_invites(n, task_limit),
+			};
+			weight = weight.saturating_add(task_weight);
+			if !done {
+				break
+			}
+			task = task.next();
+		}
+		<MaintenanceStep<T>>::put(first.next());
+
+		weight
+	}
+
+	/// Raw storage key as cursor of a scan, the keys of the scanned maps are at most
+	/// `MAX_STORAGE_KEY_LENGTH` long.
+	pub(crate) fn storage_cursor(key: Vec<u8>) -> RawStorageKey {
+		RawStorageKey::try_from(key).unwrap_or_default()
+	}
+
+	/// Scan a storage map from the cursor of a maintenance task, processing the raw keys as long
+	/// as the weight limit allows the worst case weight of another key. Returns the weight used
+	/// and whether the scan reached the end of the map, the next scan starts over.
+	fn scan_storage_map(
+		task: MaintenanceTask,
+		prefix: &[u8],
+		weight_limit: Weight,
+		key_weight: Weight,
+		mut process: impl FnMut(&[u8]) -> Weight,
+	) -> (Weight, bool) {
+		// the cursor of the task
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		if weight > weight_limit {
+			return (0, false)
+		}
+
+		let read_weight = T::DbWeight::get().reads(1);
+		let mut cursor = Self::maintenance_cursor(task)
+			.map(BoundedVec::into_inner)
+			.unwrap_or_else(|| prefix.to_vec());
+		loop {
+			if weight.saturating_add(read_weight).saturating_add(key_weight) > weight_limit {
+				<MaintenanceCursors<T>>::insert(task, Self::storage_cursor(cursor));
+				return (weight, false)
+			}
+			let key = match sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(prefix))
+			{
+				Some(key) => key,
+				None => {
+					<MaintenanceCursors<T>>::remove(task);
+					return (weight.saturating_add(read_weight), true)
+				},
+			};
+			weight = weight.saturating_add(read_weight).saturating_add(process(&key));
+			cursor = key;
+		}
+	}
+
+	/// Expired games of a page of the game registry after the cursor, for the offchain worker,
+	/// with the cursor to continue after. Without a cursor left the scan starts over.
+	fn expired_games(cursor: Option<Vec<u8>>) -> (Vec<T::Hash>, Option<Vec<u8>>) {
+		let prefix = GameRegistry::<T>::final_prefix();
+		let mut cursor = cursor.unwrap_or_else(|| prefix.to_vec());
+		let mut expired = Vec::new();
+		for _ in 0..EXPIRY_SCAN_KEYS {
+			if expired.len() >= T::MaxGamesPerBlock::get() as usize {
+				break
+			}
+			let key = match sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(&prefix))
+			{
+				Some(key) => key,
+				None => return (expired, None),
+			};
+			// game registry keys end with the game hash
+			if let Ok(game_hash) = T::Hash::decode(&mut &key[prefix.len()..]) {
+				if Self::is_expired(&Self::game_registry(&game_hash)) {
+					expired.push(game_hash);
+				}
+			}
+			cursor = key;
+		}
+		(expired, Some(cursor))
+	}
+
+	/// Expire the games past their timeouts, as far as the weight limit allows.
+	fn expire_games(weight_limit: Weight) -> (Weight, bool) {
+		let prefix = GameRegistry::<T>::final_prefix();
+		let read_weight = T::DbWeight::get().reads(1);
+		let expire_weight = T::WeightInfo::expire_game();
+		Self::scan_storage_map(
+			MaintenanceTask::Expiry,
+			&prefix,
+			weight_limit,
+			read_weight.saturating_add(expire_weight),
+			|key| {
+				// game registry keys end with the game hash
+				let game_hash = match T::Hash::decode(&mut &key[prefix.len()..]) {
+					Ok(game_hash) => game_hash,
+					Err(_) => return 0,
+				};
+				let game_entry = Self::game_registry(&game_hash);
+				match Self::expiry_reason(&game_entry) {
+					Some(reason) => {
+						Self::do_expire_game(game_hash, game_entry, reason);
+						read_weight.saturating_add(expire_weight)
+					},
+					None => read_weight,
+				}
+			},
+		)
+	}
+
+	/// Remove the games that aren't waiting anymore from the game queues, as far as the weight
+	/// limit allows.
+	fn clean_game_queues(weight_limit: Weight) -> (Weight, bool) {
+		let prefix = GameQueues::<T>::final_prefix();
+		Self::scan_storage_map(
+			MaintenanceTask::StaleQueues,
+			&prefix,
+			weight_limit,
+			T::WeightInfo::clean_game_queue(T::MaxQueueSize::get()),
+			|key| {
+				// game queue keys end with the game engine version
+				let game_engine = match GameEngine::decode(&mut &key[prefix.len()..]) {
+					Ok(game_engine) => game_engine,
+					Err(_) => return 0,
+				};
+				T::WeightInfo::clean_game_queue(Self::clean_game_queue(&game_engine))
+			},
+		)
+	}
+
+	/// Remove the games that aren't waiting anymore from the game queue of a game engine version,
+	/// left behind by games moved or removed outside of the queue. Returns the queue length.
+	fn clean_game_queue(game_engine: &GameEngine) -> u32 {
+		let mut game_queue = Self::game_queues(game_engine);
+		let length = game_queue.length();
+		let stale: Vec<T::Hash> = game_queue
+			.iter()
+			.filter(|game_hash| {
+				Self::game_entry(game_hash)
+					.map_or(true, |game_entry| game_entry.game_state != GameState::Waiting)
+			})
+			.collect();
+		if stale.is_empty() {
+			return length
+		}
+
+		for game_hash in stale.iter() {
+			game_queue.remove(*game_hash);
+		}
+
+		// Emit an event.
+		Self::deposit_event(Event::StaleGamesRemoved(game_engine.clone(), stale.len() as u32));
+
+		length
+	}
+
+	/// Remove the lapsed sanctions of players, as far as the weight limit allows.
+	fn remove_lapsed_sanctions(n: T::BlockNumber, weight_limit: Weight) -> (Weight, bool) {
+		let prefix = Sanctions::<T>::final_prefix();
+		Self::scan_storage_map(
+			MaintenanceTask::Compaction,
+			&prefix,
+			weight_limit,
+			T::WeightInfo::remove_lapsed_sanction(),
+			|key| {
+				// sanction keys are the player and the sanction kind, each after its hash
+				let sanction = key.get(prefix.len() + 16..).and_then(|mut raw| {
+					let player = T::AccountId::decode(&mut raw).ok()?;
+					let kind =
+						raw.get(16..).and_then(|mut raw| SanctionKind::decode(&mut raw).ok())?;
+					Some((player, kind))
+				});
+				if let Some((player, kind)) = sanction {
+					Self::remove_lapsed_sanction(&player, kind, n);
+				}
+				T::WeightInfo::remove_lapsed_sanction()
+			},
+		)
+	}
+
+	/// Remove the match invites past their expiry, as far as the weight limit allows.
+	fn remove_expired_invites(n: T::BlockNumber, weight_limit: Weight) -> (Weight, bool) {
+		let prefix = MatchInvites::<T>::final_prefix();
+		Self::scan_storage_map(
+			MaintenanceTask::Invites,
+			&prefix,
+			weight_limit,
+			T::WeightInfo::remove_expired_invite(),
+			|key| {
+				// match invite keys end with the match id
+				if let Ok(match_id) = T::Hash::decode(&mut &key[prefix.len()..]) {
+					if Self::match_invites(&match_id).map_or(false, |invite| n >= invite.expires) {
+						<MatchInvites<T>>::remove(&match_id);
+						<InviteInitData<T>>::remove(&match_id);
+						<InviteStarts<T>>::remove(&match_id);
+
+						// Emit an event.
+						Self::deposit_event(Event::InviteExpired(match_id));
+					}
+				}
+				T::WeightInfo::remove_expired_invite()
+			},
+		)
+	}
+
+	/// Remove a sanction of a player once it lapsed, unless it's still appealed.
+	fn remove_lapsed_sanction(player: &T::AccountId, kind: SanctionKind, n: T::BlockNumber) {
+		let lapsed = Self::sanctions(player, kind).map_or(false, |until| n >= until);
+		if !lapsed || <Appeals<T>>::contains_key(player, kind) {
+			return
+		}
+		<Sanctions<T>>::remove(player, kind);
+
+		// Emit an event.
+		Self::deposit_event(Event::SanctionLapsed(player.clone(), kind));
+	}
+
+	/// Prune the games finished `GameRetention` blocks ago, as far as the weight limit allows,
+	/// resuming from the prune cursor in the next block. Returns the weight used and whether all
+	/// games due are pruned.
+	fn prune_finished_games(n: T::BlockNumber, weight_limit: Weight) -> (Weight, bool) {
+		let retention = T::GameRetention::get();
+		let prune_weight = T::WeightInfo::prune_game();
+		// the prune cursor
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		if weight > weight_limit {
+			return (0, false)
+		}
+
+		let mut cursor = Self::prune_cursor();
+		let mut done = true;
+		while cursor.saturating_add(retention) <= n {
+			// the games finished in the block
+			if weight.saturating_add(T::DbWeight::get().reads_writes(1, 1)) > weight_limit {
+				done = false;
+				break
+			}
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+			let games = <PruneSchedule<T>>::take(cursor);
+			let mut pruned = 0;
+			for game_hash in games.iter() {
+				if weight.saturating_add(prune_weight) > weight_limit {
+					break
+				}
+				weight = weight.saturating_add(prune_weight);
+				Self::prune_game(game_hash, n);
+				pruned += 1;
+			}
+			// the rest of the block is pruned in the next one
+			if pruned < games.len() {
+				let games = games.into_inner().split_off(pruned);
+				<PruneSchedule<T>>::insert(
+					cursor,
+					BlockGamesOf::<T>::try_from(games).unwrap_or_default(),
+				);
+				done = false;
+				break
+			}
+			cursor = cursor.saturating_add(One::one());
+		}
+		<PruneCursor<T>>::put(cursor);
+
+		(weight, done)
+	}
+
+	/// Schedule a game to be pruned with the games finished in a block, or in the next block
+	/// with room for it.
+	fn schedule_pruning(block: T::BlockNumber, game_hash: T::Hash) {
+		Self::schedule_in_block(block, |block| {
+			<PruneSchedule<T>>::try_mutate(block, |games| games.try_push(game_hash))
+		});
+	}
+
+	/// Push an item into the bounded list of a block, moving on to the next blocks till one has
+	/// room for it.
+	fn schedule_in_block<E>(
+		mut block: T::BlockNumber,
+		mut try_push: impl FnMut(T::BlockNumber) -> Result<(), E>,
+	) {
+		while try_push(block).is_err() {
+			block = block.saturating_add(One::one());
+		}
+	}
+
+	/// Prune a finished game down to the hash of its canonical result, disputed results are
+	/// kept for another retention period.
+	fn prune_game(game_hash: &T::Hash, n: T::BlockNumber) {
+		let game_entry = match Self::game_entry(game_hash) {
+			Some(game_entry) => game_entry,
+			// voided by the arbitration already, or dropped and only kept as record
+			None => {
+				<DroppedGames<T>>::remove(game_hash);
+				return
+			},
+		};
+		match game_entry.game_state {
+			GameState::Finished(_) => (),
+			GameState::Suspended(_) | GameState::Reconfirming(_) => {
+				Self::schedule_pruning(n, *game_hash);
+				return
+			},
+			_ => return,
+		}
+
+		<GameRegistry<T>>::remove(game_hash);
+		<ArchivedGames<T>>::remove(game_hash);
+		<GameTimelines<T>>::remove(game_hash);
+		<FinalStates<T>>::remove(game_hash);
+		<GameReplays<T>>::remove(game_hash);
+		Self::remove_match_code(game_hash);
+		<GameSeeds<T>>::remove(game_hash);
+		<GameInitData<T>>::remove(game_hash);
+		<GameClientVersions<T>>::remove(game_hash);
+		<GameTeams<T>>::remove(game_hash);
+		<ResultConfirmations<T>>::remove(game_hash);
+		Self::remove_spectators(game_hash);
+
+		// Emit an event.
+		Self::deposit_event(Event::GamePruned(*game_hash));
+	}
+
+	/// Estimated queue-to-match time of each bracket in blocks, used by the runtime api.
+	pub fn estimated_waits() -> Vec<(GameEngine, u8, T::BlockNumber)> {
+		<EstimatedWaits<T>>::iter().collect()
+	}
+
+	/// Fold the queue-to-match time of a match into the estimated wait of its bracket, the
+	/// latest match weighs `1 / WAIT_SMOOTHING`.
+	///
+	/// Brackets with an estimate or a player waiting longer than `MatchWaitSla` are degraded.
+	fn note_wait(
+		game_engine: &GameEngine,
+		bracket: u8,
+		wait: T::BlockNumber,
+		longest_wait: T::BlockNumber,
+	) {
+		let estimate = match Self::estimated_wait(game_engine, bracket) {
+			Some(average) =>
+				average.saturating_mul((WAIT_SMOOTHING - 1).into()).saturating_add(wait) /
+					WAIT_SMOOTHING.into(),
+			None => wait,
+		};
+		<EstimatedWaits<T>>::insert(game_engine, bracket, estimate);
+
+		let sla = T::MatchWaitSla::get();
+		let degraded = estimate > sla || longest_wait > sla;
+		if degraded != Self::is_degraded(game_engine, bracket) {
+			let event = if degraded {
+				<DegradedBrackets<T>>::insert(game_engine, bracket, true);
+				Event::MatchmakingDegraded(game_engine.clone(), bracket, estimate, longest_wait)
+			} else {
+				<DegradedBrackets<T>>::remove(game_engine, bracket);
+				Event::MatchmakingRecovered(game_engine.clone(), bracket, estimate)
+			};
+			Self::deposit_event(event);
+		}
+	}
+
+	/// Details of all registered game engines, used by the runtime api.
+	pub fn engines() -> Vec<EngineDetails<GameRule<GameRuleType>>> {
+		<SupportedEngines<T>>::iter()
+			.map(|(game_engine, engine_info)| {
+				let tee_coverage = Self::engine_tee_count(&game_engine);
+				EngineDetails {
+					game_rules: Self::game_requirements(&game_engine).into_inner(),
+					enabled: engine_info.successor.is_none() &&
+						!engine_info.maintenance &&
+						!Self::is_deprecated(&game_engine) &&
+						tee_coverage >= Self::min_tees(),
+					queue_depth: Self::game_queues(&game_engine).length(),
+					tee_coverage,
+					game_engine,
+					engine_info,
+				}
+			})
+			.collect()
+	}
+
+	/// Count the quick pairing of each two players of a game, suspecting collusion past the
+	/// `CollusionThreshold`.
+	fn note_pairing(players: &[T::AccountId]) {
+		for (i, first) in players.iter().enumerate() {
+			for second in players.iter().skip(i + 1) {
+				let (a, b) = if first < second { (first, second) } else { (second, first) };
+				let count = <PairingCounts<T>>::mutate(a, b, |count| {
+					*count = count.saturating_add(1);
+					*count
+				});
+				if count >= T::CollusionThreshold::get() {
+					Self::deposit_event(Event::SuspectedCollusion(a.clone(), b.clone(), count));
+				}
+			}
+		}
+	}
+
+	/// The game entry of a game, if there is one registered or archived.
+	pub fn game_entry(game_hash: &T::Hash) -> Option<GameEntryOf<T>> {
+		if GameRegistry::<T>::contains_key(game_hash) {
+			Some(Self::game_registry(game_hash))
+		} else {
+			Self::archived_game(game_hash)
+		}
+	}
+
+	/// Timeline of the states a game moved into with the blocks it moved in, oldest first.
+	///
+	/// Games from before the timelines were kept have theirs rebuilt from their timestamps.
+	pub fn game_timeline(game_hash: &T::Hash) -> Vec<(GameStateOf<T>, T::BlockNumber)> {
+		let timeline = Self::game_transitions(game_hash);
+		if !timeline.is_empty() {
+			return timeline.into_inner()
+		}
+		let game_entry = match Self::game_entry(game_hash) {
+			Some(game_entry) => game_entry,
+			None => return Vec::new(),
+		};
+		let timestamps = game_entry.timestamps;
+		let mut timeline = sp_std::vec![(GameState::Waiting, timestamps.queued)];
+		for (game_state, block) in sp_std::vec![
+			(GameState::Accepted, timestamps.accepted),
+			(GameState::Running, timestamps.started),
+			(game_entry.game_state, timestamps.finished),
+		] {
+			if !block.is_zero() {
+				timeline.push((game_state, block));
+			}
+		}
+		timeline
+	}
+
+	/// Append the state a game moved into to its timeline. Full timelines keep the first
+	/// transition and make room by dropping the oldest one after it.
+	fn note_transition(game_hash: &T::Hash, game_state: &GameStateOf<T>) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		<GameTimelines<T>>::mutate(game_hash, |timeline| {
+			if timeline.len() >= MAX_GAME_TRANSITIONS as usize {
+				timeline.remove(1);
+			}
+			let _ = timeline.try_push((game_state.clone(), now));
+		});
+	}
+
+	/// Move a finished game out of the registry into the archive, or update it there.
+	fn archive_game(game_hash: &T::Hash, game_entry: &GameEntryOf<T>) {
+		<GameRegistry<T>>::remove(game_hash);
+		<ArchivedGames<T>>::insert(game_hash, game_entry);
+	}
+
+	/// Numeric parameters of the registry, set by governance or the runtime defaults.
+	pub fn parameters() -> RegistryParameters<T::BlockNumber> {
+		<Parameters<T>>::get().unwrap_or(RegistryParameters {
+			waiting_timeout: T::WaitingTimeout::get(),
+			accepted_timeout: T::AcceptedTimeout::get(),
+			max_game_duration: T::MaxGameDuration::get(),
+			ack_batch_limit: T::MaxAckBatch::get(),
+			games_per_block: T::MaxGamesPerBlock::get(),
+			tee_calls_per_block: T::MaxTeeCallsPerBlock::get(),
+			abandonment_slash: Perbill::one(),
+			frivolous_report_slash: Perbill::one(),
+			matchmaking_weight: T::MatchmakingWeightLimit::get(),
+			maintenance_weight: T::MaintenanceWeightLimit::get(),
+		})
+	}
+
+	/// Check the invariants of the registry, for try-runtime runs and tests.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		// game entries are not readable till the stepped migration is done
+		if Self::migration_cursor().is_some() {
+			return Ok(())
+		}
+
+		for game_engine in <GameQueues<T>>::iter_keys().chain(<PriorityGameQueues<T>>::iter_keys())
+		{
+			for game_hash in Self::game_queues(&game_engine).iter() {
+				ensure!(
+					Self::game_entry(&game_hash).map(|game_entry| game_entry.game_state) ==
+						Some(GameState::Waiting),
+					"queued game is not waiting in the registry"
+				);
+			}
+		}
+
+		for (cluster_id, cluster) in <Clusters<T>>::iter() {
+			for tee in cluster.tees.iter() {
+				ensure!(
+					Self::tee_cluster(tee) == Some(cluster_id),
+					"cluster tee belongs to another cluster"
+				);
+				ensure!(
+					Self::registered_tees(tee).map(|tee_info| tee_info.supported_engines) ==
+						Some(cluster.engines.clone()),
+					"cluster tee doesn't run the engines of its cluster"
+				);
+			}
+		}
+
+		for (game_engine, _) in <SupportedEngines<T>>::iter() {
+			let tees = <RegisteredTees<T>>::iter_values()
+				.filter(|tee_info| tee_info.supported_engines.contains(&game_engine))
+				.count() as u32;
+			ensure!(
+				Self::engine_tee_count(&game_engine) == tees,
+				"engine tee count doesn't match the registered tees"
+			);
+		}
+
+		for (_, queue_info) in <QueuedPlayers<T>>::iter() {
+			ensure!(
+				<EngineBrackets<T>>::contains_key(queue_info.bracket),
+				"queued player is in a bracket without game engine"
+			);
+		}
+
+		for (player, bond) in <QueueBonds<T>>::iter() {
+			let payer = Self::bond_payers(&player).unwrap_or(player);
+			ensure!(T::Currency::reserved_balance(&payer) >= bond, "queue bond is not reserved");
+		}
+
+		for (tee, tee_load) in <TeeLoads<T>>::iter() {
+			let mut load = TeeLoad::default();
+			for game_hash in <TeeGames<T>>::iter_key_prefix(&tee) {
+				let game_entry =
+					Self::game_entry(&game_hash).ok_or("tee game has no game entry")?;
+				ensure!(game_entry.tee_id.as_ref() == Some(&tee), "tee game is assigned elsewhere");
+				match game_entry.game_state {
+					GameState::Accepted => load.accepted += 1,
+					GameState::Running => load.running += 1,
+					_ => return Err("tee game is not active"),
+				}
+			}
+			ensure!(tee_load == load, "tee load doesn't match its games");
+		}
+		ensure!(
+			<TeeGames<T>>::iter_keys().all(|(tee, _)| <TeeLoads<T>>::contains_key(&tee)),
+			"tee games are not counted in its load"
+		);
+		ensure!(
+			<GameTimelines<T>>::iter_keys().all(|game_hash| Self::game_entry(&game_hash).is_some()),
+			"timeline of a game gone"
+		);
+
+		for (player, games) in <CurrentGames<T>>::iter() {
+			for (index, game_hash) in games.iter().enumerate() {
+				ensure!(!games[..index].contains(game_hash), "player is twice in the same game");
+				let game_entry =
+					Self::game_entry(game_hash).ok_or("current game has no game entry")?;
+				ensure!(game_entry.players.contains(&player), "player is not in its current game");
+				ensure!(
+					matches!(
+						game_entry.game_state,
+						GameState::Scheduled |
+							GameState::Waiting | GameState::Accepted |
+							GameState::Running
+					),
+					"current game is over"
+				);
+			}
+		}
+		for (player, engine_id, count) in <PlayerGames<T>>::iter() {
+			let games = Self::current_games(&player)
+				.iter()
+				.filter_map(|game_hash| Self::game_entry(game_hash))
+				.filter(|game_entry| game_entry.game_engine.id == engine_id)
+				.count() as u32;
+			ensure!(count == games, "player game count doesn't match its current games");
+		}
+
+		// the blocks a game reached its states in don't go back in time
+		for game_entry in <GameRegistry<T>>::iter_values() {
+			let timestamps = &game_entry.timestamps;
+			let in_order = match game_entry.game_state {
+				GameState::None | GameState::Scheduled | GameState::Waiting => true,
+				GameState::Accepted => timestamps.queued <= timestamps.accepted,
+				GameState::Running =>
+					timestamps.queued <= timestamps.accepted &&
+						timestamps.accepted <= timestamps.started,
+				_ => timestamps.queued <= timestamps.finished,
+			};
+			ensure!(in_order, "game timestamps are not monotonic");
+		}
+
+		// values past their bounds don't decode
+		ensure!(
+			<GameRegistry<T>>::iter_keys().count() == <GameRegistry<T>>::iter_values().count(),
+			"game entry exceeds its bounds"
+		);
+		ensure!(
+			<ArchivedGames<T>>::iter_values().all(|game_entry| matches!(
+				game_entry.game_state,
+				GameState::Finished(_) | GameState::Suspended(_) | GameState::Reconfirming(_)
+			)),
+			"archived game is still in play"
+		);
+		ensure!(
+			<GameRequirments<T>>::iter_keys().count() ==
+				<GameRequirments<T>>::iter_values().count(),
+			"game rules exceed their bound"
+		);
+
+		Ok(())
+	}
+
+	/// Canonical fixed width encoding of a finalized result.
+	pub fn canonical_result(
+		result_leaf: &GameResultLeaf<T::Hash, T::AccountId, T::BlockNumber, T::MaxPlayersPerGame>,
+	) -> CanonicalResult {
+		CanonicalResult {
+			version: CANONICAL_RESULT_VERSION,
+			game_hash: Self::padded_bytes(&result_leaf.game_hash),
+			engine_id: result_leaf.game_engine.id,
+			engine_version: result_leaf.game_engine.version,
+			outcome: match result_leaf.outcome {
+				GameOutcome::Winner(_) => 0,
+				GameOutcome::Draw => 1,
+				GameOutcome::Ranked(_) => 2,
+				GameOutcome::Aborted => 3,
+			},
+			winner: result_leaf.outcome.winner().map_or([0; 32], Self::padded_bytes),
+			finished_at: result_leaf.block.unique_saturated_into(),
+		}
+	}
+
+	/// Encoding of a value zero padded to 32 bytes.
+	fn padded_bytes<E: Encode>(value: &E) -> [u8; 32] {
+		<[u8; 32]>::decode(&mut TrailingZeroInput::new(value.encode().as_ref()))
+			.expect("input is padded with zeroes; qed")
+	}
+
+	/// Move a player to its place in the on-chain leaderboard, keeping only the top players.
+	fn update_leaderboard(player: &T::AccountId, wins: u32) {
+		<Leaderboard<T>>::mutate(|leaderboard| Self::rank_player(leaderboard, player, wins));
+	}
+
+	/// Count the win of the player in the first place of an outcome, or take it back.
+	fn count_win(outcome: &GameOutcomeOf<T>, won: bool) {
+		let winner = match outcome.winner() {
+			Some(winner) => winner,
+			None => return,
+		};
+		// wins of players with a ranked ban don't count
+		if Self::is_sanctioned(winner, SanctionKind::RankedBan) {
+			return
+		}
+		let wins = <GamesWon<T>>::mutate(winner, |wins| {
+			*wins = if won { wins.saturating_add(1) } else { wins.saturating_sub(1) };
+			*wins
+		});
+		Self::update_leaderboard(winner, wins);
+	}
+
+	/// Move a player to its rank in a leaderboard of the top players by games won.
+	fn rank_player(leaderboard: &mut LeaderboardOf<T>, player: &T::AccountId, wins: u32) {
+		let mut ranked = sp_std::mem::take(leaderboard).into_inner();
+		ranked.retain(|(other, _)| other != player);
+		// players reaching the same wins earlier stay ahead
+		let rank = ranked.iter().position(|(_, other)| *other < wins);
+		ranked.insert(rank.unwrap_or(ranked.len()), (player.clone(), wins));
+		ranked.truncate(T::LeaderboardSize::get() as usize);
+		*leaderboard = LeaderboardOf::<T>::try_from(ranked).unwrap_or_default();
+	}
+
+	/// Count a finished game in the season tallies of its players, wins of players with a
+	/// ranked ban don't count. The player in the first place wins, the others lose, draws and
+	/// aborted games aren't tallied.
+	fn tally_season(players: &[T::AccountId], outcome: &GameOutcomeOf<T>) {
+		let winner = match outcome.winner() {
+			Some(winner) if !T::SeasonLength::get().is_zero() => winner,
+			_ => return,
+		};
+		let season = Self::current_season();
+		for player in players.iter() {
+			let won = player == winner;
+			if won && Self::is_sanctioned(player, SanctionKind::RankedBan) {
+				continue
+			}
+			let tally = <SeasonTallies<T>>::mutate(season, player, |tally| {
+				if won {
+					tally.wins = tally.wins.saturating_add(1);
+				} else {
+					tally.losses = tally.losses.saturating_add(1);
+				}
+				*tally
+			});
+			if won {
+				<SeasonLeaderboard<T>>::mutate(|leaderboard| {
+					Self::rank_player(leaderboard, player, tally.wins)
+				});
+			}
+		}
+	}
+
+	/// End the ongoing season, its leaderboard is kept as champions and its tallies stay
+	/// archived under the season.
+	fn end_season() {
+		let season = <CurrentSeason<T>>::mutate(|season| {
+			let ended = *season;
+			*season = season.saturating_add(1);
+			ended
+		});
+		let champions = <SeasonLeaderboard<T>>::take();
+		<SeasonChampions<T>>::insert(season, &champions);
+
+		// Emit an event.
+		Self::deposit_event(Event::SeasonEnded(season, champions.into_inner()));
+	}
+
+	/// Full leaderboard of all players with finished games, best player first.
+	pub fn build_leaderboard() -> Vec<LeaderboardEntry<T::AccountId>> {
+		let mut leaderboard: Vec<_> = <FinishedGames<T>>::iter()
+			.map(|(player, games)| LeaderboardEntry {
+				wins: Self::games_won(&player),
+				games,
+				percentile: 0,
+				player,
+			})
+			.collect();
+		leaderboard.sort_by(|a, b| (a.wins, a.games, &a.player).cmp(&(b.wins, b.games, &b.player)));
+
+		// players with the same wins share the percentile
+		let total = leaderboard.len();
+		let mut less_wins = 0;
+		for index in 0..total {
+			if index > 0 && leaderboard[index - 1].wins != leaderboard[index].wins {
+				less_wins = index;
+			}
+			leaderboard[index].percentile = (less_wins * 100 / total) as u8;
+		}
+
+		leaderboard.reverse();
+		leaderboard
+	}
+
+	/// Offchain index key of the record of a finalized game.
+	pub fn game_record_key(game_hash: &T::Hash) -> Vec<u8> {
+		(OFFCHAIN_GAME_PREFIX, game_hash).encode()
+	}
+
+	/// Offchain storage key of the block the offchain worker last submitted the expiry of a game
+	/// in.
+	pub fn expiry_lock_key(game_hash: &T::Hash) -> Vec<u8> {
+		(OFFCHAIN_EXPIRY_PREFIX, game_hash).encode()
+	}
+
+	/// Offchain index key of the games finalized in a block.
+	pub fn block_record_key(block: T::BlockNumber) -> Vec<u8> {
+		(OFFCHAIN_BLOCK_PREFIX, block).encode()
+	}
+
+	/// All games a player is part of, used by the runtime api.
+	pub fn games_of(player: T::AccountId) -> Vec<T::Hash> {
+		<GameRegistry<T>>::iter()
+			.chain(<ArchivedGames<T>>::iter())
+			.filter(|(_, game_entry)| game_entry.players.contains(&player))
+			.map(|(game_hash, _)| game_hash)
+			.collect()
+	}
+
+	/// Games an AjunaTEE acknowledged or is running.
+	pub fn tee_assignments(tee: T::AccountId) -> Vec<T::Hash> {
+		<TeeGames<T>>::iter_key_prefix(&tee).collect()
+	}
+
+	/// All games in a state, `GameState::Finished` matches finished games of any outcome.
+	pub fn games_by_state(game_state: GameStateOf<T>) -> Vec<T::Hash> {
+		let state = sp_std::mem::discriminant(&game_state);
+		<GameRegistry<T>>::iter()
+			.chain(<ArchivedGames<T>>::iter())
+			.filter(|(_, game_entry)| sp_std::mem::discriminant(&game_entry.game_state) == state)
+			.map(|(game_hash, _)| game_hash)
+			.collect()
+	}
+
+	/// A page of the running games, at most `MAX_RUNNING_GAMES_PAGE` games after skipping the
+	/// first `offset` ones.
+	pub fn running_games(
+		offset: u32,
+		limit: u32,
+	) -> Vec<RunningGame<T::Hash, T::AccountId, T::BlockNumber>> {
+		<GameRegistry<T>>::iter()
+			.filter(|(_, game_entry)| game_entry.game_state == GameState::Running)
+			.skip(offset as usize)
+			.take(limit.min(MAX_RUNNING_GAMES_PAGE) as usize)
+			.map(|(game_hash, game_entry)| RunningGame {
+				game_hash,
+				game_engine: game_entry.game_engine,
+				players: if Self::is_private_game(&game_hash) {
+					Vec::new()
+				} else {
+					game_entry.players.into_inner()
+				},
+				tee: game_entry.tee_id,
+				started_at: game_entry.timestamps.started,
+			})
+			.collect()
+	}
+
+	/// Amount of waiting games in the game queue of each game engine.
+	pub fn queue_lengths() -> Vec<(GameEngine, u32)> {
+		<GameQueues<T>>::iter_keys()
+			.map(|game_engine| {
+				let length = Self::game_queues(&game_engine).length();
+				(game_engine, length)
+			})
+			.collect()
+	}
+
+	/// Game queue of a game engine version.
+	pub fn game_queues(game_engine: &GameEngine) -> GameQueueOf<T> {
+		LanedQueue::new(game_engine.clone(), T::MaxQueueSize::get())
+	}
+
+	/// Whether a game engine version has a game queue, in either lane.
+	fn has_game_queue(game_engine: &GameEngine) -> bool {
+		GameQueues::<T>::contains_key(game_engine) ||
+			PriorityGameQueues::<T>::contains_key(game_engine)
+	}
+
+	/// Whether a game is queued in the priority lane, games of tournaments and games above the
+	/// high-value wager are.
+	pub fn is_priority_game(game_hash: &T::Hash) -> bool {
+		Self::is_tournament_game(game_hash) ||
+			Self::game_wagers(game_hash) > T::HighValueWager::get()
+	}
+
+	/// Enqueue a game into the lane of its game queue it belongs to.
+	fn enqueue_game(game_queue: &mut GameQueueOf<T>, game_hash: T::Hash) -> bool {
+		if Self::is_priority_game(&game_hash) {
+			game_queue.enqueue_priority(game_hash)
+		} else {
+			game_queue.enqueue(game_hash)
+		}
+	}
+
+	/// Move a waiting game into the priority lane of its game queue, once it belongs there.
+	fn prioritize_game(game_hash: &T::Hash) {
+		if !Self::is_priority_game(game_hash) {
+			return
+		}
+		let game_engine = Self::game_registry(game_hash).game_engine;
+		if Self::game_queues(&game_engine).promote(*game_hash) {
+			// Emit an event.
+			Self::deposit_event(Event::GamePrioritized(*game_hash, game_engine));
+		}
+	}
+
+	/// Spectator settings of a game, falling back to the default of its game engine.
+	pub fn spectator_settings(game_hash: &T::Hash, game_engine: &GameEngine) -> SpectatorSettings {
+		Self::game_spectator_settings(game_hash)
+			.or_else(|| Self::engine_spectator_settings(game_engine))
+			.unwrap_or(SpectatorSettings {
+				policy: SpectatorPolicy::Open,
+				max_spectators: T::MaxSpectators::get(),
+			})
+	}
+
+	/// Whether a waiting or accepted game is past its timeout.
+	fn is_expired(game_entry: &GameEntryOf<T>) -> bool {
+		Self::expiry_reason(game_entry).is_some()
+	}
+
+	/// Why a game expired, `None` if it didn't.
+	fn expiry_reason(game_entry: &GameEntryOf<T>) -> Option<ExpiryReason> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Self::expiry_deadline(game_entry, &game_entry.game_state)
+			.and_then(|(deadline, reason)| (now >= deadline).then(|| reason))
+	}
+
+	/// The block a game in the given state expires at, with the reason it expires for.
+	fn expiry_deadline(
+		game_entry: &GameEntryOf<T>,
+		game_state: &GameStateOf<T>,
+	) -> Option<(T::BlockNumber, ExpiryReason)> {
+		let parameters = Self::parameters();
+		let (since, timeout, reason) = match game_state {
+			GameState::Waiting => (
+				game_entry.timestamps.queued,
+				parameters.waiting_timeout,
+				ExpiryReason::NotAcknowledged,
+			),
+			GameState::Accepted => (
+				game_entry.timestamps.accepted,
+				parameters.accepted_timeout,
+				ExpiryReason::NotReady,
+			),
+			GameState::Running => (
+				game_entry.timestamps.started,
+				parameters.max_game_duration,
+				ExpiryReason::Overtime,
+			),
+			_ => return None,
+		};
+		Some((since.saturating_add(timeout), reason))
+	}
+
+	/// Schedule the expiry of a game at the deadline of its new state, replacing the expiry
+	/// scheduled for its previous state. Games leaving the ongoing states don't expire anymore.
+	fn schedule_expiry(
+		game_hash: &T::Hash,
+		game_entry: &GameEntryOf<T>,
+		game_state: &GameStateOf<T>,
+	) {
+		if !T::ScheduledExpiry::get() {
+			return
+		}
+		let id = (GAMEREGISTRY_ID, b"expiry", game_hash).encode();
+		let _ = T::Scheduler::cancel_named(id.clone());
+		if let Some((deadline, _)) = Self::expiry_deadline(game_entry, game_state) {
+			// the scheduler only takes future blocks
+			let when = deadline.max(<frame_system::Pallet<T>>::block_number() + 1u8.into());
+			// games the scheduler doesn't take are still expired by the maintenance
+			let _ = T::Scheduler::schedule_named(
+				id,
+				DispatchTime::At(when),
+				None,
+				HARD_DEADLINE,
+				frame_system::RawOrigin::Root.into(),
+				Call::<T>::expire_game { game_hash: *game_hash }.into(),
+			);
+		}
+	}
+
+	/// Remove the spectators of a game, with their settings and invites.
+	fn remove_spectators(game_hash: &T::Hash) {
+		<Spectators<T>>::remove(game_hash);
+		<GameSpectatorSettings<T>>::remove(game_hash);
+		<SpectatorInvites<T>>::remove_prefix(game_hash, None);
+	}
+
+	/// Minimum of AjunaTEEs supporting a game engine before players can queue, none in dev mode.
+	fn min_tees() -> u32 {
+		if Self::dev_mode() {
+			0
+		} else {
+			T::MinTees::get()
+		}
+	}
+
+	/// Whether an account plays itself, as main account of session keys or with queued, bonded
+	/// or ongoing games.
+	fn has_player_state(who: &T::AccountId) -> bool {
+		Self::session_key_count(who) > 0 ||
+			<QueuedPlayers<T>>::contains_key(who) ||
+			<QueueBonds<T>>::contains_key(who) ||
+			<Parties<T>>::contains_key(who) ||
+			!Self::current_games(who).is_empty()
+	}
+
+	/// Ensure the origin is signed by a player, or by a session key playing on its behalf.
+	fn ensure_player(origin: T::Origin) -> Result<T::AccountId, DispatchError> {
+		let who = frame_system::ensure_signed(origin)?;
+		Ok(Self::session_keys(&who).unwrap_or(who))
+	}
+
+	/// Follow drained game engine versions to the version that currently hosts their games,
+	/// stopping at a version already passed.
+	fn current_engine(mut game_engine: GameEngine) -> GameEngine {
+		let mut passed = Vec::new();
+		while let Some(successor) =
+			Self::supported_engines(&game_engine).and_then(|engine_info| engine_info.successor)
+		{
+			if passed.contains(&successor) {
+				break
+			}
+			passed.push(game_engine);
+			game_engine = successor;
+		}
+		game_engine
+	}
+
+	/// Check if a game engine is currently in a maintenance window.
+	fn in_maintenance(game_engine: &GameEngine) -> bool {
+		Self::supported_engines(game_engine).map_or(false, |engine_info| engine_info.maintenance)
+	}
+
+	/// Check if a game engine version is deprecated.
+	fn is_deprecated(game_engine: &GameEngine) -> bool {
+		<DeprecatedEngines<T>>::contains_key(game_engine)
+	}
+
+	/// Reserve the brackets of all rating tiers for a game engine version, starting at its first
+	/// bracket.
+	fn reserve_brackets(first: u8, game_engine: &GameEngine) -> DispatchResult {
+		let tiers = T::RatingBoundaries::get().len();
+		let last =
+			u8::try_from(first as usize + tiers).map_err(|_| Error::<T>::BracketOutOfRange)?;
+		ensure!(
+			(first..=last).all(|bracket| !EngineBrackets::<T>::contains_key(bracket)),
+			Error::<T>::BracketInUse
+		);
+		for bracket in first..=last {
+			<EngineBrackets<T>>::insert(bracket, game_engine.clone());
+		}
+		Ok(())
+	}
+
+	/// Elo rating of a player for a game engine id.
+	pub fn rating(player: &T::AccountId, engine_id: u8) -> u32 {
+		Self::player_rating(player, engine_id).unwrap_or(INITIAL_RATING)
+	}
+
+	/// Skill tier of a rating, the amount of rating boundaries it reached.
+	fn rating_tier(rating: u32) -> u8 {
+		T::RatingBoundaries::get()
+			.iter()
+			.filter(|boundary| rating >= **boundary)
+			.count() as u8
+	}
+
+	/// Move the ratings of the players of a game by its outcome. A winner beats every other
+	/// player, a ranked player every player ranked below and in a draw every pair of players
+	/// draws. The game is counted for the placement and decay of the ratings of its players.
+	fn update_ratings(players: &[T::AccountId], outcome: &GameOutcomeOf<T>, engine_id: u8) {
+		match outcome {
+			GameOutcome::Winner(winner) =>
+				for loser in players.iter().filter(|player| *player != winner) {
+					Self::rate_pair(winner, loser, false, engine_id);
+				},
+			GameOutcome::Ranked(ranking) =>
+				for (place, winner) in ranking.iter().enumerate() {
+					for loser in ranking[place + 1..].iter() {
+						Self::rate_pair(winner, loser, false, engine_id);
+					}
+				},
+			GameOutcome::Draw =>
+				for (index, player) in players.iter().enumerate() {
+					for other in players[index + 1..].iter() {
+						Self::rate_pair(player, other, true, engine_id);
+					}
+				},
+			GameOutcome::Aborted => return,
+		}
+		let now = <frame_system::Pallet<T>>::block_number();
+		for player in players.iter() {
+			<RatingActivities<T>>::mutate(player, engine_id, |activity| {
+				activity.games_played = activity.games_played.saturating_add(1);
+				activity.last_played = now;
+			});
+		}
+	}
+
+	/// K-factor of the rated games of a player, higher during its placement games.
+	fn k_factor(player: &T::AccountId, engine_id: u8) -> u32 {
+		if Self::rating_activity(player, engine_id).games_played < T::PlacementGames::get() {
+			PLACEMENT_K_FACTOR
+		} else {
+			RATING_K_FACTOR
+		}
+	}
+
+	/// Let the rating of a player drift towards the initial rating for every decay period passed
+	/// since it last finished a rated game, applied once the player queues again.
+	fn decay_rating(player: &T::AccountId, engine_id: u8) {
+		let period = T::DecayPeriod::get();
+		let rating = match Self::player_rating(player, engine_id) {
+			Some(rating) if !period.is_zero() => rating,
+			_ => return,
+		};
+		let now = <frame_system::Pallet<T>>::block_number();
+		if !<RatingActivities<T>>::contains_key(player, engine_id) {
+			// ratings from before their activity was kept start to decay from now
+			let activity = RatingActivity { games_played: 0, last_played: now };
+			<RatingActivities<T>>::insert(player, engine_id, activity);
+			return
+		}
+		let mut activity = Self::rating_activity(player, engine_id);
+		let periods = now.saturating_sub(activity.last_played) / period;
+		if periods.is_zero() {
+			return
+		}
+
+		// the part of the distance to the initial rating kept over all periods passed
+		let exponent: u32 = periods.unique_saturated_into();
+		let kept = (Perbill::one() - T::RatingDecay::get()).saturating_pow(exponent as usize);
+		let decayed = if rating >= INITIAL_RATING {
+			INITIAL_RATING.saturating_add(kept * (rating - INITIAL_RATING))
+		} else {
+			INITIAL_RATING.saturating_sub(kept * (INITIAL_RATING - rating))
+		};
+		// the periods left over are decayed on the next queue
+		activity.last_played = activity.last_played.saturating_add(periods.saturating_mul(period));
+		<RatingActivities<T>>::insert(player, engine_id, activity);
+		if decayed == rating {
+			return
+		}
+		<PlayerRating<T>>::insert(player, engine_id, decayed);
+
+		// Emit an event.
+		Self::deposit_event(Event::RatingDecayed(player.clone(), engine_id, rating, decayed));
+	}
+
+	/// Move the ratings of two players by the score of the first one against the second one, a
+	/// win or a draw.
+	///
+	/// The expected score uses the linear approximation of the Elo curve, within 400 points of
+	/// rating difference.
+	fn rate_pair(first: &T::AccountId, second: &T::AccountId, drawn: bool, engine_id: u8) {
+		// wins of players with a ranked ban don't count, neither do their draws
+		if Self::is_sanctioned(first, SanctionKind::RankedBan) ||
+			(drawn && Self::is_sanctioned(second, SanctionKind::RankedBan))
+		{
+			return
+		}
+		let first_rating = Self::rating(first, engine_id);
+		let second_rating = Self::rating(second, engine_id);
+		let difference = (first_rating as i64 - second_rating as i64).max(-400).min(400) + 400;
+		let score = if drawn { 400 } else { 800 };
+		// players in their placement games move further than the other player
+		let gain = |player| Self::k_factor(player, engine_id) as i64 * (score - difference) / 800;
+		let moved = |rating: u32, change: i64| {
+			(rating as i64).saturating_add(change).max(0).min(u32::MAX as i64) as u32
+		};
+		<PlayerRating<T>>::insert(first, engine_id, moved(first_rating, gain(first)));
+		<PlayerRating<T>>::insert(second, engine_id, moved(second_rating, -gain(second)));
+	}
+
+	/// Ensure an outcome only names players of the game.
+	fn ensure_outcome(outcome: &GameOutcomeOf<T>, players: &[T::AccountId]) -> DispatchResult {
+		ensure!(
+			outcome.fits(players),
+			match outcome {
+				GameOutcome::Ranked(_) => Error::<T>::InvalidRanking,
+				_ => Error::<T>::WinnerNotAPlayer,
+			}
+		);
+		Ok(())
+	}
+
+	/// Register a game engine version in its own bracket.
+	fn do_register_game_engine(game_engine: GameEngine, bracket: u8) -> DispatchResult {
+		ensure!(
+			!SupportedEngines::<T>::contains_key(&game_engine),
+			Error::<T>::EngineAlreadyRegistered
+		);
+		Self::reserve_brackets(bracket, &game_engine)?;
+
+		<SupportedEngines<T>>::insert(
+			&game_engine,
+			EngineInfo { bracket, successor: None, maintenance: false },
+		);
+
+		// keep track of the latest version, used when players don't ask for a version
+		match Self::latest_engine_version(game_engine.id) {
+			Some(latest) if latest >= game_engine.version => {},
+			_ => <LatestEngineVersion<T>>::insert(game_engine.id, game_engine.version),
+		}
+
+		Ok(())
+	}
+
+	/// Ensure a game engine version takes new games, for games created by other pallets.
+	fn ensure_engine_open(game_engine: &GameEngine) -> DispatchResult {
+		let engine_info =
+			Self::supported_engines(game_engine).ok_or(Error::<T>::EngineNotRegistered)?;
+		ensure!(engine_info.successor.is_none(), Error::<T>::EngineDrained);
+		ensure!(!Self::is_deprecated(game_engine), Error::<T>::EngineDeprecated);
+		ensure!(!engine_info.maintenance, Error::<T>::EngineInMaintenance);
+		ensure!(!Self::paused_engines(game_engine), Error::<T>::EngineDisabled);
+		Ok(())
+	}
+
+	/// Count a lifecycle milestone of a game, for its game engine and all of the registry.
+	fn count_game(game_engine: &GameEngine, counter: fn(&mut GameCounters) -> &mut u64) {
+		let increment = |counters: &mut GameCounters| {
+			let count = counter(counters);
+			*count = count.saturating_add(1);
+		};
+		<EngineCounters<T>>::mutate(game_engine, increment);
+		<TotalCounters<T>>::mutate(increment);
+	}
+
+	/// Update the aggregate metrics of the game engine of a game changing its state.
+	fn note_engine_stats(game_entry: &GameEntryOf<T>, from: &GameStateOf<T>, to: &GameStateOf<T>) {
+		let is_active =
+			|state: &GameStateOf<T>| matches!(state, GameState::Accepted | GameState::Running);
+		let players = game_entry.players.len() as u32;
+		<EngineStats<T>>::mutate(&game_entry.game_engine, |stats| {
+			match (from, to) {
+				(GameState::Waiting, GameState::Accepted) => {
+					let timestamps = &game_entry.timestamps;
+					let wait = timestamps.accepted.saturating_sub(timestamps.queued);
+					stats.queue_wait_blocks =
+						stats.queue_wait_blocks.saturating_add(wait.unique_saturated_into());
+					stats.queue_waits = stats.queue_waits.saturating_add(1);
+				},
+				(GameState::Running, GameState::Finished(_)) =>
+					stats.games_played = stats.games_played.saturating_add(1),
+				(from, GameState::None) if is_active(from) =>
+					stats.games_abandoned = stats.games_abandoned.saturating_add(1),
+				_ => (),
+			}
+			match (is_active(from), is_active(to)) {
+				(false, true) =>
+					stats.active_players = stats.active_players.saturating_add(players),
+				(true, false) =>
+					stats.active_players = stats.active_players.saturating_sub(players),
+				_ => (),
+			}
+		});
+	}
+
+	/// Notify the game observers and indexers of a game changing its state, and count the games
+	/// held by AjunaTEEs.
+	fn change_game_state(
+		game_hash: &T::Hash,
+		game_entry: &GameEntryOf<T>,
+		from: &GameStateOf<T>,
+		to: &GameStateOf<T>,
+		by: Option<T::AccountId>,
+	) {
+		let game_engine = &game_entry.game_engine;
+		let is_active =
+			|state: &GameStateOf<T>| matches!(state, GameState::Accepted | GameState::Running);
+		match (is_active(from), is_active(to)) {
+			(false, true) => <ActiveGames<T>>::mutate(game_engine, |count| *count += 1),
+			(true, false) =>
+				<ActiveGames<T>>::mutate(game_engine, |count| *count = count.saturating_sub(1)),
+			_ => (),
+		}
+		Self::note_engine_stats(game_entry, from, to);
+		// the games of the players count till they're over
+		let is_ongoing = |state: &GameStateOf<T>| {
+			matches!(
+				state,
+				GameState::Scheduled |
+					GameState::Waiting |
+					GameState::Accepted |
+					GameState::Running
+			)
+		};
+		match (is_ongoing(from), is_ongoing(to)) {
+			(false, true) => {
+				Self::count_player_games(&game_entry.players, game_engine.id, true);
+				Self::note_current_game(&game_entry.players, game_hash, true);
+			},
+			(true, false) => {
+				Self::count_player_games(&game_entry.players, game_engine.id, false);
+				Self::note_current_game(&game_entry.players, game_hash, false);
+			},
+			_ => (),
+		}
+		if let Some(tee) = game_entry.tee_id.as_ref() {
+			Self::note_tee_load(tee, game_hash, from, to);
+		}
+		Self::schedule_expiry(game_hash, game_entry, to);
+		if *to == GameState::None {
+			<GameTimelines<T>>::remove(game_hash);
+		} else {
+			Self::note_transition(game_hash, to);
+		}
+		T::GameObserver::on_game_state_changed(game_hash, from, to);
+		Self::deposit_event(Event::GameStateChanged(*game_hash, from.clone(), to.clone(), by));
+	}
+
+	/// Move a game of an AjunaTEE between the accepted and running games it's counted in.
+	fn note_tee_load(
+		tee: &T::AccountId,
+		game_hash: &T::Hash,
+		from: &GameStateOf<T>,
+		to: &GameStateOf<T>,
+	) {
+		match (from, to) {
+			(_, GameState::Accepted | GameState::Running) =>
+				<TeeGames<T>>::insert(tee, game_hash, ()),
+			(GameState::Accepted | GameState::Running, _) => <TeeGames<T>>::remove(tee, game_hash),
+			_ => (),
+		}
+		<TeeLoads<T>>::mutate_exists(tee, |load| {
+			let mut tee_load = load.unwrap_or_default();
+			match from {
+				GameState::Accepted => tee_load.accepted = tee_load.accepted.saturating_sub(1),
+				GameState::Running => tee_load.running = tee_load.running.saturating_sub(1),
+				_ => (),
+			}
+			match to {
+				GameState::Accepted => tee_load.accepted = tee_load.accepted.saturating_add(1),
+				GameState::Running => tee_load.running = tee_load.running.saturating_add(1),
+				_ => (),
+			}
+			*load = Some(tee_load).filter(|tee_load| tee_load.total() > 0);
+		});
+	}
+
+	/// Process the reconfirmation and rematch deadlines, tournament rounds and settlements due
+	/// in a block.
+	fn process_block_deadlines(n: T::BlockNumber) -> Weight {
+		let mut weight: Weight = 0;
+
+		// flagged results not re-confirmed in time go to arbitration
+		let unconfirmed = <ReconfirmationDeadlines<T>>::take(n);
+		weight = weight.saturating_add(T::DbWeight::get().reads(unconfirmed.len() as u64));
+		for game_hash in unconfirmed {
+			let game_entry = Self::game_entry(&game_hash).unwrap_or_default();
+			if let GameState::Reconfirming(outcome) = game_entry.game_state.clone() {
+				Self::escalate_result(game_hash, game_entry, outcome, Vec::new());
+				weight = weight.saturating_add(T::WeightInfo::escalate_result());
+			}
+		}
+
+		// rematches not requested by all players in time expire
+		let lapsed = <RematchDeadlines<T>>::take(n);
+		weight = weight.saturating_add(T::DbWeight::get().reads_writes(lapsed.len() as u64, 1));
+		for game_hash in lapsed {
+			if <RematchRequests<T>>::contains_key(&game_hash) {
+				<RematchRequests<T>>::remove(&game_hash);
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				Self::deposit_event(Event::RematchExpired(game_hash));
+			}
+		}
+
+		// tournaments start their next round once the previous one is decided
+		let tournaments = <TournamentRounds<T>>::take(n);
+		weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+		for tournament_id in tournaments {
+			weight = weight.saturating_add(Self::start_tournament_round(tournament_id));
+		}
+
+		// results past their fraud proof window are settled, disputed ones once ruled on
+		let settled = <PendingSettlements<T>>::take(n);
+		weight = weight.saturating_add(T::DbWeight::get().reads(settled.len() as u64));
+		for game_hash in settled {
+			let game_entry = Self::game_entry(&game_hash).unwrap_or_default();
+			if let GameState::Finished(outcome) = game_entry.game_state.clone() {
+				Self::settle_result(&game_hash, &game_entry, &outcome);
+				weight = weight.saturating_add(T::WeightInfo::settle_result());
+			}
+		}
+
+		weight
+	}
+
+	/// Queue the games of AjunaTEEs not seen for `TeeTimeout` blocks again, looking at up to
+	/// `MAX_TEE_TIMEOUT_SWEEP` AjunaTEEs and games in a block. The sweep continues in the next
+	/// block, with the AjunaTEE it stopped at. Returns the weight used.
+	fn reassign_offline_games(n: T::BlockNumber) -> Weight {
+		let timeout = T::TeeTimeout::get();
+		if timeout.is_zero() {
+			return 0
+		}
+
+		// the sweep cursor
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		let prefix = TeeLoads::<T>::final_prefix();
+		let mut cursor = Self::tee_timeout_cursor()
+			.map(BoundedVec::into_inner)
+			.unwrap_or_else(|| prefix.to_vec());
+		let mut budget = MAX_TEE_TIMEOUT_SWEEP;
+		while budget > 0 {
+			let key = match sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(&prefix))
+			{
+				Some(key) => key,
+				None => {
+					// all AjunaTEEs looked at, the sweep starts over in the next block
+					<TeeTimeoutCursor<T>>::kill();
+					return weight
+				},
+			};
+			budget -= 1;
+			// the load and the last heartbeat of the AjunaTEE
+			weight = weight.saturating_add(T::DbWeight::get().reads(2));
+
+			// keys of the loads end with the blake2 128 concat hash of the AjunaTEE
+			let tee = match T::AccountId::decode(&mut &key[(prefix.len() + 16).min(key.len())..]) {
+				Ok(tee) => tee,
+				Err(_) => {
+					cursor = key;
+					continue
+				},
+			};
+			let last_seen = Self::tee_last_seen(&tee).unwrap_or_default();
+			if n > last_seen.saturating_add(timeout) {
+				let games: Vec<_> =
+					<TeeGames<T>>::iter_key_prefix(&tee).take(budget as usize).collect();
+				budget -= games.len() as u32;
+				for game_hash in games {
+					Self::reassign_game(game_hash, &tee);
+					weight = weight.saturating_add(T::WeightInfo::reassign_game());
+				}
+				// games left over are reassigned in the next block
+				if budget == 0 {
+					break
+				}
+			}
+			cursor = key;
+		}
+		<TeeTimeoutCursor<T>>::put(Self::storage_cursor(cursor));
+
+		weight
+	}
+
+	/// Queue a game of an AjunaTEE gone offline again, for another AjunaTEE to pick it up.
+	/// Games not fitting into their game queue are dropped, refunding their stakes.
+	fn reassign_game(game_hash: T::Hash, tee: &T::AccountId) {
+		let mut game_entry = Self::game_registry(&game_hash);
+		let mut game_queue = Self::game_queues(&game_entry.game_engine);
+		if !Self::enqueue_game(&mut game_queue, game_hash) {
+			let game_engine = game_entry.game_engine.clone();
+			let players = if Self::is_private_game(&game_hash) {
+				Vec::new()
+			} else {
+				game_entry.players.to_vec()
+			};
+			Self::do_drop_game(game_hash, game_entry, &game_engine, DropReason::TeeFailure, None);
+
+			// Emit an event.
+			Self::deposit_event(Event::GameDropped {
+				game: game_hash,
+				engine: game_engine,
+				reason: DropReason::TeeFailure,
+				tee: tee.clone(),
+				players,
+			});
+			return
+		}
 
-	#[pallet::storage]
-	#[pallet::getter(fn game_requirements)]
-	/// Store all requirements for a sepecific game engine and it's version.
-	pub type GameRequirments<T: Config> =
-		StorageMap<_, Identity, GameEngine, Vec<GameRule<GameRuleType>>, ValueQuery>;
+		let previous_state = game_entry.game_state.clone();
+		game_entry.game_state = GameState::Waiting;
+		game_entry.timestamps.queued = <frame_system::Pallet<T>>::block_number();
+		game_entry.timestamps.accepted = 0u8.into();
 
-	// Default value for Nonce
-	#[pallet::type_value]
-	pub fn NonceDefault<T: Config>() -> u64 {
-		0
+		Self::change_game_state(
+			&game_hash,
+			&game_entry,
+			&previous_state,
+			&game_entry.game_state,
+			None,
+		);
+		game_entry.tee_id = Self::assign_tee(&game_entry.game_engine, &game_hash, Some(tee));
+		<GameRegistry<T>>::insert(&game_hash, game_entry);
+
+		// Emit an event.
+		Self::deposit_event(Event::GameReassigned(game_hash, tee.clone()));
 	}
-	// Nonce used for generating a different seed each time.
-	#[pallet::storage]
-	pub type Nonce<T: Config> = StorageValue<_, u64, ValueQuery, NonceDefault<T>>;
 
-	// The genesis config type.
-	#[pallet::genesis_config]
-	pub struct GenesisConfig<T: Config> {
-		pub founder_key: T::AccountId,
+	/// Pay the pot of a finished game to the player in the first place and move the ratings of
+	/// its players, games without a winner get their stakes refunded.
+	fn settle_result(game_hash: &T::Hash, game_entry: &GameEntryOf<T>, outcome: &GameOutcomeOf<T>) {
+		Self::update_ratings(&game_entry.players, outcome, game_entry.game_engine.id);
+		Self::reward_players(game_hash, &game_entry.game_engine, &game_entry.players, outcome);
+		match outcome.winner() {
+			Some(winner) => Self::pay_pot(game_hash, winner, game_entry.tee_id.as_ref()),
+			None => Self::refund_stakes(game_hash),
+		}
+
+		// Emit an event.
+		Self::deposit_event(Event::ResultSettled(*game_hash, outcome.clone()));
 	}
 
-	// The default value for the genesis config type.
-	#[cfg(feature = "std")]
-	impl<T: Config> Default for GenesisConfig<T> {
-		fn default() -> Self {
-			Self { founder_key: Default::default() }
+	/// Settle the finished games of the settlement queue in the order they finished, up to
+	/// `SettlementsPerBlock` games as far as the weight limit allows. Games left over are
+	/// settled in the next block, disputed results wait in the queue till they're ruled on.
+	/// Returns the weight used.
+	fn settle_queued_results(weight_limit: Weight) -> Weight {
+		let settle_weight = T::WeightInfo::settle_result();
+		// the settlement queue
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		if weight > weight_limit {
+			return 0
+		}
+
+		let mut queue = Self::settlement_queue().into_inner();
+		if queue.is_empty() {
+			return T::DbWeight::get().reads(1)
+		}
+		let mut disputed = Vec::new();
+		let mut taken = 0;
+		for game_hash in queue.iter() {
+			if taken >= T::SettlementsPerBlock::get() as usize ||
+				weight.saturating_add(settle_weight) > weight_limit
+			{
+				break
+			}
+			weight = weight.saturating_add(settle_weight);
+			taken += 1;
+
+			// results voided by the arbitration had their stakes refunded already
+			let game_entry = match Self::game_entry(game_hash) {
+				Some(game_entry) => game_entry,
+				None => continue,
+			};
+			match game_entry.game_state.clone() {
+				GameState::Finished(outcome) =>
+					Self::settle_result(game_hash, &game_entry, &outcome),
+				GameState::Suspended(_) | GameState::Reconfirming(_) => disputed.push(*game_hash),
+				_ => (),
+			}
 		}
+		queue.drain(..taken);
+		queue.extend(disputed);
+		if queue.is_empty() {
+			<SettlementQueue<T>>::kill();
+		} else {
+			// disputed results only take the places of the results taken out
+			<SettlementQueue<T>>::put(BoundedVec::try_from(queue).unwrap_or_default());
+		}
+
+		weight
 	}
 
-	// The build of genesis for the pallet.
-	#[pallet::genesis_build]
-	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
-		fn build(&self) {
-			<FounderKey<T>>::put(&self.founder_key);
+	/// Weight of finishing games, without settling them when they're settled with the
+	/// settlement queue.
+	fn finish_weight(weight: Weight, games: u32) -> Weight {
+		if T::SettlementsPerBlock::get().is_zero() {
+			return weight
 		}
+		let settle_weight = T::WeightInfo::settle_result()
+			.saturating_sub(T::DbWeight::get().reads_writes(1, 1))
+			.saturating_mul(games.into());
+		weight.saturating_sub(settle_weight)
 	}
 
-	// Pallets use events to inform users when important changes are made.
-	// https://substrate.dev/docs/en/knowledgebase/runtime/events
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// Event documentation should end with an array that provides descriptive names for event
-		/// parameters. [something, who]
-		SomethingStored(u32, T::AccountId),
+	/// Whether the results of a game engine version wait for the fraud proof window to close
+	/// before they're settled.
+	fn defers_settlement(game_engine: &GameEngine) -> bool {
+		T::DeferredSettlement::get() || Self::confirms_results(game_engine)
+	}
 
-		// Player has queued to play.
-		PlayerQueued(T::AccountId),
+	/// Whether all players of a finished game confirmed its result, settling it before the
+	/// fraud proof window closed.
+	fn result_confirmed(game_hash: &T::Hash, game_entry: &GameEntryOf<T>) -> bool {
+		Self::result_confirmations(game_hash).len() == game_entry.players.len()
+	}
 
-		/// Game queued in waiting queue
-		GameQueued(GameEngine, T::Hash),
+	/// Return the deposit of an upheld fraud proof to its submitter, slash it otherwise.
+	fn release_fraud_proof_deposit(
+		fraud_proof: &FraudProof<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		upheld: bool,
+	) {
+		if upheld {
+			T::Currency::unreserve(&fraud_proof.submitter, fraud_proof.deposit);
+		} else {
+			let (_imbalance, _remaining) =
+				T::Currency::slash_reserved(&fraud_proof.submitter, fraud_proof.deposit);
+		}
+	}
 
-		/// Amount of Games accepted by specific AjunaTEE
-		GamesAccepted(T::AccountId, u8),
+	/// Settle a disputed result that was ruled on, once its fraud proof window closed. Results
+	/// ruled on within the window are settled with the window.
+	fn settle_ruled_result(
+		game_hash: &T::Hash,
+		game_entry: &GameEntryOf<T>,
+		outcome: &GameOutcomeOf<T>,
+	) {
+		let window_end = game_entry.timestamps.finished.saturating_add(T::FraudProofWindow::get());
+		if Self::defers_settlement(&game_entry.game_engine) &&
+			<frame_system::Pallet<T>>::block_number() > window_end
+		{
+			Self::settle_result(game_hash, game_entry, outcome);
+		}
+	}
 
-		/// Game state changed to running, game is ready to play
-		GameStateReady(T::AccountId, T::Hash),
+	/// Suspend a flagged result for arbitration, on behalf of the player that flagged it.
+	fn escalate_result(
+		game_hash: T::Hash,
+		mut game_entry: GameEntryOf<T>,
+		outcome: GameOutcomeOf<T>,
+		proof: Vec<u8>,
+	) {
+		if let Some(submitter) = <ResultFlags<T>>::take(&game_hash) {
+			<FraudProofs<T>>::insert(
+				&game_hash,
+				FraudProof {
+					submitter,
+					// the outcome a re-confirmation disputes fits into a fraud proof
+					proof: BoundedVec::try_from(proof).unwrap_or_default(),
+					submitted: <frame_system::Pallet<T>>::block_number(),
+					deposit: Zero::zero(),
+				},
+			);
+		}
+		let previous_state = game_entry.game_state.clone();
+		game_entry.game_state = GameState::Suspended(outcome);
+		Self::change_game_state(
+			&game_hash,
+			&game_entry,
+			&previous_state,
+			&game_entry.game_state,
+			None,
+		);
+		Self::archive_game(&game_hash, &game_entry);
 
-		/// Game state changed to finished, with game winner
-		GameStateFinished(T::Hash, T::AccountId),
+		// Emit an event.
+		Self::deposit_event(Event::ResultEscalated(game_hash));
 	}
 
-	// Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Error names should be descriptive.
-		NoneValue,
-		/// Errors should have helpful documentation associated with them.
-		StorageOverflow,
-		/// To many games trying to acknowledge at once.
-		AckToMany,
-		/// During Acknowledge of a waiting games there was an error.
-		AckFail,
-		/// There is no game queue for the game engine version.
-		NoGameQueue,
-		/// There is no such game entry
-		NoGameEntry,
-		/// Player is already queued for a match.
-		AlreadyQueued,
+	/// Ensure an administrative origin, returns the signer to keep in the audit log.
+	fn ensure_admin<O: frame_support::traits::EnsureOrigin<T::Origin>>(
+		origin: T::Origin,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		let actor = frame_system::ensure_signed(origin.clone()).ok();
+		O::ensure_origin(origin)?;
+		Ok(actor)
 	}
 
-	// Pallet implements [`Hooks`] trait to define some logic to execute in some context.
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		// `on_initialize` is executed at the beginning of the block before any extrinsic are
-		// dispatched.
-		//
-		// This function must return the weight consumed by `on_initialize` and `on_finalize`.
-		fn on_initialize(_: T::BlockNumber) -> Weight {
-			// Anything that needs to be done at the start of the block.
-			// We don't do anything here.
+	/// Keep an administrative action in the audit log, overwriting the oldest entry when full.
+	fn log_admin_action<P: Encode>(actor: Option<T::AccountId>, action: AdminAction, payload: &P) {
+		let count = Self::audit_log_count();
+		let slot = count % T::MaxAuditEntries::get().max(1);
+		<AuditLog<T>>::insert(
+			slot,
+			AuditEntry {
+				action,
+				actor,
+				block: <frame_system::Pallet<T>>::block_number(),
+				payload_hash: T::Hashing::hash_of(payload),
+			},
+		);
+		<AuditLogCount<T>>::put(count.wrapping_add(1));
+	}
 
-			// initial weights
-			let mut tot_weights = 10_000;
-			for _i in 0..MAX_GAMES_PER_BLOCK {
-				// try to create a match till we reached max games or no more matches available
-				let result = T::MatchMaker::try_match();
-				// if result is not empty we have a valid match
-				if !result.is_empty() {
-					let game_engine = GameEngine { id: 1u8, version: 1u8 };
-					// Create new game
-					let _game_id = Self::queue_game(game_engine, result);
-					// weights need to be adjusted
-					tot_weights = tot_weights + T::DbWeight::get().reads_writes(1, 1);
-					continue
-				}
-				break
+	/// Add the result of a finished game to the match history of a player, pruning the oldest
+	/// result of a full history.
+	fn record_match(
+		player: &T::AccountId,
+		game_record: &GameRecord<T::Hash, T::AccountId, T::BlockNumber, T::MaxPlayersPerGame>,
+	) {
+		let opponents: Vec<_> =
+			game_record.players.iter().filter(|other| *other != player).cloned().collect();
+		let match_result = MatchResult {
+			game_hash: game_record.game_hash,
+			game_engine: game_record.game_engine.clone(),
+			// the opponents are fewer than the players of the game
+			opponents: BoundedVec::try_from(opponents).unwrap_or_default(),
+			outcome: game_record.outcome.clone(),
+			block: game_record.block,
+		};
+		<MatchHistory<T>>::mutate(player, |history| {
+			if history.len() as u32 >= T::MaxHistory::get() && !history.is_empty() {
+				history.remove(0);
 			}
+			// a history without room keeps no results
+			let _ = history.try_push(match_result);
+		});
+	}
 
-			// return standard weigth for trying to fiond a match
-			return tot_weights
-		}
+	/// Whether a sanction of a player is active, it ends in the block it lasts until.
+	pub fn is_sanctioned(player: &T::AccountId, kind: SanctionKind) -> bool {
+		Self::sanctions(player, kind)
+			.map_or(false, |until| <frame_system::Pallet<T>>::block_number() < until)
+	}
 
-		// `on_finalize` is executed at the end of block after all extrinsic are dispatched.
-		fn on_finalize(_n: BlockNumberFor<T>) {
-			// Perform necessary data/state clean up here.
+	/// Verify the quote of an AjunaTEE, without a quote it must be attested by the runtime
+	/// already. Returns the measurement of its enclave.
+	fn verify_attestation(
+		tee: &T::AccountId,
+		quote: Option<Vec<u8>>,
+	) -> Result<[u8; 32], DispatchError> {
+		match quote {
+			Some(quote) => T::TeeAttestation::verify_quote(tee, &quote),
+			None => T::TeeAttestation::enclave_of(tee)
+				.or_else(|| Self::dev_mode().then(|| [0; 32]))
+				.ok_or_else(|| Error::<T>::TeeNotAttested.into()),
 		}
+	}
 
-		// A runtime code run after every block and have access to extended set of APIs.
-		//
-		// For instance you can generate extrinsics for the upcoming produced block.
-		fn offchain_worker(_n: T::BlockNumber) {
-			// We don't do anything here.
-			// but we could dispatch extrinsic (transaction/unsigned/inherent) using
-			// sp_io::submit_extrinsic.
-			// To see example on offchain worker, please refer to example-offchain-worker pallet
-			// accompanied in this repository.
-		}
+	/// Ensure the sender is a registered AjunaTEE, ordinary accounts stand in for AjunaTEEs in
+	/// dev mode.
+	fn ensure_tee(who: &T::AccountId) -> DispatchResult {
+		ensure!(
+			Self::dev_mode() || <RegisteredTees<T>>::contains_key(who),
+			Error::<T>::NotRegisteredTee
+		);
+		ensure!(!<SuspendedTees<T>>::contains_key(who), Error::<T>::TeeSuspended);
+		Ok(())
 	}
 
-	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-	// These functions materialize as "extrinsics", which are often compared to transactions.
-	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			// This function will return an error if the extrinsic is not signed.
-			// https://substrate.dev/docs/en/knowledgebase/runtime/origin
-			let who = ensure_signed(origin)?;
+	/// Count a lifecycle call of an AjunaTEE in this block, failing calls are rolled back.
+	/// Suspended AjunaTEEs make no lifecycle calls.
+	fn note_tee_call(tee: &T::AccountId) -> DispatchResult {
+		ensure!(!<SuspendedTees<T>>::contains_key(tee), Error::<T>::TeeSuspended);
+		<TeeCallCount<T>>::try_mutate(tee, |calls| {
+			ensure!(*calls < Self::parameters().tee_calls_per_block, Error::<T>::TeeRateLimited);
+			*calls += 1;
+			Ok(())
+		})
+	}
 
-			// Print out log or debug message in the console via log::{error, warn, info, debug, trace},
-			// accepting format strings similar to `println!`.
-			// https://substrate.dev/rustdocs/v3.0.0/log/index.html
-			info!("New value is now: {:?}", something);
+	/// Throttle the AjunaTEEs that used up their lifecycle calls in this block and reset the
+	/// calls for the next block. AjunaTEEs throttled `MaxTeeThrottles` blocks in a row are
+	/// suspended till governance reviewed them, a block within the limit clears the throttles.
+	fn throttle_tees() {
+		let limit = Self::parameters().tee_calls_per_block;
+		let throttled: Vec<T::AccountId> = <TeeCallCount<T>>::drain()
+			.filter(|(_, calls)| *calls >= limit)
+			.map(|(tee, _)| tee)
+			.collect();
+		<TeeThrottles<T>>::translate::<u32, _>(|tee, throttles| {
+			Some(throttles).filter(|_| throttled.contains(&tee))
+		});
 
-			// Update storage.
-			<Something<T>>::put(something);
+		for tee in throttled {
+			let throttles = <TeeThrottles<T>>::mutate(&tee, |throttles| {
+				*throttles = throttles.saturating_add(1);
+				*throttles
+			});
 
 			// Emit an event.
-			Self::deposit_event(Event::SomethingStored(something, who));
-			// Return a successful DispatchResultWithPostInfo
-			Ok(())
-		}
+			Self::deposit_event(Event::TeeThrottled(tee.clone(), throttles));
 
-		/// An example dispatchable that may throw a custom error.
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
+			if throttles >= T::MaxTeeThrottles::get() {
+				<TeeThrottles<T>>::remove(&tee);
+				<SuspendedTees<T>>::insert(&tee, <frame_system::Pallet<T>>::block_number());
 
-			// Read a value from storage.
-			match <Something<T>>::get() {
-				// Return an error if the value has not been set.
-				None => Err(Error::<T>::NoneValue)?,
-				Some(old) => {
-					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-					// Update the value in storage with the incremented result.
-					<Something<T>>::put(new);
-					Ok(())
-				},
+				// Emit an event.
+				Self::deposit_event(Event::TeeSuspended(tee));
 			}
 		}
+	}
 
-		/// Queue sender up for a game, ranking brackets
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn queue(origin: OriginFor<T>) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
+	/// Account a committed player takes part in matchmaking with, till it's revealed.
+	pub fn committed_player(commitment: &T::Hash) -> T::AccountId {
+		T::AccountId::decode(&mut TrailingZeroInput::new(commitment.as_ref()))
+			.expect("infinite length input; qed")
+	}
 
-			// #TODO[MUST_HAVE, ALLREADY_REGISTRED] check if player is already in the game registry for a game.
+	/// Ongoing games a player can be in for a game engine.
+	pub fn max_games_per_player(game_engine: &GameEngine) -> u32 {
+		Self::engine_max_games_per_player(game_engine).unwrap_or_else(T::MaxGamesPerPlayer::get)
+	}
 
-			let bracket: u8 = 0;
-			// Add player to queue, duplicate check is done in matchmaker.
-			if !T::MatchMaker::add_queue(sender.clone(), bracket) {
-				return Err(Error::<T>::AlreadyQueued)?
-			}
+	/// Ensure a player can join another game of a game engine.
+	fn ensure_game_slot(player: &T::AccountId, game_engine: &GameEngine) -> DispatchResult {
+		let max_games = Self::max_games_per_player(game_engine);
+		// players in a game only queue in parallel for game engines allowing it
+		ensure!(max_games > 1 || Self::current_games(player).is_empty(), Error::<T>::AlreadyInGame);
+		ensure!(Self::player_games(player, game_engine.id) < max_games, Error::<T>::TooManyGames);
+		ensure!(
+			(Self::current_games(player).len() as u32) < T::MaxCurrentGames::get(),
+			Error::<T>::TooManyGames
+		);
+		Ok(())
+	}
 
-			// Emit an event.
-			Self::deposit_event(Event::PlayerQueued(sender));
+	/// Count a game in or out of the ongoing games of its players.
+	fn count_player_games(players: &[T::AccountId], engine_id: u8, started: bool) {
+		for player in players {
+			<PlayerGames<T>>::mutate_exists(player, engine_id, |games| {
+				let count = games.unwrap_or_default();
+				*games = if started {
+					Some(count.saturating_add(1))
+				} else {
+					count.checked_sub(1).filter(|count| *count > 0)
+				};
+			});
+		}
+	}
 
-			Ok(())
+	/// Note a game as current game of its players, or remove it once it's over.
+	fn note_current_game(players: &[T::AccountId], game_hash: &T::Hash, ongoing: bool) {
+		for player in players {
+			<CurrentGames<T>>::mutate_exists(player, |maybe_games| {
+				let mut games = maybe_games.take().unwrap_or_default();
+				games.retain(|game| game != game_hash);
+				if ongoing {
+					// players at `MaxCurrentGames` are turned away by `ensure_game_slot`
+					let _ = games.try_push(*game_hash);
+				}
+				*maybe_games = Some(games).filter(|games| !games.is_empty());
+			});
 		}
+	}
 
-		/// Drop game will remove the game from the queue and the registry.
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn drop_game(
-			origin: OriginFor<T>,
-			game_hash: T::Hash,
-			game_engine: GameEngine,
-		) -> DispatchResult {
-			// #TODO[MUST_HAVE, SIGNATURE_CHECK] check that it's signed by a registred AjunaTEE.
-			let _who = ensure_signed(origin)?;
+	/// Ensure an entry point of the registry isn't disabled.
+	fn ensure_enabled(call: RegistryCall) -> DispatchResult {
+		ensure!(!Self::call_disabled(call), Error::<T>::CallDisabled);
+		Ok(())
+	}
 
-			// retrieve game entry
-			if GameRegistry::<T>::contains_key(&game_hash) {
-				let _game_entry = GameRegistry::<T>::remove(&game_hash);
+	/// Queue a player up for a game, in the bracket of the game engine version and time control.
+	fn do_queue(
+		sender: T::AccountId,
+		payer: &T::AccountId,
+		engine_id: u8,
+		version: Option<u8>,
+		time_control: Option<u8>,
+	) -> DispatchResult {
+		ensure!(!Self::paused(), Error::<T>::RegistryPaused);
+		Self::ensure_enabled(RegistryCall::Queue)?;
+		ensure!(!Self::is_banned(&sender), Error::<T>::AccountBanned);
+		ensure!(
+			!Self::is_sanctioned(&sender, SanctionKind::QueueBan),
+			Error::<T>::PlayerSanctioned
+		);
+		let settings = Self::settings();
+		ensure!(settings.registrations_open, Error::<T>::RegistrationsClosed);
+		ensure!(
+			!settings.allowlist_only || Self::is_allowlisted(&sender),
+			Error::<T>::NotAllowlisted
+		);
 
-				let mut game_queue = Self::game_queues(&game_engine);
+		let version = match version {
+			Some(version) => version,
+			None =>
+				Self::latest_engine_version(engine_id).ok_or(Error::<T>::EngineNotRegistered)?,
+		};
+		let game_engine = GameEngine { id: engine_id, version };
+		let engine_info =
+			Self::supported_engines(&game_engine).ok_or(Error::<T>::EngineNotRegistered)?;
+		ensure!(engine_info.successor.is_none(), Error::<T>::EngineDrained);
+		ensure!(!Self::is_deprecated(&game_engine), Error::<T>::EngineDeprecated);
+		ensure!(!engine_info.maintenance, Error::<T>::EngineInMaintenance);
+		ensure!(!Self::paused_engines(&game_engine), Error::<T>::EngineDisabled);
+		ensure!(
+			Self::engine_tee_count(&game_engine) >= Self::min_tees(),
+			Error::<T>::EngineUnavailable
+		);
+		Self::decay_rating(&sender, engine_id);
+		Self::check_game_rules(&game_engine, &sender)?;
+		Self::ensure_game_slot(&sender, &game_engine)?;
+		Self::shared_client_version(&game_engine, &[sender.clone()])?;
 
-				// check if there is any elements queued
-				if game_queue.length() > 0 {
-					// remove element
-					game_queue.remove(game_hash);
-					// insert into waiting queue for Ajuna TEE
-					<GameQueues<T>>::insert(game_engine, game_queue);
-				}
-			}
+		// every time control preset is matched in its own bracket, per skill tier
+		let first_bracket = match time_control {
+			Some(index) =>
+				Self::time_controls(&game_engine)
+					.get(index as usize)
+					.ok_or(Error::<T>::NoTimeControl)?
+					.bracket,
+			None => engine_info.bracket,
+		};
+		let bracket =
+			first_bracket.saturating_add(Self::rating_tier(Self::rating(&sender, engine_id)));
 
-			// #TODO[MUST_HAVE, VEC_REMOVE] remove a game from the queue.
+		// an outstanding bond means the player is queued or didn't accept the last match yet
+		ensure!(!QueueBonds::<T>::contains_key(&sender), Error::<T>::AlreadyQueued);
+		// players that left are still in the matchmaker, they can only rejoin their bracket
+		let left_bracket = Self::left_players(&sender);
+		ensure!(left_bracket.map_or(true, |left| left == bracket), Error::<T>::AlreadyQueued);
+		let stake = Self::engine_stake(&game_engine).filter(|stake| !stake.is_zero());
+		ensure!(stake.is_none() || *payer == sender, Error::<T>::StakeRequiresAccount);
+		let bond = T::QueueBond::get();
+		if let Some(stake) = stake {
+			ensure!(
+				T::Currency::can_reserve(&sender, bond.saturating_add(stake)),
+				Error::<T>::InsufficientStake
+			);
+		}
+		T::Currency::reserve(payer, bond).map_err(|_| Error::<T>::InsufficientBalance)?;
+		if let Some(stake) = stake {
+			T::Currency::reserve(&sender, stake).map_err(|_| Error::<T>::InsufficientStake)?;
+		}
 
-			Ok(())
+		// Add player to queue, duplicate check is done in matchmaker.
+		if left_bracket.is_none() && !T::MatchMaker::add_queue(sender.clone(), bracket) {
+			T::Currency::unreserve(payer, bond);
+			if let Some(stake) = stake {
+				T::Currency::unreserve(&sender, stake);
+			}
+			return Err(Error::<T>::AlreadyQueued)?
+		}
+		<LeftPlayers<T>>::remove(&sender);
+		<QueuedPlayers<T>>::insert(&sender, QueueInfo { game_engine, time_control, bracket });
+		<QueuedSince<T>>::insert(&sender, <frame_system::Pallet<T>>::block_number());
+		<QueueBonds<T>>::insert(&sender, bond);
+		if *payer != sender {
+			<BondPayers<T>>::insert(&sender, payer);
+		}
+		Self::deposit_event(Event::QueueBondReserved(sender.clone(), bond));
+		if let Some(stake) = stake {
+			<QueueStakes<T>>::insert(&sender, stake);
+			Self::deposit_event(Event::StakeReserved(sender.clone(), stake));
 		}
 
-		/// Acknowledge game will remove from queue and set state to accepted.
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn ack_game(
-			origin: OriginFor<T>,
-			cluster: GameEngine,
-			games: Vec<T::Hash>,
-		) -> DispatchResult {
-			// #TODO[MUST_HAVE, SIGNATURE_CHECK] check that it's signed by a registred AjunaTEE.
-			let who = ensure_signed(origin)?;
+		// Emit an event.
+		Self::deposit_event(Event::PlayerQueued(sender));
+
+		Ok(())
+	}
 
-			// only up to 100 games allowed to acknowledge in one batch.
-			if games.len() > 100 {
-				return Err(Error::<T>::AckToMany)?
+	/// Queue the players of a finished game again, if their preferences ask for it.
+	fn requeue_players(game_entry: &GameEntryOf<T>) {
+		for player in game_entry.players.iter() {
+			let preferences = match Self::player_preferences(player) {
+				Some(preferences) if preferences.auto_requeue => preferences,
+				_ => continue,
+			};
+			let engine_id = preferences.engine_id.unwrap_or(game_entry.game_engine.id);
+			let result = with_transaction(|| {
+				match Self::do_queue(
+					player.clone(),
+					player,
+					engine_id,
+					None,
+					preferences.time_control,
+				) {
+					Ok(()) => TransactionOutcome::Commit(Ok(())),
+					Err(e) => TransactionOutcome::Rollback(Err(e)),
+				}
+			});
+			if let Err(e) = result {
+				Self::deposit_event(Event::AutoRequeueFailed(player.clone(), e));
 			}
+		}
+	}
 
-			// #TODO[OPTIMIZATION, STORAGE] optimize storage to use a ringbuffer instead of the vector to avoid to big elements beeing read and written down to the queue.
+	/// Release the queue bond held for a player.
+	///
+	/// The stake of a player that isn't matched yet is released with the bond.
+	fn release_queue_bond(player: &T::AccountId) {
+		if let Some(bond) = <QueueBonds<T>>::take(player) {
+			let payer = <BondPayers<T>>::take(player).unwrap_or_else(|| player.clone());
+			T::Currency::unreserve(&payer, bond);
+			Self::deposit_event(Event::QueueBondReleased(player.clone(), bond));
+		}
+		if let Some(stake) = <QueueStakes<T>>::take(player) {
+			T::Currency::unreserve(player, stake);
+			Self::deposit_event(Event::StakeReleased(player.clone(), stake));
+		}
+	}
 
-			// retrieve game queue for asked cluster
-			ensure!(GameQueues::<T>::contains_key(&cluster), Error::<T>::NoGameQueue);
-			let mut game_queue = Self::game_queues(&cluster);
+	/// Hold the stakes of matched players for the pot of their game, counted into its wager.
+	fn hold_stakes(game_hash: &T::Hash, players: &[T::AccountId]) {
+		// the players of a game fit into `MaxPlayersPerGame`
+		let stakes: Vec<_> = players
+			.iter()
+			.take(T::MaxPlayersPerGame::get() as usize)
+			.filter_map(|player| {
+				<QueueStakes<T>>::take(player).map(|stake| (player.clone(), stake))
+			})
+			.collect();
+		if stakes.is_empty() {
+			return
+		}
+		let stakes = BoundedVec::<_, T::MaxPlayersPerGame>::try_from(stakes).unwrap_or_default();
+		let pot = stakes
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |pot, (_, stake)| pot.saturating_add(*stake));
+		<GameWagers<T>>::mutate(game_hash, |wager| *wager = wager.saturating_add(pot));
+		<GameStakes<T>>::insert(game_hash, stakes);
+		Self::prioritize_game(game_hash);
+	}
 
-			let mut games_count = 0;
-			for game_hash_tee in games.iter() {
-				let game_hash = game_queue.peek();
+	/// Pay the stakes of a game to its winner, less the reward of the AjunaTEE running it and the
+	/// protocol fee.
+	fn pay_pot(game_hash: &T::Hash, winner: &T::AccountId, tee: Option<&T::AccountId>) {
+		let stakes = <GameStakes<T>>::take(game_hash);
+		if stakes.is_empty() {
+			return
+		}
+		let mut pot = BalanceOf::<T>::zero();
+		for (player, stake) in stakes {
+			if player == *winner {
+				T::Currency::unreserve(&player, stake);
+			} else {
+				// stakes already released by other means can't be moved anymore
+				let _ =
+					T::Currency::repatriate_reserved(&player, winner, stake, BalanceStatus::Free);
+			}
+			pot = pot.saturating_add(stake);
+		}
 
-				// check if peeked game matches acknowledge
-				if game_hash == Some(game_hash_tee) {
-					// dequeue game hash from waiting queue cluster
-					let _ = game_queue.dequeue();
+		// the reward and the fee are taken out of the pot paid to the winner
+		let mut paid = pot;
+		let reward = T::TeeRewardPercent::get() * pot;
+		if let Some(tee) = tee.filter(|_| !reward.is_zero()) {
+			if T::Currency::transfer(winner, tee, reward, ExistenceRequirement::AllowDeath).is_ok()
+			{
+				paid = paid.saturating_sub(reward);
+				Self::deposit_event(Event::TeeRewarded(*game_hash, tee.clone(), reward));
+			}
+		}
+		let fee = T::ProtocolFeePercent::get() * pot;
+		if !fee.is_zero() {
+			if let Ok(imbalance) = T::Currency::withdraw(
+				winner,
+				fee,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::AllowDeath,
+			) {
+				T::ProtocolFee::on_unbalanced(imbalance);
+				paid = paid.saturating_sub(fee);
+				Self::deposit_event(Event::ProtocolFeePaid(*game_hash, fee));
+			}
+		}
+		Self::deposit_event(Event::PotPaid(*game_hash, winner.clone(), paid));
+	}
 
-					// insert changed queue back
-					<GameQueues<T>>::insert(cluster.clone(), game_queue.clone());
+	/// Refund the stakes of a game without a result to its players.
+	fn refund_stakes(game_hash: &T::Hash) {
+		let stakes = <GameStakes<T>>::take(game_hash);
+		if stakes.is_empty() {
+			return
+		}
+		for (player, stake) in stakes {
+			T::Currency::unreserve(&player, stake);
+		}
+		Self::deposit_event(Event::StakesRefunded(*game_hash));
+	}
 
-					// retrieve game entry to change state
-					let mut game_entry = Self::game_registry(game_hash_tee.clone());
+	/// Slash the queue bond held for a player, the part not slashed is given back.
+	fn slash_queue_bond(player: &T::AccountId) {
+		if let Some(bond) = <QueueBonds<T>>::take(player) {
+			let payer = <BondPayers<T>>::take(player).unwrap_or_else(|| player.clone());
+			let slash = Self::parameters().abandonment_slash * bond;
+			let (imbalance, _remaining) = T::Currency::slash_reserved(&payer, slash);
+			T::BondSlash::on_unbalanced(imbalance);
+			T::Currency::unreserve(&payer, bond.saturating_sub(slash));
+			Self::deposit_event(Event::QueueBondSlashed(player.clone(), slash));
+		}
+	}
 
-					game_entry.state_change[1] = <frame_system::Pallet<T>>::block_number();
-					game_entry.game_state = GameState::Accepted;
+	/// Registered AjunaTEE running a game engine version with the least games, among the ones
+	/// with capacity left. Ties go to the first AjunaTEE in storage order.
+	pub fn least_loaded_tee(game_engine: &GameEngine) -> Option<T::AccountId> {
+		<RegisteredTees<T>>::iter()
+			.filter(|(_, tee_info)| tee_info.supported_engines.contains(game_engine))
+			.map(|(tee, _)| {
+				let load = Self::tee_loads(&tee).total();
+				(tee, load)
+			})
+			.filter(|(_, load)| *load < T::MaxGamesPerTee::get())
+			.min_by_key(|(_, load)| *load)
+			.map(|(tee, _)| tee)
+	}
 
-					// insert changed game entry back
-					<GameRegistry<T>>::insert(game_hash_tee, game_entry);
+	/// AjunaTEE a queued game is assigned to, by the assignment of the runtime. Only AjunaTEEs of
+	/// the game engine version with capacity left are assigned games, besides the one leaving the
+	/// game. Games without an assigned AjunaTEE are acknowledged by any AjunaTEE.
+	fn assign_tee(
+		game_engine: &GameEngine,
+		game_hash: &T::Hash,
+		leaving: Option<&T::AccountId>,
+	) -> Option<T::AccountId> {
+		let assignment = T::TeeAssignment::get();
+		if assignment == TeeAssignment::FirstCome {
+			return None
+		}
+		let tees: Vec<T::AccountId> = <RegisteredTees<T>>::iter()
+			.filter(|(tee, tee_info)| {
+				tee_info.supported_engines.contains(game_engine) &&
+					Some(tee) != leaving &&
+					!<SuspendedTees<T>>::contains_key(tee) &&
+					Self::tee_loads(tee).total() < T::MaxGamesPerTee::get()
+			})
+			.map(|(tee, _)| tee)
+			.collect();
+		if tees.is_empty() {
+			return None
+		}
+		let turn = match assignment {
+			TeeAssignment::RoundRobin => <AssignmentTurns<T>>::mutate(game_engine, |turn| {
+				let current = *turn;
+				*turn = turn.wrapping_add(1);
+				current
+			}),
+			_ => {
+				let (random, _) = T::Randomness::random(&[&GAMEREGISTRY_ID[..], b"tee"].concat());
+				let subject = (random, game_hash).using_encoded(T::Hashing::hash);
+				u32::decode(&mut TrailingZeroInput::new(subject.as_ref())).unwrap_or_default()
+			},
+		};
+		let tee = tees[turn as usize % tees.len()].clone();
 
-					// Increase counter
-					games_count += 1;
-				} else {
-					return Err(Error::<T>::AckFail)?
-				}
+		// Emit an event.
+		Self::deposit_event(Event::GameAssigned(*game_hash, tee.clone()));
+		Some(tee)
+	}
+
+	/// Minimum and maximum amount of players of a game of a game engine, if limited by its rules.
+	pub fn players_per_game(game_engine: &GameEngine) -> Option<(u8, u8)> {
+		Self::game_requirements(game_engine).iter().find_map(|game_rule| {
+			match game_rule.game_rule_type {
+				GameRuleType::PlayersPerGame([min, max]) => Some((min, max)),
+				_ => None,
 			}
+		})
+	}
 
-			// Emit an event.
-			Self::deposit_event(Event::GamesAccepted(who, games_count));
+	/// Add matched players to the party forming in their bracket, returns the party once it has
+	/// enough players for a game. Players of a forming party stay queued, so they can still leave.
+	fn form_party(
+		queue_info: &QueueInfo,
+		players: Vec<T::AccountId>,
+		party_size: usize,
+	) -> Option<Vec<T::AccountId>> {
+		let mut party: Vec<T::AccountId> = <FormingParties<T>>::take(queue_info.bracket)
+			.into_iter()
+			.filter(|player| {
+				// players that left while the party was forming are skipped
+				<LeftPlayers<T>>::remove(player);
+				<QueuedPlayers<T>>::take(player).is_some()
+			})
+			.collect();
+		party.extend(players);
 
-			// Return a successful DispatchResultWithPostInfo
-			Ok(())
+		let rest = party.split_off(party_size.min(party.len()));
+		let waiting = if party.len() < party_size { &party } else { &rest };
+		for player in waiting.iter() {
+			<QueuedPlayers<T>>::insert(player, queue_info.clone());
+		}
+		if !waiting.is_empty() {
+			// fewer players than a game has wait, the matchmaker matching less at once
+			<FormingParties<T>>::insert(
+				queue_info.bracket,
+				BoundedVec::try_from(waiting.clone()).unwrap_or_default(),
+			);
 		}
 
-		/// Drop game will remove the game from the queue and the registry.
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn ready_game(origin: OriginFor<T>, game_hash: T::Hash) -> DispatchResult {
-			// #TODO[MUST_HAVE, SIGNATURE_CHECK] check that it's signed by a registred AjunaTEE.
-			let who = ensure_signed(origin)?;
-
-			// retrieve game entry
-			ensure!(GameRegistry::<T>::contains_key(&game_hash), Error::<T>::NoGameEntry);
-			let mut game_entry = Self::game_registry(&game_hash);
-
-			game_entry.tee_id = Some(who.clone());
-			game_entry.state_change[2] = <frame_system::Pallet<T>>::block_number();
-			game_entry.game_state = GameState::Running;
+		(party.len() == party_size).then(|| party)
+	}
 
-			// insert changed game entry back
-			<GameRegistry<T>>::insert(game_hash, game_entry.clone());
+	/// Highest game client version a game engine version and the clients of the players all
+	/// support, `None` if neither of them restricts the versions.
+	fn shared_client_version(
+		game_engine: &GameEngine,
+		players: &[T::AccountId],
+	) -> Result<Option<u16>, DispatchError> {
+		let mut shared = Self::engine_client_versions(game_engine);
+		for client in players.iter().filter_map(Self::player_client) {
+			shared = match shared {
+				Some(versions) =>
+					Some(versions.overlap(&client).ok_or(Error::<T>::ClientNotSupported)?),
+				None => Some(client),
+			};
+		}
+		Ok(shared.map(|versions| versions.max))
+	}
 
-			// Emit an event.
-			Self::deposit_event(Event::GameStateReady(who, game_hash));
+	/// Check a player may play a game of a game engine outside of matchmaking.
+	fn ensure_can_play(player: &T::AccountId, game_engine: &GameEngine) -> DispatchResult {
+		ensure!(!Self::is_banned(player), Error::<T>::AccountBanned);
+		ensure!(!Self::is_sanctioned(player, SanctionKind::QueueBan), Error::<T>::PlayerSanctioned);
+		Self::check_game_rules(game_engine, player)?;
+		Self::ensure_game_slot(player, game_engine)
+	}
 
-			Ok(())
+	/// Check the game rules of a game engine, a player has to meet before queueing.
+	fn check_game_rules(game_engine: &GameEngine, player: &T::AccountId) -> DispatchResult {
+		let current_block = <frame_system::Pallet<T>>::block_number();
+		for game_rule in Self::game_requirements(game_engine).iter() {
+			let rule_met = match game_rule.game_rule_type {
+				GameRuleType::MinAccountAge(blocks) => Self::player_first_seen(player)
+					.map_or(false, |first_seen| current_block - first_seen >= blocks.into()),
+				GameRuleType::MinFinishedGames(games) => Self::finished_games(player) >= games,
+				GameRuleType::MinBalance(balance) =>
+					T::Currency::free_balance(player).unique_saturated_into() >= balance,
+				GameRuleType::MinRating(rating) => Self::rating(player, game_engine.id) >= rating,
+				GameRuleType::RequiredAssetOwnership(asset_id) =>
+					T::AssetOwnership::owns_asset(player, asset_id),
+				_ => true,
+			};
+			ensure!(rule_met, Error::<T>::RequirementNotMet);
 		}
+		Ok(())
+	}
 
-		/// Drop game will remove the game from the queue and the registry.
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn finish_game(
-			origin: OriginFor<T>,
-			game_hash: T::Hash,
-			winner: T::AccountId,
-		) -> DispatchResult {
-			// #TODO[MUST_HAVE, SIGNATURE_CHECK] check that it's signed by a registred AjunaTEE.
-			let who = ensure_signed(origin)?;
+	/// Check a game rule is sound, games for its players can be formed.
+	fn ensure_valid_rule(game_rule: &GameRule<GameRuleType>) -> DispatchResult {
+		if let GameRuleType::PlayersPerGame([min, max]) = game_rule.game_rule_type {
+			ensure!(
+				min > 0 && min <= max && max as u32 <= T::MaxPlayersPerGame::get(),
+				Error::<T>::InvalidGameRules
+			);
+		}
+		Ok(())
+	}
 
-			// retrieve game entry
-			ensure!(GameRegistry::<T>::contains_key(&game_hash), Error::<T>::NoGameEntry);
-			let mut game_entry = Self::game_registry(&game_hash);
+	/// Remove duplicated game engines from a list of supported game engines.
+	fn dedup_engines(game_engines: Vec<GameEngine>) -> Result<EnginesOf, DispatchError> {
+		let mut unique_engines = Vec::new();
+		for game_engine in game_engines {
+			if !unique_engines.contains(&game_engine) {
+				unique_engines.push(game_engine);
+			}
+		}
+		EnginesOf::try_from(unique_engines).map_err(|_| Error::<T>::TooManyEngines.into())
+	}
 
-			game_entry.state_change[3] = <frame_system::Pallet<T>>::block_number();
-			game_entry.game_state = GameState::Finished(winner.clone());
+	/// Set the game engine versions a registered AjunaTEE runs, keeping the AjunaTEE count of
+	/// each game engine.
+	fn set_tee_engines(tee: &T::AccountId, engines: &EnginesOf) {
+		<RegisteredTees<T>>::mutate(tee, |maybe_info| {
+			if let Some(tee_info) = maybe_info {
+				Self::update_engine_tee_count(&tee_info.supported_engines, engines);
+				tee_info.supported_engines = engines.clone();
+			}
+		});
+	}
 
-			// insert changed game entry back
-			<GameRegistry<T>>::insert(game_hash, game_entry.clone());
+	/// Update the AjunaTEE count of each game engine, when a TEE changes its supported engines.
+	fn update_engine_tee_count(old_engines: &[GameEngine], new_engines: &[GameEngine]) {
+		for game_engine in old_engines.iter().filter(|e| !new_engines.contains(e)) {
+			<EngineTeeCount<T>>::mutate(game_engine, |count| *count = count.saturating_sub(1));
+		}
+		for game_engine in new_engines.iter().filter(|e| !old_engines.contains(e)) {
+			<EngineTeeCount<T>>::mutate(game_engine, |count| *count = count.saturating_add(1));
+		}
+	}
 
-			// Emit an event.
-			Self::deposit_event(Event::GameStateFinished(game_hash, winner));
+	/// Short match code of the game with the sequential index, e.g. `AJN-4F7K`.
+	pub fn encode_match_code(mut index: u64) -> Vec<u8> {
+		let mut digits = Vec::new();
+		while index > 0 || digits.len() < MATCH_CODE_MIN_LENGTH {
+			digits.push(MATCH_CODE_ALPHABET[(index % 32) as usize]);
+			index /= 32;
+		}
+		digits.reverse();
+		[MATCH_CODE_PREFIX, &digits[..]].concat()
+	}
 
-			Ok(())
+	/// Forget the match code of a game that was voided.
+	fn remove_match_code(game_hash: &T::Hash) {
+		if let Some(match_code) = <MatchCodes<T>>::take(game_hash) {
+			<MatchCodeGames<T>>::remove(match_code);
 		}
 	}
-}
 
-impl<T: Config> Pallet<T> {
 	/// Update nonce once used.
-	fn encode_and_update_nonce() -> Vec<u8> {
+	fn next_nonce() -> u64 {
 		let nonce = <Nonce<T>>::get();
 		<Nonce<T>>::put(nonce.wrapping_add(1));
-		nonce.encode()
+		nonce
 	}
 
-	/// Generates a random hash out of a seed.
-	fn generate_random_hash(phrase: &[u8], sender: T::AccountId) -> T::Hash {
-		let (seed, _) = T::Randomness::random(phrase);
-		let seed = <[u8; 32]>::decode(&mut TrailingZeroInput::new(seed.as_ref()))
-			.expect("input is padded with zeroes; qed");
-		return (seed, &sender, Self::encode_and_update_nonce()).using_encoded(T::Hashing::hash)
+	/// Generates the random seed of a game, distinct from its id.
+	fn generate_game_seed(game_hash: &T::Hash) -> T::Hash {
+		let (random, _) = T::Randomness::random(&[&GAMEREGISTRY_ID[..], b"seed"].concat());
+		(random, game_hash).using_encoded(T::Hashing::hash)
 	}
 
 	/// Generate a new game between two players.
-	fn queue_game(game_engine: GameEngine, players: Vec<T::AccountId>) -> DispatchResult {
-		// check if requirements for this game are meet, for all the players.
-		let game_rules = Self::game_requirements(&game_engine);
-		for _game_rule in game_rules.iter() {
-			// #TODO[MUST_HAVE, REQUIRMENTS_CHECK] check if game engine requirments are meet for the players.
+	fn queue_game(
+		game_engine: GameEngine,
+		players: Vec<T::AccountId>,
+		time_control: Option<TimeControl>,
+		private: bool,
+		init_data: Option<InitDataOf<T>>,
+	) -> Result<T::Hash, DispatchError> {
+		Self::new_game(game_engine, players, time_control, private, init_data, None)
+	}
+
+	/// Create a game, queued for the AjunaTEEs right away or scheduled to be queued at
+	/// `start_at` by the scheduler.
+	///
+	/// Nothing of a game that can't be created is kept, e.g. for a full game queue or a game id
+	/// already taken, and the failure is reported with `GameCreationFailed`.
+	fn new_game(
+		game_engine: GameEngine,
+		players: Vec<T::AccountId>,
+		time_control: Option<TimeControl>,
+		private: bool,
+		init_data: Option<InitDataOf<T>>,
+		start_at: Option<T::BlockNumber>,
+	) -> Result<T::Hash, DispatchError> {
+		let result = with_transaction(|| {
+			match Self::do_new_game(
+				game_engine.clone(),
+				players.clone(),
+				time_control,
+				private,
+				init_data,
+				start_at,
+			) {
+				Ok(game_hash) => TransactionOutcome::Commit(Ok(game_hash)),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			}
+		});
+		if let Err(e) = result {
+			Self::deposit_event(Event::GameCreationFailed(game_engine, players, e));
+		}
+		result
+	}
+
+	/// Create a game within the transaction of `new_game`.
+	fn do_new_game(
+		game_engine: GameEngine,
+		players: Vec<T::AccountId>,
+		time_control: Option<TimeControl>,
+		private: bool,
+		init_data: Option<InitDataOf<T>>,
+		start_at: Option<T::BlockNumber>,
+	) -> Result<T::Hash, DispatchError> {
+		// must have at least one player, each meeting the game rules.
+		ensure!(!players.is_empty(), Error::<T>::NoPlayers);
+		if let Some((min, max)) = Self::players_per_game(&game_engine) {
+			ensure!(
+				(min as usize..=max as usize).contains(&players.len()),
+				Error::<T>::PlayerCountMismatch
+			);
 		}
+		for player in players.iter() {
+			Self::check_game_rules(&game_engine, player)?;
+		}
+		let players = BoundedVec::<_, T::MaxPlayersPerGame>::try_from(players)
+			.map_err(|_| Error::<T>::TooManyPlayers)?;
 
-		// #TODO[MUST_HAVE, HAS_A_PLAYER] must have at least one player.
+		// remember when players entered their first game, for account age rules
+		let current_block = <frame_system::Pallet<T>>::block_number();
+		for player in players.iter() {
+			if !PlayerFirstSeen::<T>::contains_key(player) {
+				<PlayerFirstSeen<T>>::insert(player, current_block);
+			}
+		}
+		Self::count_player_games(&players, game_engine.id, true);
 
-		// create new game entry with corresponding informations
-		let game_entry = Self::create_game_entry(game_engine.clone(), players);
+		// create new game entry with corresponding informations, scheduled games get their
+		// AjunaTEE once they start
+		let mut game_entry = Self::create_game_entry(game_engine.clone(), players, time_control);
+		ensure!(!<GameRegistry<T>>::contains_key(&game_entry.id), Error::<T>::DuplicateGameId);
+		if start_at.is_some() {
+			game_entry.game_state = GameState::Scheduled;
+		} else {
+			game_entry.tee_id = Self::assign_tee(&game_engine, &game_entry.id, None);
+		}
 
 		// insert game entry into registry.
 		<GameRegistry<T>>::insert(game_entry.id.clone(), game_entry.clone());
+		Self::note_current_game(&game_entry.players, &game_entry.id, true);
+		Self::schedule_expiry(&game_entry.id, &game_entry, &game_entry.game_state);
+		if private {
+			<PrivateGames<T>>::insert(&game_entry.id, true);
+		}
+		let game_index = <NextGameIndex<T>>::mutate(|index| {
+			*index = index.wrapping_add(1);
+			*index - 1
+		});
+		// match codes of a `u64` index are at most `MAX_MATCH_CODE_LENGTH` long
+		let match_code =
+			MatchCode::try_from(Self::encode_match_code(game_index)).unwrap_or_default();
+		<MatchCodeGames<T>>::insert(&match_code, &game_entry.id);
+		<MatchCodes<T>>::insert(&game_entry.id, match_code);
+		// the seed is committed before an AjunaTEE picks the game up, so the randomness of the game
+		// can be audited against its replay
+		<GameSeeds<T>>::insert(&game_entry.id, Self::generate_game_seed(&game_entry.id));
+		// games without their own initialization data start from the defaults of the game engine
+		if let Some(init_data) = init_data.or_else(|| Self::engine_init_data(&game_engine)) {
+			<GameInitData<T>>::insert(&game_entry.id, init_data);
+		}
+		T::GameObserver::on_game_created(&game_entry.id, &game_entry.players);
+		Self::count_game(&game_engine, |counters| &mut counters.created);
+		Self::note_transition(&game_entry.id, &game_entry.game_state);
+		Self::deposit_event(Event::GameStateChanged(
+			game_entry.id,
+			GameState::None,
+			game_entry.game_state.clone(),
+			None,
+		));
 
-		// retrieve game queue for asked cluster
-		let mut game_queue = Queue::new(MAX_QUEUE_SIZE.into());
-		if GameQueues::<T>::contains_key(&game_engine) {
-			game_queue = Self::game_queues(&game_engine);
+		if let Some(start_at) = start_at {
+			T::Scheduler::schedule_named(
+				(GAMEREGISTRY_ID, b"start", &game_entry.id).encode(),
+				DispatchTime::At(start_at),
+				None,
+				HARD_DEADLINE,
+				frame_system::RawOrigin::Root.into(),
+				Call::<T>::start_scheduled_game { game_hash: game_entry.id }.into(),
+			)
+			.map_err(|_| Error::<T>::GameScheduleFailed)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::GameScheduled(game_entry.id, game_engine, start_at));
+		} else {
+			Self::enqueue_new_game(&game_entry)?;
 		}
 
-		// enqueue new game id
-		game_queue.enqueue(game_entry.id.clone());
+		// Return the id of the created game
+		Ok(game_entry.id)
+	}
 
-		// insert into waiting queue for Ajuna TEE
-		<GameQueues<T>>::insert(&game_engine, game_queue);
+	/// Enqueue a new game into the waiting queue for Ajuna TEE, announcing it to the clients.
+	fn enqueue_new_game(game_entry: &GameEntryOf<T>) -> DispatchResult {
+		// retrieve game queue for asked cluster
+		let game_engine = &game_entry.game_engine;
+		let mut game_queue = Self::game_queues(game_engine);
+		ensure!(game_queue.enqueue(game_entry.id), Error::<T>::QueueFull);
 
 		// Emit an event.
-		Self::deposit_event(Event::GameQueued(game_engine, game_entry.id));
-
-		// Return a successful DispatchResultWithPostInfo
+		let bracket = Self::supported_engines(game_engine).map_or(0, |info| info.bracket);
+		let players = if Self::is_private_game(&game_entry.id) {
+			Vec::new()
+		} else {
+			game_entry.players.clone().into_inner()
+		};
+		Self::deposit_event(Event::GameQueued {
+			game: game_entry.id,
+			engine: game_engine.clone(),
+			players,
+			bracket,
+			time_control: game_entry.time_control.clone(),
+			init_data: Self::game_init_data(&game_entry.id)
+				.map(|init_data| init_data.into_inner())
+				.unwrap_or_default(),
+		});
 		Ok(())
 	}
 
 	/// Generate a new game entry in waiting state.
 	fn create_game_entry(
 		game_engine: GameEngine,
-		players: Vec<T::AccountId>,
-	) -> GameEntry<T::Hash, T::AccountId, GameEngine, GameState<T::AccountId>, T::BlockNumber> {
-		// get a random hash as game id
-		let game_id = Self::generate_random_hash(&GAMEREGISTRY_ID, players[0].clone());
+		players: BoundedVec<T::AccountId, T::MaxPlayersPerGame>,
+		time_control: Option<TimeControl>,
+	) -> GameEntryOf<T> {
+		// get a unique hash as game id
+		let game_id =
+			T::GameIdGenerator::generate_game_id(&game_engine, &players, Self::next_nonce());
 
 		// get current blocknumber
-		let mut state_change: [T::BlockNumber; 4] = [0u8.into(); 4];
-		state_change[0] = <frame_system::Pallet<T>>::block_number();
+		let timestamps = GameTimestamps {
+			queued: <frame_system::Pallet<T>>::block_number(),
+			..Default::default()
+		};
+
+		// players that queued from another chain keep their location on the game
+		let foreign_players: Vec<_> = players
+			.iter()
+			.filter_map(|player| {
+				<ForeignPlayers<T>>::take(player).map(|location| (player.clone(), location))
+			})
+			.collect();
+		let foreign_players = BoundedVec::try_from(foreign_players)
+			.expect("foreign players are players of the game; qed");
 
 		// create a new empty game
 		let game_entry = GameEntry {
@@ -567,7 +9575,10 @@ impl<T: Config> Pallet<T> {
 			game_engine,
 			players,
 			game_state: GameState::Waiting,
-			state_change,
+			timestamps,
+			result: None,
+			time_control,
+			foreign_players,
 		};
 
 		return game_entry
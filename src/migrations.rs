@@ -0,0 +1,362 @@
+use super::*;
+use frame_support::{
+	storage::{migration::remove_storage_prefix, unhashed, StoragePrefixedMap},
+	traits::{GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Game entry before time controls and foreign players were added.
+	#[derive(Encode, Decode)]
+	pub struct OldGameEntry<Hash, AccountId, BlockNumber> {
+		pub id: Hash,
+		pub tee_id: Option<AccountId>,
+		pub game_engine: GameEngine,
+		pub players: Vec<AccountId>,
+		pub game_state: v5::OldGameState<AccountId>,
+		pub state_change: [BlockNumber; 4],
+	}
+
+	/// Start the stepped migration of the game registry, the populated registry is too large to
+	/// migrate in a single block. The game queues keep their layout.
+	pub fn on_runtime_upgrade<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		// the founder key is replaced by the governance origin
+		remove_storage_prefix(Pallet::<T>::name().as_bytes(), b"FounderKey", b"");
+
+		<MigrationCursor<T>>::put(Pallet::<T>::storage_cursor(
+			GameRegistry::<T>::final_prefix().to_vec(),
+		));
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1, 3)
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Game entry before the named timestamps, the result and the bounded players.
+	#[derive(Encode, Decode)]
+	pub struct OldGameEntry<Hash, AccountId, BlockNumber, Location> {
+		id: Hash,
+		tee_id: Option<AccountId>,
+		game_engine: GameEngine,
+		players: Vec<AccountId>,
+		game_state: v5::OldGameState<AccountId>,
+		state_change: [BlockNumber; 4],
+		time_control: Option<TimeControl>,
+		foreign_players: Vec<(AccountId, Location)>,
+	}
+
+	impl<Hash, AccountId, BlockNumber, Location>
+		From<v1::OldGameEntry<Hash, AccountId, BlockNumber>>
+		for OldGameEntry<Hash, AccountId, BlockNumber, Location>
+	{
+		fn from(old: v1::OldGameEntry<Hash, AccountId, BlockNumber>) -> Self {
+			Self {
+				id: old.id,
+				tee_id: old.tee_id,
+				game_engine: old.game_engine,
+				players: old.players,
+				game_state: old.game_state,
+				state_change: old.state_change,
+				time_control: None,
+				foreign_players: Vec::new(),
+			}
+		}
+	}
+
+	/// Start the stepped migration of the game entries into the layout with named timestamps,
+	/// continuing a stepped migration from the first layout if it's ongoing.
+	pub fn on_runtime_upgrade<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 2 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		if Pallet::<T>::migration_cursor().is_none() {
+			<MigrationCursor<T>>::put(Pallet::<T>::storage_cursor(
+				GameRegistry::<T>::final_prefix().to_vec(),
+			));
+		}
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	/// Upgrade the game entry at a key from the first or second layout into the latest one.
+	///
+	/// Entries of the first layout are a prefix of the second one, so they fail to decode in
+	/// the second layout and are upgraded through it.
+	pub fn migrate_entry<T: Config>(key: &[u8]) -> Option<GameEntryOf<T>> {
+		let old = unhashed::get::<
+			OldGameEntry<T::Hash, T::AccountId, T::BlockNumber, T::ForeignLocation>,
+		>(key)
+		.or_else(|| {
+			unhashed::get::<v1::OldGameEntry<T::Hash, T::AccountId, T::BlockNumber>>(key)
+				.map(Into::into)
+		})?;
+		let result = match &old.game_state {
+			v5::OldGameState::Finished(winner) |
+			v5::OldGameState::Suspended(winner) |
+			v5::OldGameState::Reconfirming(winner) => Some(GameResult::Won(winner.clone())),
+			_ => None,
+		};
+		// games with more players than fit into a game entry can't be played anyway
+		let mut players = old.players;
+		players.truncate(T::MaxPlayersPerGame::get() as usize);
+		Some(GameEntry {
+			id: old.id,
+			tee_id: old.tee_id,
+			game_engine: old.game_engine,
+			players: BoundedVec::try_from(players).unwrap_or_default(),
+			game_state: old.game_state.into(),
+			timestamps: GameTimestamps {
+				queued: old.state_change[0],
+				accepted: old.state_change[1],
+				started: old.state_change[2],
+				finished: old.state_change[3],
+			},
+			result,
+			time_control: old.time_control,
+			foreign_players: BoundedVec::try_from(old.foreign_players).unwrap_or_default(),
+		})
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Game queue before its games were stored one by one.
+	#[derive(Encode, Decode)]
+	pub struct OldQueue<Hash> {
+		pub max_size: u32,
+		pub queue: Vec<Hash>,
+	}
+
+	/// Move the games of the game queues into the ring buffer layout. There is a game queue per
+	/// game engine version, so they are migrated in a single block.
+	pub fn on_runtime_upgrade<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 3 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut queues: Weight = 0;
+		let mut games: Weight = 0;
+		GameQueues::<T>::translate::<OldQueue<T::Hash>, _>(|game_engine, old| {
+			for (position, game_hash) in old.queue.iter().enumerate() {
+				<GameQueueItems<T>>::insert(&game_engine, position as u32, game_hash);
+			}
+			queues += 1;
+			games += old.queue.len() as Weight;
+			Some(QueueBounds { head: 0, tail: old.queue.len() as u32 })
+		});
+		StorageVersion::new(3).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(queues + 1, queues + games + 1)
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	/// Bound the game rules of the game engine versions, rules past `MaxRulesPerEngine` are
+	/// dropped. There are only a few game engine versions, so they are migrated in a single
+	/// block.
+	pub fn on_runtime_upgrade<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 4 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut engines: Weight = 0;
+		GameRequirments::<T>::translate::<Vec<GameRule<GameRuleType>>, _>(|_, mut game_rules| {
+			game_rules.truncate(T::MaxRulesPerEngine::get() as usize);
+			engines += 1;
+			BoundedVec::try_from(game_rules).ok()
+		});
+		StorageVersion::new(4).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(engines + 1, engines + 1)
+	}
+}
+
+pub mod v5 {
+	use super::*;
+
+	/// Game state before finished games carried their outcome.
+	#[derive(Encode, Decode)]
+	pub enum OldGameState<AccountId> {
+		None,
+		Waiting,
+		Accepted,
+		Running,
+		Finished(AccountId),
+		Suspended(AccountId),
+		Reconfirming(AccountId),
+	}
+
+	impl<AccountId, MaxPlayers> From<OldGameState<AccountId>> for GameState<AccountId, MaxPlayers> {
+		fn from(old: OldGameState<AccountId>) -> Self {
+			match old {
+				OldGameState::None => GameState::None,
+				OldGameState::Waiting => GameState::Waiting,
+				OldGameState::Accepted => GameState::Accepted,
+				OldGameState::Running => GameState::Running,
+				OldGameState::Finished(winner) => GameState::Finished(GameOutcome::Winner(winner)),
+				OldGameState::Suspended(winner) =>
+					GameState::Suspended(GameOutcome::Winner(winner)),
+				OldGameState::Reconfirming(winner) =>
+					GameState::Reconfirming(GameOutcome::Winner(winner)),
+			}
+		}
+	}
+
+	/// Game entry before finished games carried their outcome.
+	#[derive(Encode, Decode)]
+	pub struct OldGameEntry<Hash, AccountId, BlockNumber, Location, MaxPlayers> {
+		pub id: Hash,
+		pub tee_id: Option<AccountId>,
+		pub game_engine: GameEngine,
+		pub players: BoundedVec<AccountId, MaxPlayers>,
+		pub game_state: OldGameState<AccountId>,
+		pub timestamps: GameTimestamps<BlockNumber>,
+		pub result: Option<GameResult<AccountId, MaxPlayers>>,
+		pub time_control: Option<TimeControl>,
+		pub foreign_players: Vec<(AccountId, Location)>,
+	}
+
+	impl<Hash, AccountId, BlockNumber, Location, MaxPlayers: Get<u32>>
+		From<OldGameEntry<Hash, AccountId, BlockNumber, Location, MaxPlayers>>
+		for GameEntry<
+			Hash,
+			AccountId,
+			GameEngine,
+			GameState<AccountId, MaxPlayers>,
+			BlockNumber,
+			Location,
+			MaxPlayers,
+		>
+	{
+		fn from(old: OldGameEntry<Hash, AccountId, BlockNumber, Location, MaxPlayers>) -> Self {
+			Self {
+				id: old.id,
+				tee_id: old.tee_id,
+				game_engine: old.game_engine,
+				players: old.players,
+				game_state: old.game_state.into(),
+				timestamps: old.timestamps,
+				result: old.result,
+				time_control: old.time_control,
+				foreign_players: BoundedVec::try_from(old.foreign_players).unwrap_or_default(),
+			}
+		}
+	}
+
+	/// Result in the match history before it carried the outcome of the game.
+	#[derive(Encode, Decode)]
+	pub struct OldMatchResult<Hash, AccountId, BlockNumber> {
+		pub game_hash: Hash,
+		pub game_engine: GameEngine,
+		pub opponents: Vec<AccountId>,
+		pub winner: AccountId,
+		pub block: BlockNumber,
+	}
+
+	/// Start the stepped migration of the game entries and match histories to the outcomes of
+	/// finished games.
+	///
+	/// A stepped migration from the first layouts that's still ongoing migrates the game
+	/// entries straight into the latest layout, the storage version is put once it's done.
+	pub fn on_runtime_upgrade<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 5 {
+			return T::DbWeight::get().reads(1)
+		}
+		if Pallet::<T>::migration_cursor().is_some() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		<MigrationCursor<T>>::put(Pallet::<T>::storage_cursor(
+			GameRegistry::<T>::final_prefix().to_vec(),
+		));
+		StorageVersion::new(5).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	/// Migrate the next game entries after the cursor and then the next match histories,
+	/// returns the cursor to continue from or `None` once all of them are migrated.
+	pub fn migrate_step<T: Config>(cursor: Vec<u8>, limit: u32) -> (Option<Vec<u8>>, Weight) {
+		let registry = GameRegistry::<T>::final_prefix();
+		let history = MatchHistory::<T>::final_prefix();
+		// the storage version is only put early by the upgrade of the game entries of the
+		// previous layout
+		let from_first_layouts = Pallet::<T>::on_chain_storage_version() < 5;
+		let mut cursor = cursor;
+		let mut weight = T::DbWeight::get().reads(1);
+		let mut migrated = 0;
+
+		while migrated < limit {
+			let in_registry = cursor.starts_with(&registry);
+			let prefix = if in_registry { &registry[..] } else { &history[..] };
+			let key = match sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(prefix))
+			{
+				Some(key) => key,
+				None if in_registry => {
+					cursor = history.to_vec();
+					continue
+				},
+				None => {
+					StorageVersion::new(5).put::<Pallet<T>>();
+					return (None, weight + T::DbWeight::get().reads_writes(1, 1))
+				},
+			};
+
+			if in_registry {
+				let game_entry: Option<GameEntryOf<T>> = if from_first_layouts {
+					v2::migrate_entry::<T>(&key)
+				} else {
+					unhashed::get::<
+						OldGameEntry<
+							T::Hash,
+							T::AccountId,
+							T::BlockNumber,
+							T::ForeignLocation,
+							T::MaxPlayersPerGame,
+						>,
+					>(&key)
+					.map(Into::into)
+				};
+				if let Some(game_entry) = game_entry {
+					unhashed::put(&key, &game_entry);
+				}
+			} else if let Some(old) = unhashed::get::<
+				BoundedVec<OldMatchResult<T::Hash, T::AccountId, T::BlockNumber>, T::MaxHistory>,
+			>(&key)
+			{
+				let history: Vec<MatchResultOf<T>> = old
+					.into_iter()
+					.map(|old| MatchResult {
+						game_hash: old.game_hash,
+						game_engine: old.game_engine,
+						// the opponents were taken from the bounded players of the game
+						opponents: BoundedVec::try_from(old.opponents).unwrap_or_default(),
+						outcome: GameOutcome::Winner(old.winner),
+						block: old.block,
+					})
+					.collect();
+				unhashed::put(&key, &history);
+			}
+
+			weight += T::DbWeight::get().reads_writes(2, 1);
+			migrated += 1;
+			cursor = key;
+		}
+
+		(Some(cursor), weight)
+	}
+}
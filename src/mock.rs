@@ -1,24 +1,28 @@
 use super::*;
 use crate as pallet_gameregistry;
 
-use sp_core::H256;
+use sp_core::{ed25519, Pair, H256};
+use sp_keystore::{testing::KeyStore, KeystoreExt};
+use std::{cell::RefCell, sync::Arc};
 
 use frame_support::{
-	construct_runtime, parameter_types,
-	traits::{EqualPrivilegeOnly, OnFinalize, OnInitialize},
+	assert_ok, construct_runtime,
+	dispatch::DispatchResultWithPostInfo,
+	ord_parameter_types, parameter_types,
+	traits::{EnsureOrigin, EqualPrivilegeOnly, OnFinalize, OnIdle, OnInitialize},
 	weights::Weight,
 };
 
 use frame_support_test::TestRandomness;
 
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage, Perbill,
+	BuildStorage, Perbill, Percent,
 };
 
-type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 // Configure a mock runtime to test the pallet.
@@ -29,9 +33,10 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Scheduler: pallet_scheduler::{Pallet, Call, Config, Storage, Event<T>},
 		MatchMaker: pallet_matchmaker::{Pallet, Call, Storage, Event<T>},
-		Registry: pallet_gameregistry::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Registry: pallet_gameregistry::{Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned},
 	}
 );
 
@@ -60,7 +65,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -68,6 +73,22 @@ impl frame_system::Config for Test {
 	type OnSetCode = ();
 }
 
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
 }
@@ -96,26 +117,422 @@ impl pallet_matchmaker::Config for Test {
 	type AmountBrackets = AmountBrackets;
 }
 
+ord_parameter_types! {
+	pub const Founder: u64 = FOUNDER;
+}
+
+parameter_types! {
+	pub const QueueBond: u64 = 10;
+	pub const MinTees: u32 = 1;
+	pub const WaitingTimeout: u64 = 5;
+	pub const AcceptedTimeout: u64 = 5;
+	pub const InviteTimeout: u64 = 5;
+	pub const MaxGameDuration: u64 = 10;
+	pub const MigrationBatchSize: u32 = 2;
+	pub const MaxSpectators: u32 = 2;
+	pub const MaxGamesPerPlayer: u32 = 1;
+	pub const MaxCurrentGames: u32 = 8;
+	pub const MaxHistory: u32 = 2;
+	pub const MaxRulesPerEngine: u32 = 2;
+	#[derive(Clone, Default, PartialEq, Debug, TypeInfo)]
+	pub const MaxPlayersPerGame: u32 = 16;
+	pub const MaxInitData: u32 = 4;
+	pub const ReportDeposit: u64 = 5;
+	pub const MaxReports: u32 = 2;
+	pub const FraudProofWindow: u64 = 5;
+	pub const FraudProofDeposit: u64 = 10;
+	pub const GameRetention: u64 = 50;
+	pub const ReconfirmationPeriod: u64 = 3;
+	pub const MaxAuditEntries: u32 = 3;
+	pub const CollusionWindow: u64 = 3;
+	pub const CollusionThreshold: u32 = 2;
+	pub const NoShowGracePeriod: u64 = 3;
+	pub const MaxNoShows: u32 = 2;
+	pub const NoShowBanDuration: u64 = 10;
+	pub const RematchWindow: u64 = 5;
+	#[derive(Clone, Default, PartialEq, Debug, TypeInfo)]
+	pub const MaxTournamentPlayers: u32 = 8;
+	#[derive(Clone, Default, PartialEq, Debug, TypeInfo)]
+	pub const MaxPartySize: u32 = 4;
+	pub const MaxGameResumes: u32 = 2;
+	pub const GameRewardPoints: RewardPoints =
+		RewardPoints { participation: 1, win: 10, loss: 2, streak_bonus: 5, max_streak: 2 };
+	pub const MaxGamesPerBlock: u8 = 10;
+	pub const MaxQueueSize: u32 = 64;
+	pub const MaxAckBatch: u32 = 100;
+	pub const MaxTeeCallsPerBlock: u32 = 100;
+	pub const MaxTeeThrottles: u32 = 3;
+	pub const HighValueWager: u64 = 100;
+	pub const HighValueTeeBond: u64 = 50;
+	pub const AttestationValidity: u64 = 20;
+	pub const MaxGamesPerTee: u32 = 3;
+	pub const LeaderboardSize: u32 = 2;
+	pub static LeaderboardInterval: u64 = 5;
+	pub const HeartbeatInterval: u64 = 4;
+	pub const MatchWaitSla: u64 = 6;
+}
+
+parameter_types! {
+	pub static MatchmakingWeightLimit: Weight = Weight::max_value();
+	// off by default, so tests can expire games through the extrinsic
+	pub static MaintenanceWeightLimit: Weight = 0;
+	// off by default, so tests can expire games through the extrinsic
+	pub static ScheduledExpiry: bool = false;
+	// off by default, so tests see stakes and ratings right after a game finished
+	pub static DeferredSettlement: bool = false;
+	// off by default, so tests see stakes and ratings right after a game finished
+	pub static SettlementsPerBlock: u32 = 0;
+	// high enough by default for the games a test finishes in a block
+	pub static MaxFinishedPerBlock: u32 = 16;
+	// games go to the first AjunaTEE acknowledging them by default
+	pub static TeeAssignmentMode: TeeAssignment = TeeAssignment::FirstCome;
+	// off by default, so games of AjunaTEEs without heartbeats stay with them
+	pub static TeeTimeout: u64 = 0;
+	// off by default, so the registry heartbeat is the only event of `on_initialize`
+	pub static SeasonLength: u64 = 0;
+	// a single skill tier by default, so game engines can use neighbouring brackets
+	pub static RatingBoundaries: Vec<u32> = Vec::new();
+	// off by default, so ratings only move by the games played
+	pub static DecayPeriod: u64 = 0;
+	pub static PlacementGames: u32 = 0;
+	pub RatingDecay: Perbill = Perbill::from_percent(50);
+	// off by default, so winners are paid the whole pot
+	pub static TeeRewardPercent: Percent = Percent::zero();
+	pub static ProtocolFeePercent: Percent = Percent::zero();
+}
+
+thread_local! {
+	pub static OBSERVED: RefCell<Vec<(&'static str, H256)>> = RefCell::new(Vec::new());
+}
+
+/// Records the game lifecycle callbacks it receives.
+pub struct MockObserver;
+impl traits::GameObserver<u64, H256, MaxPlayersPerGame> for MockObserver {
+	fn on_game_created(game_hash: &H256, _players: &[u64]) {
+		OBSERVED.with(|observed| observed.borrow_mut().push(("created", *game_hash)));
+	}
+
+	fn on_game_state_changed(game_hash: &H256, _from: &GameStateOf<Test>, _to: &GameStateOf<Test>) {
+		OBSERVED.with(|observed| observed.borrow_mut().push(("state_changed", *game_hash)));
+	}
+
+	fn on_game_started(game_hash: &H256, _players: &[u64]) {
+		OBSERVED.with(|observed| observed.borrow_mut().push(("started", *game_hash)));
+	}
+
+	fn on_game_finished(game_hash: &H256, _outcome: &GameOutcomeOf<Test>) {
+		OBSERVED.with(|observed| observed.borrow_mut().push(("finished", *game_hash)));
+	}
+}
+
+pub fn observed() -> Vec<(&'static str, H256)> {
+	OBSERVED.with(|observed| observed.borrow().clone())
+}
+
+thread_local! {
+	pub static RESULTS: RefCell<Vec<(H256, Vec<u64>, GameResultOf<Test>)>> = RefCell::new(Vec::new());
+}
+
+/// Records the final game results it receives.
+pub struct MockResultHandler;
+impl traits::OnGameResult<u64, H256, MaxPlayersPerGame> for MockResultHandler {
+	fn on_game_result(
+		game_hash: &H256,
+		_game_engine: &GameEngine,
+		players: &[u64],
+		result: &GameResultOf<Test>,
+	) {
+		RESULTS.with(|results| {
+			results.borrow_mut().push((*game_hash, players.to_vec(), result.clone()))
+		});
+	}
+}
+
+pub fn results() -> Vec<(H256, Vec<u64>, GameResultOf<Test>)> {
+	RESULTS.with(|results| results.borrow().clone())
+}
+
+thread_local! {
+	pub static REWARDS: RefCell<Vec<(u64, u32, u32)>> = RefCell::new(Vec::new());
+}
+
+/// Records the reward points it receives, with the win streaks.
+pub struct MockRewardHandler;
+impl traits::RewardHandler<u64> for MockRewardHandler {
+	fn on_reward(player: &u64, _game_engine: &GameEngine, points: u32, win_streak: u32) {
+		REWARDS.with(|rewards| rewards.borrow_mut().push((*player, points, win_streak)));
+	}
+}
+
+pub fn rewards() -> Vec<(u64, u32, u32)> {
+	REWARDS.with(|rewards| rewards.borrow().clone())
+}
+
+pub const BOARD_ENGINE_ID: u8 = 2;
+
+/// Stores the game hash under the player key, for games of the board engine.
+pub struct MockGameStartCall;
+impl traits::GameStartCall<u64, H256, Call> for MockGameStartCall {
+	fn game_start_call(
+		game_hash: &H256,
+		game_engine: &GameEngine,
+		players: &[u64],
+	) -> Option<Call> {
+		if game_engine.id != BOARD_ENGINE_ID {
+			return None
+		}
+		let items = players.iter().map(|player| (player.encode(), game_hash.encode())).collect();
+		Some(Call::System(frame_system::Call::set_storage { items }))
+	}
+}
+
+thread_local! {
+	pub static ASSETS: RefCell<Vec<(u64, u32)>> = RefCell::new(Vec::new());
+}
+
+/// Assets given to accounts with `give_asset`.
+pub struct MockAssets;
+impl traits::AssetOwnership<u64> for MockAssets {
+	fn owns_asset(who: &u64, asset_id: u32) -> bool {
+		ASSETS.with(|assets| assets.borrow().contains(&(*who, asset_id)))
+	}
+}
+
+pub fn give_asset(who: u64, asset_id: u32) {
+	ASSETS.with(|assets| assets.borrow_mut().push((who, asset_id)));
+}
+
+/// Pays the protocol fees and slashed bonds to the founder.
+pub struct FeeToFounder;
+impl OnUnbalanced<NegativeImbalanceOf<Test>> for FeeToFounder {
+	fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<Test>) {
+		Balances::resolve_creating(&FOUNDER, amount);
+	}
+}
+
+/// Signed accounts from here on are treated as locations on another chain.
+pub const FOREIGN_LOCATIONS: u64 = 1_000;
+pub const FOREIGN_ACCOUNTS: u64 = 10_000;
+
+/// Origin of players queueing from another chain, the location being the signer.
+pub struct EnsureForeignPlayer;
+impl EnsureOrigin<Origin> for EnsureForeignPlayer {
+	type Success = u32;
+
+	fn try_origin(o: Origin) -> Result<u32, Origin> {
+		match Into::<Result<frame_system::RawOrigin<u64>, Origin>>::into(o.clone()) {
+			Ok(frame_system::RawOrigin::Signed(who)) if who >= FOREIGN_LOCATIONS => Ok(who as u32),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::signed(FOREIGN_LOCATIONS)
+	}
+}
+
+/// Derives the registry account of a foreign player from its location.
+pub struct ForeignAccount;
+impl Convert<u32, u64> for ForeignAccount {
+	fn convert(location: u32) -> u64 {
+		FOREIGN_ACCOUNTS + location as u64
+	}
+}
+
+thread_local! {
+	pub static NOTIFIED: RefCell<Vec<(u32, H256, GameOutcomeOf<Test>)>> = RefCell::new(Vec::new());
+}
+
+/// Records the results sent to foreign players.
+pub struct MockResultNotifier;
+impl traits::ForeignResultNotifier<u32, H256, u64, MaxPlayersPerGame> for MockResultNotifier {
+	fn notify_result(
+		destination: &u32,
+		game_hash: &H256,
+		outcome: &GameOutcomeOf<Test>,
+	) -> DispatchResult {
+		NOTIFIED.with(|notified| {
+			notified.borrow_mut().push((*destination, *game_hash, outcome.clone()))
+		});
+		Ok(())
+	}
+}
+
+pub const UNATTESTED_TEE: u64 = 30;
+pub const VALID_QUOTE: &[u8] = b"quote";
+/// Attested enclave, that signs its results.
+pub const SIGNING_TEE: u64 = 8;
+
+/// Key pair the enclave of `SIGNING_TEE` signs its results with.
+pub fn enclave_pair() -> ed25519::Pair {
+	ed25519::Pair::from_seed(&[SIGNING_TEE as u8; 32])
+}
+
+/// Hash of the terminal game state the mocked enclaves finish their games with.
+pub fn final_state() -> H256 {
+	H256::repeat_byte(0xf1)
+}
+
+/// Sign a result like the enclave of `SIGNING_TEE`.
+pub fn sign_result(game_hash: H256, outcome: &GameOutcomeOf<Test>, nonce: u64) -> MultiSignature {
+	enclave_pair()
+		.sign(&(game_hash, outcome, final_state(), nonce, None::<Vec<u8>>, None::<H256>).encode())
+		.into()
+}
+
+/// Sign an acknowledgement like the enclave of `SIGNING_TEE`.
+pub fn sign_ack(game_engine: &GameEngine, games: &[H256], nonce: u64) -> MultiSignature {
+	enclave_pair().sign(&(game_engine, games, nonce).encode()).into()
+}
+
+/// Accounts below `UNATTESTED_TEE` are attested enclaves, others need a valid quote.
+pub struct MockAttestation;
+impl traits::TeeAttestation<u64> for MockAttestation {
+	fn verify_quote(_account: &u64, quote: &[u8]) -> Result<[u8; 32], DispatchError> {
+		if quote == VALID_QUOTE {
+			Ok([1; 32])
+		} else {
+			Err(DispatchError::Other("invalid quote"))
+		}
+	}
+
+	fn enclave_of(account: &u64) -> Option<[u8; 32]> {
+		if *account < UNATTESTED_TEE {
+			Some([*account as u8; 32])
+		} else {
+			None
+		}
+	}
+
+	fn signing_key_of(account: &u64) -> Option<AccountId32> {
+		(*account == SIGNING_TEE).then(|| enclave_pair().public().into())
+	}
+}
+
 impl pallet_gameregistry::Config for Test {
 	type Proposal = Call;
 	type Event = Event;
 	type Randomness = TestRandomness<Self>;
+	type GameIdGenerator = traits::RandomGameIds<Self>;
 	type Scheduler = Scheduler;
 	type PalletsOrigin = OriginCaller;
 	type MatchMaker = MatchMaker;
+	type GovernanceOrigin = EnsureSignedBy<Founder, u64>;
+	type OperatorOrigin = EnsureSignedBy<Founder, u64>;
+	type ModerationOrigin = EnsureSignedBy<Founder, u64>;
+	type ArbitrationOrigin = EnsureSignedBy<Founder, u64>;
+	type Currency = Balances;
+	type QueueBond = QueueBond;
+	type BondSlash = FeeToFounder;
+	type GameObserver = MockObserver;
+	type OnGameResult = MockResultHandler;
+	type GameStartCall = MockGameStartCall;
+	type AssetOwnership = MockAssets;
+	type RewardHandler = MockRewardHandler;
+	type RewardPoints = GameRewardPoints;
+	type ForeignLocation = u32;
+	type ForeignOrigin = EnsureForeignPlayer;
+	type LocationToAccountId = ForeignAccount;
+	type ResultNotifier = MockResultNotifier;
+	type MinTees = MinTees;
+	type TeeAttestation = MockAttestation;
+	type WaitingTimeout = WaitingTimeout;
+	type AcceptedTimeout = AcceptedTimeout;
+	type InviteTimeout = InviteTimeout;
+	type MaxGameDuration = MaxGameDuration;
+	type MigrationBatchSize = MigrationBatchSize;
+	type MaxSpectators = MaxSpectators;
+	type RatingBoundaries = RatingBoundaries;
+	type DecayPeriod = DecayPeriod;
+	type RatingDecay = RatingDecay;
+	type PlacementGames = PlacementGames;
+	type MaxGamesPerPlayer = MaxGamesPerPlayer;
+	type MaxCurrentGames = MaxCurrentGames;
+	type MaxHistory = MaxHistory;
+	type MaxRulesPerEngine = MaxRulesPerEngine;
+	type MaxPlayersPerGame = MaxPlayersPerGame;
+	type MaxInitData = MaxInitData;
+	type ReportDeposit = ReportDeposit;
+	type MaxReports = MaxReports;
+	type FraudProofWindow = FraudProofWindow;
+	type FraudProofDeposit = FraudProofDeposit;
+	type GameRetention = GameRetention;
+	type ReconfirmationPeriod = ReconfirmationPeriod;
+	type MaxAuditEntries = MaxAuditEntries;
+	type CollusionWindow = CollusionWindow;
+	type CollusionThreshold = CollusionThreshold;
+	type NoShowGracePeriod = NoShowGracePeriod;
+	type MaxNoShows = MaxNoShows;
+	type NoShowBanDuration = NoShowBanDuration;
+	type RematchWindow = RematchWindow;
+	type MaxTournamentPlayers = MaxTournamentPlayers;
+	type MaxPartySize = MaxPartySize;
+	type MaxGameResumes = MaxGameResumes;
+	type HighValueWager = HighValueWager;
+	type HighValueTeeBond = HighValueTeeBond;
+	type TeeRewardPercent = TeeRewardPercent;
+	type ProtocolFeePercent = ProtocolFeePercent;
+	type ProtocolFee = FeeToFounder;
+	type AttestationValidity = AttestationValidity;
+	type MaxGamesPerTee = MaxGamesPerTee;
+	type TeeTimeout = TeeTimeout;
+	type SeasonLength = SeasonLength;
+	type LeaderboardSize = LeaderboardSize;
+	type LeaderboardInterval = LeaderboardInterval;
+	type HeartbeatInterval = HeartbeatInterval;
+	type MatchWaitSla = MatchWaitSla;
+	type MatchmakingWeightLimit = MatchmakingWeightLimit;
+	type MaxGamesPerBlock = MaxGamesPerBlock;
+	type MaxQueueSize = MaxQueueSize;
+	type MaxAckBatch = MaxAckBatch;
+	type MaxTeeCallsPerBlock = MaxTeeCallsPerBlock;
+	type MaxTeeThrottles = MaxTeeThrottles;
+	type MaintenanceWeightLimit = MaintenanceWeightLimit;
+	type ScheduledExpiry = ScheduledExpiry;
+	type DeferredSettlement = DeferredSettlement;
+	type SettlementsPerBlock = SettlementsPerBlock;
+	type MaxFinishedPerBlock = MaxFinishedPerBlock;
+	type TeeAssignment = TeeAssignmentMode;
+	type WeightInfo = ();
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	Call: From<C>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = UncheckedExtrinsic;
 }
 
+/// Account of the governance and operator origins.
+pub const FOUNDER: u64 = 99;
+pub const INITIAL_BALANCE: u64 = 1_000;
+
 /// Build genesis storage according to the mock runtime.
-pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-	//frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with(RegistryConfig::default())
+}
+
+/// Build genesis storage according to the mock runtime, with a registry genesis.
+pub fn new_test_ext_with(registry: RegistryConfig) -> sp_io::TestExternalities {
 	let t = GenesisConfig {
 		system: Default::default(),
+		balances: BalancesConfig {
+			balances: (1..=10)
+				.chain(Some(FOREIGN_ACCOUNTS + FOREIGN_LOCATIONS))
+				.map(|who| (who, INITIAL_BALANCE))
+				.collect(),
+		},
 		scheduler: Default::default(),
-		registry: Default::default(),
+		registry,
 	}
 	.build_storage()
 	.unwrap();
-	t.into()
+	let mut ext = sp_io::TestExternalities::from(t);
+	// benchmarks sign the submissions of enclaves with keys of the keystore
+	ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
+	ext
 }
 
 pub fn run_next_block() {
@@ -138,5 +555,91 @@ pub fn run_to_block(n: u64) {
 		System::on_initialize(System::block_number());
 		Scheduler::on_initialize(System::block_number());
 		Registry::on_initialize(System::block_number());
+
+		// mock on_idle of an empty block
+		Registry::on_idle(System::block_number(), Weight::max_value());
+	}
+}
+
+/// AjunaTEE of the mock runtime, attested by `MockAttestation`.
+pub const TEE: u64 = 7;
+
+/// Register a game engine version, supported by the mock AjunaTEE.
+pub fn register_engine(game_engine: &GameEngine, bracket: u8) {
+	assert_ok!(Registry::register_game_engine(
+		Origin::signed(FOUNDER),
+		game_engine.clone(),
+		bracket
+	));
+	let mut supported_engines = Registry::registered_tees(TEE)
+		.map(|tee_info| tee_info.supported_engines.into_inner())
+		.unwrap_or_default();
+	supported_engines.push(game_engine.clone());
+	assert_ok!(Registry::register_tee(Origin::signed(FOUNDER), TEE, supported_engines, None));
+}
+
+/// Mock AjunaTEE, driving games through their lifecycle like the enclave would.
+pub struct MockTee(pub u64);
+impl MockTee {
+	/// Acknowledge the next games of a game engine.
+	pub fn ack(&self, game_engine: &GameEngine, games: Vec<H256>) -> DispatchResultWithPostInfo {
+		Registry::ack_game(Origin::signed(self.0), game_engine.clone(), games)
 	}
+
+	/// Start running an acknowledged game.
+	pub fn ready(&self, game_hash: H256) -> DispatchResult {
+		Registry::ready_game(Origin::signed(self.0), game_hash)
+	}
+
+	/// Finish a running game with a winner, signed if the enclave has a signing key.
+	pub fn finish(&self, game_hash: H256, winner: u64) -> DispatchResult {
+		self.finish_with(game_hash, GameOutcome::Winner(winner))
+	}
+
+	/// Finish a game with any outcome, not only a winner.
+	pub fn finish_with(&self, game_hash: H256, outcome: GameOutcomeOf<Test>) -> DispatchResult {
+		let signature = (self.0 == SIGNING_TEE)
+			.then(|| sign_result(game_hash, &outcome, Registry::tee_result_nonce(self.0)));
+		Registry::finish_game(
+			Origin::signed(self.0),
+			game_hash,
+			outcome,
+			final_state(),
+			signature,
+			None,
+			None,
+		)
+	}
+
+	/// Drop a game of a game engine.
+	pub fn drop(
+		&self,
+		game_hash: H256,
+		game_engine: &GameEngine,
+		reason: DropReason,
+	) -> DispatchResultWithPostInfo {
+		Registry::drop_game(Origin::signed(self.0), game_hash, game_engine.clone(), reason)
+	}
+
+	pub fn checkpoint(&self, game_hash: H256, state_root: H256, turn: u32) -> DispatchResult {
+		Registry::checkpoint_game(Origin::signed(self.0), game_hash, state_root, turn)
+	}
+
+	/// Resume running games after a restart of the AjunaTEE.
+	pub fn resume(&self, games: Vec<H256>) -> DispatchResult {
+		let games = BoundedVec::try_from(games).expect("games fit into a batch");
+		Registry::resume_games(Origin::signed(self.0), games)
+	}
+}
+
+/// Create a game and play it till the end on the mock AjunaTEE, returns the game hash.
+pub fn play_game(game_engine: &GameEngine, players: Vec<u64>, winner: u64) -> H256 {
+	let game_hash =
+		<Registry as traits::CreateGame<_, _>>::create_game(game_engine.clone(), players)
+			.expect("game engine is available");
+	let tee = MockTee(TEE);
+	assert_ok!(tee.ack(game_engine, vec![game_hash]));
+	assert_ok!(tee.ready(game_hash));
+	assert_ok!(tee.finish(game_hash, winner));
+	game_hash
 }
@@ -0,0 +1,144 @@
+use super::*;
+
+/// Premade party of players queueing together, formed by its leader.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Party<AccountId, MaxSize> {
+	pub game_engine: GameEngine,
+	/// Players that accepted to play together, the leader first.
+	pub members: BoundedVec<AccountId, MaxSize>,
+	/// Invited players that didn't accept yet, the party is queued once all accepted.
+	pub pending: BoundedVec<AccountId, MaxSize>,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Match the parties queued for a game engine version in the order they queued. A party meets
+	/// a team of the parties queued after it with as many players, solo players being parties of
+	/// one. Parties left without opponents wait for the next block.
+	pub(crate) fn match_parties(game_engine: &GameEngine) -> Weight {
+		let queue = Self::party_queue(game_engine);
+		let parties = queue.len() as u32;
+		if Self::ensure_engine_open(game_engine).is_err() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		// parties with members banned while queued are disbanded
+		let mut queued = Vec::new();
+		for leader in queue.into_inner() {
+			match Self::parties(&leader) {
+				Some(party)
+					if party.members.iter().any(|member| {
+						Self::is_banned(member) ||
+							Self::is_sanctioned(member, SanctionKind::QueueBan)
+					}) =>
+					Self::dissolve_party(&leader, party),
+				Some(party) => queued.push((leader, party)),
+				None => (),
+			}
+		}
+
+		// parties of games that can't be created wait for the next block, ahead of the others
+		let mut deferred = Vec::new();
+		let mut index = 0;
+		while index < queued.len() {
+			let team_size = queued[index].1.members.len();
+			let mut opponents = Vec::new();
+			let mut players = 0;
+			for (other, (_, party)) in queued.iter().enumerate().skip(index + 1) {
+				if players + party.members.len() <= team_size {
+					opponents.push(other);
+					players += party.members.len();
+				}
+				if players == team_size {
+					break
+				}
+			}
+			if players < team_size {
+				index += 1;
+				continue
+			}
+
+			// matched parties leave the queue, the later ones first to keep the indices
+			let mut opposing = Vec::new();
+			for other in opponents.into_iter().rev() {
+				opposing.insert(0, queued.remove(other));
+			}
+			let (leader, party) = queued.remove(index);
+			let team = party.members.into_inner();
+			let opposing_team: Vec<_> =
+				opposing.iter().flat_map(|(_, party)| party.members.clone()).collect();
+			let players = [team.clone(), opposing_team.clone()].concat();
+			let mut leaders = sp_std::vec![leader];
+			leaders.extend(opposing.into_iter().map(|(leader, _)| leader));
+			match Self::queue_game(game_engine.clone(), players, None, false, None) {
+				Ok(game_hash) => {
+					for leader in leaders.iter() {
+						<Parties<T>>::remove(leader);
+					}
+					// both teams together fit into a game
+					let teams: Vec<_> = sp_std::vec![team, opposing_team]
+						.into_iter()
+						.map(|team| BoundedVec::try_from(team).unwrap_or_default())
+						.collect();
+					<GameTeams<T>>::insert(
+						&game_hash,
+						TeamsOf::<T>::try_from(teams).unwrap_or_default(),
+					);
+
+					// Emit an event.
+					Self::deposit_event(Event::PartiesMatched(game_hash, leaders));
+				},
+				Err(_) => deferred.extend(leaders),
+			}
+		}
+
+		let queue: Vec<_> = deferred
+			.into_iter()
+			.chain(queued.into_iter().map(|(leader, _)| leader))
+			.collect();
+		if queue.is_empty() {
+			<PartyQueues<T>>::remove(game_engine);
+		} else {
+			// the parties left over were taken from the queue
+			<PartyQueues<T>>::insert(game_engine, BoundedVec::try_from(queue).unwrap_or_default());
+		}
+		T::WeightInfo::match_parties(parties)
+	}
+
+	/// Reserve the queue bond of a party member, released or slashed like the bond of a solo
+	/// player once its game is over.
+	pub(crate) fn reserve_party_bond(player: &T::AccountId) -> DispatchResult {
+		// an outstanding bond means the player is queued or didn't accept the last match yet
+		ensure!(!QueueBonds::<T>::contains_key(player), Error::<T>::AlreadyQueued);
+		let bond = T::QueueBond::get();
+		T::Currency::reserve(player, bond).map_err(|_| Error::<T>::InsufficientBalance)?;
+		<QueueBonds<T>>::insert(player, bond);
+		Self::deposit_event(Event::QueueBondReserved(player.clone(), bond));
+		Ok(())
+	}
+
+	/// Queue a party with all its members for opponents, the party queue of a game engine
+	/// version holds `MaxQueueSize` parties.
+	pub(crate) fn enqueue_party(
+		leader: &T::AccountId,
+		game_engine: &GameEngine,
+		size: u32,
+	) -> DispatchResult {
+		<PartyQueues<T>>::try_mutate(game_engine, |queue| queue.try_push(leader.clone()))
+			.map_err(|_| Error::<T>::QueueFull)?;
+
+		// Emit an event.
+		Self::deposit_event(Event::PartyQueued(leader.clone(), size));
+		Ok(())
+	}
+
+	/// Disband a party taken out of its queue, releasing the bonds of its members.
+	pub(crate) fn dissolve_party(leader: &T::AccountId, party: PartyOf<T>) {
+		<Parties<T>>::remove(leader);
+		for member in party.members.iter() {
+			Self::release_queue_bond(member);
+		}
+
+		// Emit an event.
+		Self::deposit_event(Event::PartyDisbanded(leader.clone()));
+	}
+}
@@ -1,47 +1,249 @@
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+use frame_support::storage::{StorageDoubleMap, StorageMap};
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
+use sp_runtime::RuntimeDebug;
+use sp_std::{marker::PhantomData, vec::Vec};
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, TypeInfo)]
-pub struct Queue<T> {
+/// Head and tail position of a queue, the positions wrap around like in a ring buffer.
+#[derive(
+	Encode, Decode, MaxEncodedLen, Default, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+)]
+pub struct QueueBounds {
+	pub head: u32,
+	pub tail: u32,
+}
+
+impl QueueBounds {
+	pub fn length(&self) -> u32 {
+		self.tail.wrapping_sub(self.head)
+	}
+}
+
+/// Queue with its items stored one by one at their position, so enqueueing and dequeueing only
+/// touch the item and the bounds instead of the whole queue.
+///
+/// Changes are written to storage right away.
+pub struct Queue<K, V, Bounds, Items> {
+	key: K,
+	bounds: QueueBounds,
 	max_size: u32,
-	queue: Vec<T>,
+	_phantom: PhantomData<(V, Bounds, Items)>,
 }
 
-impl<T: PartialEq> Queue<T> {
-	pub fn new(size: u32) -> Self {
-		Queue { max_size: size, queue: Vec::new() }
+impl<K, V, Bounds, Items> Queue<K, V, Bounds, Items>
+where
+	K: FullCodec,
+	V: FullCodec + PartialEq,
+	Bounds: StorageMap<K, QueueBounds, Query = QueueBounds>,
+	Items: StorageDoubleMap<K, u32, V, Query = Option<V>>,
+{
+	pub fn new(key: K, size: u32) -> Self {
+		let bounds = Bounds::get(&key);
+		Queue { key, bounds, max_size: size, _phantom: PhantomData }
 	}
 
-	pub fn enqueue(&mut self, item: T) -> bool {
-		if self.queue.len() < self.max_size as usize {
-			self.queue.push(item);
+	pub fn enqueue(&mut self, item: V) -> bool {
+		if self.length() < self.max_size {
+			Items::insert(&self.key, self.bounds.tail, item);
+			self.bounds.tail = self.bounds.tail.wrapping_add(1);
+			Bounds::insert(&self.key, self.bounds);
 			return true
 		}
 		return false
 	}
 
-	pub fn dequeue(&mut self) -> T {
-		self.queue.remove(0)
+	pub fn dequeue(&mut self) -> Option<V> {
+		if self.is_empty() {
+			return None
+		}
+		let item = Items::take(&self.key, self.bounds.head);
+		self.bounds.head = self.bounds.head.wrapping_add(1);
+		Bounds::insert(&self.key, self.bounds);
+		item
+	}
+
+	/// Dequeue up to `max` items from the head, in the order they were enqueued.
+	pub fn dequeue_many(&mut self, max: u32) -> Vec<V> {
+		let count = self.length().min(max);
+		if count == 0 {
+			return Vec::new()
+		}
+		let items = (0..count)
+			.filter_map(|offset| Items::take(&self.key, self.bounds.head.wrapping_add(offset)))
+			.collect();
+		self.bounds.head = self.bounds.head.wrapping_add(count);
+		Bounds::insert(&self.key, self.bounds);
+		items
+	}
+
+	pub fn max_size(&self) -> u32 {
+		self.max_size
 	}
 
 	pub fn length(&self) -> u32 {
-		self.queue.len() as u32
+		self.bounds.length()
+	}
+
+	/// Items that can still be enqueued.
+	pub fn remaining_capacity(&self) -> u32 {
+		self.max_size.saturating_sub(self.length())
 	}
 
 	pub fn is_empty(&self) -> bool {
-		self.queue.is_empty()
+		self.length() == 0
+	}
+
+	pub fn is_full(&self) -> bool {
+		self.remaining_capacity() == 0
+	}
+
+	pub fn peek(&self) -> Option<V> {
+		if self.is_empty() {
+			return None
+		}
+		Items::get(&self.key, self.bounds.head)
+	}
+
+	/// Iterate the items from the head to the tail, without dequeueing them.
+	pub fn iter(&self) -> impl Iterator<Item = V> + '_ {
+		(0..self.length())
+			.filter_map(move |offset| Items::get(&self.key, self.bounds.head.wrapping_add(offset)))
+	}
+
+	pub fn contains(&self, item: V) -> bool {
+		self.iter().any(|queued| queued == item)
+	}
+
+	/// Remove every occurrence of an item, the items behind it move up in their order.
+	pub fn remove(&mut self, item: V) {
+		let mut removed = 0u32;
+		for offset in 0..self.length() {
+			let position = self.bounds.head.wrapping_add(offset);
+			match Items::get(&self.key, position) {
+				Some(queued) if queued == item => {
+					Items::remove(&self.key, position);
+					removed += 1;
+				},
+				Some(queued) if removed > 0 => {
+					Items::insert(&self.key, position.wrapping_sub(removed), queued);
+					Items::remove(&self.key, position);
+				},
+				_ => (),
+			}
+		}
+		if removed > 0 {
+			self.bounds.tail = self.bounds.tail.wrapping_sub(removed);
+			Bounds::insert(&self.key, self.bounds);
+		}
+	}
+
+	/// Remove the queue with all its items from storage.
+	pub fn clear(&mut self) {
+		Items::remove_prefix(&self.key, None);
+		Bounds::remove(&self.key);
+		self.bounds = QueueBounds::default();
+	}
+}
+
+/// Queue with a priority lane in front of its normal lane, items of the priority lane leave the
+/// queue first. Both lanes together hold up to the size of the queue.
+pub struct LanedQueue<K, V, Bounds, Items, PriorityBounds, PriorityItems> {
+	priority: Queue<K, V, PriorityBounds, PriorityItems>,
+	normal: Queue<K, V, Bounds, Items>,
+}
+
+impl<K, V, Bounds, Items, PriorityBounds, PriorityItems>
+	LanedQueue<K, V, Bounds, Items, PriorityBounds, PriorityItems>
+where
+	K: FullCodec + Clone,
+	V: FullCodec + PartialEq + Clone,
+	Bounds: StorageMap<K, QueueBounds, Query = QueueBounds>,
+	Items: StorageDoubleMap<K, u32, V, Query = Option<V>>,
+	PriorityBounds: StorageMap<K, QueueBounds, Query = QueueBounds>,
+	PriorityItems: StorageDoubleMap<K, u32, V, Query = Option<V>>,
+{
+	pub fn new(key: K, size: u32) -> Self {
+		LanedQueue { priority: Queue::new(key.clone(), size), normal: Queue::new(key, size) }
+	}
+
+	/// Enqueue an item into the normal lane.
+	pub fn enqueue(&mut self, item: V) -> bool {
+		self.length() < self.max_size() && self.normal.enqueue(item)
+	}
+
+	/// Enqueue an item into the priority lane.
+	pub fn enqueue_priority(&mut self, item: V) -> bool {
+		self.length() < self.max_size() && self.priority.enqueue(item)
+	}
+
+	/// Move an item of the normal lane to the back of the priority lane.
+	pub fn promote(&mut self, item: V) -> bool {
+		if !self.normal.contains(item.clone()) {
+			return false
+		}
+		self.normal.remove(item.clone());
+		self.priority.enqueue(item)
+	}
+
+	pub fn dequeue(&mut self) -> Option<V> {
+		self.priority.dequeue().or_else(|| self.normal.dequeue())
+	}
+
+	/// Dequeue up to `max` items, the priority lane first.
+	pub fn dequeue_many(&mut self, max: u32) -> Vec<V> {
+		let mut items = self.priority.dequeue_many(max);
+		items.extend(self.normal.dequeue_many(max.saturating_sub(items.len() as u32)));
+		items
+	}
+
+	pub fn max_size(&self) -> u32 {
+		self.normal.max_size()
+	}
+
+	pub fn length(&self) -> u32 {
+		self.priority.length().saturating_add(self.normal.length())
+	}
+
+	pub fn priority_length(&self) -> u32 {
+		self.priority.length()
+	}
+
+	/// Items that can still be enqueued, in either lane.
+	pub fn remaining_capacity(&self) -> u32 {
+		self.max_size().saturating_sub(self.length())
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.length() == 0
+	}
+
+	pub fn is_full(&self) -> bool {
+		self.remaining_capacity() == 0
+	}
+
+	/// Peek the head of the priority lane, or of the normal lane once the priority lane is empty.
+	pub fn peek(&self) -> Option<V> {
+		self.priority.peek().or_else(|| self.normal.peek())
+	}
+
+	/// Iterate the items in the order they leave the queue, without dequeueing them.
+	pub fn iter(&self) -> impl Iterator<Item = V> + '_ {
+		self.priority.iter().chain(self.normal.iter())
 	}
 
-	pub fn peek(&self) -> Option<&T> {
-		self.queue.first()
+	pub fn contains(&self, item: V) -> bool {
+		self.iter().any(|queued| queued == item)
 	}
 
-	pub fn contains(&self, item: T) -> bool {
-		self.queue.contains(&item)
+	/// Remove every occurrence of an item from either lane, the items behind it move up.
+	pub fn remove(&mut self, item: V) {
+		self.priority.remove(item.clone());
+		self.normal.remove(item);
 	}
 
-	pub fn remove(&mut self, item: T) {
-		self.queue.retain(|x| x != &item)
+	/// Remove both lanes with all their items from storage.
+	pub fn clear(&mut self) {
+		self.priority.clear();
+		self.normal.clear();
 	}
 }
@@ -0,0 +1,68 @@
+use super::*;
+
+/// Reward points the players of a settled game earn.
+#[derive(
+	Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo,
+)]
+pub struct RewardPoints {
+	/// Points of every player of the game.
+	pub participation: u32,
+	/// Points of the winner on top.
+	pub win: u32,
+	/// Points of the other players on top, for games with a winner.
+	pub loss: u32,
+	/// Points of the winner on top for every previous win of its streak.
+	pub streak_bonus: u32,
+	/// Previous wins of a streak the bonus is paid for at most.
+	pub max_streak: u32,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Reward the players of a settled game and hand the points to the `RewardHandler`. A win
+	/// extends the win streak of the player for the game engine, draws and losses end it.
+	/// Aborted games earn nothing.
+	pub(crate) fn reward_players(
+		game_hash: &T::Hash,
+		game_engine: &GameEngine,
+		players: &[T::AccountId],
+		outcome: &GameOutcomeOf<T>,
+	) {
+		if matches!(outcome, GameOutcome::Aborted) {
+			return
+		}
+
+		let reward_points = T::RewardPoints::get();
+		let winner = outcome.winner();
+		let mut rewards = Vec::new();
+		for player in players.iter() {
+			let mut points = reward_points.participation;
+			let streak = if winner == Some(player) {
+				let previous_wins = Self::win_streak(player, game_engine.id);
+				let bonus = reward_points
+					.streak_bonus
+					.saturating_mul(previous_wins.min(reward_points.max_streak));
+				points = points.saturating_add(reward_points.win).saturating_add(bonus);
+				previous_wins.saturating_add(1)
+			} else {
+				if winner.is_some() {
+					points = points.saturating_add(reward_points.loss);
+				}
+				0
+			};
+
+			if streak == 0 {
+				<WinStreaks<T>>::remove(player, game_engine.id);
+			} else {
+				<WinStreaks<T>>::insert(player, game_engine.id, streak);
+			}
+			<LifetimePoints<T>>::mutate(player, |lifetime| {
+				*lifetime = lifetime.saturating_add(points.into())
+			});
+			T::RewardHandler::on_reward(player, game_engine, points, streak);
+			rewards.push((player.clone(), points));
+		}
+
+		// Emit an event.
+		Self::deposit_event(Event::PlayersRewarded(*game_hash, rewards));
+	}
+}
@@ -1,25 +1,39 @@
 use super::*;
 
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
-
-#[test]
-fn it_works_for_default_value() {
-	new_test_ext().execute_with(|| {
-		// Dispatch a signed extrinsic.
-		assert_ok!(Registry::do_something(Origin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(Registry::something(), Some(42));
-	});
-}
-
-#[test]
-fn correct_error_for_none_value() {
-	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(Registry::cause_error(Origin::signed(1)), Error::<Test>::NoneValue);
-	});
-}
+use crate::{
+	migrations::v5::OldGameState,
+	mock::*,
+	traits::{
+		CreateGame, CreateWageredGame, DeterministicGameIds, GameIdGenerator, GameRegistryProvider,
+		RandomGameIds,
+	},
+	Error,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	inherent::{InherentData, ProvideInherent},
+	storage::unhashed,
+	traits::{Get, GetStorageVersion, Hooks, ReservableCurrency, StorageVersion},
+	unsigned::ValidateUnsigned,
+};
+use proptest::{
+	arbitrary::any,
+	collection, prop_oneof, proptest,
+	strategy::{Just, Strategy},
+};
+use sp_core::{
+	offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	H256,
+};
+use sp_runtime::{
+	traits::{BadOrigin, BlakeTwo256},
+	transaction_validity::{InvalidTransaction, TransactionPriority, TransactionSource},
+	Perbill,
+};
+use std::collections::VecDeque;
 
 #[test]
 fn regsitry_test() {
@@ -41,18 +55,30 @@ fn regsitry_test() {
 		// start from block 100
 		run_to_block(current_block);
 
+		assert_ok!(Registry::register_game_engine(
+			Origin::signed(FOUNDER),
+			game_engine1.clone(),
+			0
+		));
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			tee,
+			vec![game_engine1.clone()],
+			None
+		));
+
 		let queue_test1 = Registry::game_queues(&game_engine1);
 		assert_eq!(queue_test1.length(), 0);
 
 		// queue up matchmaker first player
-		assert_ok!(Registry::queue(Origin::signed(player1)));
+		assert_ok!(Registry::queue(Origin::signed(player1), 1, None, None));
 
 		run_next_block();
 		current_block = current_block + 1;
 		assert_eq!(System::block_number(), current_block);
 
 		// queue up matchmaker second player
-		assert_ok!(Registry::queue(Origin::signed(player2)));
+		assert_ok!(Registry::queue(Origin::signed(player2), 1, Some(1), None));
 
 		run_next_block();
 		current_block = current_block + 1;
@@ -95,20 +121,6382 @@ fn regsitry_test() {
 		run_next_block();
 
 		// finish game
-		assert_ok!(Registry::finish_game(Origin::signed(tee), game_hash.clone(), player1.clone()));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(tee),
+			game_hash.clone(),
+			GameOutcome::Winner(player1.clone()),
+			final_state(),
+			None,
+			None,
+			None
+		));
 
 		// check correct game state
-		let game_entry4 = Registry::game_registry(&game_hash);
-		assert_eq!(game_entry4.game_state, GameState::Finished(player1));
+		let game_entry4 = Registry::archived_game(&game_hash).unwrap();
+		assert_eq!(game_entry4.game_state, GameState::Finished(GameOutcome::Winner(player1)));
+		assert_eq!(<Registry as GameRegistryProvider<_, _>>::winner(&game_hash), Some(player1));
+		assert_eq!(<Registry as GameRegistryProvider<_, _>>::tee(&game_hash), Some(tee));
+		let mut game_players = <Registry as GameRegistryProvider<_, _>>::players(&game_hash);
+		game_players.sort();
+		assert_eq!(game_players, players);
 
 		// drop game
 		assert_ok!(Registry::drop_game(
 			Origin::signed(tee),
 			game_hash.clone(),
-			game_engine1.clone()
+			game_engine1.clone(),
+			DropReason::NoShow
 		));
 
+		assert_eq!(
+			observed(),
+			vec![
+				("created", *game_hash),
+				("state_changed", *game_hash),
+				("state_changed", *game_hash),
+				("started", *game_hash),
+				("state_changed", *game_hash),
+				("finished", *game_hash),
+				("state_changed", *game_hash),
+			]
+		);
+
 		let game_entry5 = Registry::game_registry(&game_hash);
 		assert_eq!(game_entry5.game_state, GameState::None);
+		assert_eq!(<Registry as GameRegistryProvider<_, _>>::game_state(&game_hash), None);
+	});
+}
+
+#[test]
+fn engine_versions_are_matched_separately() {
+	new_test_ext().execute_with(|| {
+		let engine_v1 = GameEngine::new(1, 1);
+		let engine_v2 = GameEngine::new(1, 2);
+
+		run_to_block(10);
+
+		assert_noop!(
+			Registry::register_game_engine(Origin::signed(1), engine_v1.clone(), 0),
+			BadOrigin
+		);
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::EngineNotRegistered
+		);
+
+		register_engine(&engine_v1, 0);
+		assert_noop!(
+			Registry::register_game_engine(Origin::signed(FOUNDER), engine_v2.clone(), 0),
+			Error::<Test>::BracketInUse
+		);
+		register_engine(&engine_v2, 1);
+		assert_eq!(Registry::latest_engine_version(1), Some(2));
+
+		// one player on each version, no match possible
+		assert_ok!(Registry::queue(Origin::signed(1), 1, Some(1), None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine_v1).length(), 0);
+		assert_eq!(Registry::game_queues(&engine_v2).length(), 0);
+
+		// second player on the latest version
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine_v1).length(), 0);
+		assert_eq!(Registry::game_queues(&engine_v2).length(), 1);
+
+		let game_hash = Registry::game_queues(&engine_v2).peek().unwrap().clone();
+		assert_eq!(Registry::game_registry(&game_hash).game_engine, engine_v2);
+		assert_eq!(
+			Registry::queued_players(1).map(|queue_info| queue_info.game_engine),
+			Some(engine_v1)
+		);
+		assert_eq!(Registry::queued_players(3), None);
+	});
+}
+
+#[test]
+fn upgrade_game_engine_moves_waiting_games() {
+	new_test_ext().execute_with(|| {
+		let engine_v1 = GameEngine::new(1, 1);
+		let engine_v2 = GameEngine::new(1, 2);
+
+		run_to_block(10);
+
+		register_engine(&engine_v1, 0);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine_v1).length(), 1);
+		let game_hash = Registry::game_queues(&engine_v1).peek().unwrap().clone();
+
+		assert_noop!(
+			Registry::upgrade_game_engine(
+				Origin::signed(FOUNDER),
+				engine_v1.clone(),
+				engine_v2.clone()
+			),
+			Error::<Test>::EngineNotRegistered
+		);
+		register_engine(&engine_v2, 1);
+		assert_ok!(Registry::upgrade_game_engine(
+			Origin::signed(FOUNDER),
+			engine_v1.clone(),
+			engine_v2.clone()
+		));
+
+		assert_eq!(Registry::game_queues(&engine_v1).length(), 0);
+		assert_eq!(Registry::game_queues(&engine_v2).length(), 1);
+		assert_eq!(Registry::game_registry(&game_hash).game_engine, engine_v2);
+		assert_noop!(
+			Registry::queue(Origin::signed(3), 1, Some(1), None),
+			Error::<Test>::EngineDrained
+		);
+	});
+}
+
+#[test]
+fn game_engines_are_not_upgraded_to_themselves() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::upgrade_game_engine(Origin::signed(FOUNDER), engine.clone(), engine.clone()),
+			Error::<Test>::SameGameEngine
+		);
+
+		// the waiting games stay in their queue
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+		assert_noop!(
+			Registry::upgrade_game_engine(Origin::signed(FOUNDER), engine.clone(), engine.clone()),
+			Error::<Test>::SameGameEngine
+		);
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+	});
+}
+
+#[test]
+fn current_engine_stops_at_successor_cycles() {
+	new_test_ext().execute_with(|| {
+		let engine_v1 = GameEngine::new(1, 1);
+		let engine_v2 = GameEngine::new(1, 2);
+
+		register_engine(&engine_v1, 0);
+		register_engine(&engine_v2, 1);
+		SupportedEngines::<Test>::mutate(&engine_v1, |engine_info| {
+			engine_info.as_mut().unwrap().successor = Some(engine_v1.clone())
+		});
+		assert_eq!(Registry::current_engine(engine_v1.clone()), engine_v1);
+
+		SupportedEngines::<Test>::mutate(&engine_v1, |engine_info| {
+			engine_info.as_mut().unwrap().successor = Some(engine_v2.clone())
+		});
+		SupportedEngines::<Test>::mutate(&engine_v2, |engine_info| {
+			engine_info.as_mut().unwrap().successor = Some(engine_v1.clone())
+		});
+		assert_eq!(Registry::current_engine(engine_v1.clone()), engine_v2);
+	});
+}
+
+#[test]
+fn maintenance_window_pauses_engine() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::schedule_maintenance(Origin::signed(FOUNDER), engine.clone(), 12, 5));
+		assert_noop!(
+			Registry::set_engine_maintenance(Origin::signed(FOUNDER), engine.clone(), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		run_to_block(12);
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::EngineInMaintenance
+		);
+
+		run_to_block(17);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+	});
+}
+
+#[test]
+fn colliding_maintenance_windows_are_not_scheduled() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::schedule_maintenance(Origin::signed(FOUNDER), engine.clone(), 12, 5));
+
+		// the window ending as the other one starts isn't started either
+		assert_noop!(
+			Registry::schedule_maintenance(Origin::signed(FOUNDER), engine.clone(), 11, 1),
+			Error::<Test>::MaintenanceScheduleFailed
+		);
+		run_to_block(11);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+
+		run_to_block(17);
+		assert!(!Registry::in_maintenance(&engine));
+	});
+}
+
+#[test]
+fn drain_game_queue_drops_waiting_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+
+		assert_noop!(Registry::drain_game_queue(Origin::signed(1), engine.clone()), BadOrigin);
+		assert_ok!(Registry::drain_game_queue(Origin::signed(FOUNDER), engine.clone()));
+
+		assert_eq!(Registry::game_queues(&engine).length(), 0);
+		assert!(!GameRegistry::<Test>::contains_key(&game_hash));
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameDrained(
+			engine, game_hash,
+		)));
+	});
+}
+
+#[test]
+fn ack_game_requires_capable_tee() {
+	new_test_ext().execute_with(|| {
+		let engine_v1 = GameEngine::new(1, 1);
+		let engine_v2 = GameEngine::new(1, 2);
+		let tee: u64 = 8u64;
+
+		run_to_block(10);
+
+		register_engine(&engine_v1, 0);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let games = vec![Registry::game_queues(&engine_v1).peek().unwrap().clone()];
+
+		assert_noop!(
+			Registry::ack_game(Origin::signed(tee), engine_v1.clone(), games.clone()),
+			Error::<Test>::NotRegisteredTee
+		);
+
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			tee,
+			vec![engine_v2.clone()],
+			None
+		));
+		assert_noop!(
+			Registry::ack_game(Origin::signed(tee), engine_v1.clone(), games.clone()),
+			Error::<Test>::EngineNotSupported
+		);
+
+		assert_ok!(Registry::update_tee_engines(Origin::signed(tee), vec![engine_v1.clone()]));
+		assert_ok!(Registry::ack_game(Origin::signed(tee), engine_v1.clone(), games));
+
+		assert_ok!(Registry::deregister_tee(Origin::signed(FOUNDER), tee));
+		assert_eq!(Registry::registered_tees(tee), None);
+	});
+}
+
+#[test]
+fn queue_requires_min_tees() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		assert_ok!(Registry::register_game_engine(Origin::signed(FOUNDER), engine.clone(), 0));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::EngineUnavailable
+		);
+
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			TEE,
+			vec![engine.clone(), engine.clone()],
+			None
+		));
+		assert_eq!(Registry::engine_tee_count(&engine), 1);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+
+		assert_ok!(Registry::deregister_tee(Origin::signed(FOUNDER), TEE));
+		assert_eq!(Registry::engine_tee_count(&engine), 0);
+		assert_noop!(
+			Registry::queue(Origin::signed(2), 1, None, None),
+			Error::<Test>::EngineUnavailable
+		);
+	});
+}
+
+#[test]
+fn queue_checks_experience_rules() {
+	new_test_ext().execute_with(|| {
+		let casual = GameEngine::new(1, 1);
+		let ranked = GameEngine::new(2, 1);
+
+		run_to_block(10);
+
+		register_engine(&casual, 0);
+		register_engine(&ranked, 1);
+		assert_ok!(Registry::set_game_rules(
+			Origin::signed(FOUNDER),
+			ranked.clone(),
+			vec![
+				GameRule::new(GameRuleType::MinAccountAge(20), [0u8; 16]),
+				GameRule::new(GameRuleType::MinFinishedGames(1), [0u8; 16]),
+			]
+		));
+
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 2, None, None),
+			Error::<Test>::RequirementNotMet
+		);
+
+		// play a casual game first
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::player_first_seen(1), Some(11));
+		let game_hash = Registry::game_queues(&casual).peek().unwrap().clone();
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), casual.clone(), vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(1),
+			final_state(),
+			None,
+			None,
+			None
+		));
+		assert_eq!(Registry::finished_games(1), 1);
+
+		// account still too young for ranked
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 2, None, None),
+			Error::<Test>::RequirementNotMet
+		);
+
+		run_to_block(31);
+		assert_ok!(Registry::queue(Origin::signed(1), 2, None, None));
+	});
+}
+
+#[test]
+fn queue_checks_balance_rating_and_asset_rules() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let rule = |game_rule_type| GameRule::new(game_rule_type, [0u8; 16]);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::set_game_rule(
+				Origin::signed(1),
+				engine.clone(),
+				rule(GameRuleType::MinRating(1_501))
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Registry::set_game_rule(
+				Origin::signed(FOUNDER),
+				engine.clone(),
+				rule(GameRuleType::PlayersPerGame([0, 2]))
+			),
+			Error::<Test>::InvalidGameRules
+		);
+
+		// rules of the same kind replace each other
+		assert_ok!(Registry::set_game_rule(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			rule(GameRuleType::MinBalance(2 * INITIAL_BALANCE as u128))
+		));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameRulesSet(
+			engine.clone(),
+		)));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), engine.id, None, None),
+			Error::<Test>::RequirementNotMet
+		);
+		assert_ok!(Registry::set_game_rule(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			rule(GameRuleType::MinBalance(500))
+		));
+		assert_ok!(Registry::set_game_rule(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			rule(GameRuleType::MinRating(1_501))
+		));
+		assert_eq!(
+			Registry::game_requirements(&engine).to_vec(),
+			vec![rule(GameRuleType::MinBalance(500)), rule(GameRuleType::MinRating(1_501))]
+		);
+		assert_noop!(
+			Registry::set_game_rule(
+				Origin::signed(FOUNDER),
+				engine.clone(),
+				rule(GameRuleType::RequiredAssetOwnership(7))
+			),
+			Error::<Test>::TooManyGameRules
+		);
+		assert_noop!(
+			Registry::queue(Origin::signed(1), engine.id, None, None),
+			Error::<Test>::RequirementNotMet
+		);
+
+		assert_ok!(Registry::clear_game_rules(Origin::signed(FOUNDER), engine.clone()));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameRulesCleared(
+			engine.clone(),
+		)));
+		assert!(Registry::game_requirements(&engine).is_empty());
+
+		// games are only created for players meeting the rules
+		assert_ok!(Registry::set_game_rule(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			rule(GameRuleType::RequiredAssetOwnership(7))
+		));
+		give_asset(1, 7);
+		assert_noop!(
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2]),
+			Error::<Test>::RequirementNotMet
+		);
+		give_asset(2, 7);
+		assert_ok!(<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2]));
+
+		// players no longer meeting the rules are taken out of matchmaking
+		assert_ok!(Registry::set_game_rule(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			rule(GameRuleType::MinBalance(500))
+		));
+		give_asset(3, 7);
+		give_asset(4, 7);
+		assert_ok!(Registry::queue(Origin::signed(3), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(4), engine.id, None, None));
+		assert_ok!(Balances::transfer(Origin::signed(3), 5, 600));
+		run_next_block();
+		System::assert_has_event(mock::Event::Registry(crate::Event::PlayerDequeued(
+			3,
+			DequeueReason::RequirementNotMet,
+		)));
+		assert!(Registry::queued_players(3).is_none());
+		assert!(Registry::queued_players(4).is_some());
+	});
+}
+
+#[test]
+fn queue_bond_is_released_on_accept_and_slashed_on_drop() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_eq!(Balances::reserved_balance(1), QueueBond::get());
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::AlreadyQueued
+		);
+		assert_noop!(
+			Registry::queue(Origin::signed(11), 1, None, None),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+
+		// bonds are held till the ready-check
+		assert_eq!(Balances::reserved_balance(1), QueueBond::get());
+		assert_noop!(
+			Registry::accept_match(Origin::signed(3), game_hash),
+			Error::<Test>::NotAPlayer
+		);
+		assert_ok!(Registry::accept_match(Origin::signed(1), game_hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Registry::queue_bonds(1), None);
+
+		// player 2 never showed up
+		let founder_balance = Balances::free_balance(FOUNDER);
+		assert_ok!(Registry::drop_game(Origin::signed(TEE), game_hash, engine, DropReason::NoShow));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - QueueBond::get());
+		assert_eq!(Balances::free_balance(FOUNDER), founder_balance + QueueBond::get());
+	});
+}
+
+#[test]
+fn players_are_matched_with_a_shared_client_version() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let versions = |min, max| Some(ClientVersions { min, max });
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::set_engine_client_versions(Origin::signed(1), engine.clone(), versions(2, 5)),
+			BadOrigin
+		);
+		assert_noop!(
+			Registry::set_engine_client_versions(
+				Origin::signed(FOUNDER),
+				engine.clone(),
+				versions(5, 2)
+			),
+			Error::<Test>::InvalidClientVersions
+		);
+		assert_ok!(Registry::set_engine_client_versions(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			versions(2, 5)
+		));
+		System::assert_last_event(mock::Event::Registry(crate::Event::EngineClientVersionsSet(
+			engine.clone(),
+			versions(2, 5),
+		)));
+
+		// clients sharing no version with the game engine version can't queue
+		assert_ok!(Registry::set_client_versions(Origin::signed(1), versions(6, 8)));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::ClientNotSupported
+		);
+
+		assert_ok!(Registry::set_client_versions(Origin::signed(1), versions(1, 3)));
+		assert_ok!(Registry::set_client_versions(Origin::signed(2), versions(3, 4)));
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		assert_eq!(Registry::game_client_version(&game_hash), Some(3));
+
+		// players without a shared client version stay in matchmaking
+		assert_ok!(Registry::set_client_versions(Origin::signed(3), versions(2, 2)));
+		assert_ok!(Registry::set_client_versions(Origin::signed(4), versions(4, 5)));
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(4), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+		assert!(Registry::queued_players(3).is_some());
+		assert!(Registry::queued_players(4).is_some());
+
+		assert_ok!(Registry::set_client_versions(Origin::signed(4), None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 2);
+		let game_hash = Registry::game_queues(&engine).iter().last().unwrap();
+		assert_eq!(Registry::game_client_version(&game_hash), Some(2));
+	});
+}
+
+#[test]
+fn engines_lists_registered_engines() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		assert_ok!(Registry::register_game_engine(Origin::signed(FOUNDER), engine.clone(), 0));
+		let engines = Registry::engines();
+		assert_eq!(engines.len(), 1);
+		assert_eq!(engines[0].game_engine, engine);
+		assert!(!engines[0].enabled);
+
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			TEE,
+			vec![engine.clone()],
+			None
+		));
+		let engines = Registry::engines();
+		assert!(engines[0].enabled);
+		assert_eq!(engines[0].tee_coverage, 1);
+		assert_eq!(engines[0].queue_depth, 0);
+	});
+}
+
+#[test]
+fn time_controls_are_matched_separately() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let blitz = TimeControl::new(*b"blitz\0\0\0", 180, 2, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::add_time_control(
+				Origin::signed(FOUNDER),
+				engine.clone(),
+				TimeControl::new(*b"long\0\0\0\0", 3600, 0, 0)
+			),
+			Error::<Test>::BracketInUse
+		);
+		assert_ok!(Registry::add_time_control(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			blitz.clone()
+		));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, Some(1)),
+			Error::<Test>::NoTimeControl
+		);
+
+		// one player without preset, one with blitz
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, Some(0)));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 0);
+
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, Some(0)));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		assert_eq!(Registry::game_registry(&game_hash).time_control, Some(blitz));
+	});
+}
+
+#[test]
+fn create_game_queues_game_for_tees() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		assert_noop!(
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2]),
+			Error::<Test>::EngineNotRegistered
+		);
+		register_engine(&engine, 0);
+		assert_noop!(
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![]),
+			Error::<Test>::NoPlayers
+		);
+
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2, 3])
+			.expect("engine is registered");
+		assert_eq!(Registry::game_queues(&engine).peek(), Some(game_hash));
+		assert_eq!(Registry::game_registry(&game_hash).players.to_vec(), vec![1, 2, 3]);
+		assert_eq!(Registry::game_registry(&game_hash).game_state, GameState::Waiting);
+	});
+}
+
+#[test]
+fn ready_game_dispatches_game_start_call() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(BOARD_ENGINE_ID, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine, vec![game_hash]));
+		assert_eq!(sp_io::storage::get(&1u64.encode()), None);
+
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_eq!(sp_io::storage::get(&1u64.encode()), Some(game_hash.encode()));
+		assert_eq!(sp_io::storage::get(&2u64.encode()), Some(game_hash.encode()));
+	});
+}
+
+#[test]
+fn foreign_players_keep_their_location() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let location = FOREIGN_LOCATIONS as u32;
+		let foreign_player = FOREIGN_ACCOUNTS + FOREIGN_LOCATIONS;
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::queue_foreign(Origin::signed(1), 1, None, None),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Registry::queue_foreign(Origin::signed(FOREIGN_LOCATIONS), 1, None, None));
+		assert_eq!(Registry::foreign_players(foreign_player), Some(location));
+		assert_eq!(Balances::reserved_balance(foreign_player), QueueBond::get());
+
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		run_next_block();
+
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		let game_entry = Registry::game_registry(&game_hash);
+		assert!(game_entry.players.contains(&foreign_player));
+		assert_eq!(game_entry.foreign_players.to_vec(), vec![(foreign_player, location)]);
+		assert_eq!(Registry::foreign_players(foreign_player), None);
+
+		// result is reported back to the foreign chain
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine, vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(foreign_player),
+			final_state(),
+			None,
+			None,
+			None
+		));
+		assert_eq!(
+			NOTIFIED.with(|notified| notified.borrow().clone()),
+			vec![(location, game_hash, GameOutcome::Winner(foreign_player))]
+		);
+	});
+}
+
+#[test]
+fn finished_results_are_committed() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(2),
+			final_state(),
+			None,
+			None,
+			None
+		));
+
+		let leaf = GameResultLeaf {
+			game_hash,
+			game_engine: engine,
+			outcome: GameOutcome::Winner(2u64),
+			block: 10u64,
+		};
+		let leaf_hash = BlakeTwo256::hash_of(&leaf);
+		assert_eq!(Registry::block_result_leaves(), vec![leaf_hash]);
+
+		let canonical_result = Registry::canonical_result(&leaf);
+		assert_eq!(canonical_result.encode().len(), 72);
+		assert_eq!(canonical_result.game_hash, game_hash.0);
+		assert_eq!(canonical_result.outcome, 0);
+		assert_eq!(&canonical_result.winner[..8], &2u64.to_le_bytes());
+		assert_eq!(canonical_result.finished_at, 10);
+		assert_eq!(
+			Registry::canonical_result_hashes(&game_hash),
+			Some(sp_io::hashing::keccak_256(&canonical_result.encode()))
+		);
+
+		run_next_block();
+		assert_eq!(Registry::block_result_leaves(), vec![]);
+		assert_eq!(Registry::results_commitment(), BlakeTwo256::hash_of(&vec![leaf_hash]));
+		assert_eq!(
+			<Registry as pallet_mmr_primitives::LeafDataProvider>::leaf_data(),
+			Registry::results_commitment()
+		);
+
+		run_next_block();
+		assert_eq!(Registry::results_commitment(), H256::default());
+	});
+}
+
+#[test]
+fn game_queries_find_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game1 = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let game2 = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![2, 3])
+			.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game1]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game1));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game1,
+			GameOutcome::Winner(1),
+			final_state(),
+			None,
+			None,
+			None
+		));
+
+		assert_eq!(Registry::games_of(1), vec![game1]);
+		assert_eq!(Registry::games_of(2).len(), 2);
+		assert_eq!(
+			Registry::games_by_state(GameState::Finished(GameOutcome::Winner(0))),
+			vec![game1]
+		);
+		assert_eq!(Registry::games_by_state(GameState::Waiting), vec![game2]);
+		assert_eq!(Registry::queue_lengths(), vec![(engine, 1)]);
+	});
+}
+
+#[test]
+fn finished_games_are_exported_to_offchain_index() {
+	let mut ext = new_test_ext();
+	let (game_hash, engine) = ext.execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(2),
+			final_state(),
+			None,
+			None,
+			None
+		));
+		assert_eq!(Registry::block_finished_games(), vec![game_hash]);
+
+		run_next_block();
+		assert_eq!(Registry::block_finished_games(), vec![]);
+		(game_hash, engine)
+	});
+	ext.persist_offchain_overlay();
+
+	let offchain_db = ext.offchain_db();
+	let game_record = GameRecord {
+		game_hash,
+		game_engine: engine,
+		players: vec![1u64, 2],
+		outcome: GameOutcome::Winner(2u64),
+		block: 10u64,
+	};
+	assert_eq!(offchain_db.get(&Registry::game_record_key(&game_hash)), Some(game_record.encode()));
+	assert_eq!(offchain_db.get(&Registry::block_record_key(10)), Some(vec![game_hash].encode()));
+}
+
+#[test]
+fn match_history_keeps_the_latest_results() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let first = play_game(&engine, vec![1, 2], 1);
+		assert_eq!(
+			Registry::match_history(1).to_vec(),
+			vec![MatchResult {
+				game_hash: first,
+				game_engine: engine.clone(),
+				opponents: BoundedVec::try_from(vec![2]).expect("fits the game"),
+				outcome: GameOutcome::Winner(1),
+				block: 10,
+			}]
+		);
+		assert_eq!(Registry::match_history(2)[0].opponents, vec![1]);
+
+		// the oldest result is pruned from a full history
+		run_next_block();
+		let second = play_game(&engine, vec![1, 3], 3);
+		let third = play_game(&engine, vec![1, 2], 2);
+		let games: Vec<_> =
+			Registry::match_history(1).iter().map(|result| result.game_hash).collect();
+		assert_eq!(games, vec![second, third]);
+		assert_eq!(Registry::match_history(1)[1].outcome, GameOutcome::Winner(2));
+		assert_eq!(Registry::match_history(2).len(), 2);
+		assert_eq!(Registry::match_history(3).len(), 1);
 	});
 }
+
+#[test]
+fn offchain_worker_builds_full_leaderboard() {
+	let mut ext = new_test_ext();
+	let (offchain, _state) = TestOffchainExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+
+	ext.execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		for (players, winner) in [(vec![1, 2], 1), (vec![3, 4], 3), (vec![1, 3], 1)] {
+			let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), players)
+				.expect("engine is registered");
+			assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]));
+			assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+			assert_ok!(Registry::finish_game(
+				Origin::signed(TEE),
+				game_hash,
+				GameOutcome::Winner(winner),
+				final_state(),
+				None,
+				None,
+				None
+			));
+		}
+
+		// only the top players are kept on chain
+		assert_eq!(Registry::leaderboard(), vec![(1, 2), (3, 1)]);
+
+		let entry =
+			|player, wins, games, percentile| LeaderboardEntry { player, wins, games, percentile };
+		let leaderboard =
+			vec![entry(1, 2, 2, 75), entry(3, 1, 2, 50), entry(4, 0, 1, 0), entry(2, 0, 1, 0)];
+		assert_eq!(Registry::build_leaderboard(), leaderboard);
+
+		let stored = || {
+			StorageValueRef::persistent(OFFCHAIN_LEADERBOARD_KEY)
+				.get::<Vec<LeaderboardEntry<u64>>>()
+				.expect("leaderboard decodes")
+		};
+		<Registry as Hooks<u64>>::offchain_worker(11);
+		assert_eq!(stored(), None);
+		<Registry as Hooks<u64>>::offchain_worker(15);
+		assert_eq!(stored(), Some(leaderboard));
+
+		// a zero interval switches the leaderboard off
+		LeaderboardInterval::set(0);
+		StorageValueRef::persistent(OFFCHAIN_LEADERBOARD_KEY).clear();
+		<Registry as Hooks<u64>>::offchain_worker(20);
+		assert_eq!(stored(), None);
+	});
+}
+
+#[test]
+fn session_keys_play_on_behalf_of_main_account() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let session = 20;
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::set_session_key(Origin::signed(1), session));
+		assert_eq!(Registry::session_keys(session), None);
+		assert_noop!(
+			Registry::accept_session_key(Origin::signed(session), 2),
+			Error::<Test>::NoSessionKeyProposal
+		);
+		assert_ok!(Registry::accept_session_key(Origin::signed(session), 1));
+		System::assert_last_event(mock::Event::Registry(crate::Event::SessionKeySet(1, session)));
+		assert_noop!(
+			Registry::set_session_key(Origin::signed(2), session),
+			Error::<Test>::SessionKeyInUse
+		);
+
+		// bond is reserved from the main account
+		assert_ok!(Registry::queue(Origin::signed(session), 1, None, None));
+		assert_eq!(Balances::reserved_balance(1), QueueBond::get());
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+
+		assert_ok!(Registry::accept_match(Origin::signed(session), game_hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		assert_noop!(
+			Registry::concede(Origin::signed(session), game_hash),
+			Error::<Test>::GameNotRunning
+		);
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine, vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_noop!(Registry::concede(Origin::signed(3), game_hash), Error::<Test>::NotAPlayer);
+		assert_ok!(Registry::concede(Origin::signed(session), game_hash));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameConceded(game_hash, 1)));
+
+		assert_noop!(
+			Registry::remove_session_key(Origin::signed(2), session),
+			Error::<Test>::NotSessionKeyOwner
+		);
+		assert_ok!(Registry::remove_session_key(Origin::signed(1), session));
+		assert_eq!(Registry::session_keys(session), None);
+		assert_eq!(Registry::session_key_count(1), 0);
+	});
+}
+
+#[test]
+fn accounts_cant_be_claimed_as_session_keys() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let (attacker, victim) = (3, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::set_session_key(Origin::signed(attacker), attacker),
+			Error::<Test>::InvalidSessionKey
+		);
+
+		// a proposal doesn't make the victim play on behalf of the attacker
+		assert_ok!(Registry::set_session_key(Origin::signed(attacker), victim));
+		assert_eq!(Registry::session_keys(victim), None);
+		assert_ok!(Registry::queue(Origin::signed(victim), engine.id, None, None));
+		assert_eq!(Balances::reserved_balance(victim), QueueBond::get());
+		assert_eq!(Balances::reserved_balance(attacker), 0);
+
+		// accounts playing themselves can't become session keys
+		assert_noop!(
+			Registry::accept_session_key(Origin::signed(victim), attacker),
+			Error::<Test>::SessionKeyIsPlayer
+		);
+		assert_ok!(Registry::set_session_key(Origin::signed(victim), 20));
+		assert_ok!(Registry::accept_session_key(Origin::signed(20), victim));
+		assert_ok!(Registry::set_session_key(Origin::signed(2), 22));
+		assert_ok!(Registry::accept_session_key(Origin::signed(22), 2));
+		assert_ok!(Registry::set_session_key(Origin::signed(attacker), 2));
+		assert_noop!(
+			Registry::accept_session_key(Origin::signed(2), attacker),
+			Error::<Test>::SessionKeyIsPlayer
+		);
+
+		// session keys can't propose session keys of their own, and leave their main account
+		assert_noop!(
+			Registry::set_session_key(Origin::signed(20), 21),
+			Error::<Test>::InvalidSessionKey
+		);
+		assert_ok!(Registry::remove_session_key(Origin::signed(20), 20));
+		System::assert_last_event(mock::Event::Registry(crate::Event::SessionKeyRemoved(
+			victim, 20,
+		)));
+	});
+}
+
+#[test]
+fn register_tee_requires_attestation() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = UNATTESTED_TEE;
+
+		run_to_block(10);
+
+		assert_ok!(Registry::register_game_engine(Origin::signed(FOUNDER), engine.clone(), 0));
+		assert_noop!(
+			Registry::register_tee(Origin::signed(FOUNDER), tee, vec![engine.clone()], None),
+			Error::<Test>::TeeNotAttested
+		);
+		assert_noop!(
+			Registry::register_tee(
+				Origin::signed(FOUNDER),
+				tee,
+				vec![engine.clone()],
+				Some(b"forged".to_vec())
+			),
+			DispatchError::Other("invalid quote")
+		);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			tee,
+			vec![engine.clone()],
+			Some(VALID_QUOTE.to_vec())
+		));
+		assert_eq!(Registry::registered_tees(tee).map(|tee_info| tee_info.enclave), Some([1; 32]));
+
+		// attested by the runtime already
+		assert_ok!(Registry::register_tee(Origin::signed(FOUNDER), TEE, vec![engine], None));
+		assert_eq!(Registry::registered_tees(TEE).map(|tee_info| tee_info.enclave), Some([7; 32]));
+	});
+}
+
+#[test]
+fn play_game_runs_the_full_lifecycle() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = play_game(&engine, vec![1, 2], 1);
+		assert_eq!(
+			Registry::archived_game(&game_hash).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(1))
+		);
+		assert_eq!(Registry::games_won(1), 1);
+	});
+}
+
+#[test]
+fn game_results_are_reported_to_handlers() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let finished = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let dropped = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![finished]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), finished));
+		assert_eq!(results(), vec![]);
+
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			finished,
+			GameOutcome::Winner(2),
+			final_state(),
+			None,
+			None,
+			None
+		));
+		assert_ok!(Registry::drop_game(Origin::signed(TEE), dropped, engine, DropReason::NoShow));
+		assert_eq!(
+			results(),
+			vec![
+				(finished, vec![1, 2], GameResult::Won(2)),
+				(dropped, vec![3, 4], GameResult::Dropped)
+			]
+		);
+	});
+}
+
+#[test]
+fn spectators_register_for_a_game() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+
+		assert_ok!(Registry::register_spectator(Origin::signed(3), game_hash));
+		System::assert_last_event(mock::Event::Registry(crate::Event::SpectatorRegistered(
+			game_hash, 3,
+		)));
+		assert_noop!(
+			Registry::register_spectator(Origin::signed(3), game_hash),
+			Error::<Test>::AlreadySpectating
+		);
+		assert_ok!(Registry::register_spectator(Origin::signed(4), game_hash));
+		assert_noop!(
+			Registry::register_spectator(Origin::signed(5), game_hash),
+			Error::<Test>::TooManySpectators
+		);
+		assert_eq!(Registry::spectators(&game_hash).into_inner(), vec![3, 4]);
+
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine, vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(1),
+			final_state(),
+			None,
+			None,
+			None
+		));
+		assert_eq!(Registry::spectators(&game_hash).len(), 0);
+		assert_noop!(
+			Registry::register_spectator(Origin::signed(5), game_hash),
+			Error::<Test>::GameAlreadyFinished
+		);
+	});
+}
+
+#[test]
+fn spectator_policy_is_enforced() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let settings = |policy, max_spectators| SpectatorSettings { policy, max_spectators };
+		assert_noop!(
+			Registry::set_engine_spectator_settings(
+				Origin::signed(FOUNDER),
+				engine.clone(),
+				settings(SpectatorPolicy::Disabled, MaxSpectators::get() + 1)
+			),
+			Error::<Test>::SpectatorCapTooHigh
+		);
+		assert_ok!(Registry::set_engine_spectator_settings(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			settings(SpectatorPolicy::Disabled, 0)
+		));
+
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_noop!(
+			Registry::register_spectator(Origin::signed(3), game_hash),
+			Error::<Test>::SpectatingDisabled
+		);
+
+		// players override the engine default for their game
+		assert_noop!(
+			Registry::set_spectator_settings(
+				Origin::signed(3),
+				game_hash,
+				settings(SpectatorPolicy::Open, 1)
+			),
+			Error::<Test>::NotAPlayer
+		);
+		assert_ok!(Registry::set_spectator_settings(
+			Origin::signed(1),
+			game_hash,
+			settings(SpectatorPolicy::Invited, 1)
+		));
+		assert_noop!(
+			Registry::register_spectator(Origin::signed(3), game_hash),
+			Error::<Test>::NotInvited
+		);
+		assert_ok!(Registry::invite_spectator(Origin::signed(2), game_hash, 3));
+		assert_ok!(Registry::invite_spectator(Origin::signed(2), game_hash, 4));
+		assert_ok!(Registry::register_spectator(Origin::signed(3), game_hash));
+		assert_noop!(
+			Registry::register_spectator(Origin::signed(4), game_hash),
+			Error::<Test>::TooManySpectators
+		);
+
+		assert_ok!(Registry::drop_game(Origin::signed(TEE), game_hash, engine, DropReason::NoShow));
+		assert_eq!(Registry::game_spectator_settings(&game_hash), None);
+		assert_eq!(Registry::spectator_invites(&game_hash, 3), None);
+	});
+}
+
+#[test]
+fn offchain_worker_expires_games_past_timeouts() {
+	let mut ext = new_test_ext();
+	let (offchain, _state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let accepted = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let waiting = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![accepted]));
+
+		run_to_block(14);
+		assert_noop!(Registry::expire_game(Origin::none(), waiting), Error::<Test>::GameNotExpired);
+		assert_noop!(Registry::expire_game(Origin::signed(1), waiting), BadOrigin);
+
+		run_to_block(16);
+		<Registry as Hooks<u64>>::offchain_worker(16);
+		let mut submitted: Vec<_> = pool_state
+			.read()
+			.transactions
+			.iter()
+			.map(|tx| UncheckedExtrinsic::decode(&mut &tx[..]).expect("valid extrinsic").function)
+			.collect();
+		submitted.sort_by_key(|call| call.encode());
+		let mut expected = vec![
+			mock::Call::Registry(crate::Call::expire_game { game_hash: accepted }),
+			mock::Call::Registry(crate::Call::expire_game { game_hash: waiting }),
+		];
+		expected.sort_by_key(|call| call.encode());
+		assert_eq!(submitted, expected);
+		// the scan went through the whole registry and starts over in the next block
+		assert_eq!(
+			StorageValueRef::persistent(OFFCHAIN_EXPIRY_CURSOR_KEY)
+				.get::<Vec<u8>>()
+				.expect("cursor decodes"),
+			None
+		);
+
+		// expiries in the transaction pool aren't submitted twice
+		<Registry as Hooks<u64>>::offchain_worker(17);
+		assert_eq!(pool_state.read().transactions.len(), 2);
+		assert_eq!(
+			StorageValueRef::persistent(&Registry::expiry_lock_key(&waiting))
+				.get::<u64>()
+				.expect("block decodes"),
+			Some(16)
+		);
+		<Registry as Hooks<u64>>::offchain_worker(19);
+		assert_eq!(pool_state.read().transactions.len(), 4);
+
+		// waiting games are dropped
+		assert_ok!(Registry::expire_game(Origin::none(), waiting));
+		assert!(!GameRegistry::<Test>::contains_key(&waiting));
+		assert!(!Registry::game_queues(&engine).contains(waiting));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameExpired(
+			waiting,
+			GameState::Waiting,
+			ExpiryReason::NotAcknowledged,
+		)));
+
+		// accepted games are queued again
+		assert_ok!(Registry::expire_game(Origin::none(), accepted));
+		assert_eq!(Registry::game_registry(&accepted).game_state, GameState::Waiting);
+		assert_eq!(Registry::game_queues(&engine).peek(), Some(accepted));
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameExpired(
+			accepted,
+			GameState::Accepted,
+			ExpiryReason::NotReady,
+		)));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameRequeued(accepted)));
+	});
+}
+
+#[test]
+fn tee_liveness_is_noted_by_inherent() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let mut inherent_data = InherentData::new();
+		assert_eq!(Registry::create_inherent(&inherent_data), None);
+
+		// unregistered AjunaTEEs are left out
+		inherent_data.put_data(inherent::INHERENT_IDENTIFIER, &vec![TEE, 8u64]).unwrap();
+		let call = Registry::create_inherent(&inherent_data).expect("TEE is registered");
+		assert_eq!(call, crate::Call::note_tee_liveness { tees: vec![TEE] });
+		assert!(Registry::is_inherent(&call));
+
+		assert_noop!(Registry::note_tee_liveness(Origin::signed(1), vec![TEE]), BadOrigin);
+		assert_ok!(Registry::note_tee_liveness(Origin::none(), vec![TEE, 8]));
+		assert_eq!(Registry::tee_last_seen(TEE), Some(10));
+		assert_eq!(Registry::tee_last_seen(8), None);
+		assert_noop!(
+			Registry::note_tee_liveness(Origin::none(), vec![TEE]),
+			Error::<Test>::LivenessAlreadyNoted
+		);
+
+		run_next_block();
+		assert_ok!(Registry::note_tee_liveness(Origin::none(), vec![TEE]));
+		assert_eq!(Registry::tee_last_seen(TEE), Some(11));
+	});
+}
+
+#[test]
+fn ready_game_emits_connection_details() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let endpoint = b"wss://tee.ajuna.io:2000".to_vec();
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::set_tee_endpoint(Origin::signed(8), endpoint.clone()),
+			Error::<Test>::NotRegisteredTee
+		);
+		assert_noop!(
+			Registry::set_tee_endpoint(Origin::signed(TEE), vec![0; 129]),
+			Error::<Test>::EndpointTooLong
+		);
+		assert_ok!(Registry::set_tee_endpoint(Origin::signed(TEE), endpoint.clone()));
+
+		// the endpoint survives updates of the supported game engines
+		let other_engine = GameEngine::new(2, 1);
+		register_engine(&other_engine, 1);
+
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameConnection(
+			game_hash,
+			engine,
+			vec![1, 2],
+			TEE,
+			endpoint,
+		)));
+	});
+}
+
+#[test]
+fn registry_is_migrated_over_multiple_blocks() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		// game entries as stored before time controls and foreign players
+		StorageVersion::new(0).put::<Registry>();
+		let games: Vec<H256> = (1..=5).map(H256::repeat_byte).collect();
+		for game_hash in games.iter() {
+			let old_entry = (
+				game_hash,
+				None::<u64>,
+				engine.clone(),
+				vec![1u64, 2],
+				GameStateOf::<Test>::Waiting,
+				[10u64; 4],
+			);
+			unhashed::put(&GameRegistry::<Test>::hashed_key_for(game_hash), &old_entry);
+		}
+
+		<Registry as Hooks<u64>>::on_runtime_upgrade();
+		assert_eq!(Registry::on_chain_storage_version(), 4);
+		assert!(Registry::migration_cursor().is_some());
+		assert_noop!(
+			Registry::drop_game(Origin::signed(TEE), games[0], engine.clone(), DropReason::NoShow),
+			Error::<Test>::MigrationOngoing
+		);
+
+		// two game entries per block
+		run_to_block(12);
+		assert!(Registry::migration_cursor().is_some());
+		run_to_block(13);
+		assert_eq!(Registry::migration_cursor(), None);
+		assert_eq!(Registry::on_chain_storage_version(), 5);
+		System::assert_last_event(mock::Event::Registry(crate::Event::RegistryMigrated));
+
+		for game_hash in games.iter() {
+			let game_entry = Registry::game_entry(game_hash).expect("game entry is migrated");
+			assert_eq!(game_entry.players.to_vec(), vec![1, 2]);
+			assert_eq!(
+				game_entry.timestamps,
+				GameTimestamps { queued: 10, accepted: 10, started: 10, finished: 10 }
+			);
+			assert_eq!(game_entry.time_control, None);
+			assert_eq!(game_entry.result, None);
+		}
+
+		// already migrated
+		<Registry as Hooks<u64>>::on_runtime_upgrade();
+		assert_eq!(Registry::migration_cursor(), None);
+	});
+}
+
+#[test]
+fn deadlines_passed_during_a_migration_are_processed_after_it() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = play_game(&engine, vec![1, 2], 1);
+		assert_ok!(Registry::request_rematch(Origin::signed(1), game_hash));
+
+		// a stepped migration spans the block the rematch request expires in
+		let cursor = MatchHistory::<Test>::final_prefix().to_vec();
+		for block in 11..=10 + RematchWindow::get() + 2 {
+			MigrationCursor::<Test>::put(Registry::storage_cursor(cursor.clone()));
+			run_to_block(block);
+			assert_eq!(Registry::deadline_cursor(), Some(11));
+		}
+		assert_eq!(Registry::rematch_requests(&game_hash).to_vec(), vec![1]);
+
+		// the missed blocks are caught up on once the migration is done
+		run_to_block(10 + RematchWindow::get() + 3);
+		System::assert_has_event(mock::Event::Registry(crate::Event::RematchExpired(game_hash)));
+		assert!(Registry::rematch_requests(&game_hash).is_empty());
+		assert_eq!(Registry::deadline_cursor(), None);
+	});
+}
+
+#[test]
+fn game_entries_get_named_timestamps_and_results() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		// game entries as stored before the named timestamps and results
+		StorageVersion::new(1).put::<Registry>();
+		let finished = H256::repeat_byte(1);
+		let running = H256::repeat_byte(2);
+		for (game_hash, game_state) in
+			[(finished, OldGameState::<u64>::Finished(2)), (running, OldGameState::Running)]
+		{
+			let old_entry = (
+				game_hash,
+				Some(TEE),
+				engine.clone(),
+				vec![1u64, 2],
+				game_state,
+				[1u64, 2, 3, 4],
+				None::<TimeControl>,
+				vec![(1u64, 7u32)],
+			);
+			unhashed::put(&GameRegistry::<Test>::hashed_key_for(game_hash), &old_entry);
+		}
+
+		<Registry as Hooks<u64>>::on_runtime_upgrade();
+		assert_eq!(Registry::on_chain_storage_version(), 4);
+		run_to_block(11);
+		assert_eq!(Registry::migration_cursor(), None);
+		assert_eq!(Registry::on_chain_storage_version(), 5);
+
+		let game_entry = Registry::game_entry(&finished).expect("game entry is migrated");
+		assert_eq!(
+			game_entry.timestamps,
+			GameTimestamps { queued: 1, accepted: 2, started: 3, finished: 4 }
+		);
+		assert_eq!(game_entry.game_state, GameState::Finished(GameOutcome::Winner(2)));
+		assert_eq!(game_entry.result, Some(GameResult::Won(2)));
+		assert_eq!(game_entry.foreign_players.to_vec(), vec![(1, 7)]);
+		let game_entry = Registry::game_entry(&running).expect("game entry is migrated");
+		assert_eq!(game_entry.tee_id, Some(TEE));
+		assert_eq!(game_entry.result, None);
+
+		// finished games carry their result from now on
+		register_engine(&engine, 0);
+		let game_hash = play_game(&engine, vec![3, 4], 4);
+		assert_eq!(Registry::archived_game(&game_hash).unwrap().result, Some(GameResult::Won(4)));
+	});
+}
+
+#[test]
+fn game_queues_are_moved_into_ring_buffers() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		// game queues as stored before their games were stored one by one
+		StorageVersion::new(2).put::<Registry>();
+		let games: Vec<H256> = (1..=3).map(H256::repeat_byte).collect();
+		unhashed::put(&GameQueues::<Test>::hashed_key_for(&engine), &(64u32, games.clone()));
+
+		<Registry as Hooks<u64>>::on_runtime_upgrade();
+		assert_eq!(Registry::on_chain_storage_version(), 5);
+		let mut game_queue = Registry::game_queues(&engine);
+		assert_eq!(game_queue.iter().collect::<Vec<_>>(), games);
+
+		// games leave the queue in order, also after one is taken out of the middle
+		game_queue.remove(games[1]);
+		assert_eq!(game_queue.length(), 2);
+		assert!(game_queue.enqueue(games[1]));
+		assert_eq!(Registry::game_queues(&engine).dequeue(), Some(games[0]));
+		assert_eq!(Registry::game_queues(&engine).dequeue(), Some(games[2]));
+		assert_eq!(Registry::game_queues(&engine).dequeue(), Some(games[1]));
+		assert!(Registry::game_queues(&engine).is_empty());
+		assert_eq!(GameQueueItems::<Test>::iter_prefix(&engine).count(), 0);
+	});
+}
+
+/// Operation on a game queue, generated for the property test of the queues.
+#[derive(Clone, Debug)]
+enum QueueOp {
+	Enqueue(u8),
+	EnqueuePriority(u8),
+	Promote(u8),
+	Dequeue,
+	DequeueMany(u32),
+	Remove(u8),
+}
+
+/// Operations on a few distinct games, so games are queued more than once.
+fn queue_op() -> impl Strategy<Value = QueueOp> {
+	prop_oneof![
+		4 => (0u8..8).prop_map(QueueOp::Enqueue),
+		2 => (0u8..8).prop_map(QueueOp::EnqueuePriority),
+		1 => (0u8..8).prop_map(QueueOp::Promote),
+		2 => Just(QueueOp::Dequeue),
+		1 => (0u32..8).prop_map(QueueOp::DequeueMany),
+		2 => (0u8..8).prop_map(QueueOp::Remove),
+	]
+}
+
+proptest! {
+	#[test]
+	fn game_queues_behave_like_two_lanes_in_order(
+		start in prop_oneof![Just(0u32), Just(u32::MAX - 3), any::<u32>()],
+		ops in collection::vec(queue_op(), 0..200),
+	) {
+		new_test_ext().execute_with(|| {
+			let engine = GameEngine::new(1, 1);
+			let game = |byte: u8| H256::repeat_byte(byte);
+			let max_size = MaxQueueSize::get() as usize;
+
+			// queues start anywhere, their positions wrap around
+			GameQueues::<Test>::insert(&engine, QueueBounds { head: start, tail: start });
+			PriorityGameQueues::<Test>::insert(&engine, QueueBounds { head: start, tail: start });
+			let mut priority = VecDeque::new();
+			let mut normal = VecDeque::new();
+
+			for op in ops {
+				let mut game_queue = Registry::game_queues(&engine);
+				let full = priority.len() + normal.len() >= max_size;
+				match op {
+					QueueOp::Enqueue(byte) => {
+						assert_eq!(game_queue.enqueue(game(byte)), !full);
+						if !full {
+							normal.push_back(game(byte));
+						}
+					},
+					QueueOp::EnqueuePriority(byte) => {
+						assert_eq!(game_queue.enqueue_priority(game(byte)), !full);
+						if !full {
+							priority.push_back(game(byte));
+						}
+					},
+					QueueOp::Promote(byte) => {
+						let queued = normal.contains(&game(byte));
+						assert_eq!(game_queue.promote(game(byte)), queued);
+						if queued {
+							normal.retain(|queued| *queued != game(byte));
+							priority.push_back(game(byte));
+						}
+					},
+					QueueOp::Dequeue => assert_eq!(
+						game_queue.dequeue(),
+						priority.pop_front().or_else(|| normal.pop_front())
+					),
+					QueueOp::DequeueMany(max) => {
+						let mut dequeued = Vec::new();
+						while dequeued.len() < max as usize {
+							match priority.pop_front().or_else(|| normal.pop_front()) {
+								Some(queued) => dequeued.push(queued),
+								None => break,
+							}
+						}
+						assert_eq!(game_queue.dequeue_many(max), dequeued);
+					},
+					// dropped and acknowledged games are taken out of the middle of the queue
+					QueueOp::Remove(byte) => {
+						game_queue.remove(game(byte));
+						priority.retain(|queued| *queued != game(byte));
+						normal.retain(|queued| *queued != game(byte));
+					},
+				}
+
+				// the stored queue holds the same games in the same order
+				let game_queue = Registry::game_queues(&engine);
+				let games: Vec<_> = priority.iter().chain(normal.iter()).cloned().collect();
+				assert_eq!(game_queue.iter().collect::<Vec<_>>(), games);
+				assert_eq!(game_queue.length() as usize, games.len());
+				assert_eq!(game_queue.priority_length() as usize, priority.len());
+				assert_eq!(game_queue.remaining_capacity() as usize, max_size - games.len());
+				assert_eq!(game_queue.is_full(), games.len() == max_size);
+				assert_eq!(game_queue.is_empty(), games.is_empty());
+				assert_eq!(game_queue.peek(), games.first().cloned());
+				for byte in 0..8 {
+					assert_eq!(game_queue.contains(game(byte)), games.contains(&game(byte)));
+				}
+				assert_eq!(GameQueueItems::<Test>::iter_prefix(&engine).count(), normal.len());
+				assert_eq!(
+					PriorityGameQueueItems::<Test>::iter_prefix(&engine).count(),
+					priority.len()
+				);
+			}
+		});
+	}
+}
+
+#[test]
+fn try_state_checks_the_game_indexes() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_eq!(Registry::do_try_state(), Ok(()));
+
+		TeeLoads::<Test>::mutate(TEE, |tee_load| tee_load.running += 1);
+		assert_eq!(Registry::do_try_state(), Err("tee load doesn't match its games"));
+		TeeLoads::<Test>::mutate(TEE, |tee_load| tee_load.running -= 1);
+
+		CurrentGames::<Test>::mutate(3, |games| games.try_push(game_hash)).unwrap();
+		assert_eq!(Registry::do_try_state(), Err("player is not in its current game"));
+		CurrentGames::<Test>::remove(3);
+
+		PlayerGames::<Test>::insert(1, engine.id, 2);
+		assert_eq!(
+			Registry::do_try_state(),
+			Err("player game count doesn't match its current games")
+		);
+		PlayerGames::<Test>::insert(1, engine.id, 1);
+		assert_eq!(Registry::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn game_rules_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let game_rules: Vec<_> = (1..=3)
+			.map(|games| GameRule::new(GameRuleType::MinFinishedGames(games), [0u8; 16]))
+			.collect();
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::set_game_rules(Origin::signed(FOUNDER), engine.clone(), game_rules.clone()),
+			Error::<Test>::TooManyGameRules
+		);
+
+		// game rules as stored before they were bounded
+		StorageVersion::new(3).put::<Registry>();
+		unhashed::put(&GameRequirments::<Test>::hashed_key_for(&engine), &game_rules);
+		assert_eq!(Registry::do_try_state(), Err("game rules exceed their bound"));
+
+		<Registry as Hooks<u64>>::on_runtime_upgrade();
+		assert_eq!(Registry::on_chain_storage_version(), 5);
+		assert_eq!(Registry::game_requirements(&engine).to_vec(), game_rules[..2].to_vec());
+		assert_eq!(Registry::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn try_state_checks_invariants() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::do_try_state(), Ok(()));
+
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		GameRegistry::<Test>::remove(&game_hash);
+		assert_eq!(Registry::do_try_state(), Err("queued game is not waiting in the registry"));
+		GameQueues::<Test>::remove(&engine);
+		assert_eq!(Registry::do_try_state(), Ok(()));
+
+		EngineTeeCount::<Test>::insert(&engine, 2);
+		assert_eq!(
+			Registry::do_try_state(),
+			Err("engine tee count doesn't match the registered tees")
+		);
+	});
+}
+
+#[test]
+fn dev_mode_relaxes_tee_requirements() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		DevMode::<Test>::put(true);
+		assert_ok!(Registry::register_game_engine(Origin::signed(FOUNDER), engine.clone(), 0));
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+
+		// ordinary accounts stand in for AjunaTEEs
+		assert_ok!(Registry::ack_game(Origin::signed(5), engine.clone(), vec![game_hash]));
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			UNATTESTED_TEE,
+			vec![engine],
+			None
+		));
+		assert_eq!(
+			Registry::registered_tees(UNATTESTED_TEE).map(|tee_info| tee_info.enclave),
+			Some([0; 32])
+		);
+	});
+}
+
+#[test]
+fn settings_toggle_registry_operations() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_eq!(Registry::settings(), PalletSettings::default());
+		let settings = PalletSettings {
+			auto_matchmaking: false,
+			registrations_open: true,
+			spectators_enabled: false,
+			allowlist_only: false,
+		};
+		assert_noop!(Registry::set_settings(Origin::signed(1), settings.clone()), BadOrigin);
+		assert_ok!(Registry::set_settings(Origin::signed(FOUNDER), settings));
+
+		// players stay in matchmaking
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 0);
+
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		assert_noop!(
+			Registry::register_spectator(Origin::signed(5), game_hash),
+			Error::<Test>::SpectatingDisabled
+		);
+
+		assert_ok!(Registry::set_settings(
+			Origin::signed(FOUNDER),
+			PalletSettings { registrations_open: false, ..Default::default() }
+		));
+		assert_noop!(
+			Registry::queue(Origin::signed(3), 1, None, None),
+			Error::<Test>::RegistrationsClosed
+		);
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 2);
+	});
+}
+
+#[test]
+fn parameters_are_set_without_upgrade() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_eq!(Registry::parameters().waiting_timeout, WaitingTimeout::get());
+		assert_noop!(
+			Registry::set_parameter(Origin::signed(1), RegistryParameter::AckBatchLimit(1)),
+			BadOrigin
+		);
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::AckBatchLimit(1)
+		));
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::WaitingTimeout(20)
+		));
+		assert_eq!(Registry::parameters().ack_batch_limit, 1);
+		assert_eq!(Registry::parameters().waiting_timeout, 20);
+
+		let game1 = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let game2 = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		assert_noop!(
+			Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game1, game2]),
+			Error::<Test>::AckToMany.with_weight(<() as WeightInfo>::ack_game(0))
+		);
+
+		// not expired with the default timeout anymore
+		run_to_block(10 + WaitingTimeout::get());
+		assert_noop!(Registry::expire_game(Origin::none(), game1), Error::<Test>::GameNotExpired);
+		run_to_block(30);
+		assert_ok!(Registry::expire_game(Origin::none(), game1));
+	});
+}
+
+#[test]
+fn pause_contains_incidents() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+
+		assert_noop!(Registry::pause(Origin::signed(1)), BadOrigin);
+		assert_ok!(Registry::pause(Origin::signed(FOUNDER)));
+		assert!(Registry::paused());
+		assert_noop!(
+			Registry::queue(Origin::signed(2), 1, None, None),
+			Error::<Test>::RegistryPaused
+		);
+		assert_noop!(
+			Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]),
+			Error::<Test>::RegistryPaused
+		);
+
+		assert_noop!(Registry::unpause(Origin::signed(1)), BadOrigin);
+		assert_ok!(Registry::unpause(Origin::signed(FOUNDER)));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 2);
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine, vec![game_hash]));
+	});
+}
+
+#[test]
+fn calls_are_disabled_on_their_own() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		assert_noop!(
+			Registry::set_call_disabled(Origin::signed(1), RegistryCall::FinishGame, true),
+			BadOrigin
+		);
+		assert_ok!(Registry::set_call_disabled(
+			Origin::signed(FOUNDER),
+			RegistryCall::FinishGame,
+			true
+		));
+		assert!(Registry::call_disabled(RegistryCall::FinishGame));
+
+		// frozen payouts, while the rest of the registry keeps running
+		assert_noop!(tee.finish(game_hash, 3), Error::<Test>::CallDisabled);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+
+		assert_ok!(Registry::set_call_disabled(Origin::signed(FOUNDER), RegistryCall::Queue, true));
+		assert_noop!(
+			Registry::queue(Origin::signed(2), 1, None, None),
+			Error::<Test>::CallDisabled
+		);
+
+		assert_ok!(Registry::set_call_disabled(
+			Origin::signed(FOUNDER),
+			RegistryCall::FinishGame,
+			false
+		));
+		assert!(!Registry::call_disabled(RegistryCall::FinishGame));
+		assert_ok!(tee.finish(game_hash, 3));
+	});
+}
+
+#[test]
+fn banned_accounts_forfeit_and_cannot_play() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		assert_noop!(Registry::ban_account(Origin::signed(1), 3), BadOrigin);
+		assert_ok!(Registry::ban_account(Origin::signed(FOUNDER), 3));
+		assert!(Registry::is_banned(3));
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameConceded(game_hash, 3)));
+		System::assert_last_event(mock::Event::Registry(crate::Event::AccountBanned(3)));
+
+		assert_noop!(
+			Registry::queue(Origin::signed(3), 1, None, None),
+			Error::<Test>::AccountBanned
+		);
+		assert_noop!(
+			Registry::register_spectator(Origin::signed(3), game_hash),
+			Error::<Test>::AccountBanned
+		);
+
+		assert_ok!(Registry::unban_account(Origin::signed(FOUNDER), 3));
+		assert!(!Registry::is_banned(3));
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, None));
+	});
+}
+
+#[test]
+fn allowlist_mode_gates_queueing() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::set_settings(
+			Origin::signed(FOUNDER),
+			PalletSettings { allowlist_only: true, ..Default::default() }
+		));
+
+		assert_noop!(Registry::add_to_allowlist(Origin::signed(1), vec![1]), BadOrigin);
+		assert_noop!(
+			Registry::add_to_allowlist(Origin::signed(FOUNDER), (0..101).collect()),
+			Error::<Test>::AllowlistBatchTooLarge
+		);
+		assert_ok!(Registry::add_to_allowlist(Origin::signed(FOUNDER), vec![1, 2]));
+		assert!(Registry::is_allowlisted(1));
+
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_noop!(
+			Registry::queue(Origin::signed(3), 1, None, None),
+			Error::<Test>::NotAllowlisted
+		);
+
+		assert_ok!(Registry::remove_from_allowlist(Origin::signed(FOUNDER), vec![2]));
+		assert_noop!(
+			Registry::queue(Origin::signed(2), 1, None, None),
+			Error::<Test>::NotAllowlisted
+		);
+
+		assert_ok!(Registry::set_settings(Origin::signed(FOUNDER), PalletSettings::default()));
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, None));
+	});
+}
+
+#[test]
+fn reports_are_moderated_with_deposits() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+
+		assert_noop!(
+			Registry::report_player(Origin::signed(3), game_hash, 3, ReportCategory::Cheating),
+			Error::<Test>::CannotReportSelf
+		);
+		assert_noop!(
+			Registry::report_player(Origin::signed(5), game_hash, 3, ReportCategory::Cheating),
+			Error::<Test>::NotAPlayer
+		);
+		assert_ok!(Registry::report_player(
+			Origin::signed(3),
+			game_hash,
+			4,
+			ReportCategory::Cheating
+		));
+		assert_ok!(Registry::report_player(Origin::signed(4), game_hash, 3, ReportCategory::Abuse));
+		assert_eq!(Balances::reserved_balance(3), ReportDeposit::get());
+		assert_eq!(Registry::report_queue().into_inner(), vec![0, 1]);
+		assert_noop!(
+			Registry::report_player(Origin::signed(3), game_hash, 4, ReportCategory::Griefing),
+			Error::<Test>::ReportQueueFull
+		);
+
+		assert_noop!(Registry::resolve_report(Origin::signed(3), 0, true), BadOrigin);
+		assert_ok!(Registry::resolve_report(Origin::signed(FOUNDER), 0, true));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+
+		assert_ok!(Registry::resolve_report(Origin::signed(FOUNDER), 1, false));
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(4), INITIAL_BALANCE - ReportDeposit::get());
+
+		assert!(Registry::report_queue().is_empty());
+		assert_noop!(
+			Registry::resolve_report(Origin::signed(FOUNDER), 1, true),
+			Error::<Test>::NoReport
+		);
+	});
+}
+
+#[test]
+fn players_are_banned_till_a_block() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::ban_player(Origin::signed(1), 1, 15, ReportCategory::Cheating),
+			BadOrigin
+		);
+		assert_noop!(
+			Registry::ban_player(Origin::signed(FOUNDER), 1, 10, ReportCategory::Cheating),
+			Error::<Test>::BanAlreadyOver
+		);
+		assert_ok!(Registry::ban_player(Origin::signed(FOUNDER), 1, 15, ReportCategory::Cheating));
+		System::assert_last_event(mock::Event::Registry(crate::Event::PlayerBanned(
+			1,
+			15,
+			ReportCategory::Cheating,
+		)));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::PlayerSanctioned
+		);
+		assert_noop!(
+			Registry::create_match(Origin::signed(1), vec![2], engine.clone(), None, None),
+			Error::<Test>::PlayerSanctioned
+		);
+
+		// the ban ends by itself
+		run_to_block(15);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::leave_queue(Origin::signed(1)));
+
+		// or is lifted before
+		assert_ok!(Registry::ban_player(Origin::signed(FOUNDER), 1, 20, ReportCategory::Abuse));
+		assert_noop!(Registry::unban_player(Origin::signed(1), 1), BadOrigin);
+		assert_ok!(Registry::unban_player(Origin::signed(FOUNDER), 1));
+		System::assert_last_event(mock::Event::Registry(crate::Event::PlayerUnbanned(1)));
+		assert_noop!(Registry::unban_player(Origin::signed(FOUNDER), 1), Error::<Test>::NoSanction);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+
+		// players banned while queued don't make it into a game
+		assert_ok!(Registry::ban_player(Origin::signed(FOUNDER), 1, 30, ReportCategory::Abuse));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		assert!(Registry::game_queues(&engine).is_empty());
+		assert_eq!(Registry::queued_players(1), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(Registry::queued_players(2).is_some());
+		System::assert_has_event(mock::Event::Registry(crate::Event::PlayerDequeued(
+			1,
+			DequeueReason::Banned,
+		)));
+	});
+}
+
+#[test]
+fn sanctions_are_enforced_and_appealed() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::sanction_player(Origin::signed(1), 1, SanctionKind::QueueBan, 5),
+			BadOrigin
+		);
+		assert_ok!(Registry::sanction_player(
+			Origin::signed(FOUNDER),
+			1,
+			SanctionKind::QueueBan,
+			5
+		));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::PlayerSanctioned
+		);
+
+		assert_noop!(
+			Registry::appeal_sanction(Origin::signed(1), SanctionKind::RankedBan),
+			Error::<Test>::NoSanction
+		);
+		assert_ok!(Registry::appeal_sanction(Origin::signed(1), SanctionKind::QueueBan));
+		assert_noop!(
+			Registry::appeal_sanction(Origin::signed(1), SanctionKind::QueueBan),
+			Error::<Test>::AlreadyAppealed
+		);
+		assert_noop!(
+			Registry::resolve_appeal(Origin::signed(2), 1, SanctionKind::QueueBan, true),
+			BadOrigin
+		);
+		assert_ok!(Registry::resolve_appeal(
+			Origin::signed(FOUNDER),
+			1,
+			SanctionKind::QueueBan,
+			false
+		));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::PlayerSanctioned
+		);
+
+		// the sanction ends by itself
+		run_to_block(15);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+
+		// wins of a ranked ban don't count
+		assert_ok!(Registry::sanction_player(
+			Origin::signed(FOUNDER),
+			3,
+			SanctionKind::RankedBan,
+			5
+		));
+		play_game(&engine, vec![3, 4], 3);
+		assert_eq!(Registry::games_won(3), 0);
+		assert_ok!(Registry::appeal_sanction(Origin::signed(3), SanctionKind::RankedBan));
+		assert_ok!(Registry::resolve_appeal(
+			Origin::signed(FOUNDER),
+			3,
+			SanctionKind::RankedBan,
+			true
+		));
+		play_game(&engine, vec![3, 4], 3);
+		assert_eq!(Registry::games_won(3), 1);
+	});
+}
+
+#[test]
+fn tees_report_players_not_showing_up() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_noop!(
+			Registry::report_no_show(Origin::signed(TEE), game_hash, 2),
+			Error::<Test>::GameNotRunning
+		);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		run_to_block(12);
+		assert_noop!(
+			Registry::report_no_show(Origin::signed(TEE), game_hash, 3),
+			Error::<Test>::NotAPlayer
+		);
+		assert_noop!(
+			Registry::report_no_show(Origin::signed(TEE), game_hash, 2),
+			Error::<Test>::NoShowTooEarly
+		);
+
+		// the player left wins the game
+		run_to_block(13);
+		assert_ok!(Registry::report_no_show(Origin::signed(TEE), game_hash, 2));
+		System::assert_has_event(mock::Event::Registry(crate::Event::NoShowReported(
+			game_hash, 2, 1,
+		)));
+		assert_eq!(
+			Registry::archived_game(&game_hash).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(1))
+		);
+		assert_eq!(Registry::no_show_strikes(2), 1);
+
+		// games with more players left are aborted, repeat offenders are banned from queueing
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![2, 3, 4])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		run_to_block(16);
+		assert_ok!(Registry::report_no_show(Origin::signed(TEE), game_hash, 2));
+		assert_eq!(
+			Registry::archived_game(&game_hash).unwrap().game_state,
+			GameState::Finished(GameOutcome::Aborted)
+		);
+		System::assert_has_event(mock::Event::Registry(crate::Event::PlayerSanctioned(
+			2,
+			SanctionKind::QueueBan,
+			26,
+		)));
+		assert_eq!(Registry::no_show_strikes(2), 0);
+		assert_noop!(
+			Registry::queue(Origin::signed(2), engine.id, None, None),
+			Error::<Test>::PlayerSanctioned
+		);
+	});
+}
+
+#[test]
+fn players_rematch_finished_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = play_game(&engine, vec![1, 2], 1);
+		assert_noop!(
+			Registry::request_rematch(Origin::signed(3), game_hash),
+			Error::<Test>::NotAPlayer
+		);
+		assert_ok!(Registry::request_rematch(Origin::signed(1), game_hash));
+		System::assert_last_event(mock::Event::Registry(crate::Event::RematchRequested(
+			game_hash, 1,
+		)));
+		assert_noop!(
+			Registry::request_rematch(Origin::signed(1), game_hash),
+			Error::<Test>::RematchAlreadyRequested
+		);
+		assert_eq!(Registry::rematch_requests(&game_hash).to_vec(), vec![1]);
+
+		// the last request queues the rematch right away
+		assert_ok!(Registry::request_rematch(Origin::signed(2), game_hash));
+		let rematch = Registry::current_games(1)[0];
+		System::assert_last_event(mock::Event::Registry(crate::Event::RematchCreated(
+			game_hash, rematch,
+		)));
+		let game_entry = Registry::game_entry(&rematch).unwrap();
+		assert_eq!(game_entry.players.to_vec(), vec![1, 2]);
+		assert_eq!(game_entry.game_engine, engine);
+		assert_eq!(game_entry.game_state, GameState::Waiting);
+		assert!(Registry::rematch_requests(&game_hash).is_empty());
+
+		// requests not matched by all players expire with the window
+		let game_hash = play_game(&engine, vec![3, 4], 3);
+		assert_ok!(Registry::request_rematch(Origin::signed(3), game_hash));
+		run_to_block(10 + RematchWindow::get() + 1);
+		System::assert_has_event(mock::Event::Registry(crate::Event::RematchExpired(game_hash)));
+		assert!(Registry::rematch_requests(&game_hash).is_empty());
+		assert_noop!(
+			Registry::request_rematch(Origin::signed(4), game_hash),
+			Error::<Test>::RematchWindowClosed
+		);
+	});
+}
+
+#[test]
+fn tees_submit_unsigned_with_enclave_signatures() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			SIGNING_TEE,
+			vec![engine.clone()],
+			None
+		));
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+
+		// acknowledgements are checked against the enclave key and the next nonce in the pool
+		let ack = |tee, nonce, signature| Call::ack_game_unsigned {
+			tee,
+			cluster: engine.clone(),
+			games: vec![game_hash],
+			nonce,
+			signature,
+		};
+		let signature = sign_ack(&engine, &[game_hash], 0);
+		assert_eq!(
+			Registry::validate_unsigned(
+				TransactionSource::External,
+				&ack(TEE, 0, signature.clone())
+			),
+			InvalidTransaction::BadSigner.into()
+		);
+		assert_eq!(
+			Registry::validate_unsigned(
+				TransactionSource::External,
+				&ack(SIGNING_TEE, 0, sign_ack(&engine, &[game_hash], 1))
+			),
+			InvalidTransaction::BadProof.into()
+		);
+		let validity = Registry::validate_unsigned(
+			TransactionSource::External,
+			&ack(SIGNING_TEE, 0, signature.clone()),
+		)
+		.unwrap();
+		assert_eq!(validity.priority, TransactionPriority::max_value());
+		assert!(validity.requires.is_empty());
+
+		assert_noop!(
+			Registry::ack_game_unsigned(
+				Origin::signed(SIGNING_TEE),
+				SIGNING_TEE,
+				engine.clone(),
+				vec![game_hash],
+				0,
+				signature.clone()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Registry::ack_game_unsigned(
+				Origin::none(),
+				TEE,
+				engine.clone(),
+				vec![game_hash],
+				0,
+				signature.clone()
+			),
+			Error::<Test>::NoSigningKey
+		);
+		assert_noop!(
+			Registry::ack_game_unsigned(
+				Origin::none(),
+				SIGNING_TEE,
+				engine.clone(),
+				vec![game_hash],
+				1,
+				sign_ack(&engine, &[game_hash], 1)
+			),
+			Error::<Test>::InvalidNonce
+		);
+		assert_ok!(Registry::ack_game_unsigned(
+			Origin::none(),
+			SIGNING_TEE,
+			engine.clone(),
+			vec![game_hash],
+			0,
+			signature.clone()
+		));
+		assert_eq!(Registry::game_entry(&game_hash).unwrap().tee_id, Some(SIGNING_TEE));
+		assert_eq!(Registry::tee_result_nonce(SIGNING_TEE), 1);
+		assert_eq!(
+			Registry::validate_unsigned(
+				TransactionSource::External,
+				&ack(SIGNING_TEE, 0, signature)
+			),
+			InvalidTransaction::Stale.into()
+		);
+
+		// results are signed like the ones of signed submissions
+		assert_ok!(MockTee(SIGNING_TEE).ready(game_hash));
+		let outcome = GameOutcome::Winner(1);
+		let result = GameSubmission {
+			game_hash,
+			outcome: outcome.clone(),
+			final_state: final_state(),
+			signature: Some(sign_result(game_hash, &outcome, 1)),
+			replay: None,
+			result_proof: None,
+		};
+		let finish =
+			Call::finish_game_unsigned { tee: SIGNING_TEE, result: result.clone(), nonce: 1 };
+		assert_ok!(Registry::validate_unsigned(TransactionSource::External, &finish));
+		assert_noop!(
+			Registry::finish_game_unsigned(
+				Origin::none(),
+				SIGNING_TEE,
+				GameSubmission {
+					signature: Some(sign_result(game_hash, &GameOutcome::Winner(2), 1)),
+					..result.clone()
+				},
+				1
+			),
+			Error::<Test>::InvalidResultSignature
+		);
+		assert_ok!(Registry::finish_game_unsigned(Origin::none(), SIGNING_TEE, result, 1));
+		assert_eq!(
+			Registry::game_entry(&game_hash).unwrap().game_state,
+			GameState::Finished(outcome)
+		);
+		assert_eq!(Registry::tee_result_nonce(SIGNING_TEE), 2);
+	});
+}
+
+#[test]
+fn tees_hand_over_their_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			SIGNING_TEE,
+			vec![engine.clone()],
+			None
+		));
+		let accepted = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let running = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		let waiting = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![5, 6])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![accepted, running]));
+		assert_ok!(tee.ready(running));
+
+		assert_noop!(
+			Registry::handover_games(Origin::signed(SIGNING_TEE), TEE, vec![accepted]),
+			Error::<Test>::NotGameTee
+		);
+		assert_noop!(
+			Registry::handover_games(Origin::signed(TEE), SIGNING_TEE, vec![waiting]),
+			Error::<Test>::NotGameTee
+		);
+		assert_noop!(
+			Registry::handover_games(Origin::signed(TEE), 1, vec![accepted]),
+			Error::<Test>::NotRegisteredTee
+		);
+
+		run_to_block(11);
+		assert_ok!(Registry::handover_games(
+			Origin::signed(TEE),
+			SIGNING_TEE,
+			vec![accepted, running]
+		));
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameHandedOver(
+			running,
+			Some(TEE),
+			SIGNING_TEE,
+		)));
+		let game_entry = Registry::game_entry(&running).unwrap();
+		assert_eq!(game_entry.game_state, GameState::Accepted);
+		assert_eq!(game_entry.tee_id, Some(SIGNING_TEE));
+		assert_eq!(game_entry.timestamps.accepted, 11);
+		assert_eq!(Registry::tee_loads(TEE).total(), 0);
+		assert_eq!(Registry::tee_loads(SIGNING_TEE).accepted, 2);
+		assert_eq!(Registry::active_games(&engine), 2);
+
+		// the receiving AjunaTEE resumes the games, the operator hands over any game
+		assert_noop!(tee.ready(running), Error::<Test>::NotGameTee);
+		assert_ok!(MockTee(SIGNING_TEE).ready(running));
+		assert_ok!(Registry::handover_games(Origin::signed(FOUNDER), TEE, vec![accepted]));
+		assert_eq!(Registry::game_entry(&accepted).unwrap().tee_id, Some(TEE));
+		assert_noop!(
+			Registry::handover_games(Origin::signed(FOUNDER), TEE, vec![accepted]),
+			Error::<Test>::InvalidHandover
+		);
+		assert_eq!(Registry::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn players_abort_games_by_consent() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_noop!(
+			Registry::propose_abort(Origin::signed(1), game_hash),
+			Error::<Test>::GameNotRunning
+		);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		assert_noop!(
+			Registry::propose_abort(Origin::signed(3), game_hash),
+			Error::<Test>::NotAPlayer
+		);
+		assert_ok!(Registry::propose_abort(Origin::signed(1), game_hash));
+		System::assert_last_event(mock::Event::Registry(crate::Event::AbortProposed(game_hash, 1)));
+		assert_noop!(
+			Registry::propose_abort(Origin::signed(1), game_hash),
+			Error::<Test>::AbortAlreadyProposed
+		);
+		assert_eq!(Registry::abort_proposals(&game_hash).to_vec(), vec![1]);
+		assert_eq!(Registry::game_entry(&game_hash).unwrap().game_state, GameState::Running);
+
+		// the last player agreeing aborts the game, without touching the ratings
+		let rating = Registry::rating(&1, engine.id);
+		assert_ok!(Registry::propose_abort(Origin::signed(2), game_hash));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameAborted(
+			game_hash,
+			Some(TEE),
+		)));
+		assert_eq!(
+			Registry::archived_game(&game_hash).unwrap().game_state,
+			GameState::Finished(GameOutcome::Aborted)
+		);
+		assert!(Registry::abort_proposals(&game_hash).is_empty());
+		assert_eq!(Registry::rating(&1, engine.id), rating);
+	});
+}
+
+#[test]
+fn tournaments_are_played_out_in_rounds() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::create_tournament(Origin::signed(1), engine.clone(), 1, 10, 20),
+			Error::<Test>::InvalidTournament
+		);
+		assert_noop!(
+			Registry::create_tournament(Origin::signed(1), engine.clone(), 4, 10, 10),
+			Error::<Test>::InvalidTournament
+		);
+		assert_ok!(Registry::create_tournament(Origin::signed(1), engine.clone(), 3, 10, 20));
+		System::assert_last_event(mock::Event::Registry(crate::Event::TournamentCreated(
+			0,
+			engine.clone(),
+			1,
+		)));
+
+		for player in vec![1, 2, 3] {
+			assert_ok!(Registry::join_tournament(Origin::signed(player), 0));
+		}
+		System::assert_last_event(mock::Event::Registry(crate::Event::TournamentJoined(0, 3)));
+		assert_noop!(Registry::join_tournament(Origin::signed(3), 0), Error::<Test>::AlreadyJoined);
+		assert_noop!(
+			Registry::join_tournament(Origin::signed(4), 0),
+			Error::<Test>::TournamentFull
+		);
+		assert_noop!(Registry::join_tournament(Origin::signed(4), 1), Error::<Test>::NoTournament);
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		// the first round pairs the first two players, the third advances with a bye
+		run_to_block(20);
+		System::assert_has_event(mock::Event::Registry(crate::Event::TournamentRoundStarted(0, 1)));
+		assert_noop!(
+			Registry::join_tournament(Origin::signed(4), 0),
+			Error::<Test>::TournamentClosed
+		);
+		let semifinal = Registry::current_games(1)[0];
+		assert_eq!(Registry::game_entry(&semifinal).unwrap().players.to_vec(), vec![1, 2]);
+		assert!(Registry::is_priority_game(&semifinal));
+		assert!(Registry::current_games(3).is_empty());
+
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![semifinal]));
+		assert_ok!(tee.ready(semifinal));
+		assert_ok!(tee.finish(semifinal, 2));
+		assert_eq!(Registry::tournaments(0).unwrap().advancing, vec![Some(2), Some(3)]);
+
+		// the next round starts in the next block
+		run_to_block(21);
+		System::assert_has_event(mock::Event::Registry(crate::Event::TournamentRoundStarted(0, 2)));
+		let final_game = Registry::current_games(3)[0];
+		assert_eq!(Registry::game_entry(&final_game).unwrap().players.to_vec(), vec![2, 3]);
+
+		// games without a winner are decided for the player that joined first
+		assert_ok!(tee.ack(&engine, vec![final_game]));
+		assert_ok!(tee.ready(final_game));
+		assert_ok!(tee.finish_with(final_game, GameOutcome::Draw));
+
+		run_to_block(22);
+		System::assert_has_event(mock::Event::Registry(crate::Event::TournamentWon(0, 2, 30)));
+		assert_eq!(Registry::tournaments(0).unwrap().state, TournamentState::Won(2));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 10);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + 20);
+
+		// tournaments without enough players at their start are cancelled
+		assert_ok!(Registry::create_tournament(Origin::signed(1), engine.clone(), 4, 10, 30));
+		assert_ok!(Registry::join_tournament(Origin::signed(4), 1));
+		run_to_block(30);
+		System::assert_has_event(mock::Event::Registry(crate::Event::TournamentCancelled(1)));
+		assert_eq!(Registry::tournaments(1), None);
+		assert_eq!(Balances::reserved_balance(4), 0);
+	});
+}
+
+#[test]
+fn slashing_proportions_are_set_by_governance() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_eq!(Registry::parameters().abandonment_slash, Perbill::one());
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::AbandonmentSlash(Perbill::from_percent(50))
+		));
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::FrivolousReportSlash(Perbill::from_percent(40))
+		));
+
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		assert_ok!(Registry::report_player(Origin::signed(1), game_hash, 2, ReportCategory::Abuse));
+
+		// nobody showed up, half of the bonds are slashed
+		assert_ok!(Registry::drop_game(Origin::signed(TEE), game_hash, engine, DropReason::NoShow));
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - QueueBond::get() / 2);
+
+		assert_ok!(Registry::resolve_report(Origin::signed(FOUNDER), 0, false));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(
+			Balances::free_balance(1),
+			INITIAL_BALANCE - QueueBond::get() / 2 - ReportDeposit::get() * 2 / 5
+		);
+	});
+}
+
+#[test]
+fn admin_actions_are_kept_in_audit_log() {
+	new_test_ext().execute_with(|| {
+		run_to_block(10);
+
+		assert_ok!(Registry::pause(Origin::signed(FOUNDER)));
+		assert_ok!(Registry::ban_account(Origin::signed(FOUNDER), 3));
+		assert_eq!(
+			Registry::audit_log(1),
+			Some(AuditEntry {
+				action: AdminAction::BanAccount,
+				actor: Some(FOUNDER),
+				block: 10,
+				payload_hash: BlakeTwo256::hash_of(&3u64),
+			})
+		);
+
+		// failed actions aren't logged
+		assert_noop!(Registry::unpause(Origin::signed(1)), BadOrigin);
+
+		run_next_block();
+		assert_ok!(Registry::unpause(Origin::signed(FOUNDER)));
+		assert_ok!(Registry::unban_account(Origin::signed(FOUNDER), 3));
+
+		// the oldest entry is overwritten
+		assert_eq!(Registry::audit_log_count(), 4);
+		assert_eq!(Registry::audit_log(0).unwrap().action, AdminAction::UnbanAccount);
+		assert_eq!(Registry::audit_log(1).unwrap().action, AdminAction::BanAccount);
+		assert_eq!(Registry::audit_log(2).unwrap().action, AdminAction::Unpause);
+		assert_eq!(Registry::audit_log(2).unwrap().block, 11);
+	});
+}
+
+#[test]
+fn governance_calls_are_kept_in_audit_log() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		assert_ok!(Registry::register_game_engine(Origin::signed(FOUNDER), engine.clone(), 0));
+		assert_eq!(
+			Registry::audit_log(0),
+			Some(AuditEntry {
+				action: AdminAction::RegisterGameEngine,
+				actor: Some(FOUNDER),
+				block: 10,
+				payload_hash: BlakeTwo256::hash_of(&(&engine, 0u8)),
+			})
+		);
+		assert_ok!(Registry::set_result_confirmation(Origin::signed(FOUNDER), engine, true));
+		assert_eq!(Registry::audit_log(1).unwrap().action, AdminAction::SetResultConfirmation);
+		assert_ok!(Registry::add_to_allowlist(Origin::signed(FOUNDER), vec![1, 2]));
+		assert_eq!(Registry::audit_log(2).unwrap().action, AdminAction::AddToAllowlist);
+		assert_eq!(Registry::audit_log_count(), 3);
+	});
+}
+
+#[test]
+fn genesis_boots_a_usable_registry() {
+	let engine = GameEngine::new(1, 1);
+	let parameters = RegistryParameters {
+		waiting_timeout: 7,
+		accepted_timeout: 7,
+		max_game_duration: 7,
+		ack_batch_limit: 5,
+		games_per_block: 1,
+		tee_calls_per_block: 10,
+		abandonment_slash: Perbill::from_percent(50),
+		frivolous_report_slash: Perbill::one(),
+		matchmaking_weight: Weight::max_value(),
+		maintenance_weight: 0,
+	};
+	new_test_ext_with(RegistryConfig {
+		dev_mode: false,
+		game_engines: vec![(engine.clone(), 0)],
+		game_rules: vec![(
+			engine.clone(),
+			vec![GameRule::new(GameRuleType::MinFinishedGames(0), [0u8; 16])],
+		)],
+		tees: vec![(TEE, vec![engine.clone(), engine.clone()])],
+		parameters: Some(parameters.clone()),
+		paused: false,
+		paused_engines: vec![],
+		disabled_calls: vec![],
+	})
+	.execute_with(|| {
+		run_to_block(10);
+
+		assert_eq!(Registry::game_requirements(&engine).len(), 1);
+		assert_eq!(Registry::registered_tees(TEE).unwrap().supported_engines, vec![engine.clone()]);
+		assert_eq!(Registry::engine_tee_count(&engine), 1);
+		assert_eq!(Registry::parameters(), parameters);
+
+		// players queue without post-genesis extrinsics
+		let game_hash = play_game(&engine, vec![1, 2], 1);
+		assert_eq!(
+			Registry::archived_game(game_hash).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(1))
+		);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+	});
+}
+
+#[test]
+fn genesis_pause_flags_hold_the_registry_from_block_zero() {
+	let engine = GameEngine::new(1, 1);
+	let paused_engine = GameEngine::new(2, 1);
+	new_test_ext_with(RegistryConfig {
+		game_engines: vec![(engine.clone(), 0), (paused_engine.clone(), 0)],
+		tees: vec![(TEE, vec![engine.clone(), paused_engine.clone()])],
+		paused: true,
+		paused_engines: vec![paused_engine.clone()],
+		disabled_calls: vec![RegistryCall::Queue],
+		..Default::default()
+	})
+	.execute_with(|| {
+		run_to_block(10);
+
+		assert!(Registry::paused());
+		assert!(Registry::paused_engines(&paused_engine));
+		assert!(Registry::call_disabled(RegistryCall::Queue));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::RegistryPaused
+		);
+
+		// the flags are lifted like ones set by extrinsics
+		assert_ok!(Registry::unpause(Origin::signed(FOUNDER)));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 1, None, None),
+			Error::<Test>::CallDisabled
+		);
+		assert_ok!(Registry::set_call_disabled(
+			Origin::signed(FOUNDER),
+			RegistryCall::Queue,
+			false
+		));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), 2, None, None),
+			Error::<Test>::EngineDisabled
+		);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+	});
+}
+
+#[test]
+fn tee_lifecycle_calls_are_rate_limited() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::TeeCallsPerBlock(2)
+		));
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_noop!(tee.finish(game_hash, 3), Error::<Test>::TeeRateLimited);
+
+		// the counter is reset every block
+		run_next_block();
+		assert_ok!(tee.finish(game_hash, 3));
+	});
+}
+
+#[test]
+fn results_are_signed_by_the_enclave() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			SIGNING_TEE,
+			vec![engine.clone()],
+			None
+		));
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		let tee = MockTee(SIGNING_TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		assert_noop!(
+			Registry::finish_game(
+				Origin::signed(SIGNING_TEE),
+				game_hash,
+				GameOutcome::Winner(3),
+				final_state(),
+				None,
+				None,
+				None
+			),
+			Error::<Test>::MissingResultSignature
+		);
+		// the signature is bound to the result and the nonce
+		assert_noop!(
+			Registry::finish_game(
+				Origin::signed(SIGNING_TEE),
+				game_hash,
+				GameOutcome::Winner(3),
+				final_state(),
+				Some(sign_result(game_hash, &GameOutcome::Winner(4), 0)),
+				None,
+				None
+			),
+			Error::<Test>::InvalidResultSignature
+		);
+		assert_noop!(
+			Registry::finish_game(
+				Origin::signed(SIGNING_TEE),
+				game_hash,
+				GameOutcome::Winner(3),
+				final_state(),
+				Some(sign_result(game_hash, &GameOutcome::Winner(3), 1)),
+				None,
+				None
+			),
+			Error::<Test>::InvalidResultSignature
+		);
+		assert_ok!(Registry::finish_game(
+			Origin::signed(SIGNING_TEE),
+			game_hash,
+			GameOutcome::Winner(3),
+			final_state(),
+			Some(sign_result(game_hash, &GameOutcome::Winner(3), 0)),
+			None,
+			None
+		));
+		assert_eq!(Registry::tee_result_nonce(SIGNING_TEE), 1);
+	});
+}
+
+#[test]
+fn fraud_proofs_suspend_results_till_arbitration() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let honest = play_game(&engine, vec![1, 2], 1);
+		let fraudulent = play_game(&engine, vec![3, 4], 3);
+		assert_eq!(Registry::games_won(3), 1);
+
+		assert_noop!(
+			Registry::submit_fraud_proof(Origin::signed(5), honest, vec![0; 4097]),
+			Error::<Test>::FraudProofTooLong
+		);
+		assert_noop!(
+			Registry::submit_fraud_proof(Origin::signed(20), honest, b"proof".to_vec()),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(Registry::submit_fraud_proof(Origin::signed(5), honest, b"proof".to_vec()));
+		assert_eq!(Balances::reserved_balance(5), FraudProofDeposit::get());
+		assert_eq!(
+			Registry::archived_game(&honest).unwrap().game_state,
+			GameState::Suspended(GameOutcome::Winner(1))
+		);
+		assert_noop!(
+			Registry::submit_fraud_proof(Origin::signed(5), honest, b"proof".to_vec()),
+			Error::<Test>::GameNotFinished
+		);
+		let balance = Balances::free_balance(4);
+		assert_ok!(Registry::submit_fraud_proof(Origin::signed(4), fraudulent, b"proof".to_vec()));
+		assert_eq!(Balances::free_balance(4), balance - FraudProofDeposit::get());
+
+		// the deposits of dismissed proofs are slashed
+		assert_noop!(Registry::rule_on_fraud_proof(Origin::signed(5), honest, false), BadOrigin);
+		assert_ok!(Registry::rule_on_fraud_proof(Origin::signed(FOUNDER), honest, false));
+		assert_eq!(
+			Registry::archived_game(&honest).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(1))
+		);
+		assert_eq!(Registry::fraud_proofs(&honest), None);
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert_eq!(Balances::free_balance(5), INITIAL_BALANCE - FraudProofDeposit::get());
+
+		// fraudulent results are voided, the deposit is returned
+		assert_ok!(Registry::rule_on_fraud_proof(Origin::signed(FOUNDER), fraudulent, true));
+		assert_eq!(Registry::game_entry(&fraudulent), None);
+		assert_eq!(Balances::free_balance(4), balance);
+		assert_eq!(Registry::games_won(3), 0);
+		assert_noop!(
+			Registry::rule_on_fraud_proof(Origin::signed(FOUNDER), fraudulent, true),
+			Error::<Test>::NoFraudProof
+		);
+
+		// the window closes after the game finished
+		run_to_block(16);
+		assert_noop!(
+			Registry::submit_fraud_proof(Origin::signed(5), honest, b"proof".to_vec()),
+			Error::<Test>::FraudProofWindowClosed
+		);
+	});
+}
+
+#[test]
+fn results_are_settled_after_the_fraud_proof_window() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+		let stake = 40;
+		DeferredSettlement::set(true);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let settled = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("game engine is available");
+		for player in [1, 2] {
+			assert_ok!(Balances::reserve(&player, stake));
+		}
+		<GameStakes<Test>>::insert(&settled, vec![(1, stake), (2, stake)]);
+		assert_ok!(tee.ack(&engine, vec![settled]));
+		assert_ok!(tee.ready(settled));
+		assert_ok!(tee.finish(settled, 1));
+		let overturned = play_game(&engine, vec![3, 4], 3);
+
+		// nothing is settled while the results can be disputed
+		assert_eq!(Registry::pending_settlements(16), vec![settled, overturned]);
+		assert_eq!(Balances::reserved_balance(1), stake);
+		assert_eq!(Registry::rating(&1, engine.id), 1500);
+
+		assert_ok!(Registry::submit_fraud_proof(Origin::signed(4), overturned, b"proof".to_vec()));
+		assert_noop!(
+			Registry::overturn_result(Origin::signed(4), overturned, GameOutcome::Winner(4)),
+			BadOrigin
+		);
+		assert_noop!(
+			Registry::overturn_result(Origin::signed(FOUNDER), overturned, GameOutcome::Winner(5)),
+			Error::<Test>::WinnerNotAPlayer
+		);
+		assert_noop!(
+			Registry::overturn_result(Origin::signed(FOUNDER), settled, GameOutcome::Winner(2)),
+			Error::<Test>::NoFraudProof
+		);
+
+		run_to_block(16);
+		System::assert_has_event(mock::Event::Registry(crate::Event::PotPaid(
+			settled,
+			1,
+			2 * stake,
+		)));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + stake);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - stake);
+		assert_eq!(Registry::rating(&1, engine.id), 1501);
+		assert!(Registry::pending_settlements(16).is_empty());
+		// suspended results wait for the arbitration
+		assert_eq!(Registry::rating(&3, engine.id), 1500);
+
+		// the window is closed, so the overturned result is settled right away
+		assert_ok!(Registry::overturn_result(
+			Origin::signed(FOUNDER),
+			overturned,
+			GameOutcome::Winner(4)
+		));
+		System::assert_last_event(mock::Event::Registry(crate::Event::ResultOverturned(
+			overturned,
+			GameOutcome::Winner(4),
+		)));
+		let game_entry = Registry::archived_game(&overturned).unwrap();
+		assert_eq!(game_entry.game_state, GameState::Finished(GameOutcome::Winner(4)));
+		assert_eq!(game_entry.result, Some(GameResult::Won(4)));
+		assert_eq!(Registry::games_won(3), 0);
+		assert_eq!(Registry::games_won(4), 1);
+		assert_eq!(Registry::rating(&3, engine.id), 1499);
+		assert_eq!(Registry::rating(&4, engine.id), 1501);
+	});
+}
+
+#[test]
+fn flagged_results_are_reconfirmed_by_a_tee() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let confirmed = play_game(&engine, vec![1, 2], 1);
+		let contested = play_game(&engine, vec![3, 4], 3);
+		let unconfirmed = play_game(&engine, vec![5, 6], 5);
+
+		assert_noop!(
+			Registry::flag_result(Origin::signed(3), confirmed),
+			Error::<Test>::NotAPlayer
+		);
+		assert_ok!(Registry::flag_result(Origin::signed(2), confirmed));
+		assert_eq!(
+			Registry::archived_game(&confirmed).unwrap().game_state,
+			GameState::Reconfirming(GameOutcome::Winner(1))
+		);
+		assert_eq!(<Registry as GameRegistryProvider<_, _>>::winner(&confirmed), None);
+		assert_ok!(Registry::flag_result(Origin::signed(4), contested));
+		assert_ok!(Registry::flag_result(Origin::signed(6), unconfirmed));
+
+		assert_noop!(
+			Registry::reconfirm_result(
+				Origin::signed(UNATTESTED_TEE),
+				confirmed,
+				GameOutcome::Winner(1)
+			),
+			Error::<Test>::NotRegisteredTee
+		);
+		assert_ok!(Registry::reconfirm_result(
+			Origin::signed(TEE),
+			confirmed,
+			GameOutcome::Winner(1)
+		));
+		assert_eq!(
+			Registry::archived_game(&confirmed).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(1))
+		);
+		assert_noop!(
+			Registry::reconfirm_result(Origin::signed(TEE), confirmed, GameOutcome::Winner(1)),
+			Error::<Test>::ResultNotFlagged
+		);
+
+		// a different result goes to arbitration
+		assert_ok!(Registry::reconfirm_result(
+			Origin::signed(TEE),
+			contested,
+			GameOutcome::Winner(4)
+		));
+		assert_eq!(
+			Registry::archived_game(&contested).unwrap().game_state,
+			GameState::Suspended(GameOutcome::Winner(3))
+		);
+		assert_eq!(Registry::fraud_proofs(&contested).unwrap().submitter, 4);
+
+		// so do results not re-confirmed in time
+		run_to_block(13);
+		assert_eq!(
+			Registry::archived_game(&unconfirmed).unwrap().game_state,
+			GameState::Suspended(GameOutcome::Winner(5))
+		);
+		System::assert_has_event(mock::Event::Registry(crate::Event::ResultEscalated(unconfirmed)));
+	});
+}
+
+#[test]
+fn committed_players_are_revealed_after_matching() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let commitment1 = BlakeTwo256::hash_of(&(1u64, [1u8; 32]));
+		let commitment2 = BlakeTwo256::hash_of(&(2u64, [2u8; 32]));
+		assert_ok!(Registry::queue_committed(Origin::signed(5), 1, None, None, commitment1));
+		assert_ok!(Registry::queue_committed(Origin::signed(6), 1, None, None, commitment2));
+		assert_eq!(Balances::reserved_balance(5), QueueBond::get());
+		run_next_block();
+
+		// opponents are unknown till they accepted
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		let committed1 = Registry::committed_player(&commitment1);
+		let mut players = Registry::game_registry(&game_hash).players.into_inner();
+		players.sort();
+		let mut committed = vec![committed1, Registry::committed_player(&commitment2)];
+		committed.sort();
+		assert_eq!(players, committed);
+
+		assert_noop!(
+			Registry::reveal_identity(Origin::signed(1), game_hash, [2u8; 32]),
+			Error::<Test>::NotAPlayer
+		);
+		assert_ok!(Registry::reveal_identity(Origin::signed(1), game_hash, [1u8; 32]));
+		let players = Registry::game_registry(&game_hash).players;
+		assert!(players.contains(&1) && !players.contains(&committed1));
+		assert_eq!(Balances::reserved_balance(5), 0);
+
+		// the bond of the player that never revealed is slashed from its payer
+		assert_ok!(Registry::drop_game(Origin::signed(TEE), game_hash, engine, DropReason::NoShow));
+		assert_eq!(Balances::free_balance(6), INITIAL_BALANCE - QueueBond::get());
+	});
+}
+
+#[test]
+fn private_games_reveal_players_when_finished() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash =
+			<Registry as CreateGame<_, _>>::create_private_game(engine.clone(), vec![3, 4])
+				.expect("engine is registered");
+		assert!(Registry::is_private_game(&game_hash));
+
+		// the AjunaTEE still gets the full game entry
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_eq!(Registry::game_registry(&game_hash).players.to_vec(), vec![3, 4]);
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameConnection(
+			game_hash,
+			engine.clone(),
+			vec![],
+			TEE,
+			vec![],
+		)));
+
+		assert_ok!(Registry::concede(Origin::signed(3), game_hash));
+		assert!(!System::events().iter().any(|record| record.event ==
+			mock::Event::Registry(crate::Event::GameConceded(game_hash, 3))));
+
+		assert_ok!(tee.finish(game_hash, 4));
+		System::assert_has_event(mock::Event::Registry(crate::Event::PrivatePlayersRevealed(
+			game_hash,
+			vec![3, 4],
+		)));
+		assert!(!Registry::is_private_game(&game_hash));
+	});
+}
+
+#[test]
+fn quick_pairings_raise_collusion_suspicion() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let play = |players: Vec<u64>, blocks: u64| {
+			let game_hash =
+				<Registry as CreateGame<_, _>>::create_game(engine.clone(), players.clone())
+					.expect("engine is registered");
+			assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]));
+			assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+			run_to_block(System::block_number() + blocks);
+			assert_ok!(Registry::finish_game(
+				Origin::signed(TEE),
+				game_hash,
+				GameOutcome::Winner(players[0]),
+				final_state(),
+				None,
+				None,
+				None
+			));
+		};
+		let suspected = || {
+			System::events().iter().any(|record| {
+				matches!(record.event, mock::Event::Registry(crate::Event::SuspectedCollusion(..)))
+			})
+		};
+
+		run_to_block(10);
+		register_engine(&engine, 0);
+
+		// long games are no pairing telemetry
+		play(vec![2, 1], 4);
+		assert_eq!(Registry::pairing_count(1, 2), 0);
+
+		play(vec![2, 1], 1);
+		assert_eq!(Registry::pairing_count(1, 2), 1);
+		assert!(!suspected());
+
+		play(vec![1, 2], 0);
+		assert_eq!(Registry::pairing_count(1, 2), 2);
+		assert_eq!(Registry::pairing_count(2, 1), 0);
+		System::assert_has_event(mock::Event::Registry(crate::Event::SuspectedCollusion(1, 2, 2)));
+	});
+}
+
+#[test]
+fn high_value_games_need_bonded_tees() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateWageredGame<_, _, _>>::create_wagered_game(
+			engine.clone(),
+			vec![1, 2],
+			101,
+		)
+		.expect("engine is registered");
+		assert_eq!(Registry::game_wagers(&game_hash), 101);
+
+		assert_noop!(
+			Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]),
+			Error::<Test>::TeeBondTooLow.with_weight(<() as WeightInfo>::ack_game(1))
+		);
+
+		assert_noop!(Registry::bond_tee(Origin::signed(1), 50), Error::<Test>::NotRegisteredTee);
+		assert_ok!(Registry::bond_tee(Origin::signed(TEE), 30));
+		assert_ok!(Registry::bond_tee(Origin::signed(TEE), 20));
+		assert_eq!(Registry::tee_bonds(TEE), 50);
+		assert_eq!(Balances::reserved_balance(TEE), 50);
+		System::assert_last_event(mock::Event::Registry(crate::Event::TeeBondChanged(TEE, 50)));
+
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(1),
+			final_state(),
+			None,
+			None,
+			None
+		));
+		assert_eq!(Registry::game_wagers(&game_hash), 0);
+
+		assert_noop!(
+			Registry::unbond_tee(Origin::signed(TEE), 51),
+			Error::<Test>::InsufficientTeeBond
+		);
+		assert_ok!(Registry::unbond_tee(Origin::signed(TEE), 10));
+		assert_eq!(Registry::tee_bonds(TEE), 40);
+
+		// games up to the high-value wager are run by any AjunaTEE
+		let game_hash = <Registry as CreateWageredGame<_, _, _>>::create_wagered_game(
+			engine.clone(),
+			vec![1, 2],
+			100,
+		)
+		.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine, vec![game_hash]));
+
+		assert_ok!(Registry::deregister_tee(Origin::signed(FOUNDER), TEE));
+		assert_eq!(Registry::tee_bonds(TEE), 0);
+		assert_eq!(Balances::reserved_balance(TEE), 0);
+	});
+}
+
+#[test]
+fn priority_games_are_acknowledged_first() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::bond_tee(Origin::signed(TEE), 50));
+		let normal = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let wagered = <Registry as CreateWageredGame<_, _, _>>::create_wagered_game(
+			engine.clone(),
+			vec![3, 4],
+			101,
+		)
+		.expect("engine is registered");
+		System::assert_last_event(mock::Event::Registry(crate::Event::GamePrioritized(
+			wagered,
+			engine.clone(),
+		)));
+		let tournament =
+			<Registry as CreateGame<_, _>>::create_tournament_game(engine.clone(), vec![5, 6])
+				.expect("engine is registered");
+		assert!(Registry::is_priority_game(&tournament));
+		assert!(!Registry::is_priority_game(&normal));
+
+		let game_queue = Registry::game_queues(&engine);
+		assert_eq!(game_queue.length(), 3);
+		assert_eq!(game_queue.priority_length(), 2);
+		assert_eq!(game_queue.iter().collect::<Vec<_>>(), vec![wagered, tournament, normal]);
+		assert_eq!(Registry::queue_lengths(), vec![(engine.clone(), 3)]);
+
+		// the priority lane is drained before the normal lane
+		assert_noop!(
+			Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![normal]),
+			Error::<Test>::AckHashMismatch.with_weight(<() as WeightInfo>::ack_game(1))
+		);
+		assert_ok!(Registry::ack_game(
+			Origin::signed(TEE),
+			engine.clone(),
+			vec![wagered, tournament, normal]
+		));
+		assert!(Registry::game_queues(&engine).is_empty());
+		assert_eq!(Registry::do_try_state(), Ok(()));
+
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ready(tournament));
+		assert_ok!(tee.finish(tournament, 5));
+		assert!(!Registry::is_tournament_game(&tournament));
+	});
+}
+
+#[test]
+fn expired_attestations_get_no_new_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_eq!(Registry::attested_at(TEE), Some(10));
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+
+		run_to_block(10 + AttestationValidity::get() + 1);
+		assert_noop!(
+			Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]),
+			Error::<Test>::AttestationExpired
+		);
+
+		assert_noop!(
+			Registry::reattest_tee(Origin::signed(1), Some(VALID_QUOTE.to_vec())),
+			Error::<Test>::NotRegisteredTee
+		);
+		assert_noop!(
+			Registry::reattest_tee(Origin::signed(TEE), Some(b"forged".to_vec())),
+			DispatchError::Other("invalid quote")
+		);
+		assert_ok!(Registry::reattest_tee(Origin::signed(TEE), Some(VALID_QUOTE.to_vec())));
+		assert_eq!(Registry::attested_at(TEE), Some(31));
+		assert_eq!(Registry::registered_tees(TEE).unwrap().enclave, [1; 32]);
+		System::assert_last_event(mock::Event::Registry(crate::Event::TeeReattested(TEE, [1; 32])));
+
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine, vec![game_hash]));
+	});
+}
+
+#[test]
+fn every_state_change_is_emitted() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_ok!(tee.finish(game_hash, 2));
+		assert_ok!(Registry::flag_result(Origin::signed(1), game_hash));
+
+		let state_changes: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				mock::Event::Registry(crate::Event::GameStateChanged(hash, from, to, by)) => {
+					assert_eq!(hash, game_hash);
+					Some((from, to, by))
+				},
+				_ => None,
+			})
+			.collect();
+		assert_eq!(
+			state_changes,
+			vec![
+				(GameState::None, GameState::Waiting, None),
+				(GameState::Waiting, GameState::Accepted, Some(TEE)),
+				(GameState::Accepted, GameState::Running, Some(TEE)),
+				(GameState::Running, GameState::Finished(GameOutcome::Winner(2)), Some(TEE)),
+				(
+					GameState::Finished(GameOutcome::Winner(2)),
+					GameState::Reconfirming(GameOutcome::Winner(2)),
+					Some(1)
+				),
+			]
+		);
+	});
+}
+
+#[test]
+fn queued_games_tell_players_they_are_matched() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 3);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		let players = Registry::game_registry(&game_hash).players.into_inner();
+		assert_eq!(players.len(), 2);
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameQueued {
+			game: game_hash,
+			engine: engine.clone(),
+			players,
+			bracket: 3,
+			time_control: None,
+			init_data: Vec::new(),
+		}));
+
+		// private games keep their players out of it
+		let game_hash =
+			<Registry as CreateGame<_, _>>::create_private_game(engine.clone(), vec![3, 4])
+				.expect("engine is registered");
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameQueued {
+			game: game_hash,
+			engine,
+			players: vec![],
+			bracket: 3,
+			time_control: None,
+			init_data: Vec::new(),
+		}));
+	});
+}
+
+#[test]
+fn lifecycle_events_carry_the_game() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameAccepted {
+			game: game_hash,
+			tee: TEE,
+		}));
+		assert_ok!(tee.ready(game_hash));
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameStarted {
+			game: game_hash,
+			tee: TEE,
+			players: vec![1, 2],
+		}));
+
+		run_to_block(13);
+		assert_ok!(tee.finish(game_hash, 2));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameFinished {
+			game: game_hash,
+			engine,
+			players: vec![1, 2],
+			outcome: GameOutcome::Winner(2),
+			duration_blocks: 3,
+		}));
+	});
+}
+
+#[test]
+fn dropped_games_tell_why() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+
+		// administrative drops are up to the operator
+		assert_noop!(
+			MockTee(TEE).drop(game_hash, &engine, DropReason::AdminAction),
+			Error::<Test>::InvalidDropReason
+		);
+		assert_noop!(
+			Registry::batch_drop_games(
+				Origin::signed(TEE),
+				vec![game_hash],
+				DropReason::AdminAction
+			),
+			Error::<Test>::InvalidDropReason
+		);
+
+		// players are not to blame for a failing AjunaTEE
+		assert_ok!(MockTee(TEE).drop(game_hash, &engine, DropReason::TeeFailure));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+		assert!(Registry::game_entry(&game_hash).is_none());
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameDropped {
+			game: game_hash,
+			engine: engine.clone(),
+			reason: DropReason::TeeFailure,
+			tee: TEE,
+			players: vec![1, 2],
+		}));
+		assert_eq!(
+			Registry::dropped_game(&game_hash),
+			Some(DroppedGame { reason: DropReason::TeeFailure, by: Some(TEE), dropped: 11 })
+		);
+
+		// running games are dropped for a failing game engine, but not for a no-show
+		let running = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		assert_ok!(MockTee(TEE).ack(&engine, vec![running]));
+		assert_ok!(MockTee(TEE).ready(running));
+		assert_noop!(
+			MockTee(TEE).drop(running, &engine, DropReason::NoShow),
+			Error::<Test>::InvalidStateTransition
+		);
+		assert_ok!(Registry::batch_drop_games(
+			Origin::signed(FOUNDER),
+			vec![running],
+			DropReason::AdminAction
+		));
+		assert_eq!(Registry::dropped_game(&running).unwrap().reason, DropReason::AdminAction);
+
+		// the records are pruned with the games of their block
+		run_to_block(11 + GameRetention::get());
+		assert_eq!(Registry::dropped_game(&game_hash), None);
+		assert_eq!(Registry::dropped_game(&running), None);
+	});
+}
+
+#[test]
+fn players_leaving_matchmaking_are_told_why() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let dequeued =
+			|player, reason| mock::Event::Registry(crate::Event::PlayerDequeued(player, reason));
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(Registry::leave_queue(Origin::signed(1)), Error::<Test>::NotQueued);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		assert_ok!(Registry::leave_queue(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Registry::left_players(1), Some(0));
+		System::assert_last_event(dequeued(1, DequeueReason::Left));
+
+		// the player that left is skipped, the other one goes back into matchmaking
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		run_next_block();
+		assert!(Registry::game_queues(&engine).is_empty());
+		assert_eq!(Registry::left_players(1), None);
+		assert!(Registry::queued_players(2).is_some());
+
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+		System::assert_has_event(dequeued(1, DequeueReason::Matched));
+		System::assert_has_event(dequeued(2, DequeueReason::Matched));
+
+		// players banned while queued don't make it into a game
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(4), 1, None, None));
+		assert_ok!(Registry::ban_account(Origin::signed(FOUNDER), 3));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert!(Registry::queued_players(4).is_some());
+		System::assert_has_event(dequeued(3, DequeueReason::Banned));
+	});
+}
+
+#[test]
+fn games_running_overtime_expire() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		run_to_block(10 + MaxGameDuration::get() - 1);
+		assert_noop!(
+			Registry::expire_game(Origin::none(), game_hash),
+			Error::<Test>::GameNotExpired
+		);
+
+		run_next_block();
+		assert_ok!(Registry::expire_game(Origin::none(), game_hash));
+		assert!(Registry::game_entry(&game_hash).is_none());
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameExpired(
+			game_hash,
+			GameState::Running,
+			ExpiryReason::Overtime,
+		)));
+	});
+}
+
+#[test]
+fn scheduler_expires_games_at_their_deadline() {
+	new_test_ext().execute_with(|| {
+		ScheduledExpiry::set(true);
+		let engine = GameEngine::new(1, 1);
+		let create = |players| {
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), players)
+				.expect("engine is registered")
+		};
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let accepted = create(vec![1, 2]);
+		let running = create(vec![3, 4]);
+		let waiting = create(vec![5, 6]);
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![accepted]));
+		assert_ok!(tee.ack(&engine, vec![running]));
+		assert_ok!(tee.ready(running));
+
+		run_to_block(9 + WaitingTimeout::get());
+		assert!(Registry::game_entry(&waiting).is_some());
+		assert_eq!(Registry::game_registry(&accepted).game_state, GameState::Accepted);
+
+		// waiting games are dropped, accepted games are queued again
+		run_next_block();
+		assert!(Registry::game_entry(&waiting).is_none());
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameExpired(
+			waiting,
+			GameState::Waiting,
+			ExpiryReason::NotAcknowledged,
+		)));
+		assert_eq!(Registry::game_registry(&accepted).game_state, GameState::Waiting);
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameExpired(
+			accepted,
+			GameState::Accepted,
+			ExpiryReason::NotReady,
+		)));
+
+		// the requeued game expires with its new deadline
+		run_to_block(10 + WaitingTimeout::get() + WaitingTimeout::get());
+		assert!(Registry::game_entry(&accepted).is_none());
+
+		// the expiry of finished games is cancelled
+		assert_ok!(tee.finish(running, 3));
+		run_to_block(10 + MaxGameDuration::get() + 1);
+		assert_eq!(
+			Registry::archived_game(&running).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(3))
+		);
+		assert!(!System::events().iter().any(|record| {
+			record.event ==
+				mock::Event::Registry(crate::Event::GameExpired(
+					running,
+					GameState::Running,
+					ExpiryReason::Overtime,
+				))
+		}));
+	});
+}
+
+#[test]
+fn heartbeats_report_registry_health() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let accepted = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let _waiting = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![accepted]));
+		assert_eq!(Registry::active_games(&engine), 1);
+
+		run_to_block(12);
+		System::assert_last_event(mock::Event::Registry(crate::Event::RegistryHeartbeat(vec![(
+			engine.clone(),
+			1,
+			1,
+		)])));
+
+		// finished games are not active anymore
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), accepted));
+		assert_eq!(Registry::active_games(&engine), 1);
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			accepted,
+			GameOutcome::Winner(1),
+			final_state(),
+			None,
+			None,
+			None
+		));
+		assert_eq!(Registry::active_games(&engine), 0);
+	});
+}
+
+#[test]
+fn wait_times_are_estimated_per_bracket() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 2);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		run_to_block(18);
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+		assert_eq!(Registry::queued_since(2), Some(18));
+
+		// the first match sets the estimate, to the average wait of 9 and 1 blocks
+		run_next_block();
+		assert_eq!(Registry::queued_since(1), None);
+		assert_eq!(Registry::estimated_waits(), vec![(engine.clone(), 2, 5)]);
+
+		// later matches move it by an eighth
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(4), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::estimated_wait(&engine, 2), Some((5 * 7 + 1) / 8));
+	});
+}
+
+#[test]
+fn slow_matchmaking_degrades_a_bracket() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::queue(Origin::signed(1), 1, None, None));
+		run_to_block(17);
+		assert_ok!(Registry::queue(Origin::signed(2), 1, None, None));
+
+		// player 1 waited longer than the SLA
+		run_next_block();
+		assert!(Registry::is_degraded(&engine, 0));
+		System::assert_has_event(mock::Event::Registry(crate::Event::MatchmakingDegraded(
+			engine.clone(),
+			0,
+			4,
+			8,
+		)));
+
+		assert_ok!(Registry::queue(Origin::signed(3), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(4), 1, None, None));
+		run_next_block();
+		assert!(!Registry::is_degraded(&engine, 0));
+		System::assert_has_event(mock::Event::Registry(crate::Event::MatchmakingRecovered(
+			engine, 0, 3,
+		)));
+	});
+}
+
+#[test]
+fn games_keep_a_timeline_of_their_states() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let create = |players| {
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), players)
+				.expect("engine is registered")
+		};
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = create(vec![1, 2]);
+		let dropped = create(vec![3, 4]);
+		let tee = MockTee(TEE);
+		run_to_block(11);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		run_to_block(12);
+		assert_ok!(tee.ready(game_hash));
+		run_to_block(13);
+		assert_ok!(tee.finish(game_hash, 1));
+		assert_eq!(
+			Registry::game_timeline(&game_hash),
+			vec![
+				(GameState::Waiting, 10),
+				(GameState::Accepted, 11),
+				(GameState::Running, 12),
+				(GameState::Finished(GameOutcome::Winner(1)), 13),
+			]
+		);
+
+		// dropped games leave no timeline behind
+		assert_ok!(tee.drop(dropped, &engine, DropReason::TeeFailure));
+		assert!(Registry::game_timeline(&dropped).is_empty());
+
+		// games from before the timelines were kept have theirs rebuilt from their timestamps
+		GameTimelines::<Test>::remove(&game_hash);
+		assert_eq!(
+			Registry::game_timeline(&game_hash),
+			vec![
+				(GameState::Waiting, 10),
+				(GameState::Accepted, 11),
+				(GameState::Running, 12),
+				(GameState::Finished(GameOutcome::Winner(1)), 13),
+			]
+		);
+
+		// full timelines keep the first transition and drop the oldest after it
+		for block in 0..MAX_GAME_TRANSITIONS as u64 {
+			System::set_block_number(20 + block);
+			Registry::note_transition(&game_hash, &GameState::Waiting);
+		}
+		let timeline = Registry::game_transitions(&game_hash);
+		assert_eq!(timeline.len(), MAX_GAME_TRANSITIONS as usize);
+		assert_eq!(timeline.first(), Some(&(GameState::Waiting, 10)));
+		assert_eq!(timeline.get(1), Some(&(GameState::Waiting, 21)));
+
+		// pruned games lose their timeline
+		run_to_block(13 + GameRetention::get());
+		assert_eq!(Registry::game_entry(&game_hash), None);
+		assert!(Registry::game_transitions(&game_hash).is_empty());
+	});
+}
+
+#[test]
+fn lifetime_counters_track_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let create = || {
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+				.expect("engine is registered")
+		};
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let finished = create();
+		let dropped = create();
+		let expired = create();
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![finished]));
+		assert_ok!(tee.ready(finished));
+		assert_ok!(tee.finish(finished, 1));
+		assert_ok!(tee.drop(dropped, &engine, DropReason::TeeFailure));
+
+		run_to_block(10 + WaitingTimeout::get());
+		assert_ok!(Registry::expire_game(Origin::none(), expired));
+
+		let counters =
+			GameCounters { created: 3, acknowledged: 1, finished: 1, dropped: 1, expired: 1 };
+		assert_eq!(Registry::engine_counters(&engine), counters);
+		assert_eq!(Registry::total_counters(), counters);
+		assert_eq!(Registry::engine_counters(GameEngine::new(2, 1)), GameCounters::default());
+	});
+}
+
+#[test]
+fn engine_stats_aggregate_the_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let create = |players| {
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), players)
+				.expect("engine is registered")
+		};
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let played = create(vec![1, 2]);
+		let abandoned = create(vec![3, 4]);
+		let dropped = create(vec![5, 6]);
+		let tee = MockTee(TEE);
+		run_to_block(13);
+		assert_ok!(tee.ack(&engine, vec![played, abandoned]));
+		assert_eq!(Registry::engine_stats(&engine).active_players, 4);
+		assert_ok!(tee.ready(played));
+		assert_ok!(tee.finish(played, 1));
+		assert_ok!(tee.ready(abandoned));
+
+		// games dropped before an AjunaTEE acknowledged them aren't abandoned
+		run_next_block();
+		assert_ok!(tee.drop(abandoned, &engine, DropReason::TeeFailure));
+		assert_ok!(tee.drop(dropped, &engine, DropReason::TeeFailure));
+		let running = create(vec![7, 8]);
+		assert_ok!(tee.ack(&engine, vec![running]));
+
+		let stats = Registry::engine_stats(&engine);
+		assert_eq!(
+			stats,
+			EngineMetrics {
+				games_played: 1,
+				games_abandoned: 1,
+				queue_wait_blocks: 6,
+				queue_waits: 3,
+				active_players: 2,
+			}
+		);
+		assert_eq!(stats.average_queue_wait(), 2);
+		assert_eq!(stats.abandonment_rate(), Perbill::from_percent(50));
+		assert_eq!(Registry::engine_stats(GameEngine::new(2, 1)), EngineMetrics::default());
+		assert_eq!(EngineMetrics::default().abandonment_rate(), Perbill::zero());
+	});
+}
+
+#[test]
+fn running_games_are_listed_in_pages() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let public = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let private =
+			<Registry as CreateGame<_, _>>::create_private_game(engine.clone(), vec![3, 4])
+				.expect("engine is registered");
+		let _waiting = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![5, 6])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![public, private]));
+		assert_ok!(tee.ready(public));
+		run_next_block();
+		assert_ok!(tee.ready(private));
+
+		let mut running = Registry::running_games(0, 1);
+		assert_eq!(running.len(), 1);
+		running.extend(Registry::running_games(1, 1));
+		assert!(Registry::running_games(2, 1).is_empty());
+		running.sort_by_key(|game| game.started_at);
+		assert_eq!(
+			running,
+			vec![
+				RunningGame {
+					game_hash: public,
+					game_engine: engine.clone(),
+					players: vec![1, 2],
+					tee: Some(TEE),
+					started_at: 10,
+				},
+				RunningGame {
+					game_hash: private,
+					game_engine: engine,
+					players: vec![],
+					tee: Some(TEE),
+					started_at: 11,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn finished_games_anchor_their_replay() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		assert_noop!(
+			Registry::finish_game(
+				Origin::signed(TEE),
+				game_hash,
+				GameOutcome::Winner(1),
+				final_state(),
+				None,
+				Some(vec![0; 129]),
+				None
+			),
+			Error::<Test>::ReplayTooLong
+		);
+
+		let cid = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec();
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(1),
+			final_state(),
+			None,
+			Some(cid.clone()),
+			None
+		));
+		assert_eq!(Registry::game_replay(game_hash).map(BoundedVec::into_inner), Some(cid.clone()));
+		assert_eq!(Registry::final_state(game_hash), Some(final_state()));
+		assert!(System::events().iter().any(|record| record.event ==
+			mock::Event::Registry(crate::Event::ReplayAnchored(game_hash, cid.clone()))));
+	});
+}
+
+#[test]
+fn games_finishing_in_a_full_block_wait_for_the_next_block() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let first = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let second = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![first, second]));
+		assert_ok!(tee.ready(first));
+		assert_ok!(tee.ready(second));
+
+		MaxFinishedPerBlock::set(1);
+		let finish = |game_hash, winner| {
+			Registry::finish_game(
+				Origin::signed(TEE),
+				game_hash,
+				GameOutcome::Winner(winner),
+				final_state(),
+				None,
+				None,
+				None,
+			)
+		};
+		assert_ok!(finish(first, 1));
+		assert_noop!(finish(second, 3), Error::<Test>::TooManyFinishedGames);
+		assert_eq!(Registry::block_finished_games(), vec![first]);
+
+		run_next_block();
+		assert_ok!(finish(second, 3));
+		assert_eq!(Registry::block_finished_games(), vec![second]);
+	});
+}
+
+#[test]
+fn results_are_verified_against_their_proof() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let transcript = b"1:d4 2:d5 1:c4 2:dxc4".to_vec();
+		let result_proof = BlakeTwo256::hash(&transcript);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_noop!(
+			Registry::verify_result(Origin::signed(3), game_hash, transcript.clone()),
+			Error::<Test>::NoResultProof
+		);
+
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(1),
+			final_state(),
+			None,
+			None,
+			Some(result_proof)
+		));
+		assert_eq!(Registry::result_proof(game_hash), Some(result_proof));
+		System::assert_has_event(mock::Event::Registry(crate::Event::ResultProofAnchored(
+			game_hash,
+			result_proof,
+		)));
+
+		// anyone can check a transcript against what the AjunaTEE reported
+		assert_noop!(
+			Registry::verify_result(Origin::signed(3), game_hash, b"1:e4".to_vec()),
+			Error::<Test>::ResultProofMismatch
+		);
+		assert_noop!(
+			Registry::verify_result(Origin::signed(3), game_hash, vec![0; 65_537]),
+			Error::<Test>::TranscriptTooLong
+		);
+		assert_ok!(Registry::verify_result(Origin::signed(3), game_hash, transcript));
+		System::assert_last_event(mock::Event::Registry(crate::Event::ResultVerified(
+			game_hash, 3,
+		)));
+	});
+}
+
+#[test]
+fn checkpointed_games_survive_a_failing_tee() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			SIGNING_TEE,
+			vec![engine.clone()],
+			None
+		));
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		let state_root = H256::repeat_byte(1);
+		assert_noop!(
+			MockTee(SIGNING_TEE).checkpoint(game_hash, state_root, 1),
+			Error::<Test>::NotGameTee
+		);
+		assert_ok!(tee.checkpoint(game_hash, state_root, 1));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameCheckpointed(
+			game_hash, state_root, 1,
+		)));
+		assert_noop!(
+			tee.checkpoint(game_hash, H256::repeat_byte(2), 1),
+			Error::<Test>::StaleCheckpoint
+		);
+
+		// the game is queued again, to be resumed from the checkpoint
+		assert_ok!(tee.drop(game_hash, &engine, DropReason::TeeFailure));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameRequeued(game_hash)));
+		let game_entry = Registry::game_registry(&game_hash);
+		assert_eq!(game_entry.game_state, GameState::Waiting);
+		assert_eq!(game_entry.tee_id, None);
+		assert_eq!(Registry::game_checkpoint(game_hash).map(|c| c.state_root), Some(state_root));
+
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_ok!(tee.finish(game_hash, 1));
+		assert_eq!(Registry::game_checkpoint(game_hash), None);
+	});
+}
+
+#[test]
+fn restarted_tees_resume_their_running_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			SIGNING_TEE,
+			vec![engine.clone()],
+			None
+		));
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_noop!(tee.resume(vec![game_hash]), Error::<Test>::GameNotRunning);
+		assert_ok!(tee.ready(game_hash));
+		assert_noop!(MockTee(SIGNING_TEE).resume(vec![game_hash]), Error::<Test>::NotGameTee);
+
+		// the game is accepted again, till the AjunaTEE started its session again
+		for resume_count in 1..=MaxGameResumes::get() {
+			run_next_block();
+			assert_ok!(tee.resume(vec![game_hash]));
+			System::assert_last_event(mock::Event::Registry(crate::Event::GameResuming(
+				game_hash,
+				TEE,
+				resume_count,
+			)));
+			let game_entry = Registry::game_registry(&game_hash);
+			assert_eq!(game_entry.game_state, GameState::Accepted);
+			assert_eq!(game_entry.timestamps.accepted, System::block_number());
+			assert_eq!(Registry::resume_count(&game_hash), resume_count);
+			assert_eq!(Registry::tee_loads(TEE).accepted, 1);
+			assert_eq!(Registry::tee_loads(TEE).running, 0);
+			assert_ok!(tee.ready(game_hash));
+		}
+
+		// one resume too many drops the game
+		assert_ok!(tee.resume(vec![game_hash]));
+		assert_eq!(Registry::game_entry(&game_hash), None);
+		assert_eq!(Registry::dropped_game(&game_hash).unwrap().reason, DropReason::ResumeLimit);
+		assert_eq!(Registry::resume_count(&game_hash), 0);
+		assert_eq!(Registry::tee_loads(TEE).total(), 0);
+		assert!(!QueueBonds::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn games_get_short_match_codes() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let first = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let second = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+
+		let match_code =
+			|code: &[u8]| MatchCode::try_from(code.to_vec()).expect("fits a match code");
+		assert_eq!(Registry::match_code(first), Some(match_code(b"AJN-0000")));
+		assert_eq!(Registry::match_code(second), Some(match_code(b"AJN-0001")));
+		assert_eq!(Registry::resolve_match_code(match_code(b"AJN-0001")), Some(second));
+		assert_eq!(Registry::encode_match_code(146_675), b"AJN-4F7K".to_vec());
+		assert_eq!(Registry::encode_match_code(32u64.pow(4)), b"AJN-10000".to_vec());
+
+		// codes of voided games resolve to nothing
+		assert_ok!(MockTee(TEE).drop(first, &engine, DropReason::NoShow));
+		assert_eq!(Registry::match_code(first), None);
+		assert_eq!(Registry::resolve_match_code(match_code(b"AJN-0000")), None);
+	});
+}
+
+#[test]
+fn matchmaking_stops_at_its_weight_limit() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		for player in 1..=6 {
+			assert_ok!(Registry::queue(Origin::signed(player), 1, None, None));
+		}
+		let base = <Test as frame_system::Config>::DbWeight::get().reads(3);
+		let match_weight = <() as WeightInfo>::match_game();
+
+		// a block without leftover weight creates no games
+		assert_eq!(Registry::on_idle(11, 0), 0);
+		assert_eq!(Registry::game_queues(&engine).length(), 0);
+
+		// only one match fits into the weight left over in a block
+		assert_eq!(Registry::on_idle(11, base + match_weight), base + match_weight);
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+
+		// governance caps matchmaking below the leftover weight
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::MatchmakingWeight(base + match_weight)
+		));
+		assert_eq!(Registry::on_idle(12, Weight::max_value()), base + match_weight);
+		assert_eq!(Registry::game_queues(&engine).length(), 2);
+
+		// the budget defaults to the runtime limit
+		MatchmakingWeightLimit::set(base + match_weight);
+		<Parameters<Test>>::kill();
+		assert_eq!(Registry::on_idle(13, Weight::max_value()), base + match_weight);
+		assert_eq!(Registry::game_queues(&engine).length(), 3);
+
+		// without players left to match only the lookup is used
+		MatchmakingWeightLimit::set(Weight::max_value());
+		assert_eq!(
+			Registry::on_idle(14, Weight::max_value()),
+			base + <() as WeightInfo>::no_match()
+		);
+	});
+}
+
+#[test]
+fn tees_take_games_up_to_their_capacity() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+		let other_tee = MockTee(SIGNING_TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			SIGNING_TEE,
+			vec![engine.clone()],
+			None
+		));
+		let games: Vec<_> = (0..4)
+			.map(|index| {
+				<Registry as CreateGame<_, _>>::create_game(
+					engine.clone(),
+					vec![index + 1, index + 11],
+				)
+				.expect("engine is registered")
+			})
+			.collect();
+
+		assert_ok!(tee.ack(&engine, games[..2].to_vec()));
+		assert_ok!(tee.ready(games[0]));
+		assert_eq!(Registry::tee_loads(TEE), TeeLoad { accepted: 1, running: 1 });
+		let mut assignments = Registry::tee_assignments(TEE);
+		assignments.sort();
+		let mut acknowledged = games[..2].to_vec();
+		acknowledged.sort();
+		assert_eq!(assignments, acknowledged);
+		assert_eq!(Registry::least_loaded_tee(&engine), Some(SIGNING_TEE));
+		// only the AjunaTEE that acknowledged a game starts it
+		assert_noop!(other_tee.ready(games[1]), Error::<Test>::NotGameTee);
+
+		assert_ok!(tee.ack(&engine, vec![games[2]]));
+		assert_noop!(
+			tee.ack(&engine, vec![games[3]]),
+			Error::<Test>::TeeAtCapacity.with_weight(<() as WeightInfo>::ack_game(1))
+		);
+		assert_ok!(other_tee.ack(&engine, vec![games[3]]));
+		assert_eq!(Registry::least_loaded_tee(&engine), Some(SIGNING_TEE));
+
+		// games over free the capacity
+		assert_ok!(tee.finish(games[0], 1));
+		assert_ok!(tee.drop(games[1], &engine, DropReason::NoShow));
+		assert_eq!(Registry::tee_loads(TEE), TeeLoad { accepted: 1, running: 0 });
+		assert_ok!(tee.drop(games[2], &engine, DropReason::NoShow));
+		assert!(!TeeLoads::<Test>::contains_key(TEE));
+		assert_eq!(Registry::least_loaded_tee(&engine), Some(TEE));
+	});
+}
+
+#[test]
+fn games_of_offline_tees_are_reassigned() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+		TeeTimeout::set(3);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let games: Vec<_> = (0..2)
+			.map(|index| {
+				<Registry as CreateGame<_, _>>::create_game(
+					engine.clone(),
+					vec![index + 1, index + 11],
+				)
+				.expect("engine is registered")
+			})
+			.collect();
+		assert_ok!(tee.ack(&engine, games.clone()));
+		assert_ok!(tee.ready(games[0]));
+		assert_eq!(Registry::tee_last_seen(TEE), Some(10));
+
+		run_to_block(12);
+		assert_noop!(Registry::tee_heartbeat(Origin::signed(5)), Error::<Test>::NotRegisteredTee);
+		assert_ok!(Registry::tee_heartbeat(Origin::signed(TEE)));
+		System::assert_last_event(mock::Event::Registry(crate::Event::TeeHeartbeat(TEE)));
+
+		// the games stay with the AjunaTEE while it's seen
+		run_to_block(15);
+		assert_eq!(Registry::game_registry(games[0]).game_state, GameState::Running);
+
+		run_to_block(16);
+		for game_hash in games.iter() {
+			System::assert_has_event(mock::Event::Registry(crate::Event::GameReassigned(
+				*game_hash, TEE,
+			)));
+			let game_entry = Registry::game_registry(game_hash);
+			assert_eq!(game_entry.game_state, GameState::Waiting);
+			assert_eq!(game_entry.tee_id, None);
+		}
+		assert!(!TeeLoads::<Test>::contains_key(TEE));
+		assert_eq!(Registry::game_queues(&engine).length(), 2);
+	});
+}
+
+#[test]
+fn games_of_offline_tees_without_room_in_the_queue_are_dropped() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+		TeeTimeout::set(3);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		for index in 0..MaxQueueSize::get() as u64 {
+			<Registry as CreateGame<_, _>>::create_game(
+				engine.clone(),
+				vec![index + 100, index + 200],
+			)
+			.expect("engine is registered");
+		}
+
+		run_to_block(14);
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameDropped {
+			game: game_hash,
+			engine: engine.clone(),
+			reason: DropReason::TeeFailure,
+			tee: TEE,
+			players: vec![1, 2],
+		}));
+		assert_eq!(Registry::game_entry(&game_hash), None);
+		assert_eq!(Registry::dropped_game(&game_hash).unwrap().reason, DropReason::TeeFailure);
+		assert!(!TeeLoads::<Test>::contains_key(TEE));
+		assert_eq!(Registry::tee_timeout_cursor(), None);
+	});
+}
+
+#[test]
+fn games_are_assigned_to_tees_when_queued() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		TeeAssignmentMode::set(TeeAssignment::RoundRobin);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			SIGNING_TEE,
+			vec![engine.clone()],
+			None
+		));
+		let games: Vec<_> = (0..3)
+			.map(|index| {
+				<Registry as CreateGame<_, _>>::create_game(
+					engine.clone(),
+					vec![index + 1, index + 11],
+				)
+				.expect("engine is registered")
+			})
+			.collect();
+		let assigned: Vec<_> = games
+			.iter()
+			.map(|game_hash| Registry::game_registry(game_hash).tee_id.expect("game is assigned"))
+			.collect();
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameAssigned(
+			games[0],
+			assigned[0],
+		)));
+		// the AjunaTEEs take turns
+		assert_ne!(assigned[0], assigned[1]);
+		assert_eq!(assigned[0], assigned[2]);
+		assert_eq!(Registry::assignment_turn(&engine), 3);
+
+		// only the assigned AjunaTEE confirms a game, in any order
+		let (first, second) = (MockTee(assigned[0]), MockTee(assigned[1]));
+		assert_noop!(
+			second.ack(&engine, vec![games[0]]),
+			Error::<Test>::NotGameTee.with_weight(<() as WeightInfo>::ack_game(1))
+		);
+		assert_ok!(second.ack(&engine, vec![games[1]]));
+		assert_ok!(first.ack(&engine, vec![games[2], games[0]]));
+		assert!(Registry::game_queues(&engine).is_empty());
+		assert_eq!(Registry::game_registry(games[0]).game_state, GameState::Accepted);
+		assert_eq!(Registry::tee_loads(assigned[0]).accepted, 2);
+
+		// games of deregistered AjunaTEEs are open to the others
+		TeeAssignmentMode::set(TeeAssignment::Random);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![4, 14])
+			.expect("engine is registered");
+		let tee = Registry::game_registry(&game_hash).tee_id.expect("game is assigned");
+		assert!(tee == TEE || tee == SIGNING_TEE);
+		let other_tee = if tee == TEE { SIGNING_TEE } else { TEE };
+		assert_ok!(Registry::deregister_tee(Origin::signed(FOUNDER), tee));
+		assert_ok!(MockTee(other_tee).ack(&engine, vec![game_hash]));
+		assert_eq!(Registry::game_registry(&game_hash).tee_id, Some(other_tee));
+	});
+}
+
+#[test]
+fn seasons_crown_their_champions() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		SeasonLength::set(20);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		play_game(&engine, vec![1, 2], 1);
+		play_game(&engine, vec![3, 4], 3);
+		play_game(&engine, vec![1, 3], 1);
+		assert_eq!(Registry::current_season(), 0);
+		assert_eq!(Registry::season_tallies(0, 1), SeasonTally { wins: 2, losses: 0 });
+		assert_eq!(Registry::season_tallies(0, 3), SeasonTally { wins: 1, losses: 1 });
+		assert_eq!(Registry::season_leaderboard(), vec![(1, 2), (3, 1)]);
+
+		run_to_block(20);
+		System::assert_has_event(mock::Event::Registry(crate::Event::SeasonEnded(
+			0,
+			vec![(1, 2), (3, 1)],
+		)));
+		assert_eq!(Registry::current_season(), 1);
+		assert_eq!(Registry::season_champions(0), vec![(1, 2), (3, 1)]);
+		assert!(Registry::season_leaderboard().is_empty());
+
+		// the tallies of past seasons are kept
+		play_game(&engine, vec![2, 4], 4);
+		assert_eq!(Registry::season_tallies(1, 4), SeasonTally { wins: 1, losses: 0 });
+		assert_eq!(Registry::season_tallies(0, 4), SeasonTally { wins: 0, losses: 1 });
+		assert_eq!(Registry::season_leaderboard(), vec![(4, 1)]);
+	});
+}
+
+#[test]
+fn paused_engines_take_no_new_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let running = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![running]));
+		assert_ok!(tee.ready(running));
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), engine.id, None, None));
+
+		assert_noop!(
+			Registry::set_engine_paused(Origin::signed(1), engine.clone(), true),
+			BadOrigin
+		);
+		assert_noop!(
+			Registry::set_engine_paused(Origin::signed(FOUNDER), GameEngine::new(9, 1), true),
+			Error::<Test>::EngineNotRegistered
+		);
+		assert_ok!(Registry::set_engine_paused(Origin::signed(FOUNDER), engine.clone(), true));
+		System::assert_last_event(mock::Event::Registry(crate::Event::EnginePaused(
+			engine.clone(),
+			true,
+		)));
+		assert_noop!(
+			Registry::queue(Origin::signed(5), engine.id, None, None),
+			Error::<Test>::EngineDisabled
+		);
+		assert_noop!(
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![5, 6]),
+			Error::<Test>::EngineDisabled
+		);
+
+		// queued players wait for the game engine, games already created are played out
+		run_next_block();
+		assert!(Registry::game_queues(&engine).is_empty());
+		assert!(QueuedPlayers::<Test>::contains_key(1));
+		assert_ok!(tee.finish(running, 3));
+
+		assert_ok!(Registry::set_engine_paused(Origin::signed(FOUNDER), engine.clone(), false));
+		run_next_block();
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+	});
+}
+
+#[test]
+fn small_batches_are_refunded() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let games: Vec<_> = (0..3)
+			.map(|index| {
+				<Registry as CreateGame<_, _>>::create_game(
+					engine.clone(),
+					vec![index + 1, index + 11],
+				)
+				.expect("engine is registered")
+			})
+			.collect();
+
+		// a batch failing part way is charged up to the failing game
+		let mut batch = games.clone();
+		batch.swap(1, 2);
+		assert_noop!(
+			tee.ack(&engine, batch),
+			Error::<Test>::AckHashMismatch.with_weight(<() as WeightInfo>::ack_game(2))
+		);
+
+		let post_info = tee.ack(&engine, games[..1].to_vec()).expect("game is next in the queue");
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::ack_game(1)));
+
+		// dropping a game is charged for the actual game queue, not a full one
+		let post_info = tee.drop(games[1], &engine, DropReason::NoShow).expect("game is waiting");
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::drop_game(2)));
+		assert!(
+			post_info.actual_weight.unwrap() < <() as WeightInfo>::drop_game(MaxQueueSize::get())
+		);
+	});
+}
+
+#[test]
+fn results_are_finished_in_batches() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let games: Vec<_> = [(1, 2), (3, 4)]
+			.iter()
+			.map(|(player, opponent)| {
+				let game_hash = <Registry as CreateGame<_, _>>::create_game(
+					engine.clone(),
+					vec![*player, *opponent],
+				)
+				.expect("engine is registered");
+				assert_ok!(tee.ack(&engine, vec![game_hash]));
+				assert_ok!(tee.ready(game_hash));
+				game_hash
+			})
+			.collect();
+		let unknown = H256::repeat_byte(0xaa);
+		let submission = |game_hash, winner| GameSubmission {
+			game_hash,
+			outcome: GameOutcome::Winner(winner),
+			final_state: final_state(),
+			signature: None,
+			replay: None,
+			result_proof: None,
+		};
+		let results =
+			vec![submission(games[0], 1), submission(unknown, 1), submission(games[1], 4)];
+
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::AckBatchLimit(2)
+		));
+		assert_noop!(
+			Registry::batch_finish_games(Origin::signed(TEE), results.clone()),
+			Error::<Test>::FinishToMany
+		);
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::AckBatchLimit(3)
+		));
+
+		// results failing to finish a game don't stop the rest of the batch and aren't charged
+		let post_info = Registry::batch_finish_games(Origin::signed(TEE), results)
+			.expect("games are assigned to the AjunaTEE");
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::batch_finish_games(2)));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GamesFinished(
+			TEE,
+			2,
+			vec![unknown],
+		)));
+		assert_eq!(
+			Registry::archived_game(games[0]).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(1))
+		);
+		assert_eq!(
+			Registry::archived_game(games[1]).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(4))
+		);
+		assert_eq!(Registry::final_state(games[1]), Some(final_state()));
+		assert_eq!(Registry::game_entry(&unknown), None);
+	});
+}
+
+#[test]
+fn dead_games_are_dropped_in_batches() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let running: Vec<_> = [(1, 2), (3, 4)]
+			.iter()
+			.map(|(player, opponent)| {
+				let game_hash = <Registry as CreateGame<_, _>>::create_game(
+					engine.clone(),
+					vec![*player, *opponent],
+				)
+				.expect("engine is registered");
+				assert_ok!(tee.ack(&engine, vec![game_hash]));
+				assert_ok!(tee.ready(game_hash));
+				game_hash
+			})
+			.collect();
+		let waiting = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![5, 6])
+			.expect("engine is registered");
+		let unknown = H256::repeat_byte(0xaa);
+
+		// AjunaTEEs only drop the games assigned to them
+		assert_noop!(
+			Registry::batch_drop_games(
+				Origin::signed(TEE),
+				vec![running[0], waiting],
+				DropReason::TeeFailure
+			),
+			Error::<Test>::NotGameTee
+		);
+
+		// games already gone are skipped and not charged
+		let post_info = Registry::batch_drop_games(
+			Origin::signed(TEE),
+			vec![running[0], unknown, running[1]],
+			DropReason::TeeFailure,
+		)
+		.expect("games are assigned to the AjunaTEE");
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::batch_drop_games(2)));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GamesDropped(
+			running.clone(),
+			DropReason::TeeFailure,
+			Some(TEE),
+		)));
+		assert!(running.iter().all(|game_hash| Registry::game_entry(game_hash).is_none()));
+
+		// the operator drops any game, kept in the audit log
+		assert_ok!(Registry::batch_drop_games(
+			Origin::signed(FOUNDER),
+			vec![waiting],
+			DropReason::NoShow
+		));
+		assert_eq!(Registry::game_entry(&waiting), None);
+		assert_eq!(Registry::game_queues(&engine).length(), 0);
+		let slot = (Registry::audit_log_count() - 1) % MaxAuditEntries::get();
+		let entry = Registry::audit_log(slot).unwrap();
+		assert_eq!(entry.action, AdminAction::DropGames);
+		assert_eq!(entry.actor, Some(FOUNDER));
+	});
+}
+
+#[test]
+fn finished_games_are_pruned_after_their_retention() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		MaintenanceWeightLimit::set(Weight::max_value());
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = play_game(&engine, vec![1, 2], 2);
+		assert_eq!(Registry::prune_schedule(10), vec![game_hash]);
+
+		// the registry only keeps the games still in play
+		assert!(!GameRegistry::<Test>::contains_key(&game_hash));
+		let game_entry = Registry::archived_game(&game_hash).unwrap();
+		assert_eq!(game_entry.game_state, GameState::Finished(GameOutcome::Winner(2)));
+		assert_eq!(Registry::game_entry(&game_hash), Some(game_entry));
+		assert_eq!(
+			Registry::games_by_state(GameState::Finished(GameOutcome::Aborted)),
+			vec![game_hash]
+		);
+
+		run_to_block(10 + GameRetention::get() - 1);
+		assert!(Registry::game_entry(&game_hash).is_some());
+
+		// only the hash of the canonical result is kept
+		run_to_block(10 + GameRetention::get());
+		System::assert_last_event(mock::Event::Registry(crate::Event::GamePruned(game_hash)));
+		assert_eq!(Registry::game_entry(&game_hash), None);
+		assert_eq!(Registry::archived_game(&game_hash), None);
+		assert_eq!(Registry::final_state(&game_hash), None);
+		assert_eq!(Registry::match_code(&game_hash), None);
+		assert!(Registry::canonical_result_hashes(&game_hash).is_some());
+		assert_eq!(Registry::prune_schedule(10), Vec::<H256>::new());
+		assert_eq!(Registry::prune_cursor(), 11);
+	});
+}
+
+#[test]
+fn maintenance_cleans_up_in_on_idle() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		MaintenanceWeightLimit::set(Weight::max_value());
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let waiting = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let stale = H256::repeat_byte(1);
+		Registry::game_queues(&engine).enqueue(stale);
+		assert_ok!(Registry::sanction_player(
+			Origin::signed(FOUNDER),
+			3,
+			SanctionKind::QueueBan,
+			2
+		));
+
+		// games not waiting anymore are taken out of the queues
+		run_next_block();
+		System::assert_has_event(mock::Event::Registry(crate::Event::StaleGamesRemoved(
+			engine.clone(),
+			1,
+		)));
+		assert!(!Registry::game_queues(&engine).contains(stale));
+		assert!(Registry::sanctions(3, SanctionKind::QueueBan).is_some());
+
+		// lapsed sanctions are removed
+		run_next_block();
+		System::assert_has_event(mock::Event::Registry(crate::Event::SanctionLapsed(
+			3,
+			SanctionKind::QueueBan,
+		)));
+		assert_eq!(Registry::sanctions(3, SanctionKind::QueueBan), None);
+
+		// games past their timeout expire without the offchain worker
+		run_to_block(10 + WaitingTimeout::get());
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameExpired(
+			waiting,
+			GameState::Waiting,
+			ExpiryReason::NotAcknowledged,
+		)));
+		assert!(Registry::game_entry(&waiting).is_none());
+		assert_eq!(Registry::maintenance_cursor(MaintenanceTask::Expiry), None);
+	});
+}
+
+#[test]
+fn maintenance_resumes_in_the_next_block() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let first = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		let second = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4])
+			.expect("engine is registered");
+		run_to_block(10 + WaitingTimeout::get());
+
+		// step, cursor and a single game fit into the budget
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let budget = db.reads_writes(2, 2) + db.reads(2) + <() as WeightInfo>::expire_game();
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::MaintenanceWeight(budget)
+		));
+		Registry::on_idle(System::block_number(), Weight::max_value());
+		let expired = [first, second]
+			.iter()
+			.filter(|game_hash| Registry::game_entry(game_hash).is_none())
+			.count();
+		assert_eq!(expired, 1);
+		assert!(Registry::maintenance_cursor(MaintenanceTask::Expiry).is_some());
+		assert_eq!(Registry::maintenance_step(), MaintenanceTask::Pruning);
+
+		// the other tasks go first in the next blocks, the expiry resumes after them and finishes
+		// its pass, before the next pass starts over
+		run_to_block(System::block_number() + 2 * MaintenanceTask::COUNT as u64);
+		assert!(Registry::game_entry(&first).is_none());
+		assert!(Registry::game_entry(&second).is_none());
+	});
+}
+
+#[test]
+fn games_get_a_random_seed() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let create = || {
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+				.expect("engine is registered")
+		};
+		let game1 = create();
+		let game2 = create();
+
+		// the seed is distinct from the id and from the seeds of other games
+		let seed = Registry::game_seed(&game1).expect("seed is committed at creation");
+		assert_ne!(seed, game1);
+		assert_ne!(Registry::game_seed(&game2), Some(seed));
+
+		// and removed with the game
+		assert_ok!(MockTee(TEE).drop(game1, &engine, DropReason::TeeFailure));
+		assert_eq!(Registry::game_seed(&game1), None);
+	});
+}
+
+#[test]
+fn game_ids_come_from_the_configured_strategy() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let nonce = Nonce::<Test>::get();
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_eq!(game_hash, RandomGameIds::<Test>::generate_game_id(&engine, &[1, 2], nonce));
+		assert_eq!(Nonce::<Test>::get(), nonce + 1);
+
+		// deterministic ids only depend on the players, the game engine version and the nonce
+		let id = DeterministicGameIds::<Test>::generate_game_id(&engine, &[1, 2], nonce);
+		assert_eq!(id, DeterministicGameIds::<Test>::generate_game_id(&engine, &[1, 2], nonce));
+		assert_ne!(id, DeterministicGameIds::<Test>::generate_game_id(&engine, &[1, 2], nonce + 1));
+		assert_ne!(id, DeterministicGameIds::<Test>::generate_game_id(&engine, &[2, 1], nonce));
+	});
+}
+
+#[test]
+fn games_are_formed_for_the_players_per_game() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let players_per_game =
+			|range| vec![GameRule::new(GameRuleType::PlayersPerGame(range), [0u8; 16])];
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		for range in [[0, 2], [3, 2], [2, 17]] {
+			assert_noop!(
+				Registry::set_game_rules(
+					Origin::signed(FOUNDER),
+					engine.clone(),
+					players_per_game(range)
+				),
+				Error::<Test>::InvalidGameRules
+			);
+		}
+		assert_ok!(Registry::set_game_rules(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			players_per_game([3, 3])
+		));
+		assert_noop!(
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2]),
+			Error::<Test>::PlayerCountMismatch
+		);
+
+		// the matches of two players are combined into games of three
+		for player in 1..=4 {
+			assert_ok!(Registry::queue(Origin::signed(player), 1, None, None));
+		}
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().expect("game is queued");
+		assert_eq!(Registry::game_registry(&game_hash).players.len(), 3);
+
+		// the remaining player waits for the next match, and can still leave
+		let waiting = Registry::forming_parties(0);
+		assert_eq!(waiting.len(), 1);
+		assert!(Registry::queued_players(waiting[0]).is_some());
+		assert_ok!(Registry::leave_queue(Origin::signed(waiting[0])));
+		assert_ok!(Registry::queue(Origin::signed(5), 1, None, None));
+		assert_ok!(Registry::queue(Origin::signed(6), 1, None, None));
+		run_next_block();
+		assert_eq!(Registry::forming_parties(0), vec![5, 6]);
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+	});
+}
+
+#[test]
+fn players_are_limited_to_concurrent_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_eq!(Registry::player_games(1, engine.id), 1);
+		assert_noop!(
+			Registry::queue(Origin::signed(1), engine.id, None, None),
+			Error::<Test>::AlreadyInGame
+		);
+
+		assert_noop!(
+			Registry::set_max_games_per_player(Origin::signed(1), engine.clone(), Some(2)),
+			BadOrigin
+		);
+		assert_ok!(Registry::set_max_games_per_player(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			Some(2)
+		));
+		System::assert_last_event(mock::Event::Registry(crate::Event::MaxGamesPerPlayerSet(
+			engine.clone(),
+			Some(2),
+		)));
+		assert_eq!(Registry::max_games_per_player(&engine), 2);
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+
+		assert_ok!(Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![game_hash]));
+		assert_ok!(Registry::ready_game(Origin::signed(TEE), game_hash));
+		assert_ok!(Registry::finish_game(
+			Origin::signed(TEE),
+			game_hash,
+			GameOutcome::Winner(1),
+			final_state(),
+			None,
+			None,
+			None
+		));
+		assert_eq!(Registry::player_games(1, engine.id), 0);
+		assert_eq!(Registry::player_games(2, engine.id), 0);
+
+		assert_ok!(Registry::set_max_games_per_player(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			None
+		));
+		assert_eq!(Registry::max_games_per_player(&engine), MaxGamesPerPlayer::get());
+	});
+}
+
+#[test]
+fn lifecycle_calls_fail_with_specific_errors() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+
+		assert_noop!(tee.ready(game_hash), Error::<Test>::GameNotAccepted);
+		assert_noop!(tee.finish(game_hash, 1), Error::<Test>::GameNotRunning);
+		assert_noop!(
+			tee.ack(&engine, vec![H256::repeat_byte(1)]),
+			Error::<Test>::AckHashMismatch.with_weight(<() as WeightInfo>::ack_game(1))
+		);
+		assert_noop!(
+			tee.ack(&engine, vec![game_hash, H256::repeat_byte(1)]),
+			Error::<Test>::QueueEmpty.with_weight(<() as WeightInfo>::ack_game(2))
+		);
+
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			SIGNING_TEE,
+			vec![engine.clone()],
+			None
+		));
+		assert_noop!(MockTee(SIGNING_TEE).finish(game_hash, 1), Error::<Test>::NotGameTee);
+		assert_ok!(tee.finish(game_hash, 1));
+	});
+}
+
+#[test]
+fn friends_are_challenged_to_matches() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		for opponents in [vec![], vec![1, 2], vec![2, 2]] {
+			assert_noop!(
+				Registry::create_match(Origin::signed(1), opponents, engine.clone(), None, None),
+				Error::<Test>::InvalidOpponents
+			);
+		}
+		assert_ok!(Registry::create_match(
+			Origin::signed(1),
+			vec![2, 3],
+			engine.clone(),
+			None,
+			None
+		));
+		let match_id = MatchInvites::<Test>::iter_keys().next().expect("match is created");
+		System::assert_last_event(mock::Event::Registry(crate::Event::MatchCreated(
+			match_id,
+			1,
+			vec![2, 3],
+		)));
+
+		// the game is only queued once all opponents accepted
+		assert_noop!(
+			Registry::accept_invite(Origin::signed(4), match_id),
+			Error::<Test>::NotInvitedToMatch
+		);
+		assert_ok!(Registry::accept_invite(Origin::signed(2), match_id));
+		assert_noop!(
+			Registry::accept_invite(Origin::signed(2), match_id),
+			Error::<Test>::NotInvitedToMatch
+		);
+		assert!(Registry::game_queues(&engine).is_empty());
+		assert_ok!(Registry::accept_invite(Origin::signed(3), match_id));
+		assert_eq!(Registry::match_invites(&match_id), None);
+		let game_hash = Registry::game_queues(&engine).peek().expect("game is queued");
+		assert_eq!(Registry::game_registry(&game_hash).players.to_vec(), vec![1, 2, 3]);
+		System::assert_last_event(mock::Event::Registry(crate::Event::MatchStarted(
+			match_id, game_hash,
+		)));
+
+		// invites not accepted in time expire
+		assert_ok!(Registry::create_match(Origin::signed(4), vec![5], engine.clone(), None, None));
+		let match_id = MatchInvites::<Test>::iter_keys().next().expect("match is created");
+		run_to_block(10 + InviteTimeout::get());
+		assert_noop!(
+			Registry::accept_invite(Origin::signed(5), match_id),
+			Error::<Test>::MatchInviteExpired
+		);
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::MaintenanceWeight(Weight::max_value())
+		));
+		run_next_block();
+		assert_eq!(Registry::match_invites(&match_id), None);
+		System::assert_has_event(mock::Event::Registry(crate::Event::InviteExpired(match_id)));
+	});
+}
+
+#[test]
+fn matches_start_at_their_scheduled_block() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::create_match(Origin::signed(1), vec![2], engine.clone(), None, Some(10)),
+			Error::<Test>::InvalidStartBlock
+		);
+		assert_ok!(Registry::create_match(
+			Origin::signed(1),
+			vec![2],
+			engine.clone(),
+			None,
+			Some(15)
+		));
+		let match_id = MatchInvites::<Test>::iter_keys().next().expect("match is created");
+		assert_eq!(Registry::invite_start(&match_id), Some(15));
+
+		// the accepted match waits for its start block outside the queue
+		assert_ok!(Registry::accept_invite(Origin::signed(2), match_id));
+		assert_eq!(Registry::invite_start(&match_id), None);
+		let game_hash = Registry::current_games(&1)[0];
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameScheduled(
+			game_hash,
+			engine.clone(),
+			15,
+		)));
+		let game_entry = Registry::game_registry(&game_hash);
+		assert_eq!(game_entry.game_state, GameState::Scheduled);
+		assert_eq!(game_entry.tee_id, None);
+		assert_eq!(Registry::game_queues(&engine).length(), 0);
+		assert_eq!(Registry::player_games(&1, engine.id), 1);
+		assert_noop!(Registry::start_scheduled_game(Origin::signed(1), game_hash), BadOrigin);
+		assert_ok!(Registry::do_try_state());
+
+		// the scheduler queues it for an AjunaTEE at its start block
+		run_to_block(14);
+		assert_eq!(Registry::game_registry(&game_hash).game_state, GameState::Scheduled);
+		run_to_block(15);
+		let game_entry = Registry::game_registry(&game_hash);
+		assert_eq!(game_entry.game_state, GameState::Waiting);
+		assert_eq!(game_entry.timestamps.queued, 15);
+		assert_eq!(Registry::game_queues(&engine).iter().collect::<Vec<_>>(), vec![game_hash]);
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameQueued {
+			game: game_hash,
+			engine: engine.clone(),
+			players: vec![1, 2],
+			bracket: 0,
+			time_control: None,
+			init_data: vec![],
+		}));
+		assert_noop!(
+			Registry::start_scheduled_game(Origin::root(), game_hash),
+			Error::<Test>::GameNotScheduled
+		);
+		assert_ok!(Registry::do_try_state());
+
+		// dropped scheduled games don't start anymore
+		assert_ok!(Registry::create_match(
+			Origin::signed(3),
+			vec![4],
+			engine.clone(),
+			None,
+			Some(20)
+		));
+		let match_id = MatchInvites::<Test>::iter_keys().next().expect("match is created");
+		assert_ok!(Registry::accept_invite(Origin::signed(4), match_id));
+		let game_hash = Registry::current_games(&3)[0];
+		assert_ok!(Registry::batch_drop_games(
+			Origin::signed(FOUNDER),
+			vec![game_hash],
+			DropReason::AdminAction
+		));
+		assert_eq!(Registry::player_games(&3, engine.id), 0);
+		run_to_block(20);
+		assert_eq!(Registry::game_entry(&game_hash), None);
+		assert_eq!(Registry::game_queues(&engine).length(), 1);
+
+		// matches accepted past their start block are queued right away
+		assert_ok!(Registry::create_match(
+			Origin::signed(5),
+			vec![6],
+			engine.clone(),
+			None,
+			Some(22)
+		));
+		let match_id = MatchInvites::<Test>::iter_keys().next().expect("match is created");
+		run_to_block(22);
+		assert_ok!(Registry::accept_invite(Origin::signed(6), match_id));
+		let game_hash = Registry::current_games(&5)[0];
+		assert_eq!(Registry::game_registry(&game_hash).game_state, GameState::Waiting);
+		assert_eq!(Registry::game_queues(&engine).length(), 2);
+	});
+}
+
+#[test]
+fn games_carry_their_init_data() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::set_engine_init_data(Origin::signed(1), engine.clone(), Some(vec![8])),
+			BadOrigin
+		);
+		assert_noop!(
+			Registry::set_engine_init_data(
+				Origin::signed(FOUNDER),
+				engine.clone(),
+				Some(vec![0; 5])
+			),
+			Error::<Test>::InitDataTooLong
+		);
+		assert_ok!(Registry::set_engine_init_data(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			Some(vec![8, 8])
+		));
+		System::assert_last_event(mock::Event::Registry(crate::Event::EngineInitDataSet(
+			engine.clone(),
+		)));
+
+		// games of the game engine start from its defaults
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_eq!(
+			Registry::game_init_data(&game_hash).map(|data| data.to_vec()),
+			Some(vec![8, 8])
+		);
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameQueued {
+			game: game_hash,
+			engine: engine.clone(),
+			players: vec![1, 2],
+			bracket: 0,
+			time_control: None,
+			init_data: vec![8, 8],
+		}));
+
+		// matches bring their own
+		assert_noop!(
+			Registry::create_match(
+				Origin::signed(3),
+				vec![4],
+				engine.clone(),
+				Some(vec![0; 5]),
+				None
+			),
+			Error::<Test>::InitDataTooLong
+		);
+		assert_ok!(Registry::create_match(
+			Origin::signed(3),
+			vec![4],
+			engine.clone(),
+			Some(vec![1, 2, 3]),
+			None
+		));
+		let match_id = MatchInvites::<Test>::iter_keys().next().expect("match is created");
+		assert_ok!(Registry::accept_invite(Origin::signed(4), match_id));
+		assert_eq!(Registry::invite_init_data(&match_id), None);
+		let game_hash = Registry::game_queues(&engine).iter().last().expect("game is queued");
+		assert_eq!(
+			Registry::game_init_data(&game_hash).map(|data| data.to_vec()),
+			Some(vec![1, 2, 3])
+		);
+
+		// cleared defaults leave new games without init data
+		assert_ok!(Registry::set_engine_init_data(Origin::signed(FOUNDER), engine.clone(), None));
+		assert_eq!(Registry::engine_init_data(&engine), None);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine, vec![5, 6])
+			.expect("engine is registered");
+		assert_eq!(Registry::game_init_data(&game_hash), None);
+	});
+}
+
+#[test]
+fn players_with_auto_requeue_queue_again() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let preferences = |engine_id| PlayerPreferences {
+			auto_requeue: true,
+			engine_id,
+			time_control: None,
+			region: Some(3),
+		};
+		assert_ok!(Registry::set_preferences(Origin::signed(1), Some(preferences(None))));
+		System::assert_last_event(mock::Event::Registry(crate::Event::PreferencesSet(1)));
+		assert_ok!(Registry::set_preferences(Origin::signed(2), Some(preferences(Some(9)))));
+		assert_eq!(Registry::player_preferences(1), Some(preferences(None)));
+
+		play_game(&engine, vec![1, 2], 1);
+		assert!(Registry::queued_players(1).is_some());
+		assert!(Registry::queued_players(2).is_none());
+		System::assert_has_event(mock::Event::Registry(crate::Event::AutoRequeueFailed(
+			2,
+			Error::<Test>::EngineNotRegistered.into(),
+		)));
+
+		assert_ok!(Registry::set_preferences(Origin::signed(1), None));
+		assert_eq!(Registry::player_preferences(1), None);
+	});
+}
+
+#[test]
+fn tee_calls_need_a_registered_tee() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let impostor = MockTee(5);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+
+		assert_noop!(impostor.ack(&engine, vec![game_hash]), Error::<Test>::NotRegisteredTee);
+		assert_noop!(
+			impostor.drop(game_hash, &engine, DropReason::NoShow),
+			Error::<Test>::NotRegisteredTee
+		);
+		assert_ok!(MockTee(TEE).ack(&engine, vec![game_hash]));
+		assert_noop!(impostor.ready(game_hash), Error::<Test>::NotRegisteredTee);
+		assert_ok!(MockTee(TEE).ready(game_hash));
+		assert_noop!(
+			impostor.checkpoint(game_hash, H256::repeat_byte(1), 1),
+			Error::<Test>::NotRegisteredTee
+		);
+		assert_noop!(impostor.finish(game_hash, 1), Error::<Test>::NotRegisteredTee);
+		assert_noop!(
+			Registry::batch_finish_games(Origin::signed(5), Vec::new()),
+			Error::<Test>::NotRegisteredTee
+		);
+		assert_ok!(MockTee(TEE).finish(game_hash, 1));
+	});
+}
+
+#[test]
+fn game_states_only_move_along_the_lifecycle() {
+	use GameState::*;
+	let states: Vec<GameStateOf<Test>> = vec![
+		None,
+		Waiting,
+		Accepted,
+		Running,
+		Finished(GameOutcome::Winner(1)),
+		Suspended(GameOutcome::Winner(1)),
+		Reconfirming(GameOutcome::Winner(1)),
+	];
+	let allowed = [
+		(0, 1),
+		(1, 2),
+		(2, 3),
+		(2, 1),
+		(3, 1),
+		(3, 2),
+		(3, 4),
+		(4, 6),
+		(4, 5),
+		(6, 4),
+		(6, 5),
+		(5, 4),
+		(1, 0),
+		(2, 0),
+		(3, 0),
+		(4, 0),
+		(5, 0),
+	];
+	for (from, from_state) in states.iter().enumerate() {
+		for (to, to_state) in states.iter().enumerate() {
+			assert_eq!(
+				from_state.can_transition_to(to_state),
+				allowed.contains(&(from, to)),
+				"{:?} -> {:?}",
+				from_state,
+				to_state
+			);
+		}
+	}
+}
+
+#[test]
+fn illegal_transitions_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_noop!(tee.ready(game_hash), Error::<Test>::GameNotAccepted);
+		assert_noop!(tee.finish(game_hash, 1), Error::<Test>::GameNotRunning);
+
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_noop!(tee.finish(game_hash, 1), Error::<Test>::GameNotRunning);
+		assert_ok!(tee.ready(game_hash));
+		assert_noop!(tee.ready(game_hash), Error::<Test>::GameNotAccepted);
+
+		// running games are only dropped for a failing AjunaTEE
+		assert_noop!(
+			tee.drop(game_hash, &engine, DropReason::NoShow),
+			Error::<Test>::InvalidStateTransition
+		);
+		assert_noop!(
+			Registry::batch_drop_games(Origin::signed(TEE), vec![game_hash], DropReason::NoShow),
+			Error::<Test>::InvalidStateTransition
+		);
+
+		assert_ok!(tee.finish(game_hash, 1));
+		assert_noop!(tee.ready(game_hash), Error::<Test>::GameNotAccepted);
+		assert_noop!(tee.finish(game_hash, 1), Error::<Test>::GameNotRunning);
+
+		// flagged results wait for their re-confirmation
+		assert_ok!(Registry::flag_result(Origin::signed(2), game_hash));
+		assert_noop!(
+			tee.drop(game_hash, &engine, DropReason::TeeFailure),
+			Error::<Test>::InvalidStateTransition
+		);
+	});
+}
+
+#[test]
+fn players_in_a_game_cant_queue_again() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let other_engine = GameEngine::new(2, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		register_engine(&other_engine, 1);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_eq!(Registry::current_games(1), vec![game_hash]);
+		assert_eq!(Registry::current_games(2), vec![game_hash]);
+
+		// players can't queue for another game engine while in a game either
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_noop!(
+			Registry::queue(Origin::signed(1), other_engine.id, None, None),
+			Error::<Test>::AlreadyInGame
+		);
+		assert_ok!(Registry::queue(Origin::signed(3), other_engine.id, None, None));
+
+		// game engines allowing parallel games only limit the games of their own
+		assert_ok!(Registry::set_max_games_per_player(
+			Origin::signed(FOUNDER),
+			other_engine.clone(),
+			Some(2)
+		));
+		assert_ok!(Registry::queue(Origin::signed(2), other_engine.id, None, None));
+
+		assert_ok!(tee.finish(game_hash, 1));
+		assert!(Registry::current_games(1).is_empty());
+		assert!(Registry::current_games(2).is_empty());
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+	});
+}
+
+#[test]
+fn deprecated_engines_take_no_new_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(Registry::queue(Origin::signed(3), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(4), engine.id, None, None));
+
+		assert_noop!(Registry::deprecate_game_engine(Origin::signed(1), engine.clone()), BadOrigin);
+		assert_noop!(
+			Registry::deprecate_game_engine(Origin::signed(FOUNDER), GameEngine::new(9, 1)),
+			Error::<Test>::EngineNotRegistered
+		);
+		assert_ok!(Registry::deprecate_game_engine(Origin::signed(FOUNDER), engine.clone()));
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameEngineDeprecated(
+			engine.clone(),
+		)));
+		assert_eq!(Registry::deprecated_engines(&engine), Some(10));
+		assert_noop!(
+			Registry::deprecate_game_engine(Origin::signed(FOUNDER), engine.clone()),
+			Error::<Test>::EngineDeprecated
+		);
+
+		assert_noop!(
+			Registry::queue(Origin::signed(5), engine.id, None, None),
+			Error::<Test>::EngineDeprecated
+		);
+		assert_noop!(
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![5, 6]),
+			Error::<Test>::EngineDeprecated
+		);
+
+		// queued players are taken out of matchmaking with their bond
+		run_next_block();
+		assert_eq!(Registry::queued_players(3), None);
+		assert_eq!(Registry::queue_bonds(3), None);
+		System::assert_has_event(mock::Event::Registry(crate::Event::PlayerDequeued(
+			3,
+			DequeueReason::EngineDeprecated,
+		)));
+
+		// games already created are still played out
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_ok!(tee.finish(game_hash, 1));
+	});
+}
+
+#[test]
+fn ratings_place_new_players_and_decay() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		PlacementGames::set(1);
+		DecayPeriod::set(100);
+
+		// players in their placement games move twice as far
+		play_game(&engine, vec![1, 2], 1);
+		assert_eq!(Registry::player_rating(1, engine.id), Some(1532));
+		assert_eq!(Registry::player_rating(2, engine.id), Some(1468));
+		play_game(&engine, vec![1, 3], 1);
+		assert_eq!(Registry::player_rating(1, engine.id), Some(1546));
+		assert_eq!(Registry::player_rating(3, engine.id), Some(1471));
+		assert_eq!(
+			Registry::rating_activity(1, engine.id),
+			RatingActivity { games_played: 2, last_played: 10 }
+		);
+
+		// inactive players drift towards the initial rating once they queue again
+		run_to_block(160);
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		assert_eq!(Registry::player_rating(1, engine.id), Some(1523));
+		System::assert_has_event(mock::Event::Registry(crate::Event::RatingDecayed(
+			1, engine.id, 1546, 1523,
+		)));
+		assert_eq!(Registry::rating_activity(1, engine.id).last_played, 110);
+		assert_ok!(Registry::queue(Origin::signed(2), engine.id, None, None));
+		assert_eq!(Registry::player_rating(2, engine.id), Some(1484));
+
+		// ratings kept from before start to decay once their player queues
+		PlayerRating::<Test>::insert(4, engine.id, 1600);
+		assert_ok!(Registry::queue(Origin::signed(4), engine.id, None, None));
+		assert_eq!(Registry::player_rating(4, engine.id), Some(1600));
+		assert_eq!(
+			Registry::rating_activity(4, engine.id),
+			RatingActivity { games_played: 0, last_played: 160 }
+		);
+	});
+}
+
+#[test]
+fn ratings_move_players_across_brackets() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		RatingBoundaries::set(vec![1510]);
+		register_engine(&engine, 0);
+		assert_eq!(Registry::engine_brackets(1), Some(engine.clone()));
+		assert_noop!(
+			Registry::register_game_engine(Origin::signed(FOUNDER), GameEngine::new(2, 1), 1),
+			Error::<Test>::BracketInUse
+		);
+		assert_noop!(
+			Registry::register_game_engine(Origin::signed(FOUNDER), GameEngine::new(2, 1), 255),
+			Error::<Test>::BracketOutOfRange
+		);
+
+		assert_eq!(Registry::rating(&1, engine.id), 1500);
+		play_game(&engine, vec![1, 2], 1);
+		assert_eq!(Registry::player_rating(1, engine.id), Some(1516));
+		assert_eq!(Registry::player_rating(2, engine.id), Some(1484));
+
+		// the winner moved up a tier, the loser stays in the first one
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), engine.id, None, None));
+		assert_eq!(Registry::queued_players(1).unwrap().bracket, 1);
+		assert_eq!(Registry::queued_players(2).unwrap().bracket, 0);
+		assert_ok!(Registry::leave_queue(Origin::signed(1)));
+		assert_ok!(Registry::leave_queue(Origin::signed(2)));
+
+		// an upset moves the favourite back down
+		play_game(&engine, vec![1, 2], 2);
+		assert_eq!(Registry::rating(&1, engine.id), 1499);
+		assert_eq!(Registry::rating(&2, engine.id), 1501);
+		assert_ok!(Registry::queue(Origin::signed(3), engine.id, None, None));
+		assert_eq!(Registry::queued_players(3).unwrap().bracket, 0);
+	});
+}
+
+#[test]
+fn stakes_are_paid_to_the_winner() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+		let stake = 40;
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_noop!(
+			Registry::set_engine_stake(Origin::signed(1), engine.clone(), Some(stake)),
+			BadOrigin
+		);
+		assert_ok!(Registry::set_engine_stake(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			Some(stake)
+		));
+		System::assert_last_event(mock::Event::Registry(crate::Event::EngineStakeSet(
+			engine.clone(),
+			Some(stake),
+		)));
+
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		System::assert_last_event(mock::Event::Registry(crate::Event::StakeReserved(1, stake)));
+		assert_eq!(Balances::reserved_balance(1), QueueBond::get() + stake);
+		assert_ok!(Balances::transfer(Origin::signed(3), 4, INITIAL_BALANCE - 20));
+		assert_noop!(
+			Registry::queue(Origin::signed(3), engine.id, None, None),
+			Error::<Test>::InsufficientStake
+		);
+
+		// leaving the queue releases the stake
+		assert_ok!(Registry::leave_queue(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), engine.id, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		let mut stakes = Registry::game_stakes(&game_hash).into_inner();
+		stakes.sort();
+		assert_eq!(stakes, vec![(1, stake), (2, stake)]);
+		assert_eq!(Registry::game_wagers(&game_hash), 2 * stake);
+
+		assert_ok!(Registry::accept_match(Origin::signed(1), game_hash));
+		assert_ok!(Registry::accept_match(Origin::signed(2), game_hash));
+		assert_eq!(Balances::reserved_balance(2), stake);
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_ok!(tee.finish(game_hash, 2));
+		System::assert_has_event(mock::Event::Registry(crate::Event::PotPaid(
+			game_hash,
+			2,
+			2 * stake,
+		)));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - stake);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + stake);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(Registry::game_stakes(&game_hash).is_empty());
+	});
+}
+
+#[test]
+fn banned_accounts_forfeit_their_running_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+		let stake = 40;
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::set_engine_stake(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			Some(stake)
+		));
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), engine.id, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		assert_ok!(Registry::accept_match(Origin::signed(1), game_hash));
+		assert_ok!(Registry::accept_match(Origin::signed(2), game_hash));
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+
+		// the game is finished in favour of the opponent, who wins the pot
+		assert_ok!(Registry::ban_account(Origin::signed(FOUNDER), 1));
+		System::assert_has_event(mock::Event::Registry(crate::Event::GameConceded(game_hash, 1)));
+		assert_eq!(
+			Registry::game_entry(&game_hash).unwrap().game_state,
+			GameState::Finished(GameOutcome::Winner(2))
+		);
+		System::assert_has_event(mock::Event::Registry(crate::Event::PotPaid(
+			game_hash,
+			2,
+			2 * stake,
+		)));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - stake);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + stake);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(Registry::game_stakes(&game_hash).is_empty());
+		assert!(Registry::current_games(1).is_empty());
+	});
+}
+
+#[test]
+fn pots_reward_the_tee_and_pay_the_protocol_fee() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+		let stake = 40;
+		TeeRewardPercent::set(Percent::from_percent(10));
+		ProtocolFeePercent::set(Percent::from_percent(5));
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::set_engine_stake(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			Some(stake)
+		));
+		let tee_balance = Balances::free_balance(TEE);
+		let founder_balance = Balances::free_balance(FOUNDER);
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), engine.id, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+		assert_ok!(Registry::accept_match(Origin::signed(1), game_hash));
+		assert_ok!(Registry::accept_match(Origin::signed(2), game_hash));
+		assert_ok!(tee.ack(&engine, vec![game_hash]));
+		assert_ok!(tee.ready(game_hash));
+		assert_ok!(tee.finish(game_hash, 2));
+
+		// 10% of the pot of 80 goes to the TEE, 5% to the founder and the rest to the winner
+		System::assert_has_event(mock::Event::Registry(crate::Event::TeeRewarded(
+			game_hash, TEE, 8,
+		)));
+		System::assert_has_event(mock::Event::Registry(crate::Event::ProtocolFeePaid(
+			game_hash, 4,
+		)));
+		System::assert_has_event(mock::Event::Registry(crate::Event::PotPaid(game_hash, 2, 68)));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - stake);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + stake - 12);
+		assert_eq!(Balances::free_balance(TEE), tee_balance + 8);
+		assert_eq!(Balances::free_balance(FOUNDER), founder_balance + 4);
+	});
+}
+
+#[test]
+fn games_end_in_draws_and_rankings() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+		let stake = 40;
+		let ranking = |players: Vec<u64>| BoundedVec::try_from(players).expect("fits the game");
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		play_game(&engine, vec![1, 2], 1);
+		assert_eq!(Registry::rating(&1, engine.id), 1516);
+		assert_eq!(Registry::rating(&2, engine.id), 1484);
+
+		let drawn = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		<GameStakes<Test>>::insert(&drawn, vec![(1, stake), (2, stake)]);
+		assert_ok!(Balances::reserve(&1, stake));
+		assert_ok!(Balances::reserve(&2, stake));
+		assert_ok!(tee.ack(&engine, vec![drawn]));
+		assert_ok!(tee.ready(drawn));
+		assert_noop!(
+			tee.finish_with(drawn, GameOutcome::Winner(9)),
+			Error::<Test>::WinnerNotAPlayer
+		);
+		assert_noop!(
+			tee.finish_with(drawn, GameOutcome::Ranked(ranking(vec![1]))),
+			Error::<Test>::InvalidRanking
+		);
+		assert_noop!(
+			tee.finish_with(drawn, GameOutcome::Ranked(ranking(vec![1, 1]))),
+			Error::<Test>::InvalidRanking
+		);
+
+		// a draw refunds the stakes and moves the favourite towards the underdog
+		assert_ok!(tee.finish_with(drawn, GameOutcome::Draw));
+		System::assert_has_event(mock::Event::Registry(crate::Event::StakesRefunded(drawn)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+		assert_eq!(Registry::rating(&1, engine.id), 1515);
+		assert_eq!(Registry::rating(&2, engine.id), 1485);
+		assert_eq!(Registry::games_won(1), 1);
+
+		// every place of a ranking beats the ones below it
+		let ranked = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![3, 4, 5])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![ranked]));
+		assert_ok!(tee.ready(ranked));
+		assert_ok!(tee.finish_with(ranked, GameOutcome::Ranked(ranking(vec![3, 4, 5]))));
+		assert_eq!(Registry::rating(&3, engine.id), 1531);
+		assert_eq!(Registry::rating(&4, engine.id), 1500);
+		assert_eq!(Registry::rating(&5, engine.id), 1469);
+		assert_eq!(Registry::games_won(3), 1);
+		assert_eq!(Registry::games_won(4), 0);
+
+		// an aborted game leaves the ratings alone
+		let aborted = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![9, 10])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![aborted]));
+		assert_ok!(tee.ready(aborted));
+		assert_ok!(tee.finish_with(aborted, GameOutcome::Aborted));
+		assert_eq!(
+			Registry::archived_game(&aborted).unwrap().game_state,
+			GameState::Finished(GameOutcome::Aborted)
+		);
+		assert_eq!(Registry::rating(&9, engine.id), 1500);
+		assert_eq!(Registry::games_won(9) + Registry::games_won(10), 0);
+
+		assert_eq!(
+			results().into_iter().skip(1).map(|(_, _, result)| result).collect::<Vec<_>>(),
+			vec![GameResult::Draw, GameResult::Ranked(ranking(vec![3, 4, 5])), GameResult::Dropped]
+		);
+	});
+}
+
+#[test]
+fn settled_games_reward_their_players() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let tee = MockTee(TEE);
+
+		run_to_block(10);
+
+		// winners earn a bonus for every previous win of their streak, up to `max_streak` wins
+		register_engine(&engine, 0);
+		let first = play_game(&engine, vec![1, 2], 1);
+		System::assert_has_event(mock::Event::Registry(crate::Event::PlayersRewarded(
+			first,
+			vec![(1, 11), (2, 3)],
+		)));
+		for _ in 0..3 {
+			run_next_block();
+			play_game(&engine, vec![1, 2], 1);
+		}
+		assert_eq!(Registry::win_streak(1, engine.id), 4);
+		assert_eq!(Registry::win_streak(2, engine.id), 0);
+		assert_eq!(Registry::lifetime_points(1), 11 + 16 + 21 + 21);
+		assert_eq!(Registry::lifetime_points(2), 4 * 3);
+
+		// a draw ends the streaks and only earns the participation points
+		run_next_block();
+		let drawn = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![drawn]));
+		assert_ok!(tee.ready(drawn));
+		assert_ok!(tee.finish_with(drawn, GameOutcome::Draw));
+		assert_eq!(Registry::win_streak(1, engine.id), 0);
+		assert_eq!(Registry::lifetime_points(1), 70);
+
+		// aborted games earn nothing
+		run_next_block();
+		let aborted = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_ok!(tee.ack(&engine, vec![aborted]));
+		assert_ok!(tee.ready(aborted));
+		assert_ok!(tee.finish_with(aborted, GameOutcome::Aborted));
+		assert_eq!(Registry::lifetime_points(1), 70);
+
+		assert_eq!(
+			rewards().into_iter().filter(|(player, _, _)| *player == 1).collect::<Vec<_>>(),
+			vec![(1, 11, 1), (1, 16, 2), (1, 21, 3), (1, 21, 4), (1, 1, 0)]
+		);
+	});
+}
+
+#[test]
+fn finished_games_are_settled_with_the_settlement_queue() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		SettlementsPerBlock::set(2);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		let games: Vec<_> = [(1, 2), (3, 4), (5, 6)]
+			.iter()
+			.map(|(a, b)| play_game(&engine, vec![*a, *b], *a))
+			.collect();
+		assert_eq!(Registry::settlement_queue(), games);
+		assert_eq!(Registry::lifetime_points(1), 0);
+		assert!(rewards().is_empty());
+
+		// the settlements of a block are bounded, the rest is carried forward
+		run_next_block();
+		System::assert_has_event(mock::Event::Registry(crate::Event::ResultSettled(
+			games[1],
+			GameOutcome::Winner(3),
+		)));
+		assert_eq!(Registry::lifetime_points(1), 11);
+		assert_eq!(Registry::lifetime_points(4), 3);
+		assert_eq!(Registry::lifetime_points(5), 0);
+		assert_eq!(Registry::settlement_queue(), vec![games[2]]);
+
+		run_next_block();
+		assert_eq!(Registry::lifetime_points(5), 11);
+		assert!(Registry::settlement_queue().is_empty());
+	});
+}
+
+#[test]
+fn flooding_tees_are_throttled_and_suspended() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::set_parameter(
+			Origin::signed(FOUNDER),
+			RegistryParameter::TeeCallsPerBlock(1)
+		));
+		assert_ok!(Registry::tee_heartbeat(Origin::signed(TEE)));
+		assert_noop!(Registry::tee_heartbeat(Origin::signed(TEE)), Error::<Test>::TeeRateLimited);
+
+		// a block within the limit clears the throttles
+		run_next_block();
+		System::assert_has_event(mock::Event::Registry(crate::Event::TeeThrottled(TEE, 1)));
+		assert_eq!(Registry::tee_throttles(TEE), 1);
+		run_next_block();
+		assert_eq!(Registry::tee_throttles(TEE), 0);
+
+		// throttled blocks in a row suspend the AjunaTEE
+		for _ in 0..MaxTeeThrottles::get() {
+			assert_ok!(Registry::tee_heartbeat(Origin::signed(TEE)));
+			run_next_block();
+		}
+		System::assert_has_event(mock::Event::Registry(crate::Event::TeeSuspended(TEE)));
+		assert!(Registry::suspended_tees(TEE).is_some());
+		assert_eq!(Registry::tee_throttles(TEE), 0);
+		assert_noop!(Registry::tee_heartbeat(Origin::signed(TEE)), Error::<Test>::TeeSuspended);
+		assert_noop!(
+			Registry::ack_game(Origin::signed(TEE), engine.clone(), vec![]),
+			Error::<Test>::TeeSuspended
+		);
+
+		// governance lifts the suspension
+		assert_noop!(Registry::unsuspend_tee(Origin::signed(1), TEE), BadOrigin);
+		assert_ok!(Registry::unsuspend_tee(Origin::signed(FOUNDER), TEE));
+		System::assert_last_event(mock::Event::Registry(crate::Event::TeeUnsuspended(TEE)));
+		assert_noop!(
+			Registry::unsuspend_tee(Origin::signed(FOUNDER), TEE),
+			Error::<Test>::TeeNotSuspended
+		);
+		assert_ok!(Registry::tee_heartbeat(Origin::signed(TEE)));
+	});
+}
+
+#[test]
+fn stakes_are_refunded_for_dropped_games() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		assert_ok!(Registry::set_engine_stake(Origin::signed(FOUNDER), engine.clone(), Some(40)));
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), engine.id, None, None));
+		run_next_block();
+		let game_hash = Registry::game_queues(&engine).peek().unwrap().clone();
+
+		assert_ok!(MockTee(TEE).drop(game_hash, &engine, DropReason::NoShow));
+		System::assert_has_event(mock::Event::Registry(crate::Event::StakesRefunded(game_hash)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - QueueBond::get());
+		assert!(Registry::game_stakes(&game_hash).is_empty());
+	});
+}
+
+#[test]
+fn parties_are_matched_against_teams_of_the_same_size() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(1);
+
+		register_engine(&engine, 0);
+		let party = |members: Vec<u64>| BoundedVec::try_from(members).expect("fits the party");
+		assert_noop!(
+			Registry::queue_party(Origin::signed(1), engine.clone(), party(vec![1])),
+			Error::<Test>::InvalidParty
+		);
+		assert_noop!(
+			Registry::queue_party(Origin::signed(1), engine.clone(), party(vec![2, 3, 4, 5])),
+			Error::<Test>::InvalidParty
+		);
+
+		// the party is queued once all invited members accepted
+		assert_ok!(Registry::queue_party(Origin::signed(1), engine.clone(), party(vec![2])));
+		System::assert_last_event(mock::Event::Registry(crate::Event::PartyCreated(
+			1,
+			engine.clone(),
+			vec![2],
+		)));
+		assert_eq!(Balances::reserved_balance(1), QueueBond::get());
+		assert!(Registry::party_queue(&engine).is_empty());
+		assert_noop!(
+			Registry::accept_party_invite(Origin::signed(3), 1),
+			Error::<Test>::NotInvitedToParty
+		);
+		assert_ok!(Registry::accept_party_invite(Origin::signed(2), 1));
+		System::assert_last_event(mock::Event::Registry(crate::Event::PartyQueued(1, 2)));
+		assert_eq!(Balances::reserved_balance(2), QueueBond::get());
+		assert_eq!(Registry::party_queue(&engine), vec![1]);
+		assert_noop!(
+			Registry::queue(Origin::signed(2), engine.id, None, None),
+			Error::<Test>::AlreadyQueued
+		);
+
+		// a party waits for opponents of its size, solo players team up
+		assert_ok!(Registry::queue_party(Origin::signed(3), engine.clone(), party(vec![])));
+		run_to_block(2);
+		assert_eq!(Registry::party_queue(&engine), vec![1, 3]);
+		assert_ok!(Registry::queue_party(Origin::signed(4), engine.clone(), party(vec![])));
+		run_to_block(3);
+		let game_hash = Registry::current_games(&1)[0];
+		System::assert_has_event(mock::Event::Registry(crate::Event::PartiesMatched(
+			game_hash,
+			vec![1, 3, 4],
+		)));
+		assert_eq!(Registry::game_registry(&game_hash).players.into_inner(), vec![1, 2, 3, 4]);
+		let teams: Vec<_> = Registry::game_teams(&game_hash)
+			.unwrap()
+			.iter()
+			.map(|team| team.to_vec())
+			.collect();
+		assert_eq!(teams, vec![vec![1, 2], vec![3, 4]]);
+		assert!(Registry::party_queue(&engine).is_empty());
+		assert_eq!(Registry::parties(&1), None);
+		assert_ok!(Registry::do_try_state());
+
+		// disbanded parties release the bonds of their members
+		assert_ok!(Registry::queue_party(Origin::signed(5), engine.clone(), party(vec![6])));
+		assert_ok!(Registry::accept_party_invite(Origin::signed(6), 5));
+		assert_noop!(Registry::disband_party(Origin::signed(6)), Error::<Test>::NoParty);
+		assert_ok!(Registry::disband_party(Origin::signed(5)));
+		System::assert_last_event(mock::Event::Registry(crate::Event::PartyDisbanded(5)));
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert_eq!(Balances::reserved_balance(6), 0);
+		assert!(Registry::party_queue(&engine).is_empty());
+	});
+}
+
+#[test]
+fn games_not_fitting_the_game_queue_are_not_created() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		for index in 0..MaxQueueSize::get() as u64 {
+			assert_ok!(<Registry as CreateGame<_, _>>::create_game(
+				engine.clone(),
+				vec![index + 100, index + 200]
+			));
+		}
+
+		// nothing of the game is kept
+		let games = GameRegistry::<Test>::iter().count();
+		assert_eq!(
+			<Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![300, 301]),
+			Err(Error::<Test>::QueueFull.into())
+		);
+		System::assert_last_event(mock::Event::Registry(crate::Event::GameCreationFailed(
+			engine.clone(),
+			vec![300, 301],
+			Error::<Test>::QueueFull.into(),
+		)));
+		assert_eq!(GameRegistry::<Test>::iter().count(), games);
+		assert!(Registry::current_games(&300).is_empty());
+		assert_eq!(Registry::player_games(&300, engine.id), 0);
+
+		// matched players go back into matchmaking
+		assert_ok!(Registry::queue(Origin::signed(1), engine.id, None, None));
+		assert_ok!(Registry::queue(Origin::signed(2), engine.id, None, None));
+		run_to_block(11);
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			mock::Event::Registry(crate::Event::GameCreationFailed(_, players, _))
+				if players.contains(&1) && players.contains(&2)
+		)));
+		assert!(Registry::queued_players(&1).is_some());
+		assert!(Registry::queued_players(&2).is_some());
+		assert_eq!(Registry::queue_bonds(&1), Some(QueueBond::get()));
+		assert_ok!(Registry::do_try_state());
+	});
+}
+
+#[test]
+fn clusters_move_their_tees_to_their_engines() {
+	new_test_ext().execute_with(|| {
+		let engine_v1 = GameEngine::new(1, 1);
+		let engine_v2 = GameEngine::new(1, 2);
+		let operator = 50;
+		let tee = 8;
+
+		run_to_block(10);
+
+		register_engine(&engine_v1, 0);
+		assert_ok!(Registry::register_tee(
+			Origin::signed(FOUNDER),
+			tee,
+			vec![engine_v1.clone()],
+			None
+		));
+		assert_eq!(Registry::engine_tee_count(&engine_v1), 2);
+		assert_noop!(
+			Registry::register_cluster(Origin::signed(operator), vec![engine_v2.clone()], operator),
+			BadOrigin
+		);
+		assert_ok!(Registry::register_cluster(
+			Origin::signed(FOUNDER),
+			vec![engine_v2.clone()],
+			operator
+		));
+		System::assert_last_event(mock::Event::Registry(crate::Event::ClusterRegistered(
+			0, operator,
+		)));
+
+		// AjunaTEEs added by the operator run the game engine versions of the cluster
+		assert_noop!(
+			Registry::add_cluster_tee(Origin::signed(1), 0, tee),
+			Error::<Test>::NotClusterOperator
+		);
+		assert_noop!(
+			Registry::add_cluster_tee(Origin::signed(operator), 0, 30),
+			Error::<Test>::NotRegisteredTee
+		);
+		assert_ok!(Registry::add_cluster_tee(Origin::signed(operator), 0, tee));
+		assert_eq!(Registry::tee_cluster(&tee), Some(0));
+		assert_eq!(Registry::engine_tee_count(&engine_v1), 1);
+		assert_eq!(Registry::engine_tee_count(&engine_v2), 1);
+		assert_noop!(
+			Registry::update_tee_engines(Origin::signed(tee), vec![engine_v1.clone()]),
+			Error::<Test>::TeeInCluster
+		);
+		let game_hash = <Registry as CreateGame<_, _>>::create_game(engine_v1.clone(), vec![1, 2])
+			.expect("engine is registered");
+		assert_noop!(
+			Registry::ack_game(Origin::signed(tee), engine_v1.clone(), vec![game_hash]),
+			Error::<Test>::EngineNotSupported
+		);
+		assert_ok!(Registry::do_try_state());
+
+		// rebalancing the cluster moves all of its AjunaTEEs
+		assert_ok!(Registry::set_cluster_engines(
+			Origin::signed(FOUNDER),
+			0,
+			vec![engine_v1.clone()]
+		));
+		assert_eq!(Registry::engine_tee_count(&engine_v1), 2);
+		assert_eq!(Registry::engine_tee_count(&engine_v2), 0);
+		assert_ok!(Registry::ack_game(Origin::signed(tee), engine_v1.clone(), vec![game_hash]));
+		assert_ok!(Registry::do_try_state());
+
+		// removed AjunaTEEs run nothing till they update their game engine versions
+		assert_ok!(Registry::remove_cluster_tee(Origin::signed(operator), 0, tee));
+		assert_eq!(Registry::tee_cluster(&tee), None);
+		assert!(Registry::registered_tees(tee).unwrap().supported_engines.is_empty());
+		assert_ok!(Registry::update_tee_engines(Origin::signed(tee), vec![engine_v1.clone()]));
+		assert_ok!(Registry::do_try_state());
+	});
+}
+
+#[test]
+fn results_confirmed_by_all_players_are_settled_right_away() {
+	new_test_ext().execute_with(|| {
+		let engine = GameEngine::new(1, 1);
+		let unconfirmed_engine = GameEngine::new(2, 1);
+		let stake = 40;
+
+		run_to_block(10);
+
+		register_engine(&engine, 0);
+		register_engine(&unconfirmed_engine, 1);
+		assert_noop!(
+			Registry::set_result_confirmation(Origin::signed(1), engine.clone(), true),
+			BadOrigin
+		);
+		assert_ok!(Registry::set_result_confirmation(
+			Origin::signed(FOUNDER),
+			engine.clone(),
+			true
+		));
+		System::assert_last_event(mock::Event::Registry(crate::Event::ResultConfirmationSet(
+			engine.clone(),
+			true,
+		)));
+		for player in [1, 2] {
+			assert_ok!(Balances::reserve(&player, stake));
+		}
+		let confirmed = <Registry as CreateGame<_, _>>::create_game(engine.clone(), vec![1, 2])
+			.expect("game engine is available");
+		<GameStakes<Test>>::insert(&confirmed, vec![(1, stake), (2, stake)]);
+		let tee = MockTee(TEE);
+		assert_ok!(tee.ack(&engine, vec![confirmed]));
+		assert_ok!(tee.ready(confirmed));
+		assert_ok!(tee.finish(confirmed, 1));
+		let lapsed = play_game(&engine, vec![3, 4], 3);
+		assert_eq!(Registry::pending_settlements(16), vec![confirmed, lapsed]);
+
+		// the result waits for the confirmation of all players
+		assert_noop!(
+			Registry::confirm_result(Origin::signed(3), confirmed),
+			Error::<Test>::NotAPlayer
+		);
+		assert_ok!(Registry::confirm_result(Origin::signed(1), confirmed));
+		System::assert_last_event(mock::Event::Registry(crate::Event::ResultConfirmed(
+			confirmed, 1,
+		)));
+		assert_noop!(
+			Registry::confirm_result(Origin::signed(1), confirmed),
+			Error::<Test>::ResultAlreadyConfirmed
+		);
+		assert_eq!(Balances::reserved_balance(1), stake);
+		assert_eq!(Registry::rating(&1, engine.id), 1500);
+
+		// the last confirmation settles it, it can't be disputed anymore
+		assert_ok!(Registry::confirm_result(Origin::signed(2), confirmed));
+		System::assert_last_event(mock::Event::Registry(crate::Event::ResultSettled(
+			confirmed,
+			GameOutcome::Winner(1),
+		)));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + stake);
+		assert_eq!(Registry::rating(&1, engine.id), 1501);
+		assert_eq!(Registry::pending_settlements(16), vec![lapsed]);
+		assert_noop!(
+			Registry::confirm_result(Origin::signed(1), confirmed),
+			Error::<Test>::ResultNotPending
+		);
+		assert_noop!(
+			Registry::flag_result(Origin::signed(2), confirmed),
+			Error::<Test>::FraudProofWindowClosed
+		);
+
+		// results not confirmed by all players are settled with the window
+		assert_ok!(Registry::confirm_result(Origin::signed(3), lapsed));
+		run_to_block(16);
+		System::assert_has_event(mock::Event::Registry(crate::Event::ResultSettled(
+			lapsed,
+			GameOutcome::Winner(3),
+		)));
+		assert_eq!(Registry::rating(&1, engine.id), 1501);
+
+		// results of other game engines are settled right away
+		let settled = play_game(&unconfirmed_engine, vec![5, 6], 5);
+		assert_noop!(
+			Registry::confirm_result(Origin::signed(5), settled),
+			Error::<Test>::ResultNotPending
+		);
+	});
+}
+
+#[test]
+fn stored_types_have_bounded_encodings() {
+	// types without a bound on their encoding don't compile here
+	assert_eq!(GameEngine::max_encoded_len(), 2);
+	assert_eq!(TimeControl::max_encoded_len(), 17);
+	assert_eq!(GameTimestamps::<u64>::max_encoded_len(), 32);
+	assert_eq!(GameRule::<GameRuleType>::max_encoded_len(), 33);
+	assert_eq!(RegistryParameters::<u64>::max_encoded_len(), 57);
+	assert_eq!(RewardPoints::max_encoded_len(), 20);
+
+	// rankings hold every player of a game at most
+	assert_eq!(GameOutcomeOf::<Test>::max_encoded_len(), 1 + 1 + 16 * 8);
+	assert_eq!(
+		GameStateOf::<Test>::max_encoded_len(),
+		1 + GameOutcomeOf::<Test>::max_encoded_len()
+	);
+	assert_eq!(GameEntryOf::<Test>::max_encoded_len(), 677);
+
+	// parties and tournaments hold `MaxPartySize` and `MaxTournamentPlayers` players at most
+	assert_eq!(PartyOf::<Test>::max_encoded_len(), 2 + 2 * (1 + 4 * 8));
+	assert_eq!(TournamentOf::<Test>::max_encoded_len(), 246);
+}
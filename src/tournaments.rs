@@ -0,0 +1,193 @@
+use super::*;
+
+/// Stage of a tournament.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum TournamentState<AccountId> {
+	/// Players can join till the start block.
+	Open,
+	/// Rounds are played, the players of the current round are in the bracket.
+	Running,
+	/// The tournament was won by the player.
+	Won(AccountId),
+}
+
+/// Single-elimination tournament, the winners of a round meet in the next round till one player
+/// is left.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Tournament<AccountId, Balance, BlockNumber, MaxPlayers> {
+	pub game_engine: GameEngine,
+	/// The player that created the tournament.
+	pub creator: AccountId,
+	/// Players that can join the tournament.
+	pub max_players: u32,
+	/// Fee every player reserves to join, paid out to the winner.
+	pub entry_fee: Balance,
+	/// Block the first round starts in.
+	pub start_block: BlockNumber,
+	/// The players that joined, in the order they joined.
+	pub players: BoundedVec<AccountId, MaxPlayers>,
+	/// Round played, zero till the tournament started.
+	pub round: u32,
+	/// Players of the current round, the first meets the second, the third the fourth and so on.
+	pub bracket: BoundedVec<AccountId, MaxPlayers>,
+	/// Players advancing from the matches of the current round, once decided.
+	pub advancing: BoundedVec<Option<AccountId>, MaxPlayers>,
+	pub state: TournamentState<AccountId>,
+}
+
+impl<AccountId, Balance, BlockNumber, MaxPlayers>
+	Tournament<AccountId, Balance, BlockNumber, MaxPlayers>
+{
+	/// Whether all matches of the current round are decided.
+	pub fn round_decided(&self) -> bool {
+		self.advancing.iter().all(Option::is_some)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Start the next round of a tournament. The first round pairs the players in the order they
+	/// joined, later rounds pair the winners of neighbouring matches. A player left without an
+	/// opponent advances with a bye, the last player left wins the tournament.
+	pub(crate) fn start_tournament_round(tournament_id: u32) -> Weight {
+		let mut tournament = match Self::tournaments(tournament_id) {
+			Some(tournament) => tournament,
+			None => return T::DbWeight::get().reads(1),
+		};
+
+		match tournament.state {
+			TournamentState::Open if tournament.players.len() < 2 => {
+				Self::cancel_tournament(tournament_id, &tournament);
+				return T::WeightInfo::start_tournament_round(tournament.players.len() as u32)
+			},
+			TournamentState::Open => {
+				tournament.bracket = tournament.players.clone();
+				tournament.state = TournamentState::Running;
+			},
+			TournamentState::Running if tournament.round_decided() => {
+				// the players advancing are fewer than the players of the round
+				let advancing: Vec<_> = tournament.advancing.iter().flatten().cloned().collect();
+				tournament.bracket = BoundedVec::try_from(advancing).unwrap_or_default();
+			},
+			_ => return T::DbWeight::get().reads(1),
+		}
+
+		let players = tournament.bracket.len() as u32;
+		if let [winner] = &tournament.bracket[..] {
+			let winner = winner.clone();
+			let prize = Self::pay_tournament_prize(&tournament, &winner);
+			tournament.state = TournamentState::Won(winner.clone());
+			tournament.advancing = BoundedVec::default();
+			<Tournaments<T>>::insert(tournament_id, tournament);
+
+			// Emit an event.
+			Self::deposit_event(Event::TournamentWon(tournament_id, winner, prize));
+			return T::WeightInfo::start_tournament_round(players)
+		}
+
+		tournament.round += 1;
+		// a round has half as many matches as players
+		tournament.advancing =
+			BoundedVec::try_from(sp_std::vec![None; (tournament.bracket.len() + 1) / 2])
+				.unwrap_or_default();
+		for (slot, pairing) in tournament.bracket.chunks(2).enumerate() {
+			let decided = match tournament.advancing.get_mut(slot) {
+				Some(decided) => decided,
+				None => continue,
+			};
+			if pairing.len() < 2 {
+				*decided = Some(pairing[0].clone());
+				continue
+			}
+			match Self::queue_game(
+				tournament.game_engine.clone(),
+				pairing.to_vec(),
+				None,
+				false,
+				None,
+			) {
+				Ok(game_hash) => {
+					<TournamentGames<T>>::insert(&game_hash, true);
+					<TournamentMatches<T>>::insert(&game_hash, (tournament_id, slot as u32));
+					Self::prioritize_game(&game_hash);
+				},
+				// pairings that can't be played are decided for the player that joined first
+				Err(_) => *decided = Some(pairing[0].clone()),
+			}
+		}
+		if tournament.round_decided() {
+			Self::schedule_tournament_round(tournament_id);
+		}
+		let round = tournament.round;
+		<Tournaments<T>>::insert(tournament_id, tournament);
+
+		// Emit an event.
+		Self::deposit_event(Event::TournamentRoundStarted(tournament_id, round));
+
+		T::WeightInfo::start_tournament_round(players)
+	}
+
+	/// Decide the match of a tournament game once it's over, games without a winner are decided
+	/// for the player that joined first. The next round starts in the next block, once all
+	/// matches of the current round are decided.
+	pub(crate) fn advance_tournament(game_hash: &T::Hash, winner: Option<&T::AccountId>) {
+		<TournamentGames<T>>::remove(game_hash);
+		let (tournament_id, slot) = match <TournamentMatches<T>>::take(game_hash) {
+			Some(tournament_match) => tournament_match,
+			None => return,
+		};
+		<Tournaments<T>>::mutate(tournament_id, |tournament| {
+			if let Some(tournament) = tournament {
+				let pairing = tournament.bracket.chunks(2).nth(slot as usize).unwrap_or_default();
+				let advancing = winner
+					.filter(|winner| pairing.contains(winner))
+					.or_else(|| pairing.first())
+					.cloned();
+				if let Some(decided) = tournament.advancing.get_mut(slot as usize) {
+					*decided = advancing;
+				}
+				if tournament.round_decided() {
+					Self::schedule_tournament_round(tournament_id);
+				}
+			}
+		});
+	}
+
+	/// Start the next round of a tournament in the next block.
+	fn schedule_tournament_round(tournament_id: u32) {
+		let next_block = <frame_system::Pallet<T>>::block_number() + One::one();
+		Self::schedule_in_block(next_block, |block| {
+			<TournamentRounds<T>>::try_mutate(block, |rounds| rounds.try_push(tournament_id))
+		});
+	}
+
+	/// Pay the entry fees of all players of a tournament to its winner. Returns the prize.
+	fn pay_tournament_prize(tournament: &TournamentOf<T>, winner: &T::AccountId) -> BalanceOf<T> {
+		let mut prize = BalanceOf::<T>::zero();
+		for player in tournament.players.iter() {
+			if player == winner {
+				T::Currency::unreserve(player, tournament.entry_fee);
+			} else {
+				// entry fees already released by other means can't be moved anymore
+				let _ = T::Currency::repatriate_reserved(
+					player,
+					winner,
+					tournament.entry_fee,
+					BalanceStatus::Free,
+				);
+			}
+			prize = prize.saturating_add(tournament.entry_fee);
+		}
+		prize
+	}
+
+	/// Cancel a tournament without enough players at its start, refunding the entry fees.
+	fn cancel_tournament(tournament_id: u32, tournament: &TournamentOf<T>) {
+		for player in tournament.players.iter() {
+			T::Currency::unreserve(player, tournament.entry_fee);
+		}
+		<Tournaments<T>>::remove(tournament_id);
+
+		// Emit an event.
+		Self::deposit_event(Event::TournamentCancelled(tournament_id));
+	}
+}
@@ -0,0 +1,299 @@
+use super::*;
+use pallet_matchmaker::MatchFunc;
+use sp_std::marker::PhantomData;
+
+/// Read access to the game registry, for other pallets consuming game data.
+pub trait GameRegistryProvider<AccountId, Hash, MaxPlayers> {
+	/// The state of a game, `None` if there is no such game.
+	fn game_state(game_hash: &Hash) -> Option<GameState<AccountId, MaxPlayers>>;
+
+	/// The game engine version a game is played on.
+	fn game_engine(game_hash: &Hash) -> Option<GameEngine>;
+
+	/// The players of a game.
+	fn players(game_hash: &Hash) -> Vec<AccountId>;
+
+	/// The AjunaTEE assigned to run a game.
+	fn tee(game_hash: &Hash) -> Option<AccountId>;
+
+	/// The winner of a finished game, the player in the first place of a ranking.
+	fn winner(game_hash: &Hash) -> Option<AccountId>;
+
+	/// The outcome of a finished game.
+	fn outcome(game_hash: &Hash) -> Option<GameOutcome<AccountId, MaxPlayers>>;
+}
+
+/// Hooks into the lifecycle of games, so other pallets can react without coupling to storage.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait GameObserver<AccountId, Hash, MaxPlayers> {
+	/// A new game was created and queued for the AjunaTEEs.
+	fn on_game_created(game_hash: &Hash, players: &[AccountId]);
+
+	/// A game changed its state, a removed game changes to `GameState::None`.
+	fn on_game_state_changed(
+		game_hash: &Hash,
+		from: &GameState<AccountId, MaxPlayers>,
+		to: &GameState<AccountId, MaxPlayers>,
+	);
+
+	/// A game started running on its AjunaTEE.
+	fn on_game_started(game_hash: &Hash, players: &[AccountId]);
+
+	/// A game was finished with its outcome.
+	fn on_game_finished(game_hash: &Hash, outcome: &GameOutcome<AccountId, MaxPlayers>);
+}
+
+/// Matchmaking of the queued players, so runtimes plug in `pallet_matchmaker`, a custom or an
+/// off-chain matchmaker without the registry depending on it.
+///
+/// Matches don't need to have the players of a whole game, the registry gathers the matches of a
+/// bracket till there are enough players for a game of its game engine version.
+pub trait Matchmaker<AccountId> {
+	/// Add a player to the matchmaking of a bracket, `false` if it's already queued.
+	fn add_queue(player: AccountId, bracket: u8) -> bool;
+
+	/// Take a player out of the matchmaking of a bracket, `false` if the matchmaker can't remove
+	/// players, they're skipped once they're matched then.
+	fn remove_queue(player: &AccountId, bracket: u8) -> bool;
+
+	/// The players of the next match of any bracket, empty if there is none.
+	fn try_match() -> Vec<AccountId>;
+}
+
+/// `pallet_matchmaker` matches players of a bracket first come, first served, it can't remove
+/// players.
+impl<AccountId, M: MatchFunc<AccountId>> Matchmaker<AccountId> for M {
+	fn add_queue(player: AccountId, bracket: u8) -> bool {
+		<M as MatchFunc<AccountId>>::add_queue(player, bracket)
+	}
+
+	fn remove_queue(_player: &AccountId, _bracket: u8) -> bool {
+		false
+	}
+
+	fn try_match() -> Vec<AccountId> {
+		<M as MatchFunc<AccountId>>::try_match()
+	}
+}
+
+/// Final results of games, designed for pallets settling by game hash, e.g. betting markets.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnGameResult<AccountId, Hash, MaxPlayers> {
+	/// A game got its final result, it won't change anymore.
+	fn on_game_result(
+		game_hash: &Hash,
+		game_engine: &GameEngine,
+		players: &[AccountId],
+		result: &GameResult<AccountId, MaxPlayers>,
+	);
+}
+
+/// Reward points the players of settled games earn, e.g. minted by a rewards or assets pallet.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait RewardHandler<AccountId> {
+	/// A player earned reward points with a game of the game engine version, with the games it
+	/// won in a row since.
+	fn on_reward(player: &AccountId, game_engine: &GameEngine, points: u32, win_streak: u32);
+}
+
+/// Build the call dispatched when a game starts running, e.g. to create the board of the game.
+pub trait GameStartCall<AccountId, Hash, Call> {
+	/// The call to dispatch for the game, `None` if nothing needs to be dispatched.
+	fn game_start_call(
+		game_hash: &Hash,
+		game_engine: &GameEngine,
+		players: &[AccountId],
+	) -> Option<Call>;
+}
+
+impl<AccountId, Hash, Call> GameStartCall<AccountId, Hash, Call> for () {
+	fn game_start_call(_: &Hash, _: &GameEngine, _: &[AccountId]) -> Option<Call> {
+		None
+	}
+}
+
+/// Generate the ids of new games.
+pub trait GameIdGenerator<AccountId, Hash> {
+	/// The id of a new game of the players, the nonce is unique for every game.
+	fn generate_game_id(game_engine: &GameEngine, players: &[AccountId], nonce: u64) -> Hash;
+}
+
+/// Game ids from the randomness of the runtime, e.g. the BABE VRF output, so players can't
+/// predict them.
+pub struct RandomGameIds<T>(PhantomData<T>);
+
+impl<T: Config> GameIdGenerator<T::AccountId, T::Hash> for RandomGameIds<T> {
+	fn generate_game_id(game_engine: &GameEngine, players: &[T::AccountId], nonce: u64) -> T::Hash {
+		let (random, _) = T::Randomness::random(&GAMEREGISTRY_ID);
+		(random, game_engine, players, nonce).using_encoded(T::Hashing::hash)
+	}
+}
+
+/// Game ids hashed from the players, the game engine version and the nonce, for chains without a
+/// good randomness source. The ids are predictable, but still unique.
+pub struct DeterministicGameIds<T>(PhantomData<T>);
+
+impl<T: Config> GameIdGenerator<T::AccountId, T::Hash> for DeterministicGameIds<T> {
+	fn generate_game_id(game_engine: &GameEngine, players: &[T::AccountId], nonce: u64) -> T::Hash {
+		(GAMEREGISTRY_ID, game_engine, players, nonce).using_encoded(T::Hashing::hash)
+	}
+}
+
+/// Report game results to the chains foreign players queued from, e.g. with an XCM message.
+pub trait ForeignResultNotifier<Location, Hash, AccountId, MaxPlayers> {
+	/// Send the result of a game to the location of a foreign player.
+	fn notify_result(
+		destination: &Location,
+		game_hash: &Hash,
+		outcome: &GameOutcome<AccountId, MaxPlayers>,
+	) -> Result<(), DispatchError>;
+}
+
+impl<Location, Hash, AccountId, MaxPlayers>
+	ForeignResultNotifier<Location, Hash, AccountId, MaxPlayers> for ()
+{
+	fn notify_result(
+		_: &Location,
+		_: &Hash,
+		_: &GameOutcome<AccountId, MaxPlayers>,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
+
+/// Ownership of assets game rules can require, e.g. implemented by an NFT pallet.
+pub trait AssetOwnership<AccountId> {
+	/// Whether the account owns the asset.
+	fn owns_asset(who: &AccountId, asset_id: u32) -> bool;
+}
+
+impl<AccountId> AssetOwnership<AccountId> for () {
+	fn owns_asset(_: &AccountId, _: u32) -> bool {
+		false
+	}
+}
+
+/// Attestation of AjunaTEEs, e.g. implemented by a teerex-style attestation pallet.
+pub trait TeeAttestation<AccountId> {
+	/// Verify an attestation quote of the enclave signing with the account, returns its
+	/// measurement.
+	fn verify_quote(account: &AccountId, quote: &[u8]) -> Result<[u8; 32], DispatchError>;
+
+	/// The measurement of the attested enclave signing with the account, if there is one.
+	fn enclave_of(account: &AccountId) -> Option<[u8; 32]>;
+
+	/// The key the attested enclave signs its results with, if it reported one in its quote.
+	fn signing_key_of(account: &AccountId) -> Option<AccountId32>;
+}
+
+/// Create games for the AjunaTEEs, for pallets that bring their own players.
+pub trait CreateGame<AccountId, Hash> {
+	/// Create a new game and queue it for the AjunaTEEs, returns the game hash.
+	fn create_game(game_engine: GameEngine, players: Vec<AccountId>)
+		-> Result<Hash, DispatchError>;
+
+	/// Create a new game, keeping its players out of public events till it's finished.
+	fn create_private_game(
+		game_engine: GameEngine,
+		players: Vec<AccountId>,
+	) -> Result<Hash, DispatchError>;
+
+	/// Create a new game of a tournament, queued in the priority lane ahead of other games.
+	fn create_tournament_game(
+		game_engine: GameEngine,
+		players: Vec<AccountId>,
+	) -> Result<Hash, DispatchError>;
+}
+
+/// Create games played for stakes, for pallets that hold the wagers of their players.
+pub trait CreateWageredGame<AccountId, Hash, Balance>: CreateGame<AccountId, Hash> {
+	/// Create a new game with the combined wager of its players, high-value games are only run
+	/// by AjunaTEEs of the high-value tier.
+	fn create_wagered_game(
+		game_engine: GameEngine,
+		players: Vec<AccountId>,
+		wager: Balance,
+	) -> Result<Hash, DispatchError>;
+}
+
+impl<T: Config> GameRegistryProvider<T::AccountId, T::Hash, T::MaxPlayersPerGame> for Pallet<T> {
+	fn game_state(game_hash: &T::Hash) -> Option<GameStateOf<T>> {
+		Self::game_entry(game_hash).map(|game_entry| game_entry.game_state)
+	}
+
+	fn game_engine(game_hash: &T::Hash) -> Option<GameEngine> {
+		Self::game_entry(game_hash).map(|game_entry| game_entry.game_engine)
+	}
+
+	fn players(game_hash: &T::Hash) -> Vec<T::AccountId> {
+		Self::game_entry(game_hash)
+			.map(|game_entry| game_entry.players.into_inner())
+			.unwrap_or_default()
+	}
+
+	fn tee(game_hash: &T::Hash) -> Option<T::AccountId> {
+		Self::game_entry(game_hash).and_then(|game_entry| game_entry.tee_id)
+	}
+
+	fn winner(game_hash: &T::Hash) -> Option<T::AccountId> {
+		Self::outcome(game_hash)?.winner().cloned()
+	}
+
+	fn outcome(game_hash: &T::Hash) -> Option<GameOutcomeOf<T>> {
+		match Self::game_entry(game_hash)?.game_state {
+			GameState::Finished(outcome) => Some(outcome),
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config> CreateGame<T::AccountId, T::Hash> for Pallet<T> {
+	fn create_game(
+		game_engine: GameEngine,
+		players: Vec<T::AccountId>,
+	) -> Result<T::Hash, DispatchError> {
+		Self::ensure_engine_open(&game_engine)?;
+		Self::queue_game(game_engine, players, None, false, None)
+	}
+
+	fn create_private_game(
+		game_engine: GameEngine,
+		players: Vec<T::AccountId>,
+	) -> Result<T::Hash, DispatchError> {
+		Self::ensure_engine_open(&game_engine)?;
+		Self::queue_game(game_engine, players, None, true, None)
+	}
+
+	fn create_tournament_game(
+		game_engine: GameEngine,
+		players: Vec<T::AccountId>,
+	) -> Result<T::Hash, DispatchError> {
+		Self::ensure_engine_open(&game_engine)?;
+		let game_hash = Self::queue_game(game_engine, players, None, false, None)?;
+		<TournamentGames<T>>::insert(&game_hash, true);
+		Self::prioritize_game(&game_hash);
+		Ok(game_hash)
+	}
+}
+
+impl<T: Config> CreateWageredGame<T::AccountId, T::Hash, BalanceOf<T>> for Pallet<T> {
+	fn create_wagered_game(
+		game_engine: GameEngine,
+		players: Vec<T::AccountId>,
+		wager: BalanceOf<T>,
+	) -> Result<T::Hash, DispatchError> {
+		let game_hash = Self::create_game(game_engine, players)?;
+		<GameWagers<T>>::insert(&game_hash, wager);
+		Self::prioritize_game(&game_hash);
+		Ok(game_hash)
+	}
+}
+
+/// Every MMR leaf commits to the result leaves of the games finalized in the previous block.
+impl<T: Config> pallet_mmr_primitives::LeafDataProvider for Pallet<T> {
+	type LeafData = T::Hash;
+
+	fn leaf_data() -> Self::LeafData {
+		Self::results_commitment()
+	}
+}
@@ -0,0 +1,3413 @@
+//! Weights for pallet_gameregistry
+//!
+//! Storage accesses are counted from the calls, execution times are estimates. Regenerate the
+//! weights on reference hardware with the benchmarks in `benchmarking.rs`:
+//!
+//! ./target/release/node benchmark --chain=dev --steps=50 --repeat=20
+//! 	--pallet=pallet_gameregistry --extrinsic='*' --execution=wasm --wasm-execution=compiled
+//! 	--heap-pages=4096 --output=./pallets/gameregistry/src/weights.rs
+//! 	--template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_gameregistry.
+pub trait WeightInfo {
+	fn set_settings() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn set_call_disabled() -> Weight;
+	fn set_parameter() -> Weight;
+	fn register_game_engine() -> Weight;
+	fn deprecate_game_engine() -> Weight;
+	fn upgrade_game_engine() -> Weight;
+	fn set_game_rules() -> Weight;
+	fn set_game_rule() -> Weight;
+	fn clear_game_rules() -> Weight;
+	fn verify_result(l: u32) -> Weight;
+	fn request_rematch() -> Weight;
+	fn create_tournament() -> Weight;
+	fn join_tournament() -> Weight;
+	fn start_tournament_round(p: u32) -> Weight;
+	fn propose_abort() -> Weight;
+	fn handover_games(g: u32) -> Weight;
+	fn set_engine_spectator_settings() -> Weight;
+	fn set_max_games_per_player() -> Weight;
+	fn set_engine_client_versions() -> Weight;
+	fn set_engine_stake() -> Weight;
+	fn add_time_control() -> Weight;
+	fn register_tee() -> Weight;
+	fn update_tee_engines() -> Weight;
+	fn set_tee_endpoint() -> Weight;
+	fn reattest_tee() -> Weight;
+	fn bond_tee() -> Weight;
+	fn unbond_tee() -> Weight;
+	fn deregister_tee() -> Weight;
+	fn register_cluster() -> Weight;
+	fn add_cluster_tee() -> Weight;
+	fn remove_cluster_tee() -> Weight;
+	fn set_cluster_engines(t: u32) -> Weight;
+	fn drain_game_queue() -> Weight;
+	fn schedule_maintenance() -> Weight;
+	fn set_engine_maintenance() -> Weight;
+	fn set_engine_paused() -> Weight;
+	fn set_engine_init_data() -> Weight;
+	fn ban_account(g: u32) -> Weight;
+	fn unban_account() -> Weight;
+	fn ban_player() -> Weight;
+	fn unban_player() -> Weight;
+	fn add_to_allowlist(a: u32) -> Weight;
+	fn remove_from_allowlist(a: u32) -> Weight;
+	fn resolve_report() -> Weight;
+	fn sanction_player() -> Weight;
+	fn appeal_sanction() -> Weight;
+	fn resolve_appeal() -> Weight;
+	fn rule_on_fraud_proof() -> Weight;
+	fn overturn_result() -> Weight;
+	fn queue() -> Weight;
+	fn leave_queue() -> Weight;
+	fn set_preferences() -> Weight;
+	fn set_client_versions() -> Weight;
+	fn queue_committed() -> Weight;
+	fn create_match(o: u32) -> Weight;
+	fn accept_invite() -> Weight;
+	fn start_scheduled_game() -> Weight;
+	fn queue_party(m: u32) -> Weight;
+	fn accept_party_invite() -> Weight;
+	fn disband_party(m: u32) -> Weight;
+	fn reveal_identity() -> Weight;
+	fn queue_foreign() -> Weight;
+	fn accept_match() -> Weight;
+	fn concede() -> Weight;
+	fn report_player() -> Weight;
+	fn flag_result() -> Weight;
+	fn reconfirm_result() -> Weight;
+	fn submit_fraud_proof() -> Weight;
+	fn register_spectator() -> Weight;
+	fn set_spectator_settings() -> Weight;
+	fn invite_spectator() -> Weight;
+	fn set_session_key() -> Weight;
+	fn accept_session_key() -> Weight;
+	fn remove_session_key() -> Weight;
+	fn drop_game(q: u32) -> Weight;
+	fn note_tee_liveness(t: u32) -> Weight;
+	fn tee_heartbeat() -> Weight;
+	fn expire_game() -> Weight;
+	fn ack_game(g: u32) -> Weight;
+	fn ack_game_unsigned(g: u32) -> Weight;
+	fn ready_game() -> Weight;
+	fn checkpoint_game() -> Weight;
+	fn queue_game(q: u32) -> Weight;
+	fn on_initialize_idle() -> Weight;
+	fn on_initialize_migration() -> Weight;
+	fn escalate_result() -> Weight;
+	fn settle_result() -> Weight;
+	fn set_result_confirmation() -> Weight;
+	fn confirm_result() -> Weight;
+	fn resume_games(g: u32) -> Weight;
+	fn unsuspend_tee() -> Weight;
+	fn reassign_game() -> Weight;
+	fn match_game() -> Weight;
+	fn no_match() -> Weight;
+	fn match_parties(p: u32) -> Weight;
+	fn heartbeat(e: u32) -> Weight;
+	fn end_season() -> Weight;
+	fn finish_game() -> Weight;
+	fn finish_game_unsigned() -> Weight;
+	fn batch_finish_games(g: u32) -> Weight;
+	fn report_no_show() -> Weight;
+	fn batch_drop_games(g: u32) -> Weight;
+	fn prune_game() -> Weight;
+	fn clean_game_queue(q: u32) -> Weight;
+	fn remove_lapsed_sanction() -> Weight;
+	fn remove_expired_invite() -> Weight;
+}
+
+/// Weights for pallet_gameregistry using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: GameRegistry Settings (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn set_settings() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Paused (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn pause() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Paused (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn unpause() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry DisabledCalls (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn set_call_disabled() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Parameters (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn set_parameter() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:1)
+	// Storage: GameRegistry EngineBrackets (r:1 w:1)
+	// Storage: GameRegistry LatestEngineVersion (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn register_game_engine() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn deprecate_game_engine() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:2 w:1)
+	// Storage: GameRegistry GameRequirments (r:2 w:0)
+	// Storage: GameRegistry GameQueues (r:2 w:2)
+	// Storage: GameRegistry PriorityGameQueues (r:2 w:2)
+	// Storage: GameRegistry TournamentGames (r:64 w:0)
+	// Storage: GameRegistry GameRegistry (r:64 w:64)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry GameQueueItems (r:64 w:128)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn upgrade_game_engine() -> Weight {
+		(440_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(140 as Weight))
+			.saturating_add(T::DbWeight::get().writes(200 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_game_rules() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_game_rule() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn clear_game_rules() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry ResultProofs (r:1 w:0)
+	fn verify_result(l: u32) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:2 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:1 w:0)
+	// Storage: GameRegistry RematchRequests (r:1 w:1)
+	// Storage: GameRegistry RematchDeadlines (r:1 w:1)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineStakes (r:1 w:0)
+	// Storage: GameRegistry EngineMaxGamesPerPlayer (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	fn request_rematch() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(27 as Weight))
+			.saturating_add(T::DbWeight::get().writes(21 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry NextTournamentId (r:1 w:1)
+	// Storage: GameRegistry Tournaments (r:0 w:1)
+	// Storage: GameRegistry TournamentRounds (r:1 w:1)
+	fn create_tournament() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Tournaments (r:1 w:1)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn join_tournament() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Tournaments (r:1 w:1)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:1 w:2)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueueItems (r:0 w:1)
+	// Storage: GameRegistry TournamentGames (r:1 w:1)
+	// Storage: GameRegistry TournamentMatches (r:0 w:1)
+	// Storage: GameRegistry TournamentRounds (r:1 w:1)
+	fn start_tournament_round(p: u32) -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add((38_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((11 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry AbortProposals (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn propose_abort() -> Weight {
+		(158_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(41 as Weight))
+			.saturating_add(T::DbWeight::get().writes(40 as Weight))
+	}
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry TeeLoads (r:2 w:2)
+	// Storage: GameRegistry DevMode (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameWagers (r:1 w:0)
+	// Storage: GameRegistry TeeGames (r:0 w:2)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	fn handover_games(g: u32) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((34_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineSpectatorSettings (r:0 w:1)
+	fn set_engine_spectator_settings() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineMaxGamesPerPlayer (r:0 w:1)
+	fn set_max_games_per_player() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineClientVersions (r:0 w:1)
+	fn set_engine_client_versions() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineStakes (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_engine_stake() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineBrackets (r:1 w:1)
+	// Storage: GameRegistry TimeControls (r:1 w:1)
+	fn add_time_control() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	// Storage: GameRegistry AttestedAt (r:0 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:0)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn register_tee() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:0)
+	fn update_tee_engines() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	fn set_tee_endpoint() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry AttestedAt (r:0 w:1)
+	fn reattest_tee() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry TeeBonds (r:1 w:1)
+	fn bond_tee() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry TeeBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unbond_tee() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	// Storage: GameRegistry AttestedAt (r:0 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	// Storage: GameRegistry TeeBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:0 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:1)
+	// Storage: GameRegistry Clusters (r:1 w:1)
+	fn deregister_tee() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: GameRegistry NextClusterId (r:1 w:1)
+	// Storage: GameRegistry Clusters (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn register_cluster() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Clusters (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	fn add_cluster_tee() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Clusters (r:1 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	fn remove_cluster_tee() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Clusters (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_cluster_engines(t: u32) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((17_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry TournamentGames (r:0 w:64)
+	// Storage: GameRegistry GameRegistry (r:64 w:64)
+	// Storage: GameRegistry PrivateGames (r:0 w:64)
+	// Storage: GameRegistry GameWagers (r:0 w:64)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:64)
+	// Storage: GameRegistry MatchCodes (r:64 w:64)
+	// Storage: GameRegistry GameSeeds (r:0 w:64)
+	// Storage: GameRegistry ActiveGames (r:0 w:0)
+	// Storage: GameRegistry QueueBonds (r:128 w:128)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:128 w:128)
+	// Storage: GameRegistry CurrentGames (r:128 w:128)
+	// Storage: GameRegistry GameStakes (r:64 w:64)
+	// Storage: GameRegistry GameQueueItems (r:64 w:65)
+	fn drain_game_queue() -> Weight {
+		(1_963_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(645 as Weight))
+			.saturating_add(T::DbWeight::get().writes(966 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: Scheduler Lookup (r:2 w:2)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn schedule_maintenance() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:1)
+	fn set_engine_maintenance() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry PausedEngines (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_engine_paused() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineInitData (r:0 w:1)
+	fn set_engine_init_data() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry BannedAccounts (r:0 w:1)
+	// Storage: GameRegistry CurrentGames (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn ban_account(g: u32) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((162_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((44 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((42 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry BannedAccounts (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn unban_account() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Sanctions (r:0 w:1)
+	// Storage: GameRegistry Appeals (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn ban_player() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Sanctions (r:1 w:1)
+	// Storage: GameRegistry Appeals (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn unban_player() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Allowlist (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn add_to_allowlist(a: u32) -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: GameRegistry Allowlist (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn remove_from_allowlist(a: u32) -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: GameRegistry Reports (r:1 w:1)
+	// Storage: GameRegistry ReportQueue (r:1 w:1)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn resolve_report() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: GameRegistry Sanctions (r:0 w:1)
+	// Storage: GameRegistry Appeals (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn sanction_player() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Appeals (r:1 w:1)
+	fn appeal_sanction() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry Appeals (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn resolve_appeal() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry FraudProofs (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn rule_on_fraud_proof() -> Weight {
+		(67_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
+	}
+	// Storage: GameRegistry FraudProofs (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:2 w:0)
+	// Storage: GameRegistry GamesWon (r:2 w:2)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn overturn_result() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: GameRegistry EngineClientVersions (r:1 w:0)
+	// Storage: GameRegistry PlayerClients (r:1 w:0)
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Settings (r:1 w:0)
+	// Storage: GameRegistry LatestEngineVersion (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry LeftPlayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Matchmaker (r:2 w:1)
+	// Storage: GameRegistry QueuedPlayers (r:0 w:1)
+	// Storage: GameRegistry QueuedSince (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry PlayerRating (r:1 w:0)
+	// Storage: GameRegistry RatingActivities (r:1 w:1)
+	// Storage: GameRegistry EngineStakes (r:1 w:0)
+	// Storage: GameRegistry QueueStakes (r:0 w:1)
+	// Storage: GameRegistry PausedEngines (r:1 w:0)
+	fn queue() -> Weight {
+		(68_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(25 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry QueuedPlayers (r:1 w:1)
+	// Storage: GameRegistry LeftPlayers (r:0 w:1)
+	// Storage: GameRegistry QueuedSince (r:0 w:1)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry BondPayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry QueueStakes (r:1 w:1)
+	fn leave_queue() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Preferences (r:0 w:1)
+	fn set_preferences() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry PlayerClients (r:0 w:1)
+	fn set_client_versions() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Settings (r:1 w:0)
+	// Storage: GameRegistry LatestEngineVersion (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry LeftPlayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Matchmaker (r:2 w:1)
+	// Storage: GameRegistry QueuedPlayers (r:0 w:1)
+	// Storage: GameRegistry QueuedSince (r:0 w:1)
+	// Storage: GameRegistry BondPayers (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	fn queue_committed() -> Weight {
+		(66_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry MinTees (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry PlayerGames (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry MatchInvites (r:0 w:1)
+	// Storage: GameRegistry InviteInitData (r:0 w:1)
+	// Storage: GameRegistry InviteStarts (r:0 w:1)
+	fn create_match(o: u32) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((6_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry MatchInvites (r:1 w:1)
+	// Storage: GameRegistry InviteInitData (r:1 w:1)
+	// Storage: GameRegistry InviteStarts (r:1 w:1)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:2 w:0)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry SupportedEngines (r:2 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineInitData (r:1 w:0)
+	// Storage: GameRegistry GameInitData (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	fn accept_invite() -> Weight {
+		(76_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(25 as Weight))
+			.saturating_add(T::DbWeight::get().writes(21 as Weight))
+	}
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:0)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameInitData (r:1 w:0)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	fn start_scheduled_game() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry Parties (r:0 w:1)
+	fn queue_party(m: u32) -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add((500_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry Parties (r:1 w:1)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry PartyQueues (r:1 w:1)
+	fn accept_party_invite() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Parties (r:1 w:1)
+	// Storage: GameRegistry PartyQueues (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry BondPayers (r:1 w:1)
+	// Storage: GameRegistry QueueStakes (r:1 w:0)
+	fn disband_party(m: u32) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(m as Weight)))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry BondPayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	fn reveal_identity() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Settings (r:1 w:0)
+	// Storage: GameRegistry LatestEngineVersion (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry LeftPlayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Matchmaker (r:2 w:1)
+	// Storage: GameRegistry QueuedPlayers (r:0 w:1)
+	// Storage: GameRegistry QueuedSince (r:0 w:1)
+	// Storage: GameRegistry ForeignPlayers (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	fn queue_foreign() -> Weight {
+		(68_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry BondPayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:0)
+	fn accept_match() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry PrivateGames (r:1 w:0)
+	fn concede() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry NextReportId (r:1 w:1)
+	// Storage: GameRegistry ReportQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry Reports (r:0 w:1)
+	fn report_player() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ResultFlags (r:0 w:1)
+	// Storage: GameRegistry ReconfirmationDeadlines (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry ResultConfirmations (r:1 w:0)
+	fn flag_result() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ResultFlags (r:0 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	fn reconfirm_result() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry FraudProofs (r:0 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry ResultConfirmations (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn submit_fraud_proof() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry Settings (r:1 w:0)
+	// Storage: GameRegistry GameSpectatorSettings (r:1 w:0)
+	// Storage: GameRegistry SpectatorInvites (r:1 w:0)
+	// Storage: GameRegistry Spectators (r:1 w:1)
+	fn register_spectator() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry GameSpectatorSettings (r:0 w:1)
+	fn set_spectator_settings() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry SpectatorInvites (r:0 w:1)
+	fn invite_spectator() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:2 w:0)
+	// Storage: GameRegistry SessionKeyProposals (r:0 w:1)
+	fn set_session_key() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeyProposals (r:1 w:1)
+	// Storage: GameRegistry SessionKeys (r:2 w:1)
+	// Storage: GameRegistry SessionKeyCount (r:2 w:1)
+	// Storage: GameRegistry QueuedPlayers (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:0)
+	// Storage: GameRegistry Parties (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	fn accept_session_key() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:1)
+	// Storage: GameRegistry SessionKeyCount (r:1 w:1)
+	fn remove_session_key() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry DroppedGames (r:0 w:1)
+	// Storage: GameRegistry PruneSchedule (r:1 w:1)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:2 w:2)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:64 w:128)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	fn drop_game(q: u32) -> Weight {
+		(73_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(T::DbWeight::get().reads(26 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(T::DbWeight::get().writes(29 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(q as Weight)))
+	}
+	// Storage: GameRegistry TeeLivenessNoted (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	fn note_tee_liveness(t: u32) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((3_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: GameRegistry DevMode (r:1 w:0)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	fn tee_heartbeat() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:2 w:2)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	fn expire_game() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(25 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry DevMode (r:1 w:0)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry AttestedAt (r:1 w:0)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry GameWagers (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	fn ack_game(g: u32) -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((11 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry TeeResultNonce (r:1 w:1)
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry DevMode (r:1 w:0)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry AttestedAt (r:1 w:0)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry GameWagers (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	fn ack_game_unsigned(g: u32) -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((11 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry PrivateGames (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	fn ready_game() -> Weight {
+		(51_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry GameCheckpoints (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	fn checkpoint_game() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineInitData (r:1 w:0)
+	// Storage: GameRegistry GameInitData (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	fn queue_game(q: u32) -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry DeadlineCursor (r:1 w:0)
+	// Storage: GameRegistry ReconfirmationDeadlines (r:1 w:1)
+	// Storage: GameRegistry RematchDeadlines (r:1 w:1)
+	// Storage: GameRegistry TournamentRounds (r:1 w:1)
+	// Storage: GameRegistry TeeLivenessNoted (r:0 w:1)
+	// Storage: GameRegistry TeeCallCount (r:0 w:1)
+	// Storage: GameRegistry TeeThrottles (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry PendingSettlements (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:0)
+	fn on_initialize_idle() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: GameRegistry MigrationCursor (r:1 w:1)
+	// Storage: GameRegistry DeadlineCursor (r:1 w:1)
+	fn on_initialize_migration() -> Weight {
+		(9_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry ResultFlags (r:1 w:1)
+	// Storage: GameRegistry FraudProofs (r:0 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	fn escalate_result() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry Balances Reserves (r:0 w:1)
+	fn settle_result() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry ResultConfirmationEngines (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_result_confirmation() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry ArchivedGames (r:1 w:0)
+	// Storage: GameRegistry ResultConfirmationEngines (r:1 w:0)
+	// Storage: GameRegistry ResultConfirmations (r:1 w:1)
+	// Storage: GameRegistry PendingSettlements (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry Balances Reserves (r:0 w:1)
+	fn confirm_result() -> Weight {
+		(64_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameResumes (r:1 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry DroppedGames (r:0 w:1)
+	// Storage: GameRegistry PruneSchedule (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry TeeGames (r:0 w:1)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	fn resume_games(g: u32) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((76_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((21 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((28 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry SuspendedTees (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn unsuspend_tee() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry TeeGames (r:0 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	fn reassign_game() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: GameRegistry EngineClientVersions (r:1 w:0)
+	// Storage: GameRegistry PlayerClients (r:2 w:0)
+	// Storage: GameRegistry GameClientVersions (r:0 w:1)
+	// Storage: Matchmaker (r:4 w:3)
+	// Storage: GameRegistry QueuedPlayers (r:2 w:2)
+	// Storage: GameRegistry BannedAccounts (r:2 w:0)
+	// Storage: GameRegistry Sanctions (r:2 w:0)
+	// Storage: GameRegistry SupportedEngines (r:2 w:0)
+	// Storage: GameRegistry QueuedSince (r:2 w:2)
+	// Storage: GameRegistry EstimatedWaits (r:1 w:1)
+	// Storage: GameRegistry DegradedBrackets (r:1 w:0)
+	// Storage: GameRegistry TimeControls (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry QueueStakes (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:0 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	// Storage: GameRegistry FormingParties (r:1 w:1)
+	fn match_game() -> Weight {
+		(121_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(37 as Weight))
+			.saturating_add(T::DbWeight::get().writes(33 as Weight))
+	}
+	// Storage: Matchmaker (r:4 w:0)
+	fn no_match() -> Weight {
+		(9_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+	}
+	// Storage: GameRegistry PartyQueues (r:1 w:1)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry Parties (r:2 w:2)
+	// Storage: GameRegistry BannedAccounts (r:2 w:0)
+	// Storage: GameRegistry Sanctions (r:2 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	// Storage: GameRegistry GameTeams (r:0 w:1)
+	fn match_parties(p: u32) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((52_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((14 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((16 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameQueues (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:0)
+	fn heartbeat(e: u32) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((6_000_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(e as Weight)))
+	}
+	// Storage: GameRegistry CurrentSeason (r:1 w:1)
+	// Storage: GameRegistry SeasonLeaderboard (r:1 w:1)
+	// Storage: GameRegistry SeasonChampions (r:0 w:1)
+	fn end_season() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry ResultProofs (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn finish_game() -> Weight {
+		(171_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(48 as Weight))
+			.saturating_add(T::DbWeight::get().writes(46 as Weight))
+	}
+	// Storage: GameRegistry TeeResultNonce (r:1 w:1)
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry ResultProofs (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn finish_game_unsigned() -> Weight {
+		(178_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(49 as Weight))
+			.saturating_add(T::DbWeight::get().writes(47 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry ResultProofs (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	fn batch_finish_games(g: u32) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((158_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads((34 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((38 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:2 w:1)
+	// Storage: GameRegistry NoShowStrikes (r:1 w:1)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn report_no_show() -> Weight {
+		(179_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(50 as Weight))
+			.saturating_add(T::DbWeight::get().writes(47 as Weight))
+	}
+	// Storage: GameRegistry DroppedGames (r:0 w:1)
+	// Storage: GameRegistry PruneSchedule (r:1 w:1)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:64 w:128)
+	fn batch_drop_games(g: u32) -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add((74_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((20 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((27 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry DroppedGames (r:0 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:1 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameSpectatorSettings (r:0 w:1)
+	// Storage: GameRegistry SpectatorInvites (r:1 w:1)
+	// Storage: GameRegistry PruneSchedule (r:1 w:1)
+	fn prune_game() -> Weight {
+		(43_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry GameQueueItems (r:64 w:128)
+	fn clean_game_queue(q: u32) -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add((5_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(q as Weight)))
+	}
+	// Storage: GameRegistry Sanctions (r:1 w:1)
+	// Storage: GameRegistry Appeals (r:1 w:0)
+	fn remove_lapsed_sanction() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry MatchInvites (r:1 w:1)
+	// Storage: GameRegistry InviteInitData (r:0 w:1)
+	fn remove_expired_invite() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: GameRegistry Settings (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn set_settings() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Paused (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn pause() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Paused (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn unpause() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry DisabledCalls (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn set_call_disabled() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Parameters (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn set_parameter() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:1)
+	// Storage: GameRegistry EngineBrackets (r:1 w:1)
+	// Storage: GameRegistry LatestEngineVersion (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn register_game_engine() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn deprecate_game_engine() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:2 w:1)
+	// Storage: GameRegistry GameRequirments (r:2 w:0)
+	// Storage: GameRegistry GameQueues (r:2 w:2)
+	// Storage: GameRegistry PriorityGameQueues (r:2 w:2)
+	// Storage: GameRegistry TournamentGames (r:64 w:0)
+	// Storage: GameRegistry GameRegistry (r:64 w:64)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry GameQueueItems (r:64 w:128)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn upgrade_game_engine() -> Weight {
+		(440_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(140 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(200 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_game_rules() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_game_rule() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn clear_game_rules() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry ResultProofs (r:1 w:0)
+	fn verify_result(l: u32) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:2 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:1 w:0)
+	// Storage: GameRegistry RematchRequests (r:1 w:1)
+	// Storage: GameRegistry RematchDeadlines (r:1 w:1)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineStakes (r:1 w:0)
+	// Storage: GameRegistry EngineMaxGamesPerPlayer (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	fn request_rematch() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(27 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(21 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry NextTournamentId (r:1 w:1)
+	// Storage: GameRegistry Tournaments (r:0 w:1)
+	// Storage: GameRegistry TournamentRounds (r:1 w:1)
+	fn create_tournament() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Tournaments (r:1 w:1)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn join_tournament() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Tournaments (r:1 w:1)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:1 w:2)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueueItems (r:0 w:1)
+	// Storage: GameRegistry TournamentGames (r:1 w:1)
+	// Storage: GameRegistry TournamentMatches (r:0 w:1)
+	// Storage: GameRegistry TournamentRounds (r:1 w:1)
+	fn start_tournament_round(p: u32) -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add((38_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((11 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry AbortProposals (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn propose_abort() -> Weight {
+		(158_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(41 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(40 as Weight))
+	}
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry TeeLoads (r:2 w:2)
+	// Storage: GameRegistry DevMode (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameWagers (r:1 w:0)
+	// Storage: GameRegistry TeeGames (r:0 w:2)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	fn handover_games(g: u32) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((34_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineSpectatorSettings (r:0 w:1)
+	fn set_engine_spectator_settings() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineMaxGamesPerPlayer (r:0 w:1)
+	fn set_max_games_per_player() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineClientVersions (r:0 w:1)
+	fn set_engine_client_versions() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineStakes (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_engine_stake() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineBrackets (r:1 w:1)
+	// Storage: GameRegistry TimeControls (r:1 w:1)
+	fn add_time_control() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	// Storage: GameRegistry AttestedAt (r:0 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:0)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn register_tee() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:0)
+	fn update_tee_engines() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	fn set_tee_endpoint() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry AttestedAt (r:0 w:1)
+	fn reattest_tee() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry TeeBonds (r:1 w:1)
+	fn bond_tee() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry TeeBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unbond_tee() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	// Storage: GameRegistry AttestedAt (r:0 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	// Storage: GameRegistry TeeBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:0 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:1)
+	// Storage: GameRegistry Clusters (r:1 w:1)
+	fn deregister_tee() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: GameRegistry NextClusterId (r:1 w:1)
+	// Storage: GameRegistry Clusters (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn register_cluster() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Clusters (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	fn add_cluster_tee() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Clusters (r:1 w:1)
+	// Storage: GameRegistry TeeClusterIds (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	fn remove_cluster_tee() -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Clusters (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:1)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_cluster_engines(t: u32) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((17_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry TournamentGames (r:0 w:64)
+	// Storage: GameRegistry GameRegistry (r:64 w:64)
+	// Storage: GameRegistry PrivateGames (r:0 w:64)
+	// Storage: GameRegistry GameWagers (r:0 w:64)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:64)
+	// Storage: GameRegistry MatchCodes (r:64 w:64)
+	// Storage: GameRegistry GameSeeds (r:0 w:64)
+	// Storage: GameRegistry ActiveGames (r:0 w:0)
+	// Storage: GameRegistry QueueBonds (r:128 w:128)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:128 w:128)
+	// Storage: GameRegistry CurrentGames (r:128 w:128)
+	// Storage: GameRegistry GameStakes (r:64 w:64)
+	// Storage: GameRegistry GameQueueItems (r:64 w:65)
+	fn drain_game_queue() -> Weight {
+		(1_963_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(645 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(966 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: Scheduler Lookup (r:2 w:2)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn schedule_maintenance() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:1)
+	fn set_engine_maintenance() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry PausedEngines (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_engine_paused() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineInitData (r:0 w:1)
+	fn set_engine_init_data() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry BannedAccounts (r:0 w:1)
+	// Storage: GameRegistry CurrentGames (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn ban_account(g: u32) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((162_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((44 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((42 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry BannedAccounts (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn unban_account() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry Sanctions (r:0 w:1)
+	// Storage: GameRegistry Appeals (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn ban_player() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Sanctions (r:1 w:1)
+	// Storage: GameRegistry Appeals (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn unban_player() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry Allowlist (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn add_to_allowlist(a: u32) -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: GameRegistry Allowlist (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn remove_from_allowlist(a: u32) -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: GameRegistry Reports (r:1 w:1)
+	// Storage: GameRegistry ReportQueue (r:1 w:1)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn resolve_report() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: GameRegistry Sanctions (r:0 w:1)
+	// Storage: GameRegistry Appeals (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn sanction_player() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Appeals (r:1 w:1)
+	fn appeal_sanction() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry Appeals (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn resolve_appeal() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry FraudProofs (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn rule_on_fraud_proof() -> Weight {
+		(67_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(16 as Weight))
+	}
+	// Storage: GameRegistry FraudProofs (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:2 w:0)
+	// Storage: GameRegistry GamesWon (r:2 w:2)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn overturn_result() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: GameRegistry EngineClientVersions (r:1 w:0)
+	// Storage: GameRegistry PlayerClients (r:1 w:0)
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Settings (r:1 w:0)
+	// Storage: GameRegistry LatestEngineVersion (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry LeftPlayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Matchmaker (r:2 w:1)
+	// Storage: GameRegistry QueuedPlayers (r:0 w:1)
+	// Storage: GameRegistry QueuedSince (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry PlayerRating (r:1 w:0)
+	// Storage: GameRegistry RatingActivities (r:1 w:1)
+	// Storage: GameRegistry EngineStakes (r:1 w:0)
+	// Storage: GameRegistry QueueStakes (r:0 w:1)
+	// Storage: GameRegistry PausedEngines (r:1 w:0)
+	fn queue() -> Weight {
+		(68_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(25 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry QueuedPlayers (r:1 w:1)
+	// Storage: GameRegistry LeftPlayers (r:0 w:1)
+	// Storage: GameRegistry QueuedSince (r:0 w:1)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry BondPayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry QueueStakes (r:1 w:1)
+	fn leave_queue() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Preferences (r:0 w:1)
+	fn set_preferences() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry PlayerClients (r:0 w:1)
+	fn set_client_versions() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Settings (r:1 w:0)
+	// Storage: GameRegistry LatestEngineVersion (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry LeftPlayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Matchmaker (r:2 w:1)
+	// Storage: GameRegistry QueuedPlayers (r:0 w:1)
+	// Storage: GameRegistry QueuedSince (r:0 w:1)
+	// Storage: GameRegistry BondPayers (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	fn queue_committed() -> Weight {
+		(66_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry MinTees (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry PlayerGames (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry MatchInvites (r:0 w:1)
+	// Storage: GameRegistry InviteInitData (r:0 w:1)
+	// Storage: GameRegistry InviteStarts (r:0 w:1)
+	fn create_match(o: u32) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((6_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry MatchInvites (r:1 w:1)
+	// Storage: GameRegistry InviteInitData (r:1 w:1)
+	// Storage: GameRegistry InviteStarts (r:1 w:1)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:2 w:0)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry SupportedEngines (r:2 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineInitData (r:1 w:0)
+	// Storage: GameRegistry GameInitData (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	fn accept_invite() -> Weight {
+		(76_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(25 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(21 as Weight))
+	}
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:0)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameInitData (r:1 w:0)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	fn start_scheduled_game() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry Parties (r:0 w:1)
+	fn queue_party(m: u32) -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add((500_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry Parties (r:1 w:1)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry PartyQueues (r:1 w:1)
+	fn accept_party_invite() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry Parties (r:1 w:1)
+	// Storage: GameRegistry PartyQueues (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry BondPayers (r:1 w:1)
+	// Storage: GameRegistry QueueStakes (r:1 w:0)
+	fn disband_party(m: u32) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((21_000_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(m as Weight)))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry BondPayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	fn reveal_identity() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry Settings (r:1 w:0)
+	// Storage: GameRegistry LatestEngineVersion (r:1 w:0)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry EngineTeeCount (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry LeftPlayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Matchmaker (r:2 w:1)
+	// Storage: GameRegistry QueuedPlayers (r:0 w:1)
+	// Storage: GameRegistry QueuedSince (r:0 w:1)
+	// Storage: GameRegistry ForeignPlayers (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	// Storage: GameRegistry DeprecatedEngines (r:1 w:0)
+	fn queue_foreign() -> Weight {
+		(68_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:1)
+	// Storage: GameRegistry BondPayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:0)
+	fn accept_match() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry PrivateGames (r:1 w:0)
+	fn concede() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry NextReportId (r:1 w:1)
+	// Storage: GameRegistry ReportQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry Reports (r:0 w:1)
+	fn report_player() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ResultFlags (r:0 w:1)
+	// Storage: GameRegistry ReconfirmationDeadlines (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry ResultConfirmations (r:1 w:0)
+	fn flag_result() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ResultFlags (r:0 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	fn reconfirm_result() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry FraudProofs (r:0 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry ResultConfirmations (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn submit_fraud_proof() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry BannedAccounts (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry Settings (r:1 w:0)
+	// Storage: GameRegistry GameSpectatorSettings (r:1 w:0)
+	// Storage: GameRegistry SpectatorInvites (r:1 w:0)
+	// Storage: GameRegistry Spectators (r:1 w:1)
+	fn register_spectator() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry GameSpectatorSettings (r:0 w:1)
+	fn set_spectator_settings() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry SpectatorInvites (r:0 w:1)
+	fn invite_spectator() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:2 w:0)
+	// Storage: GameRegistry SessionKeyProposals (r:0 w:1)
+	fn set_session_key() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry SessionKeyProposals (r:1 w:1)
+	// Storage: GameRegistry SessionKeys (r:2 w:1)
+	// Storage: GameRegistry SessionKeyCount (r:2 w:1)
+	// Storage: GameRegistry QueuedPlayers (r:1 w:0)
+	// Storage: GameRegistry QueueBonds (r:1 w:0)
+	// Storage: GameRegistry Parties (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:1 w:0)
+	fn accept_session_key() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:1)
+	// Storage: GameRegistry SessionKeyCount (r:1 w:1)
+	fn remove_session_key() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry DroppedGames (r:0 w:1)
+	// Storage: GameRegistry PruneSchedule (r:1 w:1)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:2 w:2)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:64 w:128)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	fn drop_game(q: u32) -> Weight {
+		(73_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(RocksDbWeight::get().reads(26 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(29 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(q as Weight)))
+	}
+	// Storage: GameRegistry TeeLivenessNoted (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	fn note_tee_liveness(t: u32) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((3_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: GameRegistry DevMode (r:1 w:0)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	fn tee_heartbeat() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:2 w:2)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	fn expire_game() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(25 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry DevMode (r:1 w:0)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry AttestedAt (r:1 w:0)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry GameWagers (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	fn ack_game(g: u32) -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((11 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry TeeResultNonce (r:1 w:1)
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry DevMode (r:1 w:0)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry AttestedAt (r:1 w:0)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry GameWagers (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry TeeLastSeen (r:0 w:1)
+	fn ack_game_unsigned(g: u32) -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((11 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry PrivateGames (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	fn ready_game() -> Weight {
+		(51_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry GameCheckpoints (r:1 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	fn checkpoint_game() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineInitData (r:1 w:0)
+	// Storage: GameRegistry GameInitData (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	fn queue_game(q: u32) -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry DeadlineCursor (r:1 w:0)
+	// Storage: GameRegistry ReconfirmationDeadlines (r:1 w:1)
+	// Storage: GameRegistry RematchDeadlines (r:1 w:1)
+	// Storage: GameRegistry TournamentRounds (r:1 w:1)
+	// Storage: GameRegistry TeeLivenessNoted (r:0 w:1)
+	// Storage: GameRegistry TeeCallCount (r:0 w:1)
+	// Storage: GameRegistry TeeThrottles (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry PendingSettlements (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:0)
+	fn on_initialize_idle() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: GameRegistry MigrationCursor (r:1 w:1)
+	// Storage: GameRegistry DeadlineCursor (r:1 w:1)
+	fn on_initialize_migration() -> Weight {
+		(9_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry ResultFlags (r:1 w:1)
+	// Storage: GameRegistry FraudProofs (r:0 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	fn escalate_result() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry Balances Reserves (r:0 w:1)
+	fn settle_result() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry ResultConfirmationEngines (r:0 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	fn set_result_confirmation() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry SessionKeys (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry ArchivedGames (r:1 w:0)
+	// Storage: GameRegistry ResultConfirmationEngines (r:1 w:0)
+	// Storage: GameRegistry ResultConfirmations (r:1 w:1)
+	// Storage: GameRegistry PendingSettlements (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: GameRegistry Balances Reserves (r:0 w:1)
+	fn confirm_result() -> Weight {
+		(64_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameResumes (r:1 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry DroppedGames (r:0 w:1)
+	// Storage: GameRegistry PruneSchedule (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry TeeGames (r:0 w:1)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	fn resume_games(g: u32) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((76_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((21 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((28 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry SuspendedTees (r:1 w:1)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	fn unsuspend_tee() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry TeeGames (r:0 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	fn reassign_game() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: GameRegistry EngineClientVersions (r:1 w:0)
+	// Storage: GameRegistry PlayerClients (r:2 w:0)
+	// Storage: GameRegistry GameClientVersions (r:0 w:1)
+	// Storage: Matchmaker (r:4 w:3)
+	// Storage: GameRegistry QueuedPlayers (r:2 w:2)
+	// Storage: GameRegistry BannedAccounts (r:2 w:0)
+	// Storage: GameRegistry Sanctions (r:2 w:0)
+	// Storage: GameRegistry SupportedEngines (r:2 w:0)
+	// Storage: GameRegistry QueuedSince (r:2 w:2)
+	// Storage: GameRegistry EstimatedWaits (r:1 w:1)
+	// Storage: GameRegistry DegradedBrackets (r:1 w:0)
+	// Storage: GameRegistry TimeControls (r:1 w:0)
+	// Storage: GameRegistry GameRequirments (r:1 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry QueueStakes (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:0 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	// Storage: GameRegistry FormingParties (r:1 w:1)
+	fn match_game() -> Weight {
+		(121_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(37 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(33 as Weight))
+	}
+	// Storage: Matchmaker (r:4 w:0)
+	fn no_match() -> Weight {
+		(9_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+	}
+	// Storage: GameRegistry PartyQueues (r:1 w:1)
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry Parties (r:2 w:2)
+	// Storage: GameRegistry BannedAccounts (r:2 w:0)
+	// Storage: GameRegistry Sanctions (r:2 w:0)
+	// Storage: GameRegistry PlayerFirstSeen (r:2 w:2)
+	// Storage: GameRegistry Nonce (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:0 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry NextGameIndex (r:1 w:1)
+	// Storage: GameRegistry MatchCodes (r:0 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameQueueItems (r:0 w:1)
+	// Storage: GameRegistry GameTeams (r:0 w:1)
+	fn match_parties(p: u32) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((52_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((14 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((16 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: GameRegistry SupportedEngines (r:1 w:0)
+	// Storage: GameRegistry GameQueues (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:0)
+	fn heartbeat(e: u32) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((6_000_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(e as Weight)))
+	}
+	// Storage: GameRegistry CurrentSeason (r:1 w:1)
+	// Storage: GameRegistry SeasonLeaderboard (r:1 w:1)
+	// Storage: GameRegistry SeasonChampions (r:0 w:1)
+	fn end_season() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry ResultProofs (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn finish_game() -> Weight {
+		(171_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(48 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(46 as Weight))
+	}
+	// Storage: GameRegistry TeeResultNonce (r:1 w:1)
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry ResultProofs (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn finish_game_unsigned() -> Weight {
+		(178_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(49 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(47 as Weight))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:1 w:0)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry ResultProofs (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	fn batch_finish_games(g: u32) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((158_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((34 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((38 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry TeeCallCount (r:1 w:1)
+	// Storage: GameRegistry SuspendedTees (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Paused (r:1 w:0)
+	// Storage: GameRegistry DisabledCalls (r:1 w:0)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:0 w:1)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry ActiveGames (r:1 w:1)
+	// Storage: GameRegistry BlockResultLeaves (r:1 w:1)
+	// Storage: GameRegistry CanonicalResultHashes (r:0 w:1)
+	// Storage: GameRegistry BlockFinishedGames (r:1 w:1)
+	// Storage: GameRegistry Sanctions (r:2 w:1)
+	// Storage: GameRegistry NoShowStrikes (r:1 w:1)
+	// Storage: GameRegistry GamesWon (r:1 w:1)
+	// Storage: GameRegistry Leaderboard (r:1 w:1)
+	// Storage: GameRegistry FinishedGames (r:2 w:2)
+	// Storage: GameRegistry QueueBonds (r:2 w:0)
+	// Storage: GameRegistry PairingCounts (r:1 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:0 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:1 w:1)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry Preferences (r:2 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry PlayerRating (r:2 w:2)
+	// Storage: GameRegistry RatingActivities (r:2 w:2)
+	// Storage: GameRegistry WinStreaks (r:2 w:2)
+	// Storage: GameRegistry LifetimePoints (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry MatchHistory (r:2 w:2)
+	// Storage: GameRegistry TeeLoads (r:1 w:1)
+	// Storage: GameRegistry SeasonTallies (r:2 w:2)
+	fn report_no_show() -> Weight {
+		(179_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(50 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(47 as Weight))
+	}
+	// Storage: GameRegistry DroppedGames (r:0 w:1)
+	// Storage: GameRegistry PruneSchedule (r:1 w:1)
+	// Storage: GameRegistry MigrationCursor (r:1 w:0)
+	// Storage: GameRegistry Parameters (r:1 w:0)
+	// Storage: GameRegistry AuditLogCount (r:1 w:1)
+	// Storage: GameRegistry AuditLog (r:0 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry GameCheckpoints (r:1 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry PrivateGames (r:0 w:1)
+	// Storage: GameRegistry GameWagers (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry EngineCounters (r:1 w:1)
+	// Storage: GameRegistry TotalCounters (r:1 w:1)
+	// Storage: GameRegistry QueueBonds (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: GameRegistry PlayerGames (r:2 w:2)
+	// Storage: GameRegistry RegisteredTees (r:1 w:0)
+	// Storage: GameRegistry CurrentGames (r:2 w:2)
+	// Storage: GameRegistry GameStakes (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	// Storage: GameRegistry GameQueueItems (r:64 w:128)
+	fn batch_drop_games(g: u32) -> Weight {
+		(29_000_000 as Weight)
+			.saturating_add((74_000_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((20 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((27 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: GameRegistry DroppedGames (r:0 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:1)
+	// Storage: GameRegistry GameTimelines (r:1 w:1)
+	// Storage: GameRegistry ArchivedGames (r:1 w:1)
+	// Storage: GameRegistry FinalStates (r:0 w:1)
+	// Storage: GameRegistry GameReplays (r:0 w:1)
+	// Storage: GameRegistry MatchCodes (r:1 w:1)
+	// Storage: GameRegistry GameSeeds (r:0 w:1)
+	// Storage: GameRegistry MatchCodeGames (r:0 w:1)
+	// Storage: GameRegistry Spectators (r:0 w:1)
+	// Storage: GameRegistry GameSpectatorSettings (r:0 w:1)
+	// Storage: GameRegistry SpectatorInvites (r:1 w:1)
+	// Storage: GameRegistry PruneSchedule (r:1 w:1)
+	fn prune_game() -> Weight {
+		(43_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	// Storage: GameRegistry GameQueues (r:1 w:1)
+	// Storage: GameRegistry PriorityGameQueues (r:1 w:1)
+	// Storage: GameRegistry GameRegistry (r:1 w:0)
+	// Storage: GameRegistry GameQueueItems (r:64 w:128)
+	fn clean_game_queue(q: u32) -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add((5_000_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(q as Weight)))
+	}
+	// Storage: GameRegistry Sanctions (r:1 w:1)
+	// Storage: GameRegistry Appeals (r:1 w:0)
+	fn remove_lapsed_sanction() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: GameRegistry MatchInvites (r:1 w:1)
+	// Storage: GameRegistry InviteInitData (r:0 w:1)
+	fn remove_expired_invite() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}